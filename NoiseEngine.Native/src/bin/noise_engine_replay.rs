@@ -0,0 +1,46 @@
+use std::{env, path::PathBuf, process::ExitCode};
+
+use noise_engine_native::rendering::vulkan::capture::replay;
+
+const USAGE: &str = "Usage: noise_engine_replay <capture> <output directory> [--device <name>]";
+
+fn main() -> ExitCode {
+    let mut paths = Vec::new();
+    let mut device_name = None;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--device" => match args.next() {
+                Some(name) => device_name = Some(name),
+                None => {
+                    eprintln!("{}", USAGE);
+                    return ExitCode::FAILURE;
+                }
+            },
+            "--help" | "-h" => {
+                println!("{}", USAGE);
+                return ExitCode::SUCCESS;
+            }
+            _ => paths.push(PathBuf::from(arg)),
+        }
+    }
+
+    if paths.len() != 2 {
+        eprintln!("{}", USAGE);
+        return ExitCode::FAILURE;
+    }
+
+    match replay::run(&paths[0], &paths[1], device_name.as_deref()) {
+        Ok(written) => {
+            for path in written {
+                println!("{}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("Replay failed: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -1,2 +1,3 @@
 pub mod pool;
 pub mod send_ptr;
+pub mod worker_pool;
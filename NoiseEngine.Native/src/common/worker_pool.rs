@@ -0,0 +1,148 @@
+use std::{
+    collections::VecDeque,
+    mem,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Condvar, Mutex, OnceLock,
+    },
+    thread,
+};
+
+type Job = Box<dyn FnOnce() + Send>;
+type ScopedJob<'env> = Box<dyn FnOnce() + Send + 'env>;
+
+/// Worker threads shared by the whole library, which are created on first use.
+pub struct WorkerPool {
+    jobs: Mutex<VecDeque<Job>>,
+    jobs_available: Condvar,
+}
+
+static WORKER_POOL: OnceLock<WorkerPool> = OnceLock::new();
+
+impl WorkerPool {
+    pub fn get() -> &'static Self {
+        let mut is_created = false;
+        let pool = WORKER_POOL.get_or_init(|| {
+            is_created = true;
+            Self {
+                jobs: Mutex::new(VecDeque::new()),
+                jobs_available: Condvar::new(),
+            }
+        });
+
+        if is_created {
+            let count = thread::available_parallelism().map_or(1, |parallelism| parallelism.get());
+            for _ in 0..count {
+                thread::spawn(|| pool.run());
+            }
+        }
+
+        pool
+    }
+
+    /// Queues `jobs` to be run on worker threads.
+    pub fn spawn(&self, jobs: impl IntoIterator<Item = Job>) {
+        self.jobs.lock().unwrap().extend(jobs);
+        self.jobs_available.notify_all();
+    }
+
+    /// Runs `jobs` on worker threads and on the calling thread, and returns their results in the
+    /// same order after all of them are finished. Panic of a job is returned as its error.
+    pub fn run_scoped<T: Send, F: FnOnce() -> T + Send>(
+        &self,
+        jobs: Vec<F>,
+    ) -> Vec<thread::Result<T>> {
+        let results: Vec<Mutex<Option<thread::Result<T>>>> =
+            jobs.iter().map(|_| Mutex::new(None)).collect();
+
+        let batch = Arc::new(ScopedBatch {
+            remaining: Mutex::new(jobs.len()),
+            jobs: jobs
+                .into_iter()
+                .zip(&results)
+                .map(|(job, result)| {
+                    let job: ScopedJob<'_> = Box::new(move || {
+                        *result.lock().unwrap() = Some(panic::catch_unwind(AssertUnwindSafe(job)))
+                    });
+                    Mutex::new(Some(job))
+                })
+                .collect(),
+            next: AtomicUsize::new(0),
+            finished: Condvar::new(),
+        });
+
+        // SAFETY: Jobs borrowing the environment are taken out of the batch and finished before
+        // this function returns, so after that workers can see only empty slots of the batch.
+        let erased = unsafe {
+            mem::transmute::<Arc<ScopedBatch<'_>>, Arc<ScopedBatch<'static>>>(batch.clone())
+        };
+        self.spawn((1..batch.jobs.len()).map(|_| {
+            let batch = erased.clone();
+            Box::new(move || while batch.run_next() {}) as Job
+        }));
+        drop(erased);
+
+        // Calling thread also runs jobs, so they progress even when all workers are busy.
+        while batch.run_next() {}
+        batch.wait();
+        drop(batch);
+
+        results
+            .into_iter()
+            .map(|result| result.into_inner().unwrap().unwrap())
+            .collect()
+    }
+
+    fn run(&self) {
+        loop {
+            let job = {
+                let mut jobs = self.jobs.lock().unwrap();
+                loop {
+                    match jobs.pop_front() {
+                        Some(job) => break job,
+                        None => jobs = self.jobs_available.wait(jobs).unwrap(),
+                    }
+                }
+            };
+
+            // Panicking job must not stop the worker.
+            _ = panic::catch_unwind(AssertUnwindSafe(job));
+        }
+    }
+}
+
+struct ScopedBatch<'env> {
+    jobs: Vec<Mutex<Option<ScopedJob<'env>>>>,
+    next: AtomicUsize,
+    remaining: Mutex<usize>,
+    finished: Condvar,
+}
+
+impl ScopedBatch<'_> {
+    /// Runs next job of this batch. Returns `false` when all jobs were already taken.
+    fn run_next(&self) -> bool {
+        let index = self.next.fetch_add(1, Ordering::Relaxed);
+        let job = match self.jobs.get(index) {
+            Some(job) => job.lock().unwrap().take().unwrap(),
+            None => return false,
+        };
+
+        job();
+
+        let mut remaining = self.remaining.lock().unwrap();
+        *remaining -= 1;
+        if *remaining == 0 {
+            self.finished.notify_all();
+        }
+
+        true
+    }
+
+    fn wait(&self) {
+        let mut remaining = self.remaining.lock().unwrap();
+        while *remaining > 0 {
+            remaining = self.finished.wait(remaining).unwrap();
+        }
+    }
+}
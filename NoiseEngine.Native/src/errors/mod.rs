@@ -5,3 +5,4 @@ pub mod invalid_operation;
 pub mod null_reference;
 pub mod overflow;
 pub mod platform_not_supported;
+pub mod serialization;
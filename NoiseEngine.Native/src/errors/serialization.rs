@@ -0,0 +1,45 @@
+use std::{error::Error, fmt::Display};
+
+use crate::interop::prelude::{ResultError, ResultErrorKind};
+
+#[derive(Debug)]
+pub struct SerializationError {
+    message: String,
+    offset: usize,
+}
+
+impl SerializationError {
+    pub fn new(message: String, offset: usize) -> Self {
+        Self { message, offset }
+    }
+
+    pub fn with_str(message: &str, offset: usize) -> Self {
+        Self::new(message.to_owned(), offset)
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl Error for SerializationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+impl Display for SerializationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} At byte offset {}.", self.message, self.offset)
+    }
+}
+
+impl From<SerializationError> for ResultError {
+    fn from(err: SerializationError) -> Self {
+        ResultError::with_kind(&err, ResultErrorKind::Argument)
+    }
+}
@@ -21,6 +21,14 @@ impl<'a, T> InteropReadOnlySpan<'a, T> {
     }
 }
 
+impl<'a, T> Clone for InteropReadOnlySpan<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for InteropReadOnlySpan<'a, T> {}
+
 impl<'a, T> From<InteropReadOnlySpan<'a, T>> for &'a [T] {
     fn from(span: InteropReadOnlySpan<'a, T>) -> Self {
         if cfg!(debug_assertions) {
@@ -33,6 +41,11 @@ impl<'a, T> From<InteropReadOnlySpan<'a, T>> for &'a [T] {
             }
         }
 
+        // Empty span may be passed with null reference.
+        if span.length == 0 {
+            return &[];
+        }
+
         unsafe { slice::from_raw_parts(span.reference, span.length as usize) }
     }
 }
@@ -3,6 +3,8 @@ use std::{
     mem::{ManuallyDrop, MaybeUninit},
 };
 
+use crate::rendering::vulkan::debug_message;
+
 use super::result_error::ResultError;
 
 #[repr(C)]
@@ -14,6 +16,12 @@ pub struct InteropResult<T> {
 
 impl<T> InteropResult<T> {
     pub fn with_ok(value: T) -> InteropResult<T> {
+        // In error capture mode, validation errors reported since the previous call fail this one.
+        if let Some(err) = debug_message::take_captured_error() {
+            drop(value);
+            return InteropResult::with_err(err.into());
+        }
+
         InteropResult {
             is_ok: true,
             ok: MaybeUninit::new(ManuallyDrop::new(value)),
@@ -1,12 +1,16 @@
-use crate::logging::{log_data, logger};
+use crate::logging::{log_data, logger, validation_log_data};
 
 use crate::interop::prelude::InteropResult;
 
 #[no_mangle]
 extern "C" fn logging_logging_initialize(
     handler: unsafe extern "C" fn(log_data::LogData),
+    validation_handler: unsafe extern "C" fn(validation_log_data::ValidationLogData),
 ) -> InteropResult<()> {
-    let logger = logger::Logger { handler };
+    let logger = logger::Logger {
+        handler,
+        validation_handler,
+    };
     logger::initialize(logger).into()
 }
 
@@ -1,14 +1,16 @@
+use std::sync::Arc;
+
 use crate::{
     interop::prelude::{InteropReadOnlySpan, InteropResult, InteropSpan},
     rendering::buffers::buffer::GraphicsBuffer,
 };
 
 #[no_mangle]
-extern "C" fn rendering_buffers_buffer_interop_destroy(_handle: Box<Box<dyn GraphicsBuffer>>) {}
+extern "C" fn rendering_buffers_buffer_interop_destroy(_handle: Box<Arc<dyn GraphicsBuffer>>) {}
 
 #[no_mangle]
 extern "C" fn rendering_buffers_buffer_interop_host_read(
-    buffer: &&dyn GraphicsBuffer,
+    buffer: &Arc<dyn GraphicsBuffer>,
     destination_buffer: InteropSpan<u8>,
     start: u64,
 ) -> InteropResult<()> {
@@ -17,7 +19,7 @@ extern "C" fn rendering_buffers_buffer_interop_host_read(
 
 #[no_mangle]
 extern "C" fn rendering_buffers_buffer_interop_host_write(
-    buffer: &&dyn GraphicsBuffer,
+    buffer: &Arc<dyn GraphicsBuffer>,
     data: InteropReadOnlySpan<u8>,
     start: u64,
 ) -> InteropResult<()> {
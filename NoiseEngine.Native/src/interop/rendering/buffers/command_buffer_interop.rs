@@ -1,8 +1,13 @@
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
 
 use crate::{
-    interop::prelude::InteropResult,
-    rendering::{buffers::command_buffer::GraphicsCommandBuffer, fence::GraphicsFence},
+    interop::prelude::{InteropOption, InteropReadOnlySpan, InteropResult, InteropString},
+    rendering::{
+        buffers::{command_buffer::GraphicsCommandBuffer, command_buffers::validation},
+        fence::GraphicsFence,
+        timeline_semaphore::GraphicsTimelineSemaphoreSubmit,
+        vulkan::{buffers::command_buffers::disassembler, capture},
+    },
 };
 
 #[no_mangle]
@@ -19,5 +24,71 @@ extern "C" fn rendering_buffers_command_buffer_interop_execute<
 >(
     command_buffer: &&'cbuf dyn GraphicsCommandBuffer<'init>,
 ) -> InteropResult<Box<Arc<dyn GraphicsFence + 'fence>>> {
-    command_buffer.execute()
+    command_buffer.execute(&[], &[])
+}
+
+#[no_mangle]
+extern "C" fn rendering_buffers_command_buffer_interop_execute_with_timeline_semaphores<
+    'init: 'cbuf,
+    'cbuf: 'fence,
+    'fence,
+>(
+    command_buffer: &&'cbuf dyn GraphicsCommandBuffer<'init>,
+    waits: InteropReadOnlySpan<GraphicsTimelineSemaphoreSubmit<'_, 'init>>,
+    signals: InteropReadOnlySpan<GraphicsTimelineSemaphoreSubmit<'_, 'init>>,
+) -> InteropResult<Box<Arc<dyn GraphicsFence + 'fence>>> {
+    command_buffer.execute(waits.into(), signals.into())
+}
+
+#[no_mangle]
+extern "C" fn rendering_buffers_command_buffer_interop_record(
+    command_buffer: &mut Box<dyn GraphicsCommandBuffer>,
+    data: InteropReadOnlySpan<u8>,
+    simultaneous_execute: bool,
+) -> InteropResult<()> {
+    command_buffer.record(data.into(), simultaneous_execute)
+}
+
+#[no_mangle]
+extern "C" fn rendering_buffers_command_buffer_interop_set_name(
+    command_buffer: &&dyn GraphicsCommandBuffer,
+    name: InteropString,
+) -> InteropResult<()> {
+    command_buffer.set_name(&String::from(name))
+}
+
+#[no_mangle]
+extern "C" fn rendering_buffers_command_buffer_interop_get_checked_decoding() -> bool {
+    validation::checked_decoding()
+}
+
+#[no_mangle]
+extern "C" fn rendering_buffers_command_buffer_interop_set_checked_decoding(checked: bool) {
+    validation::set_checked_decoding(checked)
+}
+
+#[no_mangle]
+extern "C" fn rendering_buffers_command_buffer_interop_get_capture_directory(
+) -> InteropOption<InteropString> {
+    capture::directory()
+        .map(|directory| directory.to_string_lossy().into_owned().into())
+        .into()
+}
+
+#[no_mangle]
+extern "C" fn rendering_buffers_command_buffer_interop_set_capture_directory(
+    directory: InteropOption<InteropString>,
+) {
+    let directory: Option<InteropString> = directory.into();
+    capture::set_directory(directory.map(|directory| PathBuf::from(String::from(directory))))
+}
+
+#[no_mangle]
+extern "C" fn rendering_buffers_command_buffer_interop_disassemble(
+    data: InteropReadOnlySpan<u8>,
+) -> InteropResult<InteropString> {
+    match disassembler::disassemble(data.into()) {
+        Ok(listing) => InteropResult::with_ok(listing.into()),
+        Err(err) => InteropResult::with_err(err.into()),
+    }
 }
@@ -6,3 +6,4 @@ pub mod cpu_texture_interop;
 pub mod fence_interop;
 pub mod texture_interop;
 pub mod texture_sampler_interop;
+pub mod timeline_semaphore_interop;
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use crate::{
+    interop::{interop_read_only_span::InteropReadOnlySpan, prelude::InteropResult},
+    rendering::timeline_semaphore::GraphicsTimelineSemaphore,
+};
+
+#[no_mangle]
+extern "C" fn rendering_timeline_semaphore_interop_destroy(
+    _handle: Box<Arc<dyn GraphicsTimelineSemaphore>>,
+) {
+}
+
+#[no_mangle]
+extern "C" fn rendering_timeline_semaphore_interop_value(
+    semaphore: &Arc<dyn GraphicsTimelineSemaphore>,
+) -> InteropResult<u64> {
+    semaphore.value()
+}
+
+#[no_mangle]
+extern "C" fn rendering_timeline_semaphore_interop_wait(
+    semaphore: &Arc<dyn GraphicsTimelineSemaphore>,
+    value: u64,
+    timeout: u64,
+) -> InteropResult<bool> {
+    semaphore.wait(value, timeout)
+}
+
+#[no_mangle]
+extern "C" fn rendering_timeline_semaphore_interop_signal(
+    semaphore: &Arc<dyn GraphicsTimelineSemaphore>,
+    value: u64,
+) -> InteropResult<()> {
+    semaphore.signal(value)
+}
+
+#[no_mangle]
+extern "C" fn rendering_timeline_semaphore_interop_wait_multiple(
+    semaphores: InteropReadOnlySpan<&Arc<dyn GraphicsTimelineSemaphore>>,
+    values: InteropReadOnlySpan<u64>,
+    wait_all: bool,
+    timeout: u64,
+) -> InteropResult<bool> {
+    let s: &[&Arc<dyn GraphicsTimelineSemaphore>] = semaphores.into();
+
+    match unsafe { s[0].wait_multiple(s, values.into(), wait_all, timeout) } {
+        Ok(is_reached) => InteropResult::with_ok(is_reached),
+        Err(err) => InteropResult::with_err(err),
+    }
+}
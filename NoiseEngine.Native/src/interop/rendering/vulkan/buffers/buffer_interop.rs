@@ -12,7 +12,7 @@ use crate::{
 
 #[repr(C)]
 struct VulkanBufferCreateReturnValue<'buf> {
-    pub handle: Box<Box<dyn GraphicsBuffer + 'buf>>,
+    pub handle: Box<Arc<dyn GraphicsBuffer + 'buf>>,
     pub inner_handle: vk::Buffer,
 }
 
@@ -27,7 +27,7 @@ extern "C" fn rendering_vulkan_buffers_buffer_interop_create<'dev: 'init, 'init:
         Ok(buffer) => {
             let inner = buffer.inner();
             InteropResult::with_ok(VulkanBufferCreateReturnValue {
-                handle: Box::new(Box::new(buffer)),
+                handle: Box::new(Arc::new(buffer)),
                 inner_handle: inner,
             })
         }
@@ -0,0 +1,10 @@
+use std::sync::Arc;
+
+use crate::rendering::vulkan::buffers::command_buffer::VulkanCommandBuffer;
+
+#[allow(clippy::redundant_allocation)]
+#[no_mangle]
+extern "C" fn rendering_vulkan_buffers_command_buffer_interop_destroy_secondary(
+    _handle: Box<Arc<VulkanCommandBuffer>>,
+) {
+}
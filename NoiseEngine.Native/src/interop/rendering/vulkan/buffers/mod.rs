@@ -1 +1,2 @@
 mod buffer_interop;
+mod command_buffer_interop;
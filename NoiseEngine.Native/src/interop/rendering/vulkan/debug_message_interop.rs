@@ -0,0 +1,11 @@
+use crate::rendering::vulkan::debug_message;
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_debug_message_interop_get_capture_errors() -> bool {
+    debug_message::capture_errors()
+}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_debug_message_interop_set_capture_errors(capture: bool) {
+    debug_message::set_capture_errors(capture)
+}
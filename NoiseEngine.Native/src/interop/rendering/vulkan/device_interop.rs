@@ -1,20 +1,51 @@
 use std::sync::Arc;
 
+use ash::vk;
+
 use crate::{
+    errors::invalid_operation::InvalidOperationError,
     interop::{
         interop_read_only_span::InteropReadOnlySpan,
-        prelude::{InteropResult, InteropString},
+        prelude::{InteropArray, InteropResult, InteropString},
     },
     rendering::{
         buffers::command_buffer::GraphicsCommandBuffer,
+        timeline_semaphore::GraphicsTimelineSemaphore,
         vulkan::{
-            buffers::command_buffer::VulkanCommandBuffer, device::VulkanDevice,
+            buffers::command_buffer::{VulkanCommandBuffer, VulkanCommandBufferInheritance},
+            device::{VulkanDevice, VulkanQueueConfiguration},
+            device_limits::VulkanDeviceLimits,
             device_support::VulkanDeviceSupport,
+            timeline_semaphore::VulkanTimelineSemaphore,
         },
     },
     serialization::reader::SerializationReader,
 };
 
+#[repr(C)]
+struct VulkanDeviceFeatureValue {
+    pub name: InteropString,
+    pub supported: bool,
+    pub enabled: bool,
+}
+
+#[repr(C)]
+struct VulkanQueueFamilyValue {
+    pub index: u32,
+    pub queue_count: u32,
+    pub graphics: bool,
+    pub computing: bool,
+    pub transfer: bool,
+}
+
+#[repr(C)]
+struct VulkanMemoryHeapValue {
+    pub size: u64,
+    pub flags: vk::MemoryHeapFlags,
+    /// Union of property flags of memory types which use this heap.
+    pub memory_properties: vk::MemoryPropertyFlags,
+}
+
 #[no_mangle]
 extern "C" fn rendering_vulkan_device_interop_destroy(_handle: Box<Arc<VulkanDevice>>) {}
 
@@ -24,15 +55,43 @@ extern "C" fn rendering_vulkan_device_interop_destroy(_handle: Box<Arc<VulkanDev
 extern "C" fn rendering_vulkan_device_interop_initialize(
     device: &Arc<VulkanDevice>,
     enabled_extensions: InteropReadOnlySpan<InteropString>,
+    enabled_features: InteropReadOnlySpan<InteropString>,
+    queues: InteropReadOnlySpan<VulkanQueueConfiguration>,
 ) -> InteropResult<()> {
     let reference = unsafe { &mut *(Arc::as_ptr(device) as *mut VulkanDevice) };
 
-    match reference.initialize(enabled_extensions.into()) {
+    match reference.initialize(
+        enabled_extensions.into(),
+        enabled_features.into(),
+        queues.into(),
+    ) {
         Ok(()) => InteropResult::with_ok(()),
         Err(err) => InteropResult::with_err(err.into()),
     }
 }
 
+#[no_mangle]
+extern "C" fn rendering_vulkan_device_interop_get_id(device: &Arc<VulkanDevice>) -> u64 {
+    device.id()
+}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_device_interop_is_lost(device: &Arc<VulkanDevice>) -> bool {
+    device.is_lost()
+}
+
+/// Creates new not initialized device for the same physical device. Objects of `device` are not
+/// moved, and they keep `device` alive until they are dropped.
+#[no_mangle]
+extern "C" fn rendering_vulkan_device_interop_recreate<'init>(
+    device: &Arc<VulkanDevice<'init>>,
+) -> Box<Arc<VulkanDevice<'init>>> {
+    Box::new(Arc::new(VulkanDevice::new(
+        device.instance(),
+        device.physical_device(),
+    )))
+}
+
 #[no_mangle]
 extern "C" fn rendering_vulkan_device_interop_create_command_buffer<
     'dev: 'init,
@@ -54,3 +113,202 @@ extern "C" fn rendering_vulkan_device_interop_create_command_buffer<
         Err(err) => InteropResult::with_err(err.into()),
     }
 }
+
+/// Records secondary command buffers in parallel, from consecutive streams in `data` with given
+/// `lengths`. Returned handles are referenced by execute secondary commands of primary command
+/// buffers, which keep them alive until their executions are completed.
+#[allow(clippy::redundant_allocation)]
+#[no_mangle]
+extern "C" fn rendering_vulkan_device_interop_create_secondary_command_buffers<
+    'dev: 'init,
+    'init: 'cbuf,
+    'cbuf,
+>(
+    device: &'dev Arc<VulkanDevice<'init>>,
+    data: InteropReadOnlySpan<u8>,
+    lengths: InteropReadOnlySpan<i32>,
+    usage: VulkanDeviceSupport,
+    simultaneous_execute: bool,
+    inheritance: VulkanCommandBufferInheritance<'init, '_>,
+) -> InteropResult<InteropArray<Box<Arc<VulkanCommandBuffer<'init, 'cbuf>>>>> {
+    let mut data: &[u8] = data.into();
+    let lengths: &[i32] = lengths.into();
+
+    let mut streams = Vec::with_capacity(lengths.len());
+    for &length in lengths {
+        if length < 0 || length as usize > data.len() {
+            return InteropResult::with_err(
+                InvalidOperationError::with_str("Length of stream exceeds remaining data.").into(),
+            );
+        }
+
+        let (stream, remaining) = data.split_at(length as usize);
+        streams.push(stream);
+        data = remaining;
+    }
+
+    match VulkanCommandBuffer::new_secondary_parallel(
+        device,
+        &streams,
+        usage,
+        simultaneous_execute,
+        &inheritance,
+    ) {
+        Ok(command_buffers) => InteropResult::with_ok(
+            command_buffers
+                .into_iter()
+                .map(|command_buffer| Box::new(Arc::new(command_buffer)))
+                .collect::<Vec<_>>()
+                .into(),
+        ),
+        Err(err) => InteropResult::with_err(err.into()),
+    }
+}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_device_interop_load_pipeline_cache(
+    device: &Arc<VulkanDevice>,
+    data: InteropReadOnlySpan<u8>,
+) -> InteropResult<bool> {
+    let initialized = match device.initialized() {
+        Ok(initialized) => initialized,
+        Err(err) => return InteropResult::with_err(err.into()),
+    };
+
+    match initialized.pipeline_cache().load(data.into()) {
+        Ok(loaded) => InteropResult::with_ok(loaded),
+        Err(err) => InteropResult::with_err(err.into()),
+    }
+}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_device_interop_get_pipeline_cache_data(
+    device: &Arc<VulkanDevice>,
+) -> InteropResult<InteropArray<u8>> {
+    let initialized = match device.initialized() {
+        Ok(initialized) => initialized,
+        Err(err) => return InteropResult::with_err(err.into()),
+    };
+
+    match initialized.pipeline_cache().data() {
+        Ok(data) => InteropResult::with_ok(data.into()),
+        Err(err) => InteropResult::with_err(err.into()),
+    }
+}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_device_interop_get_supported_sample_counts(
+    device: &Arc<VulkanDevice>,
+    depth_stencil: bool,
+) -> u32 {
+    device.supported_sample_counts(depth_stencil).as_raw()
+}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_device_interop_create_timeline_semaphore<'init>(
+    device: &Arc<VulkanDevice<'init>>,
+    initial_value: u64,
+) -> InteropResult<Box<Arc<dyn GraphicsTimelineSemaphore + 'init>>> {
+    match VulkanTimelineSemaphore::new(device, initial_value) {
+        Ok(semaphore) => InteropResult::with_ok(Box::new(Arc::new(semaphore))),
+        Err(err) => InteropResult::with_err(err.into()),
+    }
+}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_device_interop_set_object_name(
+    device: &Arc<VulkanDevice>,
+    object_type: vk::ObjectType,
+    handle: u64,
+    name: InteropString,
+) -> InteropResult<()> {
+    let initialized = match device.initialized() {
+        Ok(initialized) => initialized,
+        Err(err) => return InteropResult::with_err(err.into()),
+    };
+
+    match initialized.set_object_name_raw(object_type, handle, &String::from(name)) {
+        Ok(()) => InteropResult::with_ok(()),
+        Err(err) => InteropResult::with_err(err.into()),
+    }
+}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_device_interop_get_features(
+    device: &Arc<VulkanDevice>,
+) -> InteropArray<VulkanDeviceFeatureValue> {
+    let enabled = device
+        .initialized()
+        .ok()
+        .map(|initialized| initialized.features());
+
+    device
+        .supported_features()
+        .iter()
+        .map(|(name, supported)| VulkanDeviceFeatureValue {
+            name: name.into(),
+            supported,
+            enabled: enabled.and_then(|enabled| enabled.get(name)) == Some(true),
+        })
+        .collect::<Vec<_>>()
+        .into()
+}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_device_interop_get_limits(
+    device: &Arc<VulkanDevice>,
+) -> VulkanDeviceLimits {
+    device.limits()
+}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_device_interop_is_image_format_supported(
+    device: &Arc<VulkanDevice>,
+    format: vk::Format,
+    usage: vk::ImageUsageFlags,
+) -> bool {
+    device.is_image_format_supported(format, usage)
+}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_device_interop_get_memory_heaps(
+    device: &Arc<VulkanDevice>,
+) -> InteropArray<VulkanMemoryHeapValue> {
+    let properties = device.memory_properties();
+    let memory_types = &properties.memory_types[..properties.memory_type_count as usize];
+
+    properties.memory_heaps[..properties.memory_heap_count as usize]
+        .iter()
+        .enumerate()
+        .map(|(index, heap)| VulkanMemoryHeapValue {
+            size: heap.size,
+            flags: heap.flags,
+            memory_properties: memory_types
+                .iter()
+                .filter(|memory_type| memory_type.heap_index as usize == index)
+                .fold(vk::MemoryPropertyFlags::empty(), |flags, memory_type| {
+                    flags | memory_type.property_flags
+                }),
+        })
+        .collect::<Vec<_>>()
+        .into()
+}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_device_interop_get_queue_families(
+    device: &Arc<VulkanDevice>,
+) -> InteropArray<VulkanQueueFamilyValue> {
+    device
+        .queue_family_properties()
+        .into_iter()
+        .zip(0u32..)
+        .map(|(family, index)| VulkanQueueFamilyValue {
+            index,
+            queue_count: family.queue_count,
+            graphics: family.queue_flags.contains(vk::QueueFlags::GRAPHICS),
+            computing: family.queue_flags.contains(vk::QueueFlags::COMPUTE),
+            transfer: family.queue_flags.contains(vk::QueueFlags::TRANSFER),
+        })
+        .collect::<Vec<_>>()
+        .into()
+}
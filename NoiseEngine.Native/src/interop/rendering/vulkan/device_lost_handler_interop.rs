@@ -0,0 +1,11 @@
+use crate::{
+    interop::prelude::InteropResult,
+    rendering::vulkan::device_lost_handler::{self, VulkanDeviceLostHandler},
+};
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_device_lost_handler_interop_initialize(
+    handler: VulkanDeviceLostHandler,
+) -> InteropResult<()> {
+    device_lost_handler::initialize(handler).into()
+}
@@ -15,6 +15,7 @@ pub(crate) struct VulkanDeviceValue<'init> {
     pub guid: Uuid,
     pub supports_graphics: bool,
     pub supports_computing: bool,
+    pub supports_async_computing: bool,
     pub supports_presentation: bool,
     pub handle: Box<Arc<VulkanDevice<'init>>>,
 }
@@ -7,7 +7,14 @@ use crate::{
         interop_read_only_span::InteropReadOnlySpan,
         prelude::{InteropArray, InteropResult, InteropString, ResultError, ResultErrorKind},
     },
-    rendering::vulkan::instance::VulkanInstance,
+    rendering::{
+        presentation::window::Window,
+        vulkan::{
+            device::VulkanDevice,
+            device_selection::{self, VulkanDeviceRequirements},
+            instance::VulkanInstance,
+        },
+    },
 };
 
 use super::{application_info::VulkanApplicationInfo, device_value::VulkanDeviceValue};
@@ -63,3 +70,47 @@ extern "C" fn rendering_vulkan_instance_interop_get_devices(
         Err(err) => InteropResult::with_err(err.into()),
     }
 }
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_instance_interop_rank_devices(
+    instance: &Arc<VulkanInstance>,
+    devices: InteropReadOnlySpan<&Arc<VulkanDevice>>,
+    required_extensions: InteropReadOnlySpan<InteropString>,
+    required_features: InteropReadOnlySpan<InteropString>,
+    window: Option<&Arc<dyn Window>>,
+) -> InteropResult<InteropArray<u32>> {
+    let devices: &[&Arc<VulkanDevice>] = devices.into();
+    let devices: Vec<&VulkanDevice> = devices.iter().map(|device| device.as_ref()).collect();
+
+    let extensions: &[InteropString] = required_extensions.into();
+    let extensions: Vec<&str> = extensions
+        .iter()
+        .map(|extension| extension.into())
+        .collect();
+    let features: &[InteropString] = required_features.into();
+    let features: Vec<&str> = features.iter().map(|feature| feature.into()).collect();
+
+    let surface = match window.map(|window| window.create_vulkan_surface(instance)) {
+        Some(Ok(surface)) => Some(surface),
+        Some(Err(err)) => return InteropResult::with_err(err.into()),
+        None => None,
+    };
+
+    match device_selection::rank(
+        &devices,
+        &VulkanDeviceRequirements {
+            extensions: &extensions,
+            features: &features,
+            surface: surface.as_ref(),
+        },
+    ) {
+        Ok(ranking) => InteropResult::with_ok(
+            ranking
+                .into_iter()
+                .map(|index| index as u32)
+                .collect::<Vec<_>>()
+                .into(),
+        ),
+        Err(err) => InteropResult::with_err(err.into()),
+    }
+}
@@ -6,16 +6,21 @@ pub(crate) mod device_value;
 
 mod compute_pipeline_interop;
 mod conversions;
+mod debug_message_interop;
 mod device_interop;
+mod device_lost_handler_interop;
 mod framebuffer_interop;
 mod graphics_pipeline_interop;
 mod image_interop;
 mod image_view_interop;
 mod instance_interop;
 mod library_interop;
+mod pipeline_compilation_interop;
 mod pipeline_interop;
 mod pipeline_layout_interop;
+mod query_pool_interop;
 mod render_pass_interop;
 mod sampler_interop;
 mod shader_module_interop;
 mod swapchain_interop;
+mod upload_manager_interop;
@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use ash::vk;
+
+use crate::{
+    interop::{
+        interop_read_only_span::InteropReadOnlySpan,
+        prelude::{InteropArray, InteropResult},
+    },
+    rendering::vulkan::{
+        graphics_pipeline_create_info::GraphicsPipelineCreateInfo,
+        pipeline::Pipeline,
+        pipeline_compilation::{PipelineCompilation, PipelineCompilationBuilder},
+        pipeline_layout::PipelineLayout,
+        pipeline_shader_stage::PipelineShaderStage,
+        render_pass::RenderPass,
+    },
+};
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_pipeline_compilation_interop_create_builder<'init>(
+) -> Box<PipelineCompilationBuilder<'init>> {
+    Box::default()
+}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_pipeline_compilation_interop_destroy_builder(
+    _handle: Box<PipelineCompilationBuilder>,
+) {
+}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_pipeline_compilation_interop_builder_add_graphics<'init>(
+    builder: &mut PipelineCompilationBuilder<'init>,
+    render_pass: &Arc<RenderPass<'init>>,
+    layout: &Arc<PipelineLayout<'init>>,
+    stages: InteropReadOnlySpan<PipelineShaderStage<'init, '_>>,
+    flags: vk::PipelineCreateFlags,
+    create_info: GraphicsPipelineCreateInfo,
+) {
+    builder.add_graphics(render_pass, layout, stages.into(), flags, &create_info)
+}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_pipeline_compilation_interop_builder_add_compute<'init>(
+    builder: &mut PipelineCompilationBuilder<'init>,
+    layout: &Arc<PipelineLayout<'init>>,
+    stage: PipelineShaderStage<'init, '_>,
+    flags: vk::PipelineCreateFlags,
+) {
+    builder.add_compute(layout, &stage, flags)
+}
+
+/// Consumes `builder`.
+#[allow(clippy::redundant_allocation)]
+#[no_mangle]
+extern "C" fn rendering_vulkan_pipeline_compilation_interop_start(
+    builder: Box<PipelineCompilationBuilder<'static>>,
+) -> Box<Arc<PipelineCompilation<'static>>> {
+    Box::new(builder.start())
+}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_pipeline_compilation_interop_destroy(
+    _handle: Box<Arc<PipelineCompilation>>,
+) {
+}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_pipeline_compilation_interop_wait(
+    compilation: &Arc<PipelineCompilation>,
+    timeout: u64,
+) -> bool {
+    compilation.wait(timeout)
+}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_pipeline_compilation_interop_is_signaled(
+    compilation: &Arc<PipelineCompilation>,
+) -> bool {
+    compilation.is_signaled()
+}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_pipeline_compilation_interop_wait_multiple(
+    compilations: InteropReadOnlySpan<&Arc<PipelineCompilation>>,
+    wait_all: bool,
+    timeout: u64,
+) -> bool {
+    PipelineCompilation::wait_multiple(compilations.into(), wait_all, timeout)
+}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_pipeline_compilation_interop_take_results<'init>(
+    compilation: &Arc<PipelineCompilation<'init>>,
+) -> InteropResult<InteropArray<InteropResult<Box<Pipeline<'init>>>>> {
+    match compilation.take_results() {
+        Ok(results) => InteropResult::with_ok(
+            results
+                .into_iter()
+                .map(|result| match result {
+                    Ok(pipeline) => InteropResult::with_ok(Box::new(pipeline)),
+                    Err(err) => InteropResult::with_err(err.into()),
+                })
+                .collect::<Vec<_>>()
+                .into(),
+        ),
+        Err(err) => InteropResult::with_err(err.into()),
+    }
+}
@@ -1,4 +1,23 @@
-use crate::rendering::vulkan::pipeline::Pipeline;
+use crate::{
+    interop::prelude::{InteropResult, InteropString},
+    rendering::vulkan::pipeline::Pipeline,
+};
 
 #[no_mangle]
 extern "C" fn rendering_vulkan_pipeline_destroy(_handle: Box<Pipeline>) {}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_pipeline_set_name(
+    pipeline: &Pipeline,
+    name: InteropString,
+) -> InteropResult<()> {
+    let initialized = match pipeline.layout().device().initialized() {
+        Ok(initialized) => initialized,
+        Err(err) => return InteropResult::with_err(err.into()),
+    };
+
+    match initialized.set_object_name(pipeline.inner(), &String::from(name)) {
+        Ok(()) => InteropResult::with_ok(()),
+        Err(err) => InteropResult::with_err(err.into()),
+    }
+}
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use ash::vk;
+
+use crate::{
+    interop::prelude::{InteropResult, InteropSpan},
+    rendering::vulkan::{device::VulkanDevice, query_pool::VulkanQueryPool},
+};
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_query_pool_interop_create<'init>(
+    device: &Arc<VulkanDevice<'init>>,
+    query_type: vk::QueryType,
+    count: u32,
+    pipeline_statistics: vk::QueryPipelineStatisticFlags,
+) -> InteropResult<Box<VulkanQueryPool<'init>>> {
+    match VulkanQueryPool::new(device, query_type, count, pipeline_statistics) {
+        Ok(query_pool) => InteropResult::with_ok(Box::new(query_pool)),
+        Err(err) => InteropResult::with_err(err.into()),
+    }
+}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_query_pool_interop_destroy(_handle: Box<VulkanQueryPool>) {}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_query_pool_interop_get_results(
+    query_pool: &VulkanQueryPool,
+    first: u32,
+    count: u32,
+    results: InteropSpan<u64>,
+    wait: bool,
+) -> InteropResult<bool> {
+    match query_pool.get_results(first, count, results.into(), wait) {
+        Ok(is_available) => InteropResult::with_ok(is_available),
+        Err(err) => InteropResult::with_err(err.into()),
+    }
+}
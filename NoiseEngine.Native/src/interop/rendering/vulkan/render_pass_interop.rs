@@ -1,10 +1,10 @@
 use std::sync::Arc;
 
 use crate::{
-    interop::prelude::InteropResult,
+    interop::prelude::{InteropResult, InteropString},
     rendering::vulkan::{
         device::VulkanDevice,
-        render_pass::{RenderPass, RenderPassCreateInfo},
+        render_pass::{RenderPass, RenderPassCreateInfo, RenderPassDescription},
     },
 };
 
@@ -19,5 +19,37 @@ extern "C" fn rendering_vulkan_render_pass_create<'init>(
     }
 }
 
+#[no_mangle]
+extern "C" fn rendering_vulkan_render_pass_create_with_description<'init>(
+    device: &Arc<VulkanDevice<'init>>,
+    description: RenderPassDescription,
+) -> InteropResult<Box<Arc<RenderPass<'init>>>> {
+    match RenderPass::with_description(device, &description) {
+        Ok(r) => InteropResult::with_ok(Box::new(Arc::new(r))),
+        Err(err) => InteropResult::with_err(err.into()),
+    }
+}
+
 #[no_mangle]
 extern "C" fn rendering_vulkan_render_pass_destroy(_handle: Box<Arc<RenderPass>>) {}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_render_pass_set_name(
+    render_pass: &Arc<RenderPass>,
+    name: InteropString,
+) -> InteropResult<()> {
+    // Dynamic render passes do not have Vulkan object.
+    if render_pass.is_dynamic() {
+        return InteropResult::with_ok(());
+    }
+
+    let initialized = match render_pass.device().initialized() {
+        Ok(initialized) => initialized,
+        Err(err) => return InteropResult::with_err(err.into()),
+    };
+
+    match initialized.set_object_name(render_pass.inner(), &String::from(name)) {
+        Ok(()) => InteropResult::with_ok(()),
+        Err(err) => InteropResult::with_err(err.into()),
+    }
+}
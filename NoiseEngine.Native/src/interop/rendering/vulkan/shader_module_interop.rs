@@ -1,20 +1,71 @@
 use std::sync::Arc;
 
+use ash::vk;
+
 use crate::{
-    interop::{interop_read_only_span::InteropReadOnlySpan, prelude::InteropResult},
-    rendering::vulkan::{device::VulkanDevice, shader_module::ShaderModule},
+    interop::{
+        interop_read_only_span::InteropReadOnlySpan,
+        prelude::{InteropArray, InteropOption, InteropResult, InteropString},
+    },
+    rendering::vulkan::{
+        device::VulkanDevice,
+        shader_module::ShaderModule,
+        shader_reflection::{ShaderBinding, ShaderVertexInput},
+    },
 };
 
+#[repr(C)]
+struct ShaderReflectionValue {
+    entry_points: InteropArray<ShaderEntryPointValue>,
+    bindings: InteropArray<ShaderBinding>,
+    push_constant_ranges: InteropArray<vk::PushConstantRange>,
+    vertex_inputs: InteropArray<ShaderVertexInput>,
+}
+
+#[repr(C)]
+struct ShaderEntryPointValue {
+    name: InteropString,
+    stage: vk::ShaderStageFlags,
+    has_workgroup_size: bool,
+    workgroup_size: [u32; 3],
+}
+
+#[allow(clippy::redundant_allocation)]
 #[no_mangle]
 extern "C" fn rendering_vulkan_shader_module_create<'dev: 'init, 'init>(
     device: &'dev Arc<VulkanDevice<'init>>,
     code: InteropReadOnlySpan<u8>,
-) -> InteropResult<Box<ShaderModule<'init>>> {
+) -> InteropResult<Box<Arc<ShaderModule<'init>>>> {
     match ShaderModule::new(device, code.into()) {
-        Ok(s) => InteropResult::with_ok(Box::new(s)),
+        Ok(s) => InteropResult::with_ok(Box::new(Arc::new(s))),
         Err(err) => InteropResult::with_err(err.into()),
     }
 }
 
 #[no_mangle]
-extern "C" fn rendering_vulkan_shader_module_destroy(_handle: Box<ShaderModule>) {}
+extern "C" fn rendering_vulkan_shader_module_destroy(_handle: Box<Arc<ShaderModule>>) {}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_shader_module_get_reflection(
+    module: &Arc<ShaderModule>,
+) -> InteropOption<ShaderReflectionValue> {
+    module
+        .reflection()
+        .map(|reflection| ShaderReflectionValue {
+            entry_points: reflection
+                .entry_points
+                .iter()
+                .map(|entry_point| ShaderEntryPointValue {
+                    name: entry_point.name.as_str().into(),
+                    stage: entry_point.stage,
+                    has_workgroup_size: entry_point.workgroup_size.is_some(),
+                    workgroup_size: entry_point.workgroup_size.unwrap_or_default(),
+                })
+                .collect::<Vec<_>>()
+                .into(),
+            bindings: reflection.bindings.clone().into(),
+            push_constant_ranges: reflection.push_constant_ranges.clone().into(),
+            vertex_inputs: reflection.vertex_inputs.clone().into(),
+        })
+        .into()
+}
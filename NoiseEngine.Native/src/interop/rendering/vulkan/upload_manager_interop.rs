@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use crate::{
+    interop::{interop_read_only_span::InteropReadOnlySpan, prelude::InteropResult},
+    rendering::{
+        fence::GraphicsFence,
+        vulkan::{
+            buffers::buffer::VulkanBuffer,
+            device::VulkanDevice,
+            image::VulkanImage,
+            upload_manager::{VulkanUploadImageRegion, VulkanUploadManager},
+        },
+    },
+};
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_upload_manager_interop_create<'dev: 'init, 'init: 'ma, 'ma>(
+    device: &'dev Arc<VulkanDevice<'init>>,
+    capacity: u64,
+) -> InteropResult<Box<VulkanUploadManager<'init, 'ma>>> {
+    match VulkanUploadManager::new(device, capacity) {
+        Ok(manager) => InteropResult::with_ok(Box::new(manager)),
+        Err(err) => InteropResult::with_err(err.into()),
+    }
+}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_upload_manager_interop_destroy(_handle: Box<VulkanUploadManager>) {}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_upload_manager_interop_upload_buffer<'init: 'ma, 'ma>(
+    manager: &VulkanUploadManager<'init, 'ma>,
+    buffer: &Arc<VulkanBuffer<'init, 'ma>>,
+    offset: u64,
+    data: InteropReadOnlySpan<u8>,
+) -> InteropResult<()> {
+    match manager.upload_buffer(buffer, offset, data.into()) {
+        Ok(()) => InteropResult::with_ok(()),
+        Err(err) => InteropResult::with_err(err.into()),
+    }
+}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_upload_manager_interop_upload_image<'init: 'ma, 'ma>(
+    manager: &VulkanUploadManager<'init, 'ma>,
+    image: &Arc<VulkanImage<'init, 'ma>>,
+    region: VulkanUploadImageRegion,
+    data: InteropReadOnlySpan<u8>,
+) -> InteropResult<()> {
+    match manager.upload_image(image, &region, data.into()) {
+        Ok(()) => InteropResult::with_ok(()),
+        Err(err) => InteropResult::with_err(err.into()),
+    }
+}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_upload_manager_interop_flush<'init>(
+    manager: &VulkanUploadManager<'init, '_>,
+) -> InteropResult<Box<Arc<dyn GraphicsFence + 'init>>> {
+    match manager.flush() {
+        Ok(fence) => InteropResult::with_ok(Box::new(fence)),
+        Err(err) => InteropResult::with_err(err.into()),
+    }
+}
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_upload_manager_interop_wait_idle(
+    manager: &VulkanUploadManager,
+) -> InteropResult<()> {
+    match manager.wait_idle() {
+        Ok(()) => InteropResult::with_ok(()),
+        Err(err) => InteropResult::with_err(err.into()),
+    }
+}
@@ -16,6 +16,7 @@ pub enum ResultErrorKind {
     GraphicsOutOfDeviceMemory = 1003,
     GraphicsDeviceLost = 1004,
     WindowNotSupported = 1005,
+    GraphicsValidation = 1006,
 }
 
 impl From<&(dyn Error + 'static)> for ResultErrorKind {
@@ -6,10 +6,12 @@ use super::{
     error::{LoggingError, LoggingErrorKind},
     log_data::LogData,
     log_level::LogLevel,
+    validation_log_data::ValidationLogData,
 };
 
 pub(crate) struct Logger {
     pub(crate) handler: unsafe extern "C" fn(LogData),
+    pub(crate) validation_handler: unsafe extern "C" fn(ValidationLogData),
 }
 
 static INSTANCE: OnceCell<Logger> = OnceCell::new();
@@ -30,6 +32,19 @@ pub(crate) fn log(level: LogLevel, message: &str) {
     logger.log(level, message);
 }
 
+/// Forwards structured validation message in addition to its text logged by [`log`].
+///
+/// # Panics
+/// This function panics if called before [`initialize`].
+pub(crate) fn log_validation(data: ValidationLogData) {
+    let logger = INSTANCE.get().expect("logger is not initialized");
+
+    // SAFETY: The handler is set by the user of the library and is expected to be safe.
+    unsafe {
+        (logger.validation_handler)(data);
+    }
+}
+
 impl Logger {
     fn log(&self, level: LogLevel, message: InteropReadOnlySpan<u8>) {
         let log_data = LogData { level, message };
@@ -6,3 +6,4 @@ pub(crate) mod error;
 pub(crate) mod log_data;
 pub(crate) mod log_level;
 pub(crate) mod logger;
+pub(crate) mod validation_log_data;
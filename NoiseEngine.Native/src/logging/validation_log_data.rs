@@ -0,0 +1,24 @@
+use crate::interop::prelude::{InteropReadOnlySpan, InteropString};
+
+use super::log_level::LogLevel;
+
+/// Structured message of the graphics API validation. Strings are owned by the native side and are
+/// valid only during the handler call.
+#[repr(C)]
+pub struct ValidationLogData<'a> {
+    pub level: LogLevel,
+    pub message_type: u32,
+    pub message_id_number: i32,
+    pub message_id_name: InteropReadOnlySpan<'a, u8>,
+    pub message: InteropReadOnlySpan<'a, u8>,
+    pub objects: InteropReadOnlySpan<'a, ValidationLogObject>,
+    pub queue_labels: InteropReadOnlySpan<'a, InteropString>,
+    pub command_buffer_labels: InteropReadOnlySpan<'a, InteropString>,
+}
+
+#[repr(C)]
+pub struct ValidationLogObject {
+    pub object_type: InteropString,
+    pub handle: u64,
+    pub name: InteropString,
+}
@@ -1,7 +1,23 @@
 use std::sync::Arc;
 
-use crate::{interop::prelude::InteropResult, rendering::fence::GraphicsFence};
+use crate::{
+    interop::prelude::InteropResult,
+    rendering::{fence::GraphicsFence, timeline_semaphore::GraphicsTimelineSemaphoreSubmit},
+};
 
 pub trait GraphicsCommandBuffer<'init> {
-    fn execute<'cbuf>(&'cbuf self) -> InteropResult<Box<Arc<dyn GraphicsFence + 'cbuf>>>;
+    /// Executes this command buffer after `waits` values of timeline semaphores are reached, and
+    /// then signals `signals` values.
+    fn execute<'cbuf>(
+        &'cbuf self,
+        waits: &[GraphicsTimelineSemaphoreSubmit<'_, 'init>],
+        signals: &[GraphicsTimelineSemaphoreSubmit<'_, 'init>],
+    ) -> InteropResult<Box<Arc<dyn GraphicsFence + 'cbuf>>>;
+
+    /// Waits for pending executions and records new data into this command buffer.
+    fn record(&mut self, data: &[u8], simultaneous_execute: bool) -> InteropResult<()>;
+
+    /// Sets name of this command buffer, which is visible in validation messages and graphics
+    /// debuggers.
+    fn set_name(&self, name: &str) -> InteropResult<()>;
 }
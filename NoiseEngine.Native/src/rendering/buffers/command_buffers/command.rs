@@ -1,4 +1,5 @@
 #[repr(u16)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum GraphicsCommandBufferCommand {
     CopyBuffer = 0,
     CopyBufferToTexture = 1,
@@ -8,7 +9,49 @@ pub enum GraphicsCommandBufferCommand {
     AttachCameraTexture = 5,
     DetachCamera = 6,
     DrawMesh = 7,
+    ExecuteSecondary = 8,
+    NextSubpass = 9,
+    ResetQueries = 10,
+    WriteTimestamp = 11,
+    BeginQuery = 12,
+    EndQuery = 13,
+    CopyQueryResults = 14,
+    BeginLabel = 15,
+    EndLabel = 16,
+    InsertLabel = 17,
 
     AttachPipeline = 10000,
     AttachMaterial = 10001,
+    PushConstants = 10002,
+}
+
+impl TryFrom<u16> for GraphicsCommandBufferCommand {
+    type Error = u16;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::CopyBuffer,
+            1 => Self::CopyBufferToTexture,
+            2 => Self::CopyTextureToBuffer,
+            3 => Self::Dispatch,
+            4 => Self::AttachCameraWindow,
+            5 => Self::AttachCameraTexture,
+            6 => Self::DetachCamera,
+            7 => Self::DrawMesh,
+            8 => Self::ExecuteSecondary,
+            9 => Self::NextSubpass,
+            10 => Self::ResetQueries,
+            11 => Self::WriteTimestamp,
+            12 => Self::BeginQuery,
+            13 => Self::EndQuery,
+            14 => Self::CopyQueryResults,
+            15 => Self::BeginLabel,
+            16 => Self::EndLabel,
+            17 => Self::InsertLabel,
+            10000 => Self::AttachPipeline,
+            10001 => Self::AttachMaterial,
+            10002 => Self::PushConstants,
+            _ => return Err(value),
+        })
+    }
 }
@@ -0,0 +1,12 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// Validation of command streams is enabled by default only in debug builds.
+static CHECKED_DECODING: AtomicBool = AtomicBool::new(cfg!(debug_assertions));
+
+pub fn checked_decoding() -> bool {
+    CHECKED_DECODING.load(Ordering::Relaxed)
+}
+
+pub fn set_checked_decoding(checked: bool) {
+    CHECKED_DECODING.store(checked, Ordering::Relaxed)
+}
@@ -1,4 +1,5 @@
 #[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum CameraClearFlags {
     Undefined = 0,
     Nothing = 1,
@@ -0,0 +1,53 @@
+use std::{error::Error, fmt::Display};
+
+use crate::{
+    errors::serialization::SerializationError,
+    interop::prelude::{ResultError, ResultErrorKind},
+};
+
+#[derive(Debug)]
+pub struct InvalidCommandStreamError {
+    inner: SerializationError,
+    command_index: usize,
+}
+
+impl InvalidCommandStreamError {
+    pub fn new(inner: SerializationError, command_index: usize) -> Self {
+        Self {
+            inner,
+            command_index,
+        }
+    }
+
+    pub fn offset(&self) -> usize {
+        self.inner.offset()
+    }
+
+    pub fn command_index(&self) -> usize {
+        self.command_index
+    }
+}
+
+impl Error for InvalidCommandStreamError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+impl Display for InvalidCommandStreamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Invalid command stream at byte offset {} in command {}. {}",
+            self.inner.offset(),
+            self.command_index,
+            self.inner.message()
+        )
+    }
+}
+
+impl From<InvalidCommandStreamError> for ResultError {
+    fn from(err: InvalidCommandStreamError) -> Self {
+        ResultError::with_kind(&err, ResultErrorKind::Argument)
+    }
+}
@@ -1 +1,2 @@
+pub mod invalid_command_stream;
 pub mod window_not_supported;
@@ -9,3 +9,4 @@ pub mod cpu_texture_2d_encoding;
 pub mod fence;
 pub mod texture;
 pub mod texture_sampler;
+pub mod timeline_semaphore;
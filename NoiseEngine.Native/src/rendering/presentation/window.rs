@@ -12,7 +12,7 @@ use crate::{
 
 use super::input::InputData;
 
-pub trait Window {
+pub trait Window: Send + Sync {
     fn get_width(&self) -> u32;
     fn get_height(&self) -> u32;
 
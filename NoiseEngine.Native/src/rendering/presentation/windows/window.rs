@@ -548,6 +548,11 @@ impl Drop for WindowWindows {
     }
 }
 
+// Window handles and thread bound state are used only by the window thread, other threads post
+// tasks into `thread_task_queue`.
+unsafe impl Send for WindowWindows {}
+unsafe impl Sync for WindowWindows {}
+
 impl Window for WindowWindows {
     fn get_width(&self) -> u32 {
         self.data().width
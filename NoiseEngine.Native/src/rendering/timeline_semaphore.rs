@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use crate::interop::prelude::{InteropResult, ResultError};
+
+/// Semaphore with monotonically increasing value, which can be waited and signaled by command
+/// buffers executed on any queue and by the host.
+pub trait GraphicsTimelineSemaphore {
+    fn value(&self) -> InteropResult<u64>;
+    fn wait(&self, value: u64, timeout: u64) -> InteropResult<bool>;
+    fn signal(&self, value: u64) -> InteropResult<()>;
+
+    /// Waits until each of `semaphores` reaches value at the same index of `values`.
+    /// # Safety
+    /// All semaphores must be from the same API and device.
+    unsafe fn wait_multiple(
+        &self,
+        semaphores: &[&Arc<dyn GraphicsTimelineSemaphore>],
+        values: &[u64],
+        wait_all: bool,
+        timeout: u64,
+    ) -> Result<bool, ResultError>;
+}
+
+/// Value of timeline semaphore waited before or signaled after command buffer execution.
+#[repr(C)]
+pub struct GraphicsTimelineSemaphoreSubmit<'a, 'init> {
+    pub semaphore: &'a Arc<dyn GraphicsTimelineSemaphore + 'init>,
+    pub value: u64,
+}
@@ -7,6 +7,7 @@ use crate::{
     rendering::{
         buffers::buffer::GraphicsBuffer,
         vulkan::{
+            capture,
             device::{VulkanDevice, VulkanDeviceInitialized},
             errors::universal::VulkanUniversalError,
             memory_allocator::MemoryBlock,
@@ -14,8 +15,16 @@ use crate::{
     },
 };
 
+#[derive(Clone, Copy, Debug)]
+pub struct VulkanBufferDescription {
+    pub size: u64,
+    pub usage: vk::BufferUsageFlags,
+    pub map: bool,
+}
+
 pub(crate) struct VulkanBuffer<'init: 'ma, 'ma> {
     buffer: vk::Buffer,
+    description: VulkanBufferDescription,
     memory: MemoryBlock<'ma>,
     device: Arc<VulkanDevice<'init>>,
 }
@@ -29,6 +38,12 @@ impl<'dev: 'init, 'init: 'ma, 'ma> VulkanBuffer<'init, 'ma> {
     ) -> Result<Self, VulkanUniversalError> {
         let initialized = device.initialized()?;
 
+        // Buffers created while capturing can be read back, so captures contain their contents.
+        let usage = match capture::directory() {
+            Some(_) => usage | vk::BufferUsageFlags::TRANSFER_SRC,
+            None => usage,
+        };
+
         let buffer_info = Self::create_buffer_info(initialized, size, usage);
         let alloc_info = vma::AllocationCreateInfo {
             flags: match map {
@@ -51,8 +66,12 @@ impl<'dev: 'init, 'init: 'ma, 'ma> VulkanBuffer<'init, 'ma> {
             .allocator()
             .create_buffer(&buffer_info, &alloc_info)?;
 
+        let description = VulkanBufferDescription { size, usage, map };
+        initialized.register_buffer(buffer, description);
+
         Ok(VulkanBuffer {
             buffer,
+            description,
             memory,
             device: device.clone(),
         })
@@ -62,6 +81,14 @@ impl<'dev: 'init, 'init: 'ma, 'ma> VulkanBuffer<'init, 'ma> {
         self.buffer
     }
 
+    pub fn description(&self) -> &VulkanBufferDescription {
+        &self.description
+    }
+
+    pub fn memory(&self) -> &MemoryBlock<'ma> {
+        &self.memory
+    }
+
     fn create_buffer_info(
         initialized: &VulkanDeviceInitialized,
         size: u64,
@@ -103,10 +130,11 @@ impl<'dev: 'init, 'init: 'ma, 'ma> VulkanBuffer<'init, 'ma> {
 
 impl Drop for VulkanBuffer<'_, '_> {
     fn drop(&mut self) {
+        let initialized = self.device.initialized().unwrap();
+        initialized.unregister_buffer(self.buffer);
+
         unsafe {
-            self.device
-                .initialized()
-                .unwrap()
+            initialized
                 .vulkan_device()
                 .destroy_buffer(self.buffer, None);
         }
@@ -1,43 +1,178 @@
 use std::{
-    ptr,
-    sync::{Arc, Weak},
+    ffi::c_void,
+    mem, ptr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, MutexGuard, Weak,
+    },
 };
 
 use ash::vk;
 
 use crate::{
-    common::pool::PoolItem,
+    common::{pool::PoolItem, worker_pool::WorkerPool},
+    errors::{invalid_operation::InvalidOperationError, serialization::SerializationError},
     interop::prelude::InteropResult,
     rendering::{
         buffers::{
             command_buffer::GraphicsCommandBuffer,
-            command_buffers::command::GraphicsCommandBufferCommand,
+            command_buffers::{command::GraphicsCommandBufferCommand, validation},
         },
+        errors::invalid_command_stream::InvalidCommandStreamError,
         fence::GraphicsFence,
+        timeline_semaphore::GraphicsTimelineSemaphoreSubmit,
         vulkan::{
+            capture,
             device::{VulkanDevice, VulkanDeviceInitialized, VulkanQueueFamily},
             device_support::VulkanDeviceSupport,
+            dynamic_rendering::RenderingFormats,
             errors::universal::VulkanUniversalError,
             fence::VulkanFence,
             pool_wrappers::VulkanCommandPool,
+            render_pass::RenderPass,
+            timeline_semaphore::VulkanTimelineSemaphore,
         },
     },
     serialization::reader::SerializationReader,
 };
 
 use super::command_buffers::{
-    camera_commands::{self, AttachCameraWindowOutput},
-    compute_commands, draw_commands, memory_commands, misc_commands,
+    camera_commands::{self, AttachCameraWindowOutput, DynamicRenderingEnd},
+    compute_commands, debug_commands, draw_commands, memory_commands, misc_commands,
+    query_commands,
 };
 
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct VulkanCommandBufferInheritance<'init: 'rp, 'rp> {
+    pub render_pass: Option<&'rp Arc<RenderPass<'init>>>,
+    pub subpass: u32,
+    pub framebuffer: vk::Framebuffer,
+    pub extent: vk::Extent2D,
+}
+
+/// Pipeline layout of the last attached pipeline, used by commands which bind descriptor sets or
+/// push constants.
+#[derive(Clone)]
+pub struct AttachedPipelineLayout {
+    pub inner: vk::PipelineLayout,
+    pub bind_point: vk::PipelineBindPoint,
+    pub push_constant_ranges: Arc<[vk::PushConstantRange]>,
+}
+
+impl AttachedPipelineLayout {
+    /// Returns stages which must be passed when pushing `size` bytes of constants at `offset`, i.e.
+    /// stages of all push constant ranges overlapping these bytes.
+    pub fn push_constant_stages(&self, offset: u32, size: u32) -> vk::ShaderStageFlags {
+        self.push_constant_ranges
+            .iter()
+            .filter(|range| range.offset < offset + size && offset < range.offset + range.size)
+            .fold(vk::ShaderStageFlags::empty(), |stages, range| {
+                stages | range.stage_flags
+            })
+    }
+
+    /// Checks that `size` bytes of constants at `offset` can be pushed for `stage_flags`, which
+    /// requires each byte to be covered by ranges of every given stage and `stage_flags` to
+    /// contain all stages of overlapping ranges.
+    pub fn check_push_constants(
+        &self,
+        stage_flags: vk::ShaderStageFlags,
+        offset: u32,
+        size: u32,
+    ) -> Result<(), String> {
+        if self.inner == vk::PipelineLayout::null() {
+            return Err("Push constants require attached pipeline.".to_owned());
+        }
+        if stage_flags.is_empty() {
+            return Err("Push constants stage flags cannot be empty.".to_owned());
+        }
+        if size == 0 || !offset.is_multiple_of(4) || !size.is_multiple_of(4) {
+            return Err(format!(
+                "Push constants offset {} and size {} must be multiples of 4 and size cannot be zero.",
+                offset, size
+            ));
+        }
+
+        let end = offset
+            .checked_add(size)
+            .ok_or("Push constants range overflows.")?;
+        if let Some(range) = self.push_constant_ranges.iter().find(|range| {
+            range.offset < end
+                && offset < range.offset + range.size
+                && !stage_flags.contains(range.stage_flags)
+        }) {
+            return Err(format!(
+                "Push constants stage flags {:?} do not contain stages {:?} of overlapping range at offset {}.",
+                stage_flags, range.stage_flags, range.offset
+            ));
+        }
+
+        for word in (offset..end).step_by(4) {
+            let covered = self
+                .push_constant_ranges
+                .iter()
+                .filter(|range| range.offset <= word && word < range.offset + range.size)
+                .fold(vk::ShaderStageFlags::empty(), |stages, range| {
+                    stages | range.stage_flags
+                });
+            if !covered.contains(stage_flags) {
+                return Err(format!(
+                    "Byte {} of push constants is not in range of stages {:?} of attached pipeline layout.",
+                    word,
+                    stage_flags & !covered
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for AttachedPipelineLayout {
+    fn default() -> Self {
+        Self {
+            inner: vk::PipelineLayout::null(),
+            bind_point: vk::PipelineBindPoint::GRAPHICS,
+            push_constant_ranges: Arc::new([]),
+        }
+    }
+}
+
+/// Subpass of the render pass in which commands are recorded, used to validate commands which
+/// depend on it.
+#[derive(Clone, Copy)]
+pub struct ActiveSubpass {
+    pub subpass: u32,
+    pub subpass_count: u32,
+}
+
+#[derive(Clone, Copy)]
+struct SecondaryInheritance {
+    render_pass: vk::RenderPass,
+    rendering_formats: Option<RenderingFormats>,
+    subpass: u32,
+    active_subpass: Option<ActiveSubpass>,
+    framebuffer: vk::Framebuffer,
+    extent: vk::Extent2D,
+}
+
 pub struct VulkanCommandBuffer<'init: 'fam, 'fam> {
     initialized: &'init VulkanDeviceInitialized<'init>,
     inner: vk::CommandBuffer,
+    level: vk::CommandBufferLevel,
+    inheritance: Option<SecondaryInheritance>,
     queue_family: &'fam VulkanQueueFamily<'init>,
     command_pool: PoolItem<'fam, VulkanCommandPool<'init>>,
     used_fence: Option<Arc<VulkanFence<'init>>>,
+    pending_fences: Mutex<Vec<Arc<VulkanFence<'init>>>>,
+    is_executed: AtomicBool,
     attached_camera_windows: Vec<AttachCameraWindowOutput<'init, 'fam>>,
-    attached_pipeline_layout: (vk::PipelineLayout, vk::PipelineBindPoint),
+    attached_pipeline_layout: AttachedPipelineLayout,
+    dynamic_rendering_end: Option<DynamicRenderingEnd<'init>>,
+    active_subpass: Option<ActiveSubpass>,
+    secondaries: Vec<Arc<VulkanCommandBuffer<'init, 'fam>>>,
+    captured_stream: Option<Vec<u8>>,
     device: Arc<VulkanDevice<'init>>,
 }
 
@@ -47,6 +182,82 @@ impl<'dev: 'init, 'init: 'fam, 'fam> VulkanCommandBuffer<'init, 'fam> {
         data: SerializationReader,
         usage: VulkanDeviceSupport,
         simultaneous_execute: bool,
+    ) -> Result<Self, VulkanUniversalError> {
+        Self::new_worker(
+            device,
+            data,
+            usage,
+            simultaneous_execute,
+            vk::CommandBufferLevel::PRIMARY,
+            None,
+        )
+    }
+
+    pub fn new_secondary(
+        device: &'dev Arc<VulkanDevice<'init>>,
+        data: SerializationReader,
+        usage: VulkanDeviceSupport,
+        simultaneous_execute: bool,
+        inheritance: &VulkanCommandBufferInheritance<'init, '_>,
+    ) -> Result<Self, VulkanUniversalError> {
+        Self::new_worker(
+            device,
+            data,
+            usage,
+            simultaneous_execute,
+            vk::CommandBufferLevel::SECONDARY,
+            Some(inheritance),
+        )
+    }
+
+    /// Records streams in parallel on shared worker threads, each with a command pool taken from
+    /// the queue family.
+    pub fn new_secondary_parallel(
+        device: &'dev Arc<VulkanDevice<'init>>,
+        data: &[&[u8]],
+        usage: VulkanDeviceSupport,
+        simultaneous_execute: bool,
+        inheritance: &VulkanCommandBufferInheritance<'init, '_>,
+    ) -> Result<Vec<Self>, VulkanUniversalError> {
+        let results = WorkerPool::get().run_scoped(
+            data.iter()
+                .map(|stream| {
+                    move || {
+                        Self::new_secondary(
+                            device,
+                            SerializationReader::new(stream),
+                            usage,
+                            simultaneous_execute,
+                            inheritance,
+                        )
+                    }
+                })
+                .collect(),
+        );
+
+        let mut command_buffers = Vec::with_capacity(results.len());
+        for result in results {
+            match result {
+                Ok(result) => command_buffers.push(result?),
+                Err(_) => {
+                    return Err(InvalidOperationError::with_str(
+                        "Recording of secondary command buffer panicked.",
+                    )
+                    .into())
+                }
+            }
+        }
+
+        Ok(command_buffers)
+    }
+
+    fn new_worker(
+        device: &'dev Arc<VulkanDevice<'init>>,
+        data: SerializationReader,
+        usage: VulkanDeviceSupport,
+        simultaneous_execute: bool,
+        level: vk::CommandBufferLevel,
+        inheritance: Option<&VulkanCommandBufferInheritance<'init, '_>>,
     ) -> Result<Self, VulkanUniversalError> {
         let initialized = device.initialized()?;
         let queue_family = initialized.get_family(usage)?;
@@ -58,7 +269,7 @@ impl<'dev: 'init, 'init: 'fam, 'fam> VulkanCommandBuffer<'init, 'fam> {
             s_type: vk::StructureType::COMMAND_BUFFER_ALLOCATE_INFO,
             p_next: ptr::null(),
             command_pool: command_pool.inner(),
-            level: vk::CommandBufferLevel::PRIMARY,
+            level,
             command_buffer_count: 1,
         };
 
@@ -67,15 +278,38 @@ impl<'dev: 'init, 'init: 'fam, 'fam> VulkanCommandBuffer<'init, 'fam> {
         let mut result = VulkanCommandBuffer {
             initialized,
             inner: command_buffer,
+            level,
+            inheritance: inheritance.map(|inheritance| SecondaryInheritance {
+                render_pass: match inheritance.render_pass {
+                    Some(render_pass) => render_pass.inner(),
+                    None => vk::RenderPass::null(),
+                },
+                rendering_formats: inheritance
+                    .render_pass
+                    .and_then(|render_pass| render_pass.rendering_formats().copied()),
+                subpass: inheritance.subpass,
+                active_subpass: inheritance.render_pass.map(|render_pass| ActiveSubpass {
+                    subpass: inheritance.subpass,
+                    subpass_count: render_pass.subpasses().len() as u32,
+                }),
+                framebuffer: inheritance.framebuffer,
+                extent: inheritance.extent,
+            }),
             queue_family,
             command_pool,
             used_fence: None,
+            pending_fences: Mutex::new(Vec::new()),
+            is_executed: AtomicBool::new(false),
             attached_camera_windows: Vec::new(),
-            attached_pipeline_layout: (vk::PipelineLayout::null(), vk::PipelineBindPoint::GRAPHICS),
+            attached_pipeline_layout: AttachedPipelineLayout::default(),
+            dynamic_rendering_end: None,
+            active_subpass: None,
+            secondaries: Vec::new(),
+            captured_stream: None,
             device: device.clone(),
         };
 
-        result.record(data, simultaneous_execute)?;
+        result.record_worker(data, simultaneous_execute)?;
 
         Ok(result)
     }
@@ -84,14 +318,76 @@ impl<'dev: 'init, 'init: 'fam, 'fam> VulkanCommandBuffer<'init, 'fam> {
         self.inner
     }
 
-    pub fn attached_pipeline_layout(&self) -> (vk::PipelineLayout, vk::PipelineBindPoint) {
-        self.attached_pipeline_layout
+    pub fn level(&self) -> vk::CommandBufferLevel {
+        self.level
+    }
+
+    pub(crate) fn initialized(&self) -> &'init VulkanDeviceInitialized<'init> {
+        self.initialized
+    }
+
+    pub fn device(&self) -> &Arc<VulkanDevice<'init>> {
+        &self.device
+    }
+
+    pub fn queue_family(&self) -> &'fam VulkanQueueFamily<'init> {
+        self.queue_family
+    }
+
+    /// Keeps `secondaries` alive until this command buffer is reset or dropped, both of which wait
+    /// for its pending executions.
+    pub fn retain_secondaries(
+        &mut self,
+        secondaries: impl IntoIterator<Item = Arc<VulkanCommandBuffer<'init, 'fam>>>,
+    ) {
+        self.secondaries.extend(secondaries);
+    }
+
+    pub fn attached_pipeline_layout(&self) -> &AttachedPipelineLayout {
+        &self.attached_pipeline_layout
+    }
+
+    /// Returns subpass of the render pass of attached camera, or of the inherited render pass in
+    /// secondary command buffers. Returns `None` outside of render pass.
+    pub fn active_subpass(&self) -> Option<ActiveSubpass> {
+        self.active_subpass
+    }
+
+    pub fn set_active_subpass(&mut self, active_subpass: Option<ActiveSubpass>) {
+        self.active_subpass = active_subpass;
     }
 
-    pub fn execute(&self) -> Result<Arc<VulkanFence<'init>>, VulkanUniversalError> {
+    /// Submits this command buffer to the queue of its family. Submission waits for `waits` values
+    /// of timeline semaphores, which can be signaled by submissions to other queues, and signals
+    /// `signals` values after execution.
+    pub fn execute(
+        &self,
+        waits: &[GraphicsTimelineSemaphoreSubmit<'_, 'init>],
+        signals: &[GraphicsTimelineSemaphoreSubmit<'_, 'init>],
+    ) -> Result<Arc<VulkanFence<'init>>, VulkanUniversalError> {
+        if self.level != vk::CommandBufferLevel::PRIMARY {
+            return Err(InvalidOperationError::with_str(
+                "Secondary command buffer cannot be executed directly.",
+            )
+            .into());
+        }
+
+        // Swapchain images are acquired while recording, so they can be presented only once.
+        if self.is_executed.load(Ordering::Relaxed) && !self.attached_camera_windows.is_empty() {
+            return Err(InvalidOperationError::with_str(
+                "Command buffer with attached camera window must be recorded again before next execution.",
+            )
+            .into());
+        }
+
+        self.device.ensure_not_lost()?;
         let initialized = self.initialized;
         let vulkan_device = initialized.vulkan_device();
 
+        if let Some(stream) = &self.captured_stream {
+            capture::capture_if_enabled(initialized, stream);
+        }
+
         let mut wait_semaphores = Vec::new();
         let mut wait_stages = Vec::new();
         let mut signal_semaphores = Vec::new();
@@ -112,9 +408,38 @@ impl<'dev: 'init, 'init: 'fam, 'fam> VulkanCommandBuffer<'init, 'fam> {
             );
         }
 
+        // Values of binary semaphores are ignored.
+        let mut wait_values = vec![0; wait_semaphores.len()];
+        let mut signal_values = vec![0; signal_semaphores.len()];
+        let present_signal_count = signal_semaphores.len();
+
+        for wait in waits {
+            let semaphore = unsafe { VulkanTimelineSemaphore::from_submit(&self.device, wait) }?;
+            wait_semaphores.push(semaphore.inner());
+            wait_stages.push(vk::PipelineStageFlags::ALL_COMMANDS);
+            wait_values.push(wait.value);
+        }
+        for signal in signals {
+            let semaphore = unsafe { VulkanTimelineSemaphore::from_submit(&self.device, signal) }?;
+            signal_semaphores.push(semaphore.inner());
+            signal_values.push(signal.value);
+        }
+
+        let timeline_submit_info = vk::TimelineSemaphoreSubmitInfo {
+            s_type: vk::StructureType::TIMELINE_SEMAPHORE_SUBMIT_INFO,
+            p_next: ptr::null(),
+            wait_semaphore_value_count: wait_values.len() as u32,
+            p_wait_semaphore_values: wait_values.as_ptr(),
+            signal_semaphore_value_count: signal_values.len() as u32,
+            p_signal_semaphore_values: signal_values.as_ptr(),
+        };
+
         let submit_info = vk::SubmitInfo {
             s_type: vk::StructureType::SUBMIT_INFO,
-            p_next: ptr::null(),
+            p_next: match waits.is_empty() && signals.is_empty() {
+                true => ptr::null(),
+                false => &timeline_submit_info as *const _ as *const c_void,
+            },
             wait_semaphore_count: wait_semaphores.len() as u32,
             p_wait_semaphores: wait_semaphores.as_ptr(),
             p_wait_dst_stage_mask: wait_stages.as_ptr(),
@@ -129,13 +454,32 @@ impl<'dev: 'init, 'init: 'fam, 'fam> VulkanCommandBuffer<'init, 'fam> {
             None => Arc::new(initialized.pool().get_fence(&self.device)?),
         };
 
-        unsafe {
+        self.device.check_lost(unsafe {
             vulkan_device.queue_submit(
                 self.queue_family.get_queue().queue,
                 &[submit_info],
                 fence.inner(),
             )
-        }?;
+        })?;
+
+        // Flag is set only after successful submission, because failed execution does not present
+        // acquired swapchain images and they must be retired on reset.
+        self.is_executed.store(true, Ordering::Relaxed);
+
+        {
+            let mut pending_fences = self.lock_pending_fences()?;
+
+            let mut i = 0;
+            while i < pending_fences.len() {
+                if pending_fences[i].is_signaled()? {
+                    pending_fences.swap_remove(i);
+                } else {
+                    i += 1;
+                }
+            }
+
+            pending_fences.push(fence.clone());
+        }
 
         // Presentation.
         if !self.attached_camera_windows.is_empty() {
@@ -152,7 +496,7 @@ impl<'dev: 'init, 'init: 'fam, 'fam> VulkanCommandBuffer<'init, 'fam> {
             let present_info = vk::PresentInfoKHR {
                 s_type: vk::StructureType::PRESENT_INFO_KHR,
                 p_next: ptr::null(),
-                wait_semaphore_count: signal_semaphores.len() as u32,
+                wait_semaphore_count: present_signal_count as u32,
                 p_wait_semaphores: signal_semaphores.as_ptr(),
                 swapchain_count: swapchains.len() as u32,
                 p_swapchains: swapchains.as_ptr(),
@@ -166,10 +510,10 @@ impl<'dev: 'init, 'init: 'fam, 'fam> VulkanCommandBuffer<'init, 'fam> {
 
                 let _swapchain_lock = pass.lock_ash_swapchain()?;
 
-                _ = unsafe {
+                _ = self.device.check_lost(unsafe {
                     pass.ash_swapchain()
                         .queue_present(present_queue.queue, &present_info)
-                };
+                });
             }
 
             for output in &self.attached_camera_windows {
@@ -216,7 +560,53 @@ impl<'dev: 'init, 'init: 'fam, 'fam> VulkanCommandBuffer<'init, 'fam> {
         }
     }
 
-    fn record(
+    /// Waits for pending executions of this command buffer and resets it to the initial state.
+    pub fn reset(&mut self) -> Result<(), VulkanUniversalError> {
+        {
+            let mut pending_fences = self.lock_pending_fences()?;
+            for fence in pending_fences.iter() {
+                fence.wait(u64::MAX)?;
+            }
+            pending_fences.clear();
+        }
+
+        // Acquired swapchain images which were never presented must not block next frames.
+        if !self.is_executed.swap(false, Ordering::Relaxed) {
+            for output in &self.attached_camera_windows {
+                output.synchronized_fence.retire();
+            }
+        }
+
+        self.attached_camera_windows.clear();
+        self.used_fence = None;
+        self.attached_pipeline_layout = AttachedPipelineLayout::default();
+        self.dynamic_rendering_end = None;
+        self.active_subpass = None;
+        self.secondaries.clear();
+        self.captured_stream = None;
+
+        // Command pool is owned only by this command buffer, so it can be reset as a whole.
+        // https://arm-software.github.io/vulkan_best_practice_for_mobile_developers/samples/performance/command_buffer_usage/command_buffer_usage_tutorial.html#resetting-the-command-pool
+        unsafe {
+            self.initialized.vulkan_device().reset_command_pool(
+                self.command_pool.inner(),
+                vk::CommandPoolResetFlags::empty(),
+            )
+        }?;
+
+        Ok(())
+    }
+
+    pub fn record(
+        &mut self,
+        data: SerializationReader,
+        simultaneous_execute: bool,
+    ) -> Result<(), VulkanUniversalError> {
+        self.reset()?;
+        self.record_worker(data, simultaneous_execute)
+    }
+
+    fn record_worker(
         &mut self,
         mut data: SerializationReader,
         simultaneous_execute: bool,
@@ -224,82 +614,222 @@ impl<'dev: 'init, 'init: 'fam, 'fam> VulkanCommandBuffer<'init, 'fam> {
         let initialized = self.initialized;
         let vulkan_device = initialized.vulkan_device();
 
-        let mut begin_info_flags = vk::CommandBufferUsageFlags::ONE_TIME_SUBMIT;
+        // Stream is captured on execution, when referenced resources contain submitted data.
+        if self.level == vk::CommandBufferLevel::PRIMARY && capture::directory().is_some() {
+            self.captured_stream = Some(data.data[data.index..].to_vec());
+        }
+
+        let mut begin_info_flags = vk::CommandBufferUsageFlags::empty();
         if simultaneous_execute {
             begin_info_flags = vk::CommandBufferUsageFlags::SIMULTANEOUS_USE;
         }
 
+        let inheritance_rendering_info;
+        let inheritance_info;
+        let p_inheritance_info;
+        let mut render_pass_extent = None;
+
+        match &self.inheritance {
+            Some(inheritance) => {
+                if inheritance.render_pass != vk::RenderPass::null()
+                    || inheritance.rendering_formats.is_some()
+                {
+                    begin_info_flags |= vk::CommandBufferUsageFlags::RENDER_PASS_CONTINUE;
+                    render_pass_extent = Some(inheritance.extent);
+                }
+
+                inheritance_rendering_info = inheritance
+                    .rendering_formats
+                    .as_ref()
+                    .map(|formats| formats.inheritance_rendering_info());
+                inheritance_info = vk::CommandBufferInheritanceInfo {
+                    s_type: vk::StructureType::COMMAND_BUFFER_INHERITANCE_INFO,
+                    p_next: inheritance_rendering_info
+                        .as_ref()
+                        .map_or(ptr::null(), |info| info as *const _ as *const c_void),
+                    render_pass: inheritance.render_pass,
+                    subpass: inheritance.subpass,
+                    framebuffer: inheritance.framebuffer,
+                    occlusion_query_enable: vk::FALSE,
+                    query_flags: vk::QueryControlFlags::empty(),
+                    pipeline_statistics: vk::QueryPipelineStatisticFlags::empty(),
+                };
+                p_inheritance_info = &inheritance_info as *const vk::CommandBufferInheritanceInfo;
+            }
+            None => p_inheritance_info = ptr::null(),
+        }
+
         let begin_info = vk::CommandBufferBeginInfo {
             s_type: vk::StructureType::COMMAND_BUFFER_BEGIN_INFO,
             p_next: ptr::null(),
             flags: begin_info_flags,
-            p_inheritance_info: ptr::null(),
+            p_inheritance_info,
         };
 
         unsafe { vulkan_device.begin_command_buffer(self.inner, &begin_info) }?;
 
-        while let Some(command) = data.read::<GraphicsCommandBufferCommand>() {
-            match command {
-                GraphicsCommandBufferCommand::CopyBuffer => {
-                    memory_commands::copy_buffer(&mut data, self, vulkan_device)
-                }
-                GraphicsCommandBufferCommand::CopyBufferToTexture => {
-                    memory_commands::copy_buffer_to_texture(&mut data, self, vulkan_device)
-                }
-                GraphicsCommandBufferCommand::CopyTextureToBuffer => {
-                    memory_commands::copy_texture_to_buffer(&mut data, self, vulkan_device)
-                }
-                GraphicsCommandBufferCommand::Dispatch => {
-                    compute_commands::dispatch(&mut data, self, vulkan_device)
-                }
-                GraphicsCommandBufferCommand::AttachCameraWindow => {
-                    let used_fence = self.get_or_create_used_fence()?;
-                    self.attached_camera_windows
-                        .push(camera_commands::attach_camera_window(
-                            &mut data,
-                            self,
-                            vulkan_device,
-                            &used_fence,
-                        )?)
-                }
-                GraphicsCommandBufferCommand::AttachCameraTexture => {
-                    camera_commands::attach_camera_texture(&mut data, self, vulkan_device)
-                }
-                GraphicsCommandBufferCommand::DetachCamera => {
-                    camera_commands::detach_camera(self, vulkan_device)
-                }
-                GraphicsCommandBufferCommand::DrawMesh => {
-                    draw_commands::draw_mesh(&mut data, self, vulkan_device)
-                }
-                GraphicsCommandBufferCommand::AttachPipeline => {
-                    self.attached_pipeline_layout =
-                        misc_commands::attach_shader(&mut data, self, vulkan_device)
-                }
-                GraphicsCommandBufferCommand::AttachMaterial => {
-                    misc_commands::attach_material(&mut data, self, vulkan_device)
+        // Dynamic state is not inherited from the primary command buffer.
+        if let Some(extent) = render_pass_extent {
+            camera_commands::set_viewport_and_scissor(self, vulkan_device, extent);
+        }
+
+        data.checked = validation::checked_decoding();
+        self.active_subpass = self
+            .inheritance
+            .and_then(|inheritance| inheritance.active_subpass);
+
+        let mut command_index = 0;
+        while let Some(value) = data.read::<u16>() {
+            let offset = data.index - mem::size_of::<u16>();
+            let command = match GraphicsCommandBufferCommand::try_from(value) {
+                Ok(command) => command,
+                Err(value) => {
+                    return Err(InvalidCommandStreamError::new(
+                        SerializationError::new(format!("Unknown command {}.", value), offset),
+                        command_index,
+                    )
+                    .into())
                 }
             };
+
+            match self.record_command(command, &mut data, vulkan_device) {
+                Ok(()) => (),
+                Err(VulkanUniversalError::Serialization(err)) => {
+                    return Err(InvalidCommandStreamError::new(err, command_index).into())
+                }
+                Err(err) => return Err(err),
+            }
+
+            command_index += 1;
+        }
+
+        if data.checked && data.remaining() > 0 {
+            return Err(InvalidCommandStreamError::new(
+                SerializationError::with_str("Unexpected trailing data.", data.index),
+                command_index,
+            )
+            .into());
         }
 
         unsafe { vulkan_device.end_command_buffer(self.inner) }?;
 
         Ok(())
     }
+
+    fn record_command(
+        &mut self,
+        command: GraphicsCommandBufferCommand,
+        data: &mut SerializationReader,
+        vulkan_device: &ash::Device,
+    ) -> Result<(), VulkanUniversalError> {
+        match command {
+            GraphicsCommandBufferCommand::CopyBuffer => {
+                memory_commands::copy_buffer(data, self, vulkan_device)?
+            }
+            GraphicsCommandBufferCommand::CopyBufferToTexture => {
+                memory_commands::copy_buffer_to_texture(data, self, vulkan_device)?
+            }
+            GraphicsCommandBufferCommand::CopyTextureToBuffer => {
+                memory_commands::copy_texture_to_buffer(data, self, vulkan_device)?
+            }
+            GraphicsCommandBufferCommand::Dispatch => {
+                compute_commands::dispatch(data, self, vulkan_device)?
+            }
+            GraphicsCommandBufferCommand::AttachCameraWindow => {
+                self.assert_primary()?;
+                let used_fence = self.get_or_create_used_fence()?;
+                let (output, dynamic_rendering_end) =
+                    camera_commands::attach_camera_window(data, self, vulkan_device, &used_fence)?;
+                self.attached_camera_windows.push(output);
+                self.dynamic_rendering_end = dynamic_rendering_end;
+            }
+            GraphicsCommandBufferCommand::AttachCameraTexture => {
+                self.assert_primary()?;
+                self.dynamic_rendering_end =
+                    camera_commands::attach_camera_texture(data, self, vulkan_device)?
+            }
+            GraphicsCommandBufferCommand::DetachCamera => {
+                self.assert_primary()?;
+                let dynamic_rendering_end = self.dynamic_rendering_end.take();
+                camera_commands::detach_camera(data, self, vulkan_device, dynamic_rendering_end)?
+            }
+            GraphicsCommandBufferCommand::NextSubpass => {
+                self.assert_primary()?;
+                camera_commands::next_subpass(data, self, vulkan_device)?
+            }
+            GraphicsCommandBufferCommand::ResetQueries => {
+                query_commands::reset_queries(data, self, vulkan_device)?
+            }
+            GraphicsCommandBufferCommand::WriteTimestamp => {
+                query_commands::write_timestamp(data, self, vulkan_device)?
+            }
+            GraphicsCommandBufferCommand::BeginQuery => {
+                query_commands::begin_query(data, self, vulkan_device)?
+            }
+            GraphicsCommandBufferCommand::EndQuery => {
+                query_commands::end_query(data, self, vulkan_device)?
+            }
+            GraphicsCommandBufferCommand::CopyQueryResults => {
+                query_commands::copy_query_results(data, self, vulkan_device)?
+            }
+            GraphicsCommandBufferCommand::BeginLabel => debug_commands::begin_label(data, self)?,
+            GraphicsCommandBufferCommand::EndLabel => debug_commands::end_label(self),
+            GraphicsCommandBufferCommand::InsertLabel => debug_commands::insert_label(data, self)?,
+            GraphicsCommandBufferCommand::DrawMesh => {
+                draw_commands::draw_mesh(data, self, vulkan_device)?
+            }
+            GraphicsCommandBufferCommand::ExecuteSecondary => {
+                self.assert_primary()?;
+                misc_commands::execute_secondary(data, self, vulkan_device)?
+            }
+            GraphicsCommandBufferCommand::AttachPipeline => {
+                self.attached_pipeline_layout =
+                    misc_commands::attach_shader(data, self, vulkan_device)?
+            }
+            GraphicsCommandBufferCommand::AttachMaterial => {
+                misc_commands::attach_material(data, self, vulkan_device)?
+            }
+            GraphicsCommandBufferCommand::PushConstants => {
+                misc_commands::push_constants(data, self, vulkan_device)?
+            }
+        };
+
+        Ok(())
+    }
+
+    fn lock_pending_fences(
+        &self,
+    ) -> Result<MutexGuard<'_, Vec<Arc<VulkanFence<'init>>>>, InvalidOperationError> {
+        match self.pending_fences.lock() {
+            Ok(l) => Ok(l),
+            Err(_) => Err(InvalidOperationError::with_str(
+                "Another thread holding the mutex panicked.",
+            )),
+        }
+    }
+
+    fn assert_primary(&self) -> Result<(), InvalidOperationError> {
+        match self.level {
+            vk::CommandBufferLevel::PRIMARY => Ok(()),
+            _ => Err(InvalidOperationError::with_str(
+                "Command is not supported in secondary command buffer.",
+            )),
+        }
+    }
 }
 
 impl Drop for VulkanCommandBuffer<'_, '_> {
     fn drop(&mut self) {
-        let initialized = self.initialized;
-
-        // https://arm-software.github.io/vulkan_best_practice_for_mobile_developers/samples/performance/command_buffer_usage/command_buffer_usage_tutorial.html#allocate-and-free
-        /*unsafe {
-            initialized.vulkan_device().reset_command_pool(
-                self.command_pool.inner(), vk::CommandPoolResetFlags::empty()
-            )
-        }.unwrap();*/
+        // Retained secondary command buffers and the command buffer itself can be freed only
+        // after their executions are finished.
+        if let Ok(pending_fences) = self.pending_fences.get_mut() {
+            for fence in pending_fences.iter() {
+                _ = fence.wait(u64::MAX);
+            }
+        }
 
         unsafe {
-            initialized
+            self.initialized
                 .vulkan_device()
                 .free_command_buffers(self.command_pool.inner(), &[self.inner])
         }
@@ -307,10 +837,28 @@ impl Drop for VulkanCommandBuffer<'_, '_> {
 }
 
 impl<'init> GraphicsCommandBuffer<'init> for VulkanCommandBuffer<'init, '_> {
-    fn execute(&self) -> InteropResult<Box<Arc<dyn GraphicsFence + 'init>>> {
-        match self.execute() {
+    fn execute(
+        &self,
+        waits: &[GraphicsTimelineSemaphoreSubmit<'_, 'init>],
+        signals: &[GraphicsTimelineSemaphoreSubmit<'_, 'init>],
+    ) -> InteropResult<Box<Arc<dyn GraphicsFence + 'init>>> {
+        match self.execute(waits, signals) {
             Ok(fence) => InteropResult::with_ok(Box::new(fence)),
             Err(err) => InteropResult::with_err(err.into()),
         }
     }
+
+    fn record(&mut self, data: &[u8], simultaneous_execute: bool) -> InteropResult<()> {
+        match self.record(SerializationReader::new(data), simultaneous_execute) {
+            Ok(()) => InteropResult::with_ok(()),
+            Err(err) => InteropResult::with_err(err.into()),
+        }
+    }
+
+    fn set_name(&self, name: &str) -> InteropResult<()> {
+        match self.initialized.set_object_name(self.inner, name) {
+            Ok(()) => InteropResult::with_ok(()),
+            Err(err) => InteropResult::with_err(err.into()),
+        }
+    }
 }
@@ -1,10 +1,12 @@
-use std::{ptr, sync::Arc};
+use std::{mem, ptr, sync::Arc};
 
 use ash::vk;
 
 use crate::{
+    errors::serialization::SerializationError,
     rendering::vulkan::{
-        buffers::command_buffer::VulkanCommandBuffer,
+        buffers::command_buffer::{ActiveSubpass, VulkanCommandBuffer},
+        dynamic_rendering::{self, DynamicRendering, RenderingAttachmentView},
         errors::universal::VulkanUniversalError,
         fence::VulkanFence,
         framebuffer::Framebuffer,
@@ -15,6 +17,8 @@ use crate::{
     serialization::reader::SerializationReader,
 };
 
+use super::decoding;
+
 pub struct AttachCameraWindowOutput<'init: 'fam, 'fam> {
     pub pass: Arc<SwapchainPass<'init, 'fam>>,
     pub synchronized_fence: Arc<VulkanSynchronizedFence<'init>>,
@@ -22,42 +26,63 @@ pub struct AttachCameraWindowOutput<'init: 'fam, 'fam> {
     pub image_index: u32,
 }
 
+/// Layout transitions of attachments after dynamic rendering, which are otherwise done by render
+/// pass object.
+pub struct DynamicRenderingEnd<'init> {
+    dynamic_rendering: &'init DynamicRendering,
+    barriers: Vec<vk::ImageMemoryBarrier>,
+}
+
+// Barriers are created without extension structures, so they do not point to any memory.
+unsafe impl Send for DynamicRenderingEnd<'_> {}
+unsafe impl Sync for DynamicRenderingEnd<'_> {}
+
 pub fn attach_camera_window<'init: 'fam, 'fam>(
     data: &mut SerializationReader,
-    buffer: &VulkanCommandBuffer,
+    buffer: &mut VulkanCommandBuffer<'init, '_>,
     vulkan_device: &ash::Device,
     used_fence: &Arc<VulkanFence<'init>>,
-) -> Result<AttachCameraWindowOutput<'init, 'fam>, VulkanUniversalError> {
-    let render_pass = data.read_unchecked::<&Arc<RenderPass>>();
-    let swapchain = data.read_unchecked::<&Arc<Swapchain>>();
+) -> Result<
+    (
+        AttachCameraWindowOutput<'init, 'fam>,
+        Option<DynamicRenderingEnd<'init>>,
+    ),
+    VulkanUniversalError,
+> {
+    let render_pass = data.try_read_ref::<Arc<RenderPass>>()?;
+    let swapchain = data.try_read_ref::<Arc<Swapchain>>()?;
 
     let (pass, synchronized_fence, frame_index, image_index) =
         swapchain.get_swapchain_pass_and_accquire_next_image(render_pass, used_fence)?;
     let framebuffer = pass.get_framebuffer(image_index);
 
-    attach_camera_worker(
+    let dynamic_rendering_end = attach_camera_worker(
         data,
         buffer,
         vulkan_device,
         render_pass,
         framebuffer.inner(),
+        framebuffer.attachment_views(),
         framebuffer.extent(),
-    );
-
-    Ok(AttachCameraWindowOutput {
-        pass,
-        synchronized_fence,
-        frame_index,
-        image_index,
-    })
+    )?;
+
+    Ok((
+        AttachCameraWindowOutput {
+            pass,
+            synchronized_fence,
+            frame_index,
+            image_index,
+        },
+        dynamic_rendering_end,
+    ))
 }
 
-pub fn attach_camera_texture(
+pub fn attach_camera_texture<'init>(
     data: &mut SerializationReader,
-    buffer: &VulkanCommandBuffer,
+    buffer: &mut VulkanCommandBuffer<'init, '_>,
     vulkan_device: &ash::Device,
-) {
-    let framebuffer = data.read_unchecked::<&Framebuffer>();
+) -> Result<Option<DynamicRenderingEnd<'init>>, SerializationError> {
+    let framebuffer = data.try_read_ref::<Framebuffer>()?;
 
     attach_camera_worker(
         data,
@@ -65,58 +90,384 @@ pub fn attach_camera_texture(
         vulkan_device,
         framebuffer.render_pass(),
         framebuffer.inner(),
+        framebuffer.attachment_views(),
         framebuffer.extent(),
-    );
+    )
 }
 
-pub fn detach_camera(buffer: &VulkanCommandBuffer, vulkan_device: &ash::Device) {
-    unsafe { vulkan_device.cmd_end_render_pass(buffer.inner()) };
+/// Ends render pass of attached camera, which must be in its last subpass.
+pub fn detach_camera(
+    data: &SerializationReader,
+    buffer: &mut VulkanCommandBuffer,
+    vulkan_device: &ash::Device,
+    dynamic_rendering_end: Option<DynamicRenderingEnd>,
+) -> Result<(), SerializationError> {
+    match buffer.active_subpass() {
+        None => {
+            return Err(SerializationError::with_str(
+                "Camera cannot be detached, because no camera is attached.",
+                data.index,
+            ))
+        }
+        Some(active) if active.subpass + 1 < active.subpass_count => {
+            return Err(SerializationError::new(
+                format!(
+                    "Camera cannot be detached in subpass {}, render pass has {} subpasses.",
+                    active.subpass, active.subpass_count
+                ),
+                data.index,
+            ))
+        }
+        Some(_) => buffer.set_active_subpass(None),
+    }
+
+    let end = match dynamic_rendering_end {
+        Some(end) => end,
+        None => {
+            unsafe { vulkan_device.cmd_end_render_pass(buffer.inner()) };
+            return Ok(());
+        }
+    };
+
+    unsafe {
+        end.dynamic_rendering
+            .cmd_end_rendering(vulkan_device, buffer.inner());
+        vulkan_device.cmd_pipeline_barrier(
+            buffer.inner(),
+            vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT
+                | vk::PipelineStageFlags::LATE_FRAGMENT_TESTS,
+            vk::PipelineStageFlags::ALL_COMMANDS,
+            vk::DependencyFlags::empty(),
+            &[],
+            &[],
+            &end.barriers,
+        );
+    }
+
+    Ok(())
 }
 
-fn attach_camera_worker(
+pub fn next_subpass(
     data: &mut SerializationReader,
-    buffer: &VulkanCommandBuffer,
+    buffer: &mut VulkanCommandBuffer,
+    vulkan_device: &ash::Device,
+) -> Result<(), SerializationError> {
+    let offset = data.index;
+    let contents = read_subpass_contents(data)?;
+
+    let active = match buffer.active_subpass() {
+        Some(active) if active.subpass + 1 < active.subpass_count => active,
+        Some(active) => {
+            return Err(SerializationError::new(
+                format!(
+                "Render pass of attached camera has {} subpasses, next subpass {} does not exist.",
+                active.subpass_count,
+                active.subpass + 1
+            ),
+                offset,
+            ))
+        }
+        None => {
+            return Err(SerializationError::with_str(
+                "Next subpass requires attached camera.",
+                offset,
+            ))
+        }
+    };
+    buffer.set_active_subpass(Some(ActiveSubpass {
+        subpass: active.subpass + 1,
+        ..active
+    }));
+
+    unsafe { vulkan_device.cmd_next_subpass(buffer.inner(), contents) };
+    Ok(())
+}
+
+fn attach_camera_worker<'init>(
+    data: &mut SerializationReader,
+    buffer: &mut VulkanCommandBuffer<'init, '_>,
     vulkan_device: &ash::Device,
     render_pass: &Arc<RenderPass>,
     framebuffer: vk::Framebuffer,
+    attachment_views: &[RenderingAttachmentView],
     framebuffer_extent: vk::Extent2D,
-) {
+) -> Result<Option<DynamicRenderingEnd<'init>>, SerializationError> {
     let clear_color = vk::ClearValue {
-        color: *data.read_unchecked::<&vk::ClearColorValue>(),
+        color: *data.try_read_ref::<vk::ClearColorValue>()?,
     };
-
     let depth_stencil_clear = vk::ClearValue {
         depth_stencil: vk::ClearDepthStencilValue {
-            depth: 1.0,
-            stencil: 0,
+            depth: data.try_read::<f32>()?,
+            stencil: data.try_read::<u32>()?,
         },
     };
 
-    let p_clear_values = [clear_color, depth_stencil_clear];
-    let render_pass_info = vk::RenderPassBeginInfo {
-        s_type: vk::StructureType::RENDER_PASS_BEGIN_INFO,
+    let contents = read_subpass_contents(data)?;
+
+    // Clear values of the following attachments, the rest is cleared with the clear color or with
+    // the depth stencil clear value.
+    let offset = data.index;
+    let count = data.try_read_count(mem::size_of::<vk::ClearValue>())?;
+    let attachments = render_pass.attachments();
+    if count >= attachments.len() {
+        return Err(SerializationError::new(
+            format!(
+                "Camera was given {} additional clear values, but render pass has {} attachments.",
+                count,
+                attachments.len()
+            ),
+            offset,
+        ));
+    }
+
+    let mut p_clear_values = Vec::with_capacity(attachments.len());
+    p_clear_values.push(clear_color);
+    for _ in 0..count {
+        p_clear_values.push(data.try_read::<vk::ClearValue>()?);
+    }
+    for attachment in &attachments[p_clear_values.len()..] {
+        p_clear_values.push(match is_depth_stencil_format(attachment.format) {
+            true => depth_stencil_clear,
+            false => clear_color,
+        });
+    }
+
+    let render_area = vk::Rect2D {
+        offset: vk::Offset2D { x: 0, y: 0 },
+        extent: framebuffer_extent,
+    };
+
+    let dynamic_rendering_end = match buffer.initialized().dynamic_rendering() {
+        Some(dynamic_rendering) if render_pass.is_dynamic() => Some(begin_dynamic_rendering(
+            buffer,
+            vulkan_device,
+            dynamic_rendering,
+            render_pass,
+            attachment_views,
+            &p_clear_values,
+            render_area,
+            contents,
+        )),
+        _ => {
+            let render_pass_info = vk::RenderPassBeginInfo {
+                s_type: vk::StructureType::RENDER_PASS_BEGIN_INFO,
+                p_next: ptr::null(),
+                render_pass: render_pass.inner(),
+                framebuffer,
+                render_area,
+                clear_value_count: p_clear_values.len() as u32,
+                p_clear_values: p_clear_values.as_ptr(),
+            };
+
+            unsafe {
+                vulkan_device.cmd_begin_render_pass(buffer.inner(), &render_pass_info, contents)
+            };
+            None
+        }
+    };
+
+    // Viewport and scissor are recorded in secondary command buffers instead.
+    if contents == vk::SubpassContents::INLINE {
+        set_viewport_and_scissor(buffer, vulkan_device, framebuffer_extent);
+    }
+
+    buffer.set_active_subpass(Some(ActiveSubpass {
+        subpass: 0,
+        subpass_count: render_pass.subpasses().len() as u32,
+    }));
+    Ok(dynamic_rendering_end)
+}
+
+/// Begins rendering of the only subpass of `render_pass` without render pass object. Attachments
+/// are transitioned to the same layouts as by the render pass object.
+#[allow(clippy::too_many_arguments)]
+fn begin_dynamic_rendering<'init>(
+    buffer: &VulkanCommandBuffer,
+    vulkan_device: &ash::Device,
+    dynamic_rendering: &'init DynamicRendering,
+    render_pass: &RenderPass,
+    attachment_views: &[RenderingAttachmentView],
+    clear_values: &[vk::ClearValue],
+    render_area: vk::Rect2D,
+    contents: vk::SubpassContents,
+) -> DynamicRenderingEnd<'init> {
+    let attachments = render_pass.attachments();
+
+    let mut begin_barriers = Vec::with_capacity(attachments.len());
+    let mut end_barriers = Vec::with_capacity(attachments.len());
+    for (attachment, view) in attachments.iter().zip(attachment_views) {
+        let (layout, access) = match is_depth_stencil_format(attachment.format) {
+            true => (
+                vk::ImageLayout::DEPTH_STENCIL_ATTACHMENT_OPTIMAL,
+                vk::AccessFlags::DEPTH_STENCIL_ATTACHMENT_READ
+                    | vk::AccessFlags::DEPTH_STENCIL_ATTACHMENT_WRITE,
+            ),
+            false => (
+                vk::ImageLayout::COLOR_ATTACHMENT_OPTIMAL,
+                vk::AccessFlags::COLOR_ATTACHMENT_READ | vk::AccessFlags::COLOR_ATTACHMENT_WRITE,
+            ),
+        };
+
+        let barrier = vk::ImageMemoryBarrier {
+            s_type: vk::StructureType::IMAGE_MEMORY_BARRIER,
+            p_next: ptr::null(),
+            src_access_mask: vk::AccessFlags::empty(),
+            dst_access_mask: access,
+            old_layout: attachment.initial_layout,
+            new_layout: layout,
+            src_queue_family_index: vk::QUEUE_FAMILY_IGNORED,
+            dst_queue_family_index: vk::QUEUE_FAMILY_IGNORED,
+            image: view.image,
+            subresource_range: view.subresource_range,
+        };
+        begin_barriers.push(barrier);
+        end_barriers.push(vk::ImageMemoryBarrier {
+            src_access_mask: access,
+            dst_access_mask: vk::AccessFlags::MEMORY_READ | vk::AccessFlags::MEMORY_WRITE,
+            old_layout: layout,
+            new_layout: attachment.final_layout,
+            ..barrier
+        });
+    }
+
+    let attachment_info = |index: u32| {
+        let index = index as usize;
+        vk::RenderingAttachmentInfo {
+            s_type: vk::StructureType::RENDERING_ATTACHMENT_INFO,
+            p_next: ptr::null(),
+            image_view: attachment_views[index].view,
+            image_layout: match is_depth_stencil_format(attachments[index].format) {
+                true => vk::ImageLayout::DEPTH_STENCIL_ATTACHMENT_OPTIMAL,
+                false => vk::ImageLayout::COLOR_ATTACHMENT_OPTIMAL,
+            },
+            resolve_mode: vk::ResolveModeFlags::NONE,
+            resolve_image_view: vk::ImageView::null(),
+            resolve_image_layout: vk::ImageLayout::UNDEFINED,
+            load_op: attachments[index].load_op,
+            store_op: attachments[index].store_op,
+            clear_value: clear_values[index],
+        }
+    };
+
+    // Multisampled color attachments are resolved into their resolve attachments.
+    let subpass = render_pass.rendering_subpass().unwrap();
+    let color_attachments: Vec<_> = subpass
+        .color_attachments
+        .iter()
+        .enumerate()
+        .map(|(i, &index)| {
+            if index == vk::ATTACHMENT_UNUSED {
+                return vk::RenderingAttachmentInfo::default();
+            }
+
+            match subpass.resolve_attachments.get(i) {
+                Some(&resolve) if resolve != vk::ATTACHMENT_UNUSED => vk::RenderingAttachmentInfo {
+                    resolve_mode: match dynamic_rendering::is_integer_format(
+                        attachments[index as usize].format,
+                    ) {
+                        true => vk::ResolveModeFlags::SAMPLE_ZERO,
+                        false => vk::ResolveModeFlags::AVERAGE,
+                    },
+                    resolve_image_view: attachment_views[resolve as usize].view,
+                    resolve_image_layout: vk::ImageLayout::COLOR_ATTACHMENT_OPTIMAL,
+                    ..attachment_info(index)
+                },
+                _ => attachment_info(index),
+            }
+        })
+        .collect();
+
+    let (depth_attachment, stencil_attachment) = match subpass.depth_stencil_attachment {
+        Some(index) => {
+            let depth_attachment = attachment_info(index);
+            let stencil_attachment = vk::RenderingAttachmentInfo {
+                load_op: attachments[index as usize].stencil_load_op,
+                store_op: attachments[index as usize].stencil_store_op,
+                ..depth_attachment
+            };
+            (depth_attachment, stencil_attachment)
+        }
+        None => Default::default(),
+    };
+
+    let formats = render_pass.rendering_formats().unwrap();
+    let rendering_info = vk::RenderingInfo {
+        s_type: vk::StructureType::RENDERING_INFO,
         p_next: ptr::null(),
-        render_pass: render_pass.inner(),
-        framebuffer,
-        render_area: vk::Rect2D {
-            offset: vk::Offset2D { x: 0, y: 0 },
-            extent: framebuffer_extent,
+        flags: match contents {
+            vk::SubpassContents::SECONDARY_COMMAND_BUFFERS => {
+                vk::RenderingFlags::CONTENTS_SECONDARY_COMMAND_BUFFERS
+            }
+            _ => vk::RenderingFlags::empty(),
+        },
+        render_area,
+        layer_count: 1,
+        view_mask: 0,
+        color_attachment_count: color_attachments.len() as u32,
+        p_color_attachments: color_attachments.as_ptr(),
+        p_depth_attachment: match formats.depth_format {
+            vk::Format::UNDEFINED => ptr::null(),
+            _ => &depth_attachment,
         },
-        clear_value_count: match render_pass.depth_testing() {
-            true => 2,
-            false => 1,
+        p_stencil_attachment: match formats.stencil_format {
+            vk::Format::UNDEFINED => ptr::null(),
+            _ => &stencil_attachment,
         },
-        p_clear_values: p_clear_values.as_ptr(),
     };
 
     unsafe {
-        vulkan_device.cmd_begin_render_pass(
+        vulkan_device.cmd_pipeline_barrier(
             buffer.inner(),
-            &render_pass_info,
+            vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT
+                | vk::PipelineStageFlags::EARLY_FRAGMENT_TESTS
+                | vk::PipelineStageFlags::LATE_FRAGMENT_TESTS,
+            vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT
+                | vk::PipelineStageFlags::EARLY_FRAGMENT_TESTS
+                | vk::PipelineStageFlags::LATE_FRAGMENT_TESTS,
+            vk::DependencyFlags::empty(),
+            &[],
+            &[],
+            &begin_barriers,
+        );
+        dynamic_rendering.cmd_begin_rendering(vulkan_device, buffer.inner(), &rendering_info);
+    }
+
+    DynamicRenderingEnd {
+        dynamic_rendering,
+        barriers: end_barriers,
+    }
+}
+
+fn read_subpass_contents(
+    data: &mut SerializationReader,
+) -> Result<vk::SubpassContents, SerializationError> {
+    decoding::read_enum(
+        data,
+        &[
             vk::SubpassContents::INLINE,
-        )
-    };
+            vk::SubpassContents::SECONDARY_COMMAND_BUFFERS,
+        ],
+    )
+}
+
+fn is_depth_stencil_format(format: vk::Format) -> bool {
+    matches!(
+        format,
+        vk::Format::D16_UNORM
+            | vk::Format::X8_D24_UNORM_PACK32
+            | vk::Format::D32_SFLOAT
+            | vk::Format::S8_UINT
+            | vk::Format::D16_UNORM_S8_UINT
+            | vk::Format::D24_UNORM_S8_UINT
+            | vk::Format::D32_SFLOAT_S8_UINT
+    )
+}
 
+pub fn set_viewport_and_scissor(
+    buffer: &VulkanCommandBuffer,
+    vulkan_device: &ash::Device,
+    framebuffer_extent: vk::Extent2D,
+) {
     let viewport = vk::Viewport {
         x: 0.0,
         y: 0.0,
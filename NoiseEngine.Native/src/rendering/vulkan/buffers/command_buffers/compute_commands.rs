@@ -1,4 +1,5 @@
 use crate::{
+    errors::serialization::SerializationError,
     rendering::vulkan::buffers::command_buffer::VulkanCommandBuffer,
     serialization::reader::SerializationReader,
 };
@@ -7,13 +8,14 @@ pub fn dispatch(
     data: &mut SerializationReader,
     buffer: &VulkanCommandBuffer,
     vulkan_device: &ash::Device,
-) {
+) -> Result<(), SerializationError> {
+    let group_count_x = data.try_read::<u32>()?;
+    let group_count_y = data.try_read::<u32>()?;
+    let group_count_z = data.try_read::<u32>()?;
+
     unsafe {
-        vulkan_device.cmd_dispatch(
-            buffer.inner(),
-            data.read_unchecked::<u32>(),
-            data.read_unchecked::<u32>(),
-            data.read_unchecked::<u32>(),
-        );
+        vulkan_device.cmd_dispatch(buffer.inner(), group_count_x, group_count_y, group_count_z);
     }
+
+    Ok(())
 }
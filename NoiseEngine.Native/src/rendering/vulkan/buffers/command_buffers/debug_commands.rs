@@ -0,0 +1,51 @@
+use std::ffi::CString;
+
+use crate::{
+    errors::serialization::SerializationError,
+    rendering::vulkan::buffers::command_buffer::VulkanCommandBuffer,
+    serialization::reader::SerializationReader,
+};
+
+pub fn begin_label(
+    data: &mut SerializationReader,
+    buffer: &VulkanCommandBuffer,
+) -> Result<(), SerializationError> {
+    let (name, color) = read_label(data)?;
+    if let Some(debug_utils) = buffer.initialized().debug_utils() {
+        debug_utils.begin_label(buffer.inner(), &name, color);
+    }
+
+    Ok(())
+}
+
+pub fn end_label(buffer: &VulkanCommandBuffer) {
+    if let Some(debug_utils) = buffer.initialized().debug_utils() {
+        debug_utils.end_label(buffer.inner());
+    }
+}
+
+pub fn insert_label(
+    data: &mut SerializationReader,
+    buffer: &VulkanCommandBuffer,
+) -> Result<(), SerializationError> {
+    let (name, color) = read_label(data)?;
+    if let Some(debug_utils) = buffer.initialized().debug_utils() {
+        debug_utils.insert_label(buffer.inner(), &name, color);
+    }
+
+    Ok(())
+}
+
+fn read_label(data: &mut SerializationReader) -> Result<(CString, [f32; 4]), SerializationError> {
+    let color = data.try_read::<[f32; 4]>()?;
+
+    let offset = data.index;
+    let length = data.try_read_count(1)?;
+    match CString::new(data.try_read_bytes(length)?) {
+        Ok(name) => Ok((name, color)),
+        Err(_) => Err(SerializationError::with_str(
+            "Label name contains null character.",
+            offset,
+        )),
+    }
+}
@@ -0,0 +1,40 @@
+use std::fmt::Debug;
+
+use ash::vk;
+
+use crate::{
+    errors::serialization::SerializationError, serialization::reader::SerializationReader,
+};
+
+pub fn read_handle<T: vk::Handle + Copy>(
+    data: &mut SerializationReader,
+) -> Result<T, SerializationError> {
+    let offset = data.index;
+    let handle = data.try_read::<T>()?;
+
+    if data.checked && handle.as_raw() == 0 {
+        return Err(SerializationError::new(
+            format!("{:?} handle cannot be null.", T::TYPE),
+            offset,
+        ));
+    }
+
+    Ok(handle)
+}
+
+pub fn read_enum<T: Copy + PartialEq + Debug>(
+    data: &mut SerializationReader,
+    valid: &[T],
+) -> Result<T, SerializationError> {
+    let offset = data.index;
+    let value = data.try_read::<T>()?;
+
+    if data.checked && !valid.contains(&value) {
+        return Err(SerializationError::new(
+            format!("Value {:?} is not one of {:?}.", value, valid),
+            offset,
+        ));
+    }
+
+    Ok(value)
+}
@@ -0,0 +1,71 @@
+use std::fmt::Write;
+
+use ash::vk;
+
+use crate::{
+    rendering::errors::invalid_command_stream::InvalidCommandStreamError,
+    serialization::reader::SerializationReader,
+};
+
+use super::stream::{self, StreamResourceKind, StreamValue};
+
+/// Returns text listing of command stream, with one line per command in form of
+/// `offset #index Command field=value, ...`. Fields of array elements are grouped into braces.
+/// Referenced objects are printed as pointers, except clear colors which are read with checked
+/// reference read and printed as their RGBA values, so the referenced colors must be alive.
+pub fn disassemble(data: &[u8]) -> Result<String, InvalidCommandStreamError> {
+    let mut result = String::new();
+
+    for command in stream::decode(data)? {
+        write!(
+            result,
+            "{:#06x} #{} {:?}",
+            command.offset, command.index, command.command
+        )
+        .unwrap();
+
+        let mut separator = " ";
+        let mut group = None;
+
+        for field in &command.fields {
+            let (prefix, name) = match field.name.split_once("].") {
+                Some((prefix, name)) => (Some(&field.name[..prefix.len() + 1]), name),
+                None => (None, field.name.as_str()),
+            };
+
+            if group.is_some() && group != prefix {
+                result.push('}');
+                group = None;
+            }
+
+            result.push_str(separator);
+            separator = ", ";
+
+            if let (Some(prefix), None) = (prefix, group) {
+                write!(result, "{}={{", prefix).unwrap();
+                group = Some(prefix);
+            }
+
+            if let StreamValue::Reference(StreamResourceKind::ClearColor, _) = field.value {
+                let mut reader = SerializationReader::new(data);
+                reader.checked = true;
+                reader.index = field.offset;
+
+                let color = reader
+                    .try_read_ref::<vk::ClearColorValue>()
+                    .map_err(|err| InvalidCommandStreamError::new(err, command.index))?;
+                let [r, g, b, a] = unsafe { color.float32 };
+                write!(result, "{}=({}, {}, {}, {})", name, r, g, b, a).unwrap();
+            } else {
+                write!(result, "{}={}", name, field.value).unwrap();
+            }
+        }
+
+        if group.is_some() {
+            result.push('}');
+        }
+        result.push('\n');
+    }
+
+    Ok(result)
+}
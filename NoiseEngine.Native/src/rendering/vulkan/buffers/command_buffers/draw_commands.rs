@@ -1,19 +1,22 @@
 use ash::vk;
 
 use crate::{
+    errors::serialization::SerializationError,
     rendering::vulkan::buffers::command_buffer::VulkanCommandBuffer,
     serialization::reader::SerializationReader,
 };
 
+use super::decoding;
+
 pub fn draw_mesh(
     data: &mut SerializationReader,
     buffer: &VulkanCommandBuffer,
     vulkan_device: &ash::Device,
-) {
-    let vertex_buffer = data.read_unchecked::<vk::Buffer>();
-    let index_buffer = data.read_unchecked::<vk::Buffer>();
-    let index_format = data.read_unchecked::<vk::IndexType>();
-    let index_buffer_count = data.read_unchecked::<u32>();
+) -> Result<(), SerializationError> {
+    let vertex_buffer = decoding::read_handle::<vk::Buffer>(data)?;
+    let index_buffer = decoding::read_handle::<vk::Buffer>(data)?;
+    let index_format = decoding::read_enum(data, &[vk::IndexType::UINT16, vk::IndexType::UINT32])?;
+    let index_buffer_count = data.try_read::<u32>()?;
 
     unsafe {
         vulkan_device.cmd_bind_vertex_buffers(buffer.inner(), 0, &[vertex_buffer], &[0]);
@@ -21,15 +24,23 @@ pub fn draw_mesh(
         vulkan_device.cmd_bind_index_buffer(buffer.inner(), index_buffer, 0, index_format);
     }
 
-    let push_constants_size = data.read_unchecked::<u32>();
+    // Inline push constants are kept for older streams, newer ones use PushConstants command.
+    let push_constants_offset = data.index;
+    let push_constants_size = data.try_read::<u32>()?;
     if push_constants_size > 0 {
-        let push_constants_data = data.read_bytes_unchecked(push_constants_size as usize);
+        let push_constants_data = data.try_read_bytes(push_constants_size as usize)?;
+        let layout = buffer.attached_pipeline_layout();
+        let stage_flags = layout.push_constant_stages(0, push_constants_size);
+
+        if let Err(message) = layout.check_push_constants(stage_flags, 0, push_constants_size) {
+            return Err(SerializationError::new(message, push_constants_offset));
+        }
 
         unsafe {
             vulkan_device.cmd_push_constants(
                 buffer.inner(),
-                buffer.attached_pipeline_layout().0,
-                vk::ShaderStageFlags::VERTEX,
+                layout.inner,
+                stage_flags,
                 0,
                 push_constants_data,
             );
@@ -39,4 +50,6 @@ pub fn draw_mesh(
     unsafe {
         vulkan_device.cmd_draw_indexed(buffer.inner(), index_buffer_count, 1, 0, 0, 0);
     }
+
+    Ok(())
 }
@@ -1,8 +1,9 @@
-use std::sync::Arc;
+use std::{mem, sync::Arc};
 
 use ash::vk;
 
 use crate::{
+    errors::serialization::SerializationError,
     rendering::vulkan::{
         buffers::{buffer::VulkanBuffer, command_buffer::VulkanCommandBuffer},
         image::VulkanImage,
@@ -10,20 +11,28 @@ use crate::{
     serialization::reader::SerializationReader,
 };
 
+use super::decoding;
+
+pub(super) const BUFFER_IMAGE_COPY_SIZE: usize = mem::size_of::<vk::DeviceSize>()
+    + mem::size_of::<vk::ImageSubresourceLayers>()
+    + mem::size_of::<vk::Offset3D>()
+    + mem::size_of::<vk::Extent3D>();
+
 pub fn copy_buffer(
     data: &mut SerializationReader,
     buffer: &VulkanCommandBuffer,
     vulkan_device: &ash::Device,
-) {
-    let source_buffer = data.read_unchecked::<&&VulkanBuffer>();
-    let destination_buffer = data.read_unchecked::<&&VulkanBuffer>();
+) -> Result<(), SerializationError> {
+    let source_buffer = data.try_read_ref::<Arc<VulkanBuffer>>()?;
+    let destination_buffer = data.try_read_ref::<Arc<VulkanBuffer>>()?;
 
-    let mut regions = Vec::with_capacity(data.read_unchecked::<i32>() as usize);
-    for _ in 0..regions.capacity() {
+    let count = data.try_read_count(mem::size_of::<vk::BufferCopy>())?;
+    let mut regions = Vec::with_capacity(count);
+    for _ in 0..count {
         regions.push(vk::BufferCopy {
-            src_offset: data.read_unchecked(),
-            dst_offset: data.read_unchecked(),
-            size: data.read_unchecked(),
+            src_offset: data.try_read()?,
+            dst_offset: data.try_read()?,
+            size: data.try_read()?,
         });
     }
 
@@ -35,19 +44,22 @@ pub fn copy_buffer(
             &regions,
         )
     };
+
+    Ok(())
 }
 
 pub fn copy_buffer_to_texture(
     data: &mut SerializationReader,
     buffer: &VulkanCommandBuffer,
     vulkan_device: &ash::Device,
-) {
-    let source_buffer = data.read_unchecked::<vk::Buffer>();
-    let destination_texture = data.read_unchecked::<&Arc<VulkanImage>>();
+) -> Result<(), SerializationError> {
+    let source_buffer = decoding::read_handle::<vk::Buffer>(data)?;
+    let destination_texture = data.try_read_ref::<Arc<VulkanImage>>()?;
 
-    let mut regions = Vec::with_capacity(data.read_unchecked::<i32>() as usize);
-    for _ in 0..regions.capacity() {
-        regions.push(read_unchecked_buffer_image_copy(data));
+    let count = data.try_read_count(BUFFER_IMAGE_COPY_SIZE)?;
+    let mut regions = Vec::with_capacity(count);
+    for _ in 0..count {
+        regions.push(read_buffer_image_copy(data)?);
     }
 
     unsafe {
@@ -59,19 +71,22 @@ pub fn copy_buffer_to_texture(
             &regions,
         )
     };
+
+    Ok(())
 }
 
 pub fn copy_texture_to_buffer(
     data: &mut SerializationReader,
     buffer: &VulkanCommandBuffer,
     vulkan_device: &ash::Device,
-) {
-    let source_texture = data.read_unchecked::<&Arc<VulkanImage>>();
-    let destination_buffer = data.read_unchecked::<vk::Buffer>();
+) -> Result<(), SerializationError> {
+    let source_texture = data.try_read_ref::<Arc<VulkanImage>>()?;
+    let destination_buffer = decoding::read_handle::<vk::Buffer>(data)?;
 
-    let mut regions = Vec::with_capacity(data.read_unchecked::<i32>() as usize);
-    for _ in 0..regions.capacity() {
-        regions.push(read_unchecked_buffer_image_copy(data));
+    let count = data.try_read_count(BUFFER_IMAGE_COPY_SIZE)?;
+    let mut regions = Vec::with_capacity(count);
+    for _ in 0..count {
+        regions.push(read_buffer_image_copy(data)?);
     }
 
     unsafe {
@@ -83,15 +98,19 @@ pub fn copy_texture_to_buffer(
             &regions,
         )
     };
+
+    Ok(())
 }
 
-fn read_unchecked_buffer_image_copy(data: &mut SerializationReader) -> vk::BufferImageCopy {
-    vk::BufferImageCopy {
-        buffer_offset: data.read_unchecked(),
+fn read_buffer_image_copy(
+    data: &mut SerializationReader,
+) -> Result<vk::BufferImageCopy, SerializationError> {
+    Ok(vk::BufferImageCopy {
+        buffer_offset: data.try_read()?,
         buffer_row_length: 0,
         buffer_image_height: 0,
-        image_subresource: data.read_unchecked(),
-        image_offset: data.read_unchecked(),
-        image_extent: data.read_unchecked(),
-    }
+        image_subresource: data.try_read()?,
+        image_offset: data.try_read()?,
+        image_extent: data.try_read()?,
+    })
 }
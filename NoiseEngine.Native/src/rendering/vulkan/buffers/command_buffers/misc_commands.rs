@@ -1,44 +1,156 @@
+use std::{mem, sync::Arc};
+
 use ash::vk;
 
 use crate::{
+    errors::{invalid_operation::InvalidOperationError, serialization::SerializationError},
     rendering::vulkan::{
-        buffers::command_buffer::VulkanCommandBuffer, descriptors::set::DescriptorSet,
+        buffers::command_buffer::{AttachedPipelineLayout, VulkanCommandBuffer},
+        descriptors::set::DescriptorSet,
+        errors::universal::VulkanUniversalError,
         pipeline::Pipeline,
     },
     serialization::reader::SerializationReader,
 };
 
+use super::decoding;
+
 pub fn attach_shader(
     data: &mut SerializationReader,
     buffer: &mut VulkanCommandBuffer,
     vulkan_device: &ash::Device,
-) -> (vk::PipelineLayout, vk::PipelineBindPoint) {
-    let bind_point = data.read_unchecked::<vk::PipelineBindPoint>();
-    let pipeline = data.read_unchecked::<&Pipeline>();
+) -> Result<AttachedPipelineLayout, SerializationError> {
+    let bind_point = decoding::read_enum(
+        data,
+        &[
+            vk::PipelineBindPoint::GRAPHICS,
+            vk::PipelineBindPoint::COMPUTE,
+        ],
+    )?;
+    let offset = data.index;
+    let pipeline = data.try_read_ref::<Pipeline>()?;
+
+    // Graphics pipeline can be used only in the subpass it was created for.
+    if let (vk::PipelineBindPoint::GRAPHICS, Some(create_info), Some(active)) = (
+        bind_point,
+        pipeline.graphics_create_info(),
+        buffer.active_subpass(),
+    ) {
+        if create_info.subpass != active.subpass {
+            return Err(SerializationError::new(
+                format!(
+                    "Pipeline was created for subpass {}, but subpass {} is active.",
+                    create_info.subpass, active.subpass
+                ),
+                offset,
+            ));
+        }
+    }
 
     unsafe {
         vulkan_device.cmd_bind_pipeline(buffer.inner(), bind_point, pipeline.inner());
     }
 
-    (pipeline.layout().inner(), bind_point)
+    Ok(AttachedPipelineLayout {
+        inner: pipeline.layout().inner(),
+        bind_point,
+        push_constant_ranges: pipeline.layout().push_constant_ranges().clone(),
+    })
 }
 
 pub fn attach_material(
     data: &mut SerializationReader,
     buffer: &mut VulkanCommandBuffer,
     vulkan_device: &ash::Device,
-) {
-    let descriptor_set = data.read_unchecked::<&DescriptorSet>();
-    let pipeline = buffer.attached_pipeline_layout();
+) -> Result<(), SerializationError> {
+    let descriptor_set = data.try_read_ref::<DescriptorSet>()?;
+    let layout = buffer.attached_pipeline_layout();
 
     unsafe {
         vulkan_device.cmd_bind_descriptor_sets(
             buffer.inner(),
-            pipeline.1,
-            pipeline.0,
+            layout.bind_point,
+            layout.inner,
             0,
             &[descriptor_set.inner()],
             &[],
         );
     }
+
+    Ok(())
+}
+
+pub fn push_constants(
+    data: &mut SerializationReader,
+    buffer: &VulkanCommandBuffer,
+    vulkan_device: &ash::Device,
+) -> Result<(), SerializationError> {
+    let command_offset = data.index;
+    let stage_flags = vk::ShaderStageFlags::from_raw(data.try_read::<u32>()?);
+    let offset = data.try_read::<u32>()?;
+    let size = data.try_read::<u32>()?;
+    let constants = data.try_read_bytes(size as usize)?;
+
+    let layout = buffer.attached_pipeline_layout();
+    if let Err(message) = layout.check_push_constants(stage_flags, offset, size) {
+        return Err(SerializationError::new(message, command_offset));
+    }
+
+    unsafe {
+        vulkan_device.cmd_push_constants(
+            buffer.inner(),
+            layout.inner,
+            stage_flags,
+            offset,
+            constants,
+        );
+    }
+
+    Ok(())
+}
+
+pub fn execute_secondary<'init: 'fam, 'fam>(
+    data: &mut SerializationReader,
+    buffer: &mut VulkanCommandBuffer<'init, 'fam>,
+    vulkan_device: &ash::Device,
+) -> Result<(), VulkanUniversalError> {
+    let count = data.try_read_count(mem::size_of::<*const VulkanCommandBuffer>())?;
+    let mut command_buffers = Vec::with_capacity(count);
+    let mut secondaries = Vec::with_capacity(count);
+
+    for _ in 0..count {
+        let offset = data.index;
+        let secondary = data.try_read_ref::<Arc<VulkanCommandBuffer<'init, 'fam>>>()?;
+
+        if secondary.level() != vk::CommandBufferLevel::SECONDARY {
+            return Err(SerializationError::with_str(
+                "Executed command buffer is not a secondary command buffer.",
+                offset,
+            )
+            .into());
+        }
+        if !Arc::ptr_eq(secondary.device(), buffer.device()) {
+            return Err(InvalidOperationError::with_str(
+                "Secondary command buffer is from a device other than primary command buffer.",
+            )
+            .into());
+        }
+        if secondary.queue_family().index() != buffer.queue_family().index() {
+            return Err(InvalidOperationError::with_str(
+                "Secondary command buffer is from a queue family other than primary command buffer.",
+            )
+            .into());
+        }
+
+        command_buffers.push(secondary.inner());
+        secondaries.push(secondary.clone());
+    }
+
+    unsafe {
+        vulkan_device.cmd_execute_commands(buffer.inner(), &command_buffers);
+    }
+
+    // Secondary command buffers must be alive until executions of the primary one are completed.
+    buffer.retain_secondaries(secondaries);
+    Ok(())
 }
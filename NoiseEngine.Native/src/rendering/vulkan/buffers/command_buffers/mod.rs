@@ -1,5 +1,10 @@
 pub(super) mod camera_commands;
 pub(super) mod compute_commands;
+pub(super) mod debug_commands;
+mod decoding;
+pub(crate) mod disassembler;
 pub(super) mod draw_commands;
 pub(super) mod memory_commands;
 pub(super) mod misc_commands;
+pub(super) mod query_commands;
+pub(crate) mod stream;
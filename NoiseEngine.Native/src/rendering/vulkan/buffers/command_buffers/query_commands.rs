@@ -0,0 +1,251 @@
+use std::mem;
+
+use ash::vk;
+
+use crate::{
+    errors::serialization::SerializationError,
+    rendering::vulkan::{
+        buffers::command_buffer::VulkanCommandBuffer, query_pool::VulkanQueryPool,
+    },
+    serialization::reader::SerializationReader,
+};
+
+use super::decoding;
+
+pub(super) const TIMESTAMP_STAGES: &[vk::PipelineStageFlags] = &[
+    vk::PipelineStageFlags::TOP_OF_PIPE,
+    vk::PipelineStageFlags::DRAW_INDIRECT,
+    vk::PipelineStageFlags::VERTEX_INPUT,
+    vk::PipelineStageFlags::VERTEX_SHADER,
+    vk::PipelineStageFlags::FRAGMENT_SHADER,
+    vk::PipelineStageFlags::EARLY_FRAGMENT_TESTS,
+    vk::PipelineStageFlags::LATE_FRAGMENT_TESTS,
+    vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT,
+    vk::PipelineStageFlags::COMPUTE_SHADER,
+    vk::PipelineStageFlags::TRANSFER,
+    vk::PipelineStageFlags::BOTTOM_OF_PIPE,
+];
+
+pub fn reset_queries(
+    data: &mut SerializationReader,
+    buffer: &VulkanCommandBuffer,
+    vulkan_device: &ash::Device,
+) -> Result<(), SerializationError> {
+    let command_offset = data.index;
+    let query_pool = data.try_read_ref::<VulkanQueryPool>()?;
+    let first = data.try_read::<u32>()?;
+    let count = data.try_read::<u32>()?;
+
+    if let Err(message) = query_pool.check_range(first, count) {
+        return Err(SerializationError::new(message, command_offset));
+    }
+
+    unsafe {
+        vulkan_device.cmd_reset_query_pool(buffer.inner(), query_pool.inner(), first, count);
+    }
+
+    Ok(())
+}
+
+pub fn write_timestamp(
+    data: &mut SerializationReader,
+    buffer: &VulkanCommandBuffer,
+    vulkan_device: &ash::Device,
+) -> Result<(), SerializationError> {
+    let command_offset = data.index;
+    let query_pool = read_query_pool(data, true)?;
+    let stage = decoding::read_enum(data, TIMESTAMP_STAGES)?;
+    let query = read_query(data, query_pool, command_offset)?;
+
+    let family = buffer.queue_family();
+    match family.timestamp_valid_bits() {
+        0 => {
+            return Err(SerializationError::new(
+                format!(
+                    "Timestamps are not supported by queue family {}.",
+                    family.index()
+                ),
+                command_offset,
+            ))
+        }
+        valid_bits => query_pool.use_timestamp_valid_bits(valid_bits),
+    }
+
+    unsafe {
+        vulkan_device.cmd_write_timestamp(buffer.inner(), stage, query_pool.inner(), query);
+    }
+
+    Ok(())
+}
+
+pub fn begin_query(
+    data: &mut SerializationReader,
+    buffer: &VulkanCommandBuffer,
+    vulkan_device: &ash::Device,
+) -> Result<(), SerializationError> {
+    let command_offset = data.index;
+    let query_pool = read_query_pool(data, false)?;
+    let query = read_query(data, query_pool, command_offset)?;
+
+    let flags_offset = data.index;
+    let flags = vk::QueryControlFlags::from_raw(data.try_read::<u32>()?);
+    if flags.contains(vk::QueryControlFlags::PRECISE) {
+        if query_pool.query_type() != vk::QueryType::OCCLUSION {
+            return Err(SerializationError::with_str(
+                "Only occlusion queries can be precise.",
+                flags_offset,
+            ));
+        }
+        if buffer
+            .initialized()
+            .enabled_features()
+            .occlusion_query_precise
+            == vk::FALSE
+        {
+            return Err(SerializationError::with_str(
+                "Precise occlusion queries are not supported by the device.",
+                flags_offset,
+            ));
+        }
+    }
+
+    unsafe {
+        vulkan_device.cmd_begin_query(buffer.inner(), query_pool.inner(), query, flags);
+    }
+
+    Ok(())
+}
+
+pub fn end_query(
+    data: &mut SerializationReader,
+    buffer: &VulkanCommandBuffer,
+    vulkan_device: &ash::Device,
+) -> Result<(), SerializationError> {
+    let command_offset = data.index;
+    let query_pool = read_query_pool(data, false)?;
+    let query = read_query(data, query_pool, command_offset)?;
+
+    unsafe {
+        vulkan_device.cmd_end_query(buffer.inner(), query_pool.inner(), query);
+    }
+
+    Ok(())
+}
+
+pub fn copy_query_results(
+    data: &mut SerializationReader,
+    buffer: &VulkanCommandBuffer,
+    vulkan_device: &ash::Device,
+) -> Result<(), SerializationError> {
+    let command_offset = data.index;
+    let query_pool = data.try_read_ref::<VulkanQueryPool>()?;
+    let first = data.try_read::<u32>()?;
+    let count = data.try_read::<u32>()?;
+    let destination_buffer = decoding::read_handle::<vk::Buffer>(data)?;
+    let destination_offset = data.try_read::<u64>()?;
+    let flags = vk::QueryResultFlags::from_raw(data.try_read::<u32>()?);
+
+    if let Err(message) = query_pool.check_range(first, count) {
+        return Err(SerializationError::new(message, command_offset));
+    }
+
+    // Results are tightly packed, availability is written after values of every query.
+    let mut values = query_pool.values_per_query() as u64;
+    if flags.contains(vk::QueryResultFlags::WITH_AVAILABILITY) {
+        values += 1;
+    }
+    let stride = values * mem::size_of::<u64>() as u64;
+
+    let description = match buffer
+        .initialized()
+        .get_buffer_description(destination_buffer)
+    {
+        Some(description) => description,
+        None => {
+            return Err(SerializationError::with_str(
+                "Destination buffer of query results does not exist.",
+                command_offset,
+            ))
+        }
+    };
+    if !description
+        .usage
+        .contains(vk::BufferUsageFlags::TRANSFER_DST)
+    {
+        return Err(SerializationError::with_str(
+            "Destination buffer of query results has no transfer destination usage.",
+            command_offset,
+        ));
+    }
+    if !destination_offset.is_multiple_of(mem::size_of::<u64>() as u64) {
+        return Err(SerializationError::new(
+            format!(
+                "Destination offset {} of query results must be a multiple of 8.",
+                destination_offset
+            ),
+            command_offset,
+        ));
+    }
+    if (count as u64)
+        .checked_mul(stride)
+        .and_then(|size| size.checked_add(destination_offset))
+        .is_none_or(|end| end > description.size)
+    {
+        return Err(SerializationError::new(
+            format!(
+                "Results of {} queries at offset {} exceed destination buffer size, which is {} bytes.",
+                count, destination_offset, description.size
+            ),
+            command_offset,
+        ));
+    }
+
+    unsafe {
+        vulkan_device.cmd_copy_query_pool_results(
+            buffer.inner(),
+            query_pool.inner(),
+            first,
+            count,
+            destination_buffer,
+            destination_offset,
+            stride,
+            flags | vk::QueryResultFlags::TYPE_64,
+        );
+    }
+
+    Ok(())
+}
+
+/// Reads query pool, which must be a timestamp pool when `timestamp` is `true`, otherwise it
+/// must not be.
+fn read_query_pool<'a>(
+    data: &mut SerializationReader,
+    timestamp: bool,
+) -> Result<&'a VulkanQueryPool<'a>, SerializationError> {
+    let offset = data.index;
+    let query_pool = data.try_read_ref::<VulkanQueryPool>()?;
+
+    if (query_pool.query_type() == vk::QueryType::TIMESTAMP) != timestamp {
+        return Err(SerializationError::new(
+            format!(
+                "{:?} query pool cannot be used by this command.",
+                query_pool.query_type()
+            ),
+            offset,
+        ));
+    }
+
+    Ok(query_pool)
+}
+
+fn read_query(
+    data: &mut SerializationReader,
+    query_pool: &VulkanQueryPool,
+    command_offset: usize,
+) -> Result<u32, SerializationError> {
+    let query = data.try_read::<u32>()?;
+    match query_pool.check_range(query, 1) {
+        Ok(()) => Ok(query),
+        Err(message) => Err(SerializationError::new(message, command_offset)),
+    }
+}
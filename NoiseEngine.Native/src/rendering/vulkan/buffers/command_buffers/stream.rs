@@ -0,0 +1,438 @@
+use std::{
+    fmt::{Debug, Display},
+    mem,
+};
+
+use ash::vk;
+
+use crate::{
+    errors::serialization::SerializationError,
+    rendering::{
+        buffers::command_buffers::command::GraphicsCommandBufferCommand,
+        errors::invalid_command_stream::InvalidCommandStreamError,
+    },
+    serialization::reader::SerializationReader,
+};
+
+use super::decoding;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StreamResourceKind {
+    Buffer,
+    Image,
+    RenderPass,
+    Swapchain,
+    Framebuffer,
+    Pipeline,
+    DescriptorSet,
+    ClearColor,
+    CommandBuffer,
+    QueryPool,
+}
+
+#[derive(Clone, Debug)]
+pub enum StreamValue<'a> {
+    /// Pointer to the native object, which is only valid in the process that wrote the stream.
+    Reference(StreamResourceKind, usize),
+    /// Raw Vulkan handle.
+    Handle(StreamResourceKind, u64),
+    Count(usize),
+    Enum(String, i32),
+    I32(i32),
+    U32(u32),
+    U64(u64),
+    F32(f32),
+    Bytes(&'a [u8]),
+    String(&'a str),
+}
+
+impl Display for StreamValue<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamValue::Reference(kind, pointer) => write!(f, "{:?}@{:#x}", kind, pointer),
+            StreamValue::Handle(kind, handle) => write!(f, "{:?}({:#x})", kind, handle),
+            StreamValue::Count(count) => write!(f, "{}", count),
+            StreamValue::Enum(name, raw) => write!(f, "{} ({})", name, raw),
+            StreamValue::I32(value) => write!(f, "{}", value),
+            StreamValue::U32(value) => write!(f, "{}", value),
+            StreamValue::U64(value) => write!(f, "{}", value),
+            StreamValue::F32(value) => write!(f, "{}", value),
+            StreamValue::String(value) => write!(f, "{:?}", value),
+            StreamValue::Bytes(bytes) => {
+                write!(f, "[")?;
+                for (i, byte) in bytes.iter().enumerate() {
+                    match i {
+                        0 => write!(f, "{:02x}", byte)?,
+                        _ => write!(f, " {:02x}", byte)?,
+                    }
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct StreamField<'a> {
+    pub name: String,
+    pub offset: usize,
+    pub value: StreamValue<'a>,
+}
+
+#[derive(Clone, Debug)]
+pub struct StreamCommand<'a> {
+    pub index: usize,
+    pub offset: usize,
+    pub command: GraphicsCommandBufferCommand,
+    pub fields: Vec<StreamField<'a>>,
+}
+
+/// Decodes whole command stream without touching referenced objects.
+pub fn decode(data: &[u8]) -> Result<Vec<StreamCommand<'_>>, InvalidCommandStreamError> {
+    let mut reader = SerializationReader::new(data);
+    reader.checked = true;
+
+    let mut commands = Vec::new();
+    while reader.remaining() > 0 {
+        let index = commands.len();
+        let offset = reader.index;
+
+        let command = match decode_command(&mut reader, index, offset) {
+            Ok(command) => command,
+            Err(err) => return Err(InvalidCommandStreamError::new(err, index)),
+        };
+        commands.push(command);
+    }
+
+    Ok(commands)
+}
+
+fn decode_command<'a>(
+    reader: &mut SerializationReader<'a>,
+    index: usize,
+    offset: usize,
+) -> Result<StreamCommand<'a>, SerializationError> {
+    let value = reader.try_read::<u16>()?;
+    let command = match GraphicsCommandBufferCommand::try_from(value) {
+        Ok(command) => command,
+        Err(value) => {
+            return Err(SerializationError::new(
+                format!("Unknown command {}.", value),
+                offset,
+            ))
+        }
+    };
+
+    let mut fields = StreamFields {
+        reader,
+        fields: Vec::new(),
+    };
+
+    // Must be kept in sync with decoders used by the command buffer recording.
+    match command {
+        GraphicsCommandBufferCommand::CopyBuffer => {
+            fields.reference("source", StreamResourceKind::Buffer)?;
+            fields.reference("destination", StreamResourceKind::Buffer)?;
+
+            let count = fields.count("region_count", mem::size_of::<vk::BufferCopy>())?;
+            for i in 0..count {
+                fields.u64(&format!("regions[{}].source_offset", i))?;
+                fields.u64(&format!("regions[{}].destination_offset", i))?;
+                fields.u64(&format!("regions[{}].size", i))?;
+            }
+        }
+        GraphicsCommandBufferCommand::CopyBufferToTexture => {
+            fields.handle::<vk::Buffer>("source", StreamResourceKind::Buffer)?;
+            fields.reference("destination", StreamResourceKind::Image)?;
+            fields.buffer_image_copies()?;
+        }
+        GraphicsCommandBufferCommand::CopyTextureToBuffer => {
+            fields.reference("source", StreamResourceKind::Image)?;
+            fields.handle::<vk::Buffer>("destination", StreamResourceKind::Buffer)?;
+            fields.buffer_image_copies()?;
+        }
+        GraphicsCommandBufferCommand::Dispatch => {
+            fields.u32("group_count_x")?;
+            fields.u32("group_count_y")?;
+            fields.u32("group_count_z")?;
+        }
+        GraphicsCommandBufferCommand::AttachCameraWindow => {
+            fields.reference("render_pass", StreamResourceKind::RenderPass)?;
+            fields.reference("swapchain", StreamResourceKind::Swapchain)?;
+            fields.camera()?;
+        }
+        GraphicsCommandBufferCommand::AttachCameraTexture => {
+            fields.reference("framebuffer", StreamResourceKind::Framebuffer)?;
+            fields.camera()?;
+        }
+        GraphicsCommandBufferCommand::DetachCamera => (),
+        GraphicsCommandBufferCommand::NextSubpass => fields.subpass_contents()?,
+        GraphicsCommandBufferCommand::ResetQueries => {
+            fields.reference("query_pool", StreamResourceKind::QueryPool)?;
+            fields.u32("first_query")?;
+            fields.u32("query_count")?;
+        }
+        GraphicsCommandBufferCommand::WriteTimestamp => {
+            fields.reference("query_pool", StreamResourceKind::QueryPool)?;
+            fields.enumeration("stage", super::query_commands::TIMESTAMP_STAGES)?;
+            fields.u32("query")?;
+        }
+        GraphicsCommandBufferCommand::BeginQuery => {
+            fields.reference("query_pool", StreamResourceKind::QueryPool)?;
+            fields.u32("query")?;
+            fields.u32("flags")?;
+        }
+        GraphicsCommandBufferCommand::EndQuery => {
+            fields.reference("query_pool", StreamResourceKind::QueryPool)?;
+            fields.u32("query")?;
+        }
+        GraphicsCommandBufferCommand::CopyQueryResults => {
+            fields.reference("query_pool", StreamResourceKind::QueryPool)?;
+            fields.u32("first_query")?;
+            fields.u32("query_count")?;
+            fields.handle::<vk::Buffer>("destination", StreamResourceKind::Buffer)?;
+            fields.u64("destination_offset")?;
+            fields.u32("flags")?;
+        }
+        GraphicsCommandBufferCommand::BeginLabel | GraphicsCommandBufferCommand::InsertLabel => {
+            fields.f32("color_r")?;
+            fields.f32("color_g")?;
+            fields.f32("color_b")?;
+            fields.f32("color_a")?;
+            fields.string("name")?;
+        }
+        GraphicsCommandBufferCommand::EndLabel => (),
+        GraphicsCommandBufferCommand::DrawMesh => {
+            fields.handle::<vk::Buffer>("vertex_buffer", StreamResourceKind::Buffer)?;
+            fields.handle::<vk::Buffer>("index_buffer", StreamResourceKind::Buffer)?;
+            fields.enumeration(
+                "index_format",
+                &[vk::IndexType::UINT16, vk::IndexType::UINT32],
+            )?;
+            fields.u32("index_count")?;
+
+            let size = fields.u32("push_constants_size")?;
+            if size > 0 {
+                fields.bytes("push_constants", size as usize)?;
+            }
+        }
+        GraphicsCommandBufferCommand::ExecuteSecondary => {
+            let count = fields.count("command_buffer_count", mem::size_of::<usize>())?;
+            for i in 0..count {
+                fields.reference(
+                    &format!("command_buffers[{}]", i),
+                    StreamResourceKind::CommandBuffer,
+                )?;
+            }
+        }
+        GraphicsCommandBufferCommand::AttachPipeline => {
+            fields.enumeration(
+                "bind_point",
+                &[
+                    vk::PipelineBindPoint::GRAPHICS,
+                    vk::PipelineBindPoint::COMPUTE,
+                ],
+            )?;
+            fields.reference("pipeline", StreamResourceKind::Pipeline)?;
+        }
+        GraphicsCommandBufferCommand::AttachMaterial => {
+            fields.reference("descriptor_set", StreamResourceKind::DescriptorSet)?;
+        }
+        GraphicsCommandBufferCommand::PushConstants => {
+            fields.shader_stage_flags("stage_flags")?;
+            fields.u32("offset")?;
+            let size = fields.u32("size")?;
+            fields.bytes("constants", size as usize)?;
+        }
+    }
+
+    Ok(StreamCommand {
+        index,
+        offset,
+        command,
+        fields: fields.fields,
+    })
+}
+
+struct StreamFields<'a, 'r> {
+    reader: &'r mut SerializationReader<'a>,
+    fields: Vec<StreamField<'a>>,
+}
+
+impl<'a> StreamFields<'a, '_> {
+    fn push(&mut self, name: &str, offset: usize, value: StreamValue<'a>) {
+        self.fields.push(StreamField {
+            name: name.to_owned(),
+            offset,
+            value,
+        });
+    }
+
+    fn reference(
+        &mut self,
+        name: &str,
+        kind: StreamResourceKind,
+    ) -> Result<(), SerializationError> {
+        let offset = self.reader.index;
+        let pointer = self.reader.try_read::<usize>()?;
+
+        if pointer == 0 {
+            return Err(SerializationError::with_str(
+                "Reference cannot be null.",
+                offset,
+            ));
+        }
+
+        self.push(name, offset, StreamValue::Reference(kind, pointer));
+        Ok(())
+    }
+
+    fn handle<T: vk::Handle + Copy>(
+        &mut self,
+        name: &str,
+        kind: StreamResourceKind,
+    ) -> Result<(), SerializationError> {
+        let offset = self.reader.index;
+        let handle = decoding::read_handle::<T>(self.reader)?;
+        self.push(name, offset, StreamValue::Handle(kind, handle.as_raw()));
+        Ok(())
+    }
+
+    fn count(&mut self, name: &str, element_size: usize) -> Result<usize, SerializationError> {
+        let offset = self.reader.index;
+        let count = self.reader.try_read_count(element_size)?;
+        self.push(name, offset, StreamValue::Count(count));
+        Ok(count)
+    }
+
+    fn enumeration<T: Copy + PartialEq + Debug>(
+        &mut self,
+        name: &str,
+        valid: &[T],
+    ) -> Result<(), SerializationError> {
+        let offset = self.reader.index;
+        let value = decoding::read_enum(self.reader, valid)?;
+
+        let raw = i32::from_le_bytes(
+            self.reader.data[offset..offset + mem::size_of::<i32>()]
+                .try_into()
+                .unwrap(),
+        );
+        self.push(name, offset, StreamValue::Enum(format!("{:?}", value), raw));
+        Ok(())
+    }
+
+    fn i32(&mut self, name: &str) -> Result<i32, SerializationError> {
+        let offset = self.reader.index;
+        let value = self.reader.try_read::<i32>()?;
+        self.push(name, offset, StreamValue::I32(value));
+        Ok(value)
+    }
+
+    fn shader_stage_flags(&mut self, name: &str) -> Result<(), SerializationError> {
+        let offset = self.reader.index;
+        let raw = self.reader.try_read::<u32>()?;
+        let flags = vk::ShaderStageFlags::from_raw(raw);
+        self.push(
+            name,
+            offset,
+            StreamValue::Enum(format!("{:?}", flags), raw as i32),
+        );
+        Ok(())
+    }
+
+    fn u32(&mut self, name: &str) -> Result<u32, SerializationError> {
+        let offset = self.reader.index;
+        let value = self.reader.try_read::<u32>()?;
+        self.push(name, offset, StreamValue::U32(value));
+        Ok(value)
+    }
+
+    fn u64(&mut self, name: &str) -> Result<u64, SerializationError> {
+        let offset = self.reader.index;
+        let value = self.reader.try_read::<u64>()?;
+        self.push(name, offset, StreamValue::U64(value));
+        Ok(value)
+    }
+
+    fn f32(&mut self, name: &str) -> Result<f32, SerializationError> {
+        let offset = self.reader.index;
+        let value = self.reader.try_read::<f32>()?;
+        self.push(name, offset, StreamValue::F32(value));
+        Ok(value)
+    }
+
+    fn bytes(&mut self, name: &str, length: usize) -> Result<(), SerializationError> {
+        let offset = self.reader.index;
+        self.reader.try_read_bytes(length)?;
+
+        let value = &self.reader.data[offset..offset + length];
+        self.push(name, offset, StreamValue::Bytes(value));
+        Ok(())
+    }
+
+    fn string(&mut self, name: &str) -> Result<(), SerializationError> {
+        let offset = self.reader.index;
+        let length = self.reader.try_read_count(1)?;
+        let start = self.reader.index;
+        self.reader.try_read_bytes(length)?;
+
+        match std::str::from_utf8(&self.reader.data[start..start + length]) {
+            Ok(value) => {
+                self.push(name, offset, StreamValue::String(value));
+                Ok(())
+            }
+            Err(_) => Err(SerializationError::with_str(
+                "String is not valid UTF-8.",
+                offset,
+            )),
+        }
+    }
+
+    fn camera(&mut self) -> Result<(), SerializationError> {
+        self.reference("clear_color", StreamResourceKind::ClearColor)?;
+        self.f32("clear_depth")?;
+        self.u32("clear_stencil")?;
+        self.subpass_contents()?;
+
+        let count = self.count("clear_value_count", mem::size_of::<vk::ClearValue>())?;
+        for i in 0..count {
+            self.bytes(
+                &format!("clear_values[{}]", i),
+                mem::size_of::<vk::ClearValue>(),
+            )?;
+        }
+        Ok(())
+    }
+
+    fn subpass_contents(&mut self) -> Result<(), SerializationError> {
+        self.enumeration(
+            "contents",
+            &[
+                vk::SubpassContents::INLINE,
+                vk::SubpassContents::SECONDARY_COMMAND_BUFFERS,
+            ],
+        )
+    }
+
+    fn buffer_image_copies(&mut self) -> Result<(), SerializationError> {
+        let count = self.count(
+            "region_count",
+            super::memory_commands::BUFFER_IMAGE_COPY_SIZE,
+        )?;
+        for i in 0..count {
+            self.u64(&format!("regions[{}].buffer_offset", i))?;
+            self.u32(&format!("regions[{}].aspect_mask", i))?;
+            self.u32(&format!("regions[{}].mip_level", i))?;
+            self.u32(&format!("regions[{}].base_array_layer", i))?;
+            self.u32(&format!("regions[{}].layer_count", i))?;
+            self.i32(&format!("regions[{}].offset_x", i))?;
+            self.i32(&format!("regions[{}].offset_y", i))?;
+            self.i32(&format!("regions[{}].offset_z", i))?;
+            self.u32(&format!("regions[{}].extent_width", i))?;
+            self.u32(&format!("regions[{}].extent_height", i))?;
+            self.u32(&format!("regions[{}].extent_depth", i))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,835 @@
+use std::{fs, mem, path::Path};
+
+use ash::vk;
+
+use crate::{
+    errors::{invalid_operation::InvalidOperationError, serialization::SerializationError},
+    rendering::{
+        camera_clear::CameraClearFlags,
+        vulkan::{
+            buffers::{
+                buffer::VulkanBufferDescription, command_buffers::stream::StreamResourceKind,
+            },
+            descriptors::set_layout::DescriptorSetLayoutBinding,
+            errors::universal::VulkanUniversalError,
+            graphics_pipeline_create_info::{
+                OwnedGraphicsPipelineCreateInfo, PipelineColorBlendAttachmentState,
+                PipelineDepthStencilState, PipelineMultisampleState, PipelineRasterizationState,
+            },
+            image::VulkanImageCreateInfo,
+            pipeline_shader_stage::PipelineShaderStageInfo,
+            render_pass::RenderPassCreateInfo,
+        },
+    },
+    serialization::reader::SerializationReader,
+};
+
+pub const EXTENSION: &str = "necapture";
+
+const MAGIC: &[u8; 8] = b"NECAPTUR";
+const VERSION: u32 = 4;
+
+pub enum CaptureResource {
+    Buffer {
+        description: VulkanBufferDescription,
+        contents: Option<Vec<u8>>,
+    },
+    Image {
+        create_info: VulkanImageCreateInfo,
+        contents: Option<Vec<u8>>,
+    },
+    RenderPass {
+        create_info: RenderPassCreateInfo,
+    },
+    Swapchain {
+        extent: vk::Extent2D,
+        format: vk::Format,
+    },
+    Framebuffer {
+        extent: vk::Extent2D,
+        render_pass: RenderPassCreateInfo,
+    },
+    ClearColor {
+        value: [u32; 4],
+    },
+    Pipeline(Box<CapturedPipeline>),
+    DescriptorSet {
+        layout: CapturedDescriptorSetLayout,
+        writes: Vec<CapturedDescriptorWrite>,
+    },
+    /// Resource which creation data is not retained, so it cannot be rebuilt.
+    Unsupported(StreamResourceKind),
+}
+
+impl CaptureResource {
+    pub fn kind(&self) -> StreamResourceKind {
+        match self {
+            CaptureResource::Buffer { .. } => StreamResourceKind::Buffer,
+            CaptureResource::Image { .. } => StreamResourceKind::Image,
+            CaptureResource::RenderPass { .. } => StreamResourceKind::RenderPass,
+            CaptureResource::Swapchain { .. } => StreamResourceKind::Swapchain,
+            CaptureResource::Framebuffer { .. } => StreamResourceKind::Framebuffer,
+            CaptureResource::ClearColor { .. } => StreamResourceKind::ClearColor,
+            CaptureResource::Pipeline(_) => StreamResourceKind::Pipeline,
+            CaptureResource::DescriptorSet { .. } => StreamResourceKind::DescriptorSet,
+            CaptureResource::Unsupported(kind) => *kind,
+        }
+    }
+}
+
+pub struct CapturedPipeline {
+    pub set_layouts: Vec<CapturedDescriptorSetLayout>,
+    pub push_constant_ranges: Vec<vk::PushConstantRange>,
+    pub stages: Vec<PipelineShaderStageInfo>,
+    pub flags: vk::PipelineCreateFlags,
+    /// Render pass and state of graphics pipeline, or `None` for compute pipeline.
+    pub graphics: Option<(RenderPassCreateInfo, OwnedGraphicsPipelineCreateInfo)>,
+}
+
+pub struct CapturedDescriptorSetLayout {
+    pub flags: vk::DescriptorSetLayoutCreateFlags,
+    pub bindings: Vec<DescriptorSetLayoutBinding>,
+}
+
+/// Buffer descriptor, which `buffer` is id of the captured buffer.
+pub struct CapturedDescriptorWrite {
+    pub binding: u32,
+    pub array_element: u32,
+    pub descriptor_type: vk::DescriptorType,
+    pub buffer: u64,
+    pub offset: u64,
+    pub range: u64,
+}
+
+/// Command stream in which every reference and handle is replaced by id of the resource.
+pub struct CaptureFile {
+    pub resources: Vec<(u64, CaptureResource)>,
+    pub stream: Vec<u8>,
+}
+
+impl CaptureFile {
+    pub fn get_resource(&self, id: u64) -> Option<&CaptureResource> {
+        self.resources
+            .iter()
+            .find(|(resource_id, _)| *resource_id == id)
+            .map(|(_, resource)| resource)
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), VulkanUniversalError> {
+        let mut writer = CaptureWriter { data: Vec::new() };
+
+        writer.bytes(MAGIC);
+        writer.u32(VERSION);
+        writer.u8(mem::size_of::<usize>() as u8);
+
+        writer.u32(self.resources.len() as u32);
+        for (id, resource) in &self.resources {
+            writer.u64(*id);
+            writer.resource(resource);
+        }
+
+        writer.u64(self.stream.len() as u64);
+        writer.bytes(&self.stream);
+
+        match fs::write(path, writer.data) {
+            Ok(()) => Ok(()),
+            Err(err) => Err(InvalidOperationError::new(format!(
+                "Unable to write capture file {}: {}",
+                path.display(),
+                err
+            ))
+            .into()),
+        }
+    }
+
+    pub fn read(path: &Path) -> Result<Self, VulkanUniversalError> {
+        let data = match fs::read(path) {
+            Ok(data) => data,
+            Err(err) => {
+                return Err(InvalidOperationError::new(format!(
+                    "Unable to read capture file {}: {}",
+                    path.display(),
+                    err
+                ))
+                .into())
+            }
+        };
+
+        Ok(Self::from_bytes(&data)?)
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Result<Self, SerializationError> {
+        let mut reader = SerializationReader::new(data);
+        reader.checked = true;
+
+        if reader.try_read_bytes(MAGIC.len())? != MAGIC {
+            return Err(SerializationError::with_str(
+                "File is not a command stream capture.",
+                0,
+            ));
+        }
+
+        let offset = reader.index;
+        let version = reader.try_read::<u32>()?;
+        if version != VERSION {
+            return Err(SerializationError::new(
+                format!("Unsupported capture version {}.", version),
+                offset,
+            ));
+        }
+
+        let offset = reader.index;
+        let pointer_size = reader.try_read::<u8>()?;
+        if pointer_size as usize != mem::size_of::<usize>() {
+            return Err(SerializationError::new(
+                format!(
+                    "Capture was written with {} byte pointers, but this platform uses {}.",
+                    pointer_size,
+                    mem::size_of::<usize>()
+                ),
+                offset,
+            ));
+        }
+
+        let count = reader.try_read::<u32>()?;
+        let mut resources = Vec::new();
+        for _ in 0..count {
+            let id = reader.try_read::<u64>()?;
+            resources.push((id, read_resource(&mut reader)?));
+        }
+
+        let length = reader.try_read::<u64>()? as usize;
+        let stream = reader.try_read_bytes(length)?.to_vec();
+
+        Ok(Self { resources, stream })
+    }
+}
+
+struct CaptureWriter {
+    data: Vec<u8>,
+}
+
+impl CaptureWriter {
+    fn u8(&mut self, value: u8) {
+        self.data.push(value);
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.data.extend_from_slice(&value.to_ne_bytes());
+    }
+
+    fn i32(&mut self, value: i32) {
+        self.data.extend_from_slice(&value.to_ne_bytes());
+    }
+
+    fn u64(&mut self, value: u64) {
+        self.data.extend_from_slice(&value.to_ne_bytes());
+    }
+
+    fn f32(&mut self, value: f32) {
+        self.data.extend_from_slice(&value.to_ne_bytes());
+    }
+
+    fn bytes(&mut self, value: &[u8]) {
+        self.data.extend_from_slice(value);
+    }
+
+    fn bool(&mut self, value: bool) {
+        self.u8(value as u8);
+    }
+
+    fn slice(&mut self, value: &[u8]) {
+        self.u64(value.len() as u64);
+        self.bytes(value);
+    }
+
+    fn contents(&mut self, contents: &Option<Vec<u8>>) {
+        match contents {
+            Some(contents) => {
+                self.u8(1);
+                self.u64(contents.len() as u64);
+                self.bytes(contents);
+            }
+            None => self.u8(0),
+        }
+    }
+
+    fn render_pass(&mut self, create_info: &RenderPassCreateInfo) {
+        self.i32(create_info.format.as_raw());
+        self.u32(create_info.sample_count.as_raw());
+        self.u32(create_info.clear_flags as u32);
+        self.i32(create_info.final_layout.as_raw());
+        self.u8(create_info.depth_testing as u8);
+        self.i32(create_info.depth_stencil_format.as_raw());
+        self.u32(create_info.depth_stencil_sample_count.as_raw());
+        self.u32(create_info.multisample_count.as_raw());
+        self.i32(create_info.depth_store_op.as_raw());
+        self.i32(create_info.stencil_load_op.as_raw());
+        self.i32(create_info.stencil_store_op.as_raw());
+    }
+
+    fn descriptor_set_layout(&mut self, layout: &CapturedDescriptorSetLayout) {
+        self.u32(layout.flags.as_raw());
+        self.u32(layout.bindings.len() as u32);
+        for binding in &layout.bindings {
+            self.u32(binding.binding);
+            self.i32(binding.descriptor_type.as_raw());
+            self.u32(binding.descriptor_count);
+            self.u32(binding.stage_flags.as_raw());
+        }
+    }
+
+    fn stencil_op_state(&mut self, state: &vk::StencilOpState) {
+        self.i32(state.fail_op.as_raw());
+        self.i32(state.pass_op.as_raw());
+        self.i32(state.depth_fail_op.as_raw());
+        self.i32(state.compare_op.as_raw());
+        self.u32(state.compare_mask);
+        self.u32(state.write_mask);
+        self.u32(state.reference);
+    }
+
+    fn graphics_pipeline(&mut self, create_info: &OwnedGraphicsPipelineCreateInfo) {
+        self.u32(create_info.vertex_input_binding_descriptions.len() as u32);
+        for binding in &create_info.vertex_input_binding_descriptions {
+            self.u32(binding.binding);
+            self.u32(binding.stride);
+            self.i32(binding.input_rate.as_raw());
+        }
+
+        self.u32(create_info.vertex_input_attribute_descriptions.len() as u32);
+        for attribute in &create_info.vertex_input_attribute_descriptions {
+            self.u32(attribute.location);
+            self.u32(attribute.binding);
+            self.i32(attribute.format.as_raw());
+            self.u32(attribute.offset);
+        }
+
+        self.i32(create_info.primitive_topology.as_raw());
+
+        let rasterization = &create_info.rasterization;
+        self.bool(rasterization.depth_clamp);
+        self.i32(rasterization.polygon_mode.as_raw());
+        self.u32(rasterization.cull_mode.as_raw());
+        self.i32(rasterization.front_face.as_raw());
+        self.bool(rasterization.depth_bias);
+        self.f32(rasterization.depth_bias_constant_factor);
+        self.f32(rasterization.depth_bias_clamp);
+        self.f32(rasterization.depth_bias_slope_factor);
+        self.f32(rasterization.line_width);
+
+        let depth_stencil = &create_info.depth_stencil;
+        self.bool(depth_stencil.depth_test);
+        self.bool(depth_stencil.depth_write);
+        self.i32(depth_stencil.depth_compare_op.as_raw());
+        self.bool(depth_stencil.depth_bounds_test);
+        self.bool(depth_stencil.stencil_test);
+        self.stencil_op_state(&depth_stencil.front);
+        self.stencil_op_state(&depth_stencil.back);
+        self.f32(depth_stencil.min_depth_bounds);
+        self.f32(depth_stencil.max_depth_bounds);
+
+        let multisample = &create_info.multisample;
+        self.bool(multisample.sample_shading);
+        self.f32(multisample.min_sample_shading);
+        self.bool(multisample.alpha_to_coverage);
+
+        self.u32(create_info.color_blend_attachments.len() as u32);
+        for attachment in &create_info.color_blend_attachments {
+            self.bool(attachment.blend);
+            self.i32(attachment.src_color_blend_factor.as_raw());
+            self.i32(attachment.dst_color_blend_factor.as_raw());
+            self.i32(attachment.color_blend_op.as_raw());
+            self.i32(attachment.src_alpha_blend_factor.as_raw());
+            self.i32(attachment.dst_alpha_blend_factor.as_raw());
+            self.i32(attachment.alpha_blend_op.as_raw());
+            self.u32(attachment.color_write_mask.as_raw());
+        }
+
+        for constant in create_info.blend_constants {
+            self.f32(constant);
+        }
+        self.u32(create_info.subpass);
+    }
+
+    fn pipeline(&mut self, pipeline: &CapturedPipeline) {
+        self.u32(pipeline.set_layouts.len() as u32);
+        for layout in &pipeline.set_layouts {
+            self.descriptor_set_layout(layout);
+        }
+
+        self.u32(pipeline.push_constant_ranges.len() as u32);
+        for range in &pipeline.push_constant_ranges {
+            self.u32(range.stage_flags.as_raw());
+            self.u32(range.offset);
+            self.u32(range.size);
+        }
+
+        self.u32(pipeline.stages.len() as u32);
+        for stage in &pipeline.stages {
+            self.u32(stage.stage.as_raw());
+            self.slice(&stage.code);
+            self.slice(stage.name.as_bytes());
+            self.u32(stage.specialization_map_entries.len() as u32);
+            for entry in &stage.specialization_map_entries {
+                self.u32(entry.constant_id);
+                self.u32(entry.offset);
+                self.u64(entry.size as u64);
+            }
+            self.slice(&stage.specialization_data);
+        }
+
+        self.u32(pipeline.flags.as_raw());
+        match &pipeline.graphics {
+            Some((render_pass, create_info)) => {
+                self.u8(1);
+                self.render_pass(render_pass);
+                self.graphics_pipeline(create_info);
+            }
+            None => self.u8(0),
+        }
+    }
+
+    fn resource(&mut self, resource: &CaptureResource) {
+        self.u8(resource_kind_to_u8(resource.kind()));
+        self.u8(matches!(resource, CaptureResource::Unsupported(_)) as u8);
+
+        match resource {
+            CaptureResource::Buffer {
+                description,
+                contents,
+            } => {
+                self.u64(description.size);
+                self.u32(description.usage.as_raw());
+                self.u8(description.map as u8);
+                self.contents(contents);
+            }
+            CaptureResource::Image {
+                create_info,
+                contents,
+            } => {
+                self.u32(create_info.flags.as_raw());
+                self.i32(create_info.image_type.as_raw());
+                self.u32(create_info.extent.width);
+                self.u32(create_info.extent.height);
+                self.u32(create_info.extent.depth);
+                self.i32(create_info.format.as_raw());
+                self.u32(create_info.mip_levels);
+                self.u32(create_info.array_layers);
+                self.u32(create_info.sample_count);
+                self.u8(create_info.linear as u8);
+                self.u32(create_info.usage.as_raw());
+                self.u8(create_info.concurrent as u8);
+                self.i32(create_info.layout.as_raw());
+                self.contents(contents);
+            }
+            CaptureResource::RenderPass { create_info } => self.render_pass(create_info),
+            CaptureResource::Swapchain { extent, format } => {
+                self.u32(extent.width);
+                self.u32(extent.height);
+                self.i32(format.as_raw());
+            }
+            CaptureResource::Framebuffer {
+                extent,
+                render_pass,
+            } => {
+                self.u32(extent.width);
+                self.u32(extent.height);
+                self.render_pass(render_pass);
+            }
+            CaptureResource::ClearColor { value } => {
+                for component in value {
+                    self.u32(*component);
+                }
+            }
+            CaptureResource::Pipeline(pipeline) => self.pipeline(pipeline),
+            CaptureResource::DescriptorSet { layout, writes } => {
+                self.descriptor_set_layout(layout);
+                self.u32(writes.len() as u32);
+                for write in writes {
+                    self.u32(write.binding);
+                    self.u32(write.array_element);
+                    self.i32(write.descriptor_type.as_raw());
+                    self.u64(write.buffer);
+                    self.u64(write.offset);
+                    self.u64(write.range);
+                }
+            }
+            CaptureResource::Unsupported(_) => (),
+        }
+    }
+}
+
+fn resource_kind_to_u8(kind: StreamResourceKind) -> u8 {
+    match kind {
+        StreamResourceKind::Buffer => 0,
+        StreamResourceKind::Image => 1,
+        StreamResourceKind::RenderPass => 2,
+        StreamResourceKind::Swapchain => 3,
+        StreamResourceKind::Framebuffer => 4,
+        StreamResourceKind::Pipeline => 5,
+        StreamResourceKind::DescriptorSet => 6,
+        StreamResourceKind::ClearColor => 7,
+        StreamResourceKind::CommandBuffer => 8,
+        StreamResourceKind::QueryPool => 9,
+    }
+}
+
+fn read_resource_kind(
+    reader: &mut SerializationReader,
+) -> Result<StreamResourceKind, SerializationError> {
+    let offset = reader.index;
+    Ok(match reader.try_read::<u8>()? {
+        0 => StreamResourceKind::Buffer,
+        1 => StreamResourceKind::Image,
+        2 => StreamResourceKind::RenderPass,
+        3 => StreamResourceKind::Swapchain,
+        4 => StreamResourceKind::Framebuffer,
+        5 => StreamResourceKind::Pipeline,
+        6 => StreamResourceKind::DescriptorSet,
+        7 => StreamResourceKind::ClearColor,
+        8 => StreamResourceKind::CommandBuffer,
+        9 => StreamResourceKind::QueryPool,
+        value => {
+            return Err(SerializationError::new(
+                format!("Unknown resource kind {}.", value),
+                offset,
+            ))
+        }
+    })
+}
+
+fn read_bool(reader: &mut SerializationReader) -> Result<bool, SerializationError> {
+    let offset = reader.index;
+    match reader.try_read::<u8>()? {
+        0 => Ok(false),
+        1 => Ok(true),
+        value => Err(SerializationError::new(
+            format!("Value {} is not a boolean.", value),
+            offset,
+        )),
+    }
+}
+
+fn read_contents(reader: &mut SerializationReader) -> Result<Option<Vec<u8>>, SerializationError> {
+    match read_bool(reader)? {
+        true => {
+            let length = reader.try_read::<u64>()? as usize;
+            Ok(Some(reader.try_read_bytes(length)?.to_vec()))
+        }
+        false => Ok(None),
+    }
+}
+
+fn read_render_pass(
+    reader: &mut SerializationReader,
+) -> Result<RenderPassCreateInfo, SerializationError> {
+    let format = vk::Format::from_raw(reader.try_read()?);
+    let sample_count = vk::SampleCountFlags::from_raw(reader.try_read()?);
+
+    let offset = reader.index;
+    let clear_flags = match reader.try_read::<u32>()? {
+        0 => CameraClearFlags::Undefined,
+        1 => CameraClearFlags::Nothing,
+        2 => CameraClearFlags::SolidColor,
+        value => {
+            return Err(SerializationError::new(
+                format!("Unknown camera clear flags {}.", value),
+                offset,
+            ))
+        }
+    };
+
+    Ok(RenderPassCreateInfo {
+        format,
+        sample_count,
+        clear_flags,
+        final_layout: vk::ImageLayout::from_raw(reader.try_read()?),
+        depth_testing: read_bool(reader)?,
+        depth_stencil_format: vk::Format::from_raw(reader.try_read()?),
+        depth_stencil_sample_count: vk::SampleCountFlags::from_raw(reader.try_read()?),
+        multisample_count: vk::SampleCountFlags::from_raw(reader.try_read()?),
+        depth_store_op: vk::AttachmentStoreOp::from_raw(reader.try_read()?),
+        stencil_load_op: vk::AttachmentLoadOp::from_raw(reader.try_read()?),
+        stencil_store_op: vk::AttachmentStoreOp::from_raw(reader.try_read()?),
+    })
+}
+
+fn read_slice(reader: &mut SerializationReader) -> Result<Vec<u8>, SerializationError> {
+    let length = reader.try_read::<u64>()? as usize;
+    Ok(reader.try_read_bytes(length)?.to_vec())
+}
+
+fn read_descriptor_set_layout(
+    reader: &mut SerializationReader,
+) -> Result<CapturedDescriptorSetLayout, SerializationError> {
+    let flags = vk::DescriptorSetLayoutCreateFlags::from_raw(reader.try_read()?);
+
+    let count = reader.try_read::<u32>()?;
+    let mut bindings = Vec::new();
+    for _ in 0..count {
+        bindings.push(DescriptorSetLayoutBinding {
+            binding: reader.try_read()?,
+            descriptor_type: vk::DescriptorType::from_raw(reader.try_read()?),
+            descriptor_count: reader.try_read()?,
+            stage_flags: vk::ShaderStageFlags::from_raw(reader.try_read()?),
+            immutable_samplers: false,
+        });
+    }
+
+    Ok(CapturedDescriptorSetLayout { flags, bindings })
+}
+
+fn read_stencil_op_state(
+    reader: &mut SerializationReader,
+) -> Result<vk::StencilOpState, SerializationError> {
+    Ok(vk::StencilOpState {
+        fail_op: vk::StencilOp::from_raw(reader.try_read()?),
+        pass_op: vk::StencilOp::from_raw(reader.try_read()?),
+        depth_fail_op: vk::StencilOp::from_raw(reader.try_read()?),
+        compare_op: vk::CompareOp::from_raw(reader.try_read()?),
+        compare_mask: reader.try_read()?,
+        write_mask: reader.try_read()?,
+        reference: reader.try_read()?,
+    })
+}
+
+fn read_graphics_pipeline(
+    reader: &mut SerializationReader,
+) -> Result<OwnedGraphicsPipelineCreateInfo, SerializationError> {
+    let count = reader.try_read::<u32>()?;
+    let mut vertex_input_binding_descriptions = Vec::new();
+    for _ in 0..count {
+        vertex_input_binding_descriptions.push(vk::VertexInputBindingDescription {
+            binding: reader.try_read()?,
+            stride: reader.try_read()?,
+            input_rate: vk::VertexInputRate::from_raw(reader.try_read()?),
+        });
+    }
+
+    let count = reader.try_read::<u32>()?;
+    let mut vertex_input_attribute_descriptions = Vec::new();
+    for _ in 0..count {
+        vertex_input_attribute_descriptions.push(vk::VertexInputAttributeDescription {
+            location: reader.try_read()?,
+            binding: reader.try_read()?,
+            format: vk::Format::from_raw(reader.try_read()?),
+            offset: reader.try_read()?,
+        });
+    }
+
+    let primitive_topology = vk::PrimitiveTopology::from_raw(reader.try_read()?);
+
+    let rasterization = PipelineRasterizationState {
+        depth_clamp: read_bool(reader)?,
+        polygon_mode: vk::PolygonMode::from_raw(reader.try_read()?),
+        cull_mode: vk::CullModeFlags::from_raw(reader.try_read()?),
+        front_face: vk::FrontFace::from_raw(reader.try_read()?),
+        depth_bias: read_bool(reader)?,
+        depth_bias_constant_factor: reader.try_read()?,
+        depth_bias_clamp: reader.try_read()?,
+        depth_bias_slope_factor: reader.try_read()?,
+        line_width: reader.try_read()?,
+    };
+
+    let depth_stencil = PipelineDepthStencilState {
+        depth_test: read_bool(reader)?,
+        depth_write: read_bool(reader)?,
+        depth_compare_op: vk::CompareOp::from_raw(reader.try_read()?),
+        depth_bounds_test: read_bool(reader)?,
+        stencil_test: read_bool(reader)?,
+        front: read_stencil_op_state(reader)?,
+        back: read_stencil_op_state(reader)?,
+        min_depth_bounds: reader.try_read()?,
+        max_depth_bounds: reader.try_read()?,
+    };
+
+    let multisample = PipelineMultisampleState {
+        sample_shading: read_bool(reader)?,
+        min_sample_shading: reader.try_read()?,
+        alpha_to_coverage: read_bool(reader)?,
+    };
+
+    let count = reader.try_read::<u32>()?;
+    let mut color_blend_attachments = Vec::new();
+    for _ in 0..count {
+        color_blend_attachments.push(PipelineColorBlendAttachmentState {
+            blend: read_bool(reader)?,
+            src_color_blend_factor: vk::BlendFactor::from_raw(reader.try_read()?),
+            dst_color_blend_factor: vk::BlendFactor::from_raw(reader.try_read()?),
+            color_blend_op: vk::BlendOp::from_raw(reader.try_read()?),
+            src_alpha_blend_factor: vk::BlendFactor::from_raw(reader.try_read()?),
+            dst_alpha_blend_factor: vk::BlendFactor::from_raw(reader.try_read()?),
+            alpha_blend_op: vk::BlendOp::from_raw(reader.try_read()?),
+            color_write_mask: vk::ColorComponentFlags::from_raw(reader.try_read()?),
+        });
+    }
+
+    Ok(OwnedGraphicsPipelineCreateInfo {
+        vertex_input_binding_descriptions,
+        vertex_input_attribute_descriptions,
+        primitive_topology,
+        rasterization,
+        depth_stencil,
+        multisample,
+        color_blend_attachments,
+        blend_constants: [
+            reader.try_read()?,
+            reader.try_read()?,
+            reader.try_read()?,
+            reader.try_read()?,
+        ],
+        subpass: reader.try_read()?,
+    })
+}
+
+fn read_pipeline(reader: &mut SerializationReader) -> Result<CapturedPipeline, SerializationError> {
+    let count = reader.try_read::<u32>()?;
+    let mut set_layouts = Vec::new();
+    for _ in 0..count {
+        set_layouts.push(read_descriptor_set_layout(reader)?);
+    }
+
+    let count = reader.try_read::<u32>()?;
+    let mut push_constant_ranges = Vec::new();
+    for _ in 0..count {
+        push_constant_ranges.push(vk::PushConstantRange {
+            stage_flags: vk::ShaderStageFlags::from_raw(reader.try_read()?),
+            offset: reader.try_read()?,
+            size: reader.try_read()?,
+        });
+    }
+
+    let count = reader.try_read::<u32>()?;
+    let mut stages = Vec::new();
+    for _ in 0..count {
+        let stage = vk::ShaderStageFlags::from_raw(reader.try_read()?);
+        let code = read_slice(reader)?.into();
+
+        let offset = reader.index;
+        let name = match String::from_utf8(read_slice(reader)?) {
+            Ok(name) => name,
+            Err(_) => {
+                return Err(SerializationError::with_str(
+                    "Shader stage name is not valid UTF-8.",
+                    offset,
+                ))
+            }
+        };
+
+        let entry_count = reader.try_read::<u32>()?;
+        let mut specialization_map_entries = Vec::new();
+        for _ in 0..entry_count {
+            specialization_map_entries.push(vk::SpecializationMapEntry {
+                constant_id: reader.try_read()?,
+                offset: reader.try_read()?,
+                size: reader.try_read::<u64>()? as usize,
+            });
+        }
+
+        stages.push(PipelineShaderStageInfo {
+            stage,
+            code,
+            name,
+            specialization_map_entries,
+            specialization_data: read_slice(reader)?,
+        });
+    }
+
+    let flags = vk::PipelineCreateFlags::from_raw(reader.try_read()?);
+    let graphics = match read_bool(reader)? {
+        true => Some((read_render_pass(reader)?, read_graphics_pipeline(reader)?)),
+        false => None,
+    };
+
+    Ok(CapturedPipeline {
+        set_layouts,
+        push_constant_ranges,
+        stages,
+        flags,
+        graphics,
+    })
+}
+
+fn read_resource(reader: &mut SerializationReader) -> Result<CaptureResource, SerializationError> {
+    let kind = read_resource_kind(reader)?;
+    if read_bool(reader)? {
+        return Ok(CaptureResource::Unsupported(kind));
+    }
+
+    Ok(match kind {
+        StreamResourceKind::Buffer => CaptureResource::Buffer {
+            description: VulkanBufferDescription {
+                size: reader.try_read()?,
+                usage: vk::BufferUsageFlags::from_raw(reader.try_read()?),
+                map: read_bool(reader)?,
+            },
+            contents: read_contents(reader)?,
+        },
+        StreamResourceKind::Image => CaptureResource::Image {
+            create_info: VulkanImageCreateInfo {
+                flags: vk::ImageCreateFlags::from_raw(reader.try_read()?),
+                image_type: vk::ImageType::from_raw(reader.try_read()?),
+                extent: vk::Extent3D {
+                    width: reader.try_read()?,
+                    height: reader.try_read()?,
+                    depth: reader.try_read()?,
+                },
+                format: vk::Format::from_raw(reader.try_read()?),
+                mip_levels: reader.try_read()?,
+                array_layers: reader.try_read()?,
+                sample_count: reader.try_read()?,
+                linear: read_bool(reader)?,
+                usage: vk::ImageUsageFlags::from_raw(reader.try_read()?),
+                concurrent: read_bool(reader)?,
+                layout: vk::ImageLayout::from_raw(reader.try_read()?),
+            },
+            contents: read_contents(reader)?,
+        },
+        StreamResourceKind::RenderPass => CaptureResource::RenderPass {
+            create_info: read_render_pass(reader)?,
+        },
+        StreamResourceKind::Swapchain => CaptureResource::Swapchain {
+            extent: vk::Extent2D {
+                width: reader.try_read()?,
+                height: reader.try_read()?,
+            },
+            format: vk::Format::from_raw(reader.try_read()?),
+        },
+        StreamResourceKind::Framebuffer => CaptureResource::Framebuffer {
+            extent: vk::Extent2D {
+                width: reader.try_read()?,
+                height: reader.try_read()?,
+            },
+            render_pass: read_render_pass(reader)?,
+        },
+        StreamResourceKind::ClearColor => CaptureResource::ClearColor {
+            value: [
+                reader.try_read()?,
+                reader.try_read()?,
+                reader.try_read()?,
+                reader.try_read()?,
+            ],
+        },
+        StreamResourceKind::Pipeline => CaptureResource::Pipeline(Box::new(read_pipeline(reader)?)),
+        StreamResourceKind::DescriptorSet => {
+            let layout = read_descriptor_set_layout(reader)?;
+
+            let count = reader.try_read::<u32>()?;
+            let mut writes = Vec::new();
+            for _ in 0..count {
+                writes.push(CapturedDescriptorWrite {
+                    binding: reader.try_read()?,
+                    array_element: reader.try_read()?,
+                    descriptor_type: vk::DescriptorType::from_raw(reader.try_read()?),
+                    buffer: reader.try_read()?,
+                    offset: reader.try_read()?,
+                    range: reader.try_read()?,
+                });
+            }
+
+            CaptureResource::DescriptorSet { layout, writes }
+        }
+        kind => CaptureResource::Unsupported(kind),
+    })
+}
@@ -0,0 +1,85 @@
+pub mod capture_file;
+pub mod recorder;
+pub mod replay;
+
+pub(crate) mod transfer;
+
+use std::{
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+};
+
+use crate::{
+    errors::invalid_operation::InvalidOperationError, logging::log,
+    rendering::buffers::command_buffers::command::GraphicsCommandBufferCommand,
+};
+
+use super::{buffers::command_buffers::stream::StreamCommand, device::VulkanDeviceInitialized};
+
+static DIRECTORY: Mutex<Option<PathBuf>> = Mutex::new(None);
+static NEXT_INDEX: AtomicU64 = AtomicU64::new(0);
+
+/// Returns directory into which recorded primary command buffers are captured.
+pub fn directory() -> Option<PathBuf> {
+    DIRECTORY.lock().unwrap().clone()
+}
+
+pub fn set_directory(directory: Option<PathBuf>) {
+    *DIRECTORY.lock().unwrap() = directory;
+}
+
+fn next_file_path(directory: PathBuf) -> PathBuf {
+    let index = NEXT_INDEX.fetch_add(1, Ordering::Relaxed);
+    directory.join(format!("capture_{:06}.{}", index, capture_file::EXTENSION))
+}
+
+/// Returns error when stream contains commands which cannot be replayed. Secondary command buffers
+/// and queries are not captured, so replay of such streams would produce different results.
+pub(crate) fn ensure_replayable(commands: &[StreamCommand]) -> Result<(), InvalidOperationError> {
+    let mut unsupported = Vec::new();
+    for command in commands {
+        let is_supported = !matches!(
+            command.command,
+            GraphicsCommandBufferCommand::ExecuteSecondary
+                | GraphicsCommandBufferCommand::ResetQueries
+                | GraphicsCommandBufferCommand::WriteTimestamp
+                | GraphicsCommandBufferCommand::BeginQuery
+                | GraphicsCommandBufferCommand::EndQuery
+                | GraphicsCommandBufferCommand::CopyQueryResults
+        );
+
+        if !is_supported && !unsupported.contains(&command.command) {
+            unsupported.push(command.command);
+        }
+    }
+
+    match unsupported.is_empty() {
+        true => Ok(()),
+        false => Err(InvalidOperationError::new(format!(
+            "Command stream cannot be replayed, because {:?} commands are not supported by capture.",
+            unsupported
+        ))),
+    }
+}
+
+/// Captures command stream to the capture directory, when it is set. Stream is captured when it is
+/// executed, so snapshots contain contents of resources from the moment of submission. Failures
+/// are only logged, because capturing must not break execution.
+pub(crate) fn capture_if_enabled<'init>(
+    initialized: &'init VulkanDeviceInitialized<'init>,
+    data: &[u8],
+) {
+    let directory = match directory() {
+        Some(directory) => directory,
+        None => return,
+    };
+
+    let path = next_file_path(directory);
+    match recorder::capture(initialized, data).and_then(|capture| capture.write(&path)) {
+        Ok(()) => log::info(format!("Captured command buffer to {}.", path.display()).as_str()),
+        Err(err) => log::warning(format!("Unable to capture command buffer: {}", err).as_str()),
+    }
+}
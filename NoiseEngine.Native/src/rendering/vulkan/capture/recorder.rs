@@ -0,0 +1,315 @@
+use std::{collections::HashMap, mem, sync::Arc};
+
+use ash::vk::{self, Handle};
+
+use crate::{
+    logging::log,
+    rendering::vulkan::{
+        buffers::{
+            buffer::VulkanBuffer,
+            command_buffers::stream::{self, StreamResourceKind, StreamValue},
+        },
+        descriptors::{set::DescriptorSet, set_layout::DescriptorSetLayout},
+        device::VulkanDeviceInitialized,
+        errors::universal::VulkanUniversalError,
+        framebuffer::Framebuffer,
+        image::VulkanImage,
+        pipeline::Pipeline,
+        render_pass::RenderPass,
+        swapchain::Swapchain,
+    },
+};
+
+use super::{
+    capture_file::{
+        CaptureFile, CaptureResource, CapturedDescriptorSetLayout, CapturedDescriptorWrite,
+        CapturedPipeline,
+    },
+    transfer,
+};
+
+/// Captures command stream with snapshots of all resources referenced by it. Pointers and handles
+/// in the stream are replaced by ids of the resources, which are assigned in order of first use.
+/// Buffers are identified by their handles, so the same buffer used by reference, by handle or by
+/// descriptor set has a single id.
+pub(crate) fn capture<'init>(
+    initialized: &'init VulkanDeviceInitialized<'init>,
+    data: &[u8],
+) -> Result<CaptureFile, VulkanUniversalError> {
+    let commands = stream::decode(data)?;
+    super::ensure_replayable(&commands)?;
+
+    let mut stream = data.to_vec();
+    let mut recorder = Recorder {
+        initialized,
+        ids: HashMap::new(),
+        resources: Vec::new(),
+    };
+
+    for command in &commands {
+        for field in &command.fields {
+            let id = match field.value {
+                StreamValue::Reference(kind, pointer) => recorder.reference(kind, pointer)?,
+                StreamValue::Handle(kind, handle) => recorder.handle(kind, handle)?,
+                _ => continue,
+            };
+
+            match field.value {
+                StreamValue::Reference(..) => stream
+                    [field.offset..field.offset + mem::size_of::<usize>()]
+                    .copy_from_slice(&(id as usize).to_ne_bytes()),
+                _ => stream[field.offset..field.offset + mem::size_of::<u64>()]
+                    .copy_from_slice(&id.to_ne_bytes()),
+            }
+        }
+    }
+
+    Ok(CaptureFile {
+        resources: recorder.resources,
+        stream,
+    })
+}
+
+struct Recorder<'init> {
+    initialized: &'init VulkanDeviceInitialized<'init>,
+    ids: HashMap<(StreamResourceKind, u64), u64>,
+    resources: Vec<(u64, CaptureResource)>,
+}
+
+impl<'init> Recorder<'init> {
+    /// Returns id of resource, which is snapshotted on its first use.
+    fn id(
+        &mut self,
+        kind: StreamResourceKind,
+        raw: u64,
+        snapshot: impl FnOnce(&mut Self) -> Result<CaptureResource, VulkanUniversalError>,
+    ) -> Result<u64, VulkanUniversalError> {
+        if let Some(id) = self.ids.get(&(kind, raw)) {
+            return Ok(*id);
+        }
+
+        // Snapshot might add resources used by this one, e.g. buffers of descriptor set.
+        let resource = snapshot(self)?;
+        let id = self.resources.len() as u64 + 1;
+
+        self.ids.insert((kind, raw), id);
+        self.resources.push((id, resource));
+        Ok(id)
+    }
+
+    fn reference(
+        &mut self,
+        kind: StreamResourceKind,
+        pointer: usize,
+    ) -> Result<u64, VulkanUniversalError> {
+        // Pointers were already dereferenced in the same way by the command buffer decoders.
+        match kind {
+            StreamResourceKind::Buffer => {
+                let buffer = unsafe { &*(pointer as *const Arc<VulkanBuffer>) };
+                self.id(kind, buffer.inner().as_raw(), |recorder| {
+                    recorder.snapshot_buffer(buffer)
+                })
+            }
+            StreamResourceKind::Pipeline => {
+                let pipeline = unsafe { &*(pointer as *const Pipeline) };
+                self.id(kind, pointer as u64, |_| Ok(snapshot_pipeline(pipeline)))
+            }
+            StreamResourceKind::DescriptorSet => {
+                let descriptor_set = unsafe { &*(pointer as *const DescriptorSet) };
+                self.id(kind, pointer as u64, |recorder| {
+                    recorder.snapshot_descriptor_set(descriptor_set)
+                })
+            }
+            _ => self.id(kind, pointer as u64, |recorder| {
+                snapshot_reference(recorder.initialized, kind, pointer)
+            }),
+        }
+    }
+
+    fn handle(
+        &mut self,
+        kind: StreamResourceKind,
+        handle: u64,
+    ) -> Result<u64, VulkanUniversalError> {
+        self.id(kind, handle, |recorder| {
+            if kind != StreamResourceKind::Buffer {
+                return Ok(CaptureResource::Unsupported(kind));
+            }
+
+            let buffer = vk::Buffer::from_raw(handle);
+            let description = match recorder.initialized.get_buffer_description(buffer) {
+                Some(description) => description,
+                None => return Ok(CaptureResource::Unsupported(kind)),
+            };
+
+            let contents = match description
+                .usage
+                .contains(vk::BufferUsageFlags::TRANSFER_SRC)
+            {
+                true => Some(transfer::read_buffer(
+                    recorder.initialized,
+                    buffer,
+                    description.size,
+                )?),
+                false => None,
+            };
+
+            Ok(CaptureResource::Buffer {
+                description,
+                contents,
+            })
+        })
+    }
+
+    fn snapshot_buffer(
+        &self,
+        buffer: &VulkanBuffer,
+    ) -> Result<CaptureResource, VulkanUniversalError> {
+        let description = *buffer.description();
+
+        let contents = if description.map {
+            let mut contents = vec![0; description.size as usize];
+            buffer.memory().read(&mut contents, 0)?;
+            Some(contents)
+        } else if description
+            .usage
+            .contains(vk::BufferUsageFlags::TRANSFER_SRC)
+        {
+            Some(transfer::read_buffer(
+                self.initialized,
+                buffer.inner(),
+                description.size,
+            )?)
+        } else {
+            None
+        };
+
+        Ok(CaptureResource::Buffer {
+            description,
+            contents,
+        })
+    }
+
+    fn snapshot_descriptor_set(
+        &mut self,
+        descriptor_set: &DescriptorSet,
+    ) -> Result<CaptureResource, VulkanUniversalError> {
+        let layout = match capture_set_layout(descriptor_set.layout()) {
+            Some(layout) => layout,
+            None => {
+                return Ok(CaptureResource::Unsupported(
+                    StreamResourceKind::DescriptorSet,
+                ))
+            }
+        };
+
+        let mut writes = Vec::new();
+        for write in descriptor_set.writes() {
+            let info = match write.buffer {
+                Some(info) => info,
+                None => {
+                    log::warning(
+                        format!(
+                            "Descriptor set uses {:?} descriptor, which is not captured.",
+                            write.descriptor_type
+                        )
+                        .as_str(),
+                    );
+                    return Ok(CaptureResource::Unsupported(
+                        StreamResourceKind::DescriptorSet,
+                    ));
+                }
+            };
+
+            writes.push(CapturedDescriptorWrite {
+                binding: write.binding,
+                array_element: write.array_element,
+                descriptor_type: write.descriptor_type,
+                buffer: self.handle(StreamResourceKind::Buffer, info.buffer.as_raw())?,
+                offset: info.offset,
+                range: info.range,
+            });
+        }
+
+        Ok(CaptureResource::DescriptorSet { layout, writes })
+    }
+}
+
+fn snapshot_pipeline(pipeline: &Pipeline) -> CaptureResource {
+    let layout = pipeline.layout();
+    let set_layouts = match layout
+        .set_layouts()
+        .iter()
+        .map(|layout| capture_set_layout(layout))
+        .collect()
+    {
+        Some(set_layouts) => set_layouts,
+        None => return CaptureResource::Unsupported(StreamResourceKind::Pipeline),
+    };
+
+    CaptureResource::Pipeline(Box::new(CapturedPipeline {
+        set_layouts,
+        push_constant_ranges: layout.push_constant_ranges().to_vec(),
+        stages: pipeline.stages().to_vec(),
+        flags: pipeline.flags(),
+        graphics: pipeline
+            .render_pass()
+            .zip(pipeline.graphics_create_info())
+            .map(|(render_pass, create_info)| (*render_pass.create_info(), create_info.clone())),
+    }))
+}
+
+/// Returns `None` when layout uses immutable samplers, which are not captured.
+fn capture_set_layout(layout: &DescriptorSetLayout) -> Option<CapturedDescriptorSetLayout> {
+    match layout
+        .bindings()
+        .iter()
+        .any(|binding| binding.immutable_samplers)
+    {
+        true => None,
+        false => Some(CapturedDescriptorSetLayout {
+            flags: layout.flags(),
+            bindings: layout.bindings().to_vec(),
+        }),
+    }
+}
+
+fn snapshot_reference<'init>(
+    initialized: &'init VulkanDeviceInitialized<'init>,
+    kind: StreamResourceKind,
+    pointer: usize,
+) -> Result<CaptureResource, VulkanUniversalError> {
+    Ok(match kind {
+        StreamResourceKind::Image => {
+            let image = unsafe { &*(pointer as *const Arc<VulkanImage>) };
+            CaptureResource::Image {
+                create_info: *image.create_info(),
+                contents: transfer::read_image(initialized, image)?,
+            }
+        }
+        StreamResourceKind::RenderPass => {
+            let render_pass = unsafe { &*(pointer as *const Arc<RenderPass>) };
+            CaptureResource::RenderPass {
+                create_info: *render_pass.create_info(),
+            }
+        }
+        StreamResourceKind::Swapchain => {
+            let swapchain = unsafe { &*(pointer as *const Arc<Swapchain>) };
+            CaptureResource::Swapchain {
+                extent: swapchain.extent(),
+                format: swapchain.format().format,
+            }
+        }
+        StreamResourceKind::Framebuffer => {
+            let framebuffer = unsafe { &*(pointer as *const Framebuffer) };
+            CaptureResource::Framebuffer {
+                extent: framebuffer.extent(),
+                render_pass: *framebuffer.render_pass().create_info(),
+            }
+        }
+        StreamResourceKind::ClearColor => CaptureResource::ClearColor {
+            value: unsafe { *(pointer as *const [u32; 4]) },
+        },
+        kind => CaptureResource::Unsupported(kind),
+    })
+}
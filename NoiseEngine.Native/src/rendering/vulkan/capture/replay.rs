@@ -0,0 +1,787 @@
+//! Replays captured command streams on any device and writes rendered attachments to files.
+//!
+//! Camera windows are replaced by offscreen textures of the same format and size. Pipelines and
+//! materials are rebuilt from their creation data, but materials with image descriptors,
+//! secondary command buffers and queries are not captured, so streams which use them cannot be
+//! replayed.
+
+use std::{
+    collections::HashMap,
+    ffi::CStr,
+    fs, mem,
+    path::{Path, PathBuf},
+    ptr, slice,
+    sync::Arc,
+};
+
+use ash::vk::{self, Handle};
+
+use crate::{
+    errors::invalid_operation::InvalidOperationError,
+    interop::rendering::vulkan::application_info::VulkanApplicationInfo,
+    logging::{
+        log,
+        log_data::LogData,
+        log_level::LogLevel,
+        logger::{self, Logger},
+        validation_log_data::ValidationLogData,
+    },
+    rendering::{
+        buffers::command_buffers::command::GraphicsCommandBufferCommand,
+        vulkan::{
+            buffers::{
+                buffer::VulkanBuffer,
+                command_buffer::VulkanCommandBuffer,
+                command_buffers::stream::{
+                    self, StreamCommand, StreamField, StreamResourceKind, StreamValue,
+                },
+            },
+            descriptors::{
+                set::DescriptorSet, set_layout::DescriptorSetLayout,
+                update_template::DescriptorUpdateTemplate,
+            },
+            device::VulkanDevice,
+            device_support::VulkanDeviceSupport,
+            errors::universal::VulkanUniversalError,
+            framebuffer::{Framebuffer, FramebufferAttachment},
+            image::{VulkanImage, VulkanImageCreateInfo},
+            image_view::VulkanImageViewCreateInfo,
+            instance::VulkanInstance,
+            library,
+            pipeline::Pipeline,
+            pipeline_layout::PipelineLayout,
+            pipeline_shader_stage::{PipelineShaderStage, PipelineShaderStageSpecialization},
+            render_pass::{RenderPass, RenderPassCreateInfo},
+            shader_module::ShaderModule,
+        },
+    },
+    serialization::reader::SerializationReader,
+};
+
+use super::{
+    capture_file::{
+        CaptureFile, CaptureResource, CapturedDescriptorSetLayout, CapturedDescriptorWrite,
+        CapturedPipeline,
+    },
+    transfer,
+};
+
+/// Loads capture file, replays it on device which name contains `device_name`, or on the first
+/// device with graphics support, and returns paths of written attachments.
+pub fn run(
+    capture_path: &Path,
+    output_directory: &Path,
+    device_name: Option<&str>,
+) -> Result<Vec<PathBuf>, VulkanUniversalError> {
+    // Logger might be already initialized when replay runs inside of the engine.
+    _ = logger::initialize(Logger {
+        handler: log_to_stderr,
+        validation_handler: ignore_validation,
+    });
+
+    let capture = CaptureFile::read(capture_path)?;
+
+    let library = match library::create() {
+        Ok(library) => Arc::new(library),
+        Err(err) => {
+            return Err(InvalidOperationError::new(format!(
+                "Unable to load Vulkan library: {}",
+                err
+            ))
+            .into())
+        }
+    };
+
+    let instance = Arc::new(VulkanInstance::new(
+        &library,
+        VulkanApplicationInfo {
+            application_name: "NoiseEngine Replay".into(),
+            application_version: 0,
+            engine_version: 0,
+        },
+        vk::DebugUtilsMessageSeverityFlagsEXT::empty(),
+        vk::DebugUtilsMessageTypeFlagsEXT::empty(),
+        false,
+        &[],
+    )?);
+
+    let mut device = VulkanDevice::new(&instance, select_device(&instance, device_name)?);
+    device.initialize(&[], &[], &[])?;
+
+    // Resources created by the replay borrow the device for its whole lifetime, in the same way
+    // as resources created through interop.
+    let device = Box::into_raw(Box::new(Arc::new(device)));
+    let result = replay(unsafe { &*device }, &capture, output_directory);
+    drop(unsafe { Box::from_raw(device) });
+
+    result
+}
+
+fn select_device(
+    instance: &Arc<VulkanInstance>,
+    device_name: Option<&str>,
+) -> Result<vk::PhysicalDevice, VulkanUniversalError> {
+    let physical_devices = unsafe { instance.inner().enumerate_physical_devices() }?;
+
+    for physical_device in physical_devices {
+        let properties = unsafe {
+            instance
+                .inner()
+                .get_physical_device_properties(physical_device)
+        };
+        let name = unsafe { CStr::from_ptr(properties.device_name.as_ptr()) }.to_str()?;
+
+        let supports_graphics = unsafe {
+            instance
+                .inner()
+                .get_physical_device_queue_family_properties(physical_device)
+        }
+        .iter()
+        .any(|family| family.queue_flags.contains(vk::QueueFlags::GRAPHICS));
+
+        if supports_graphics && device_name.is_none_or(|device_name| name.contains(device_name)) {
+            log::info(format!("Replaying on {}.", name).as_str());
+            return Ok(physical_device);
+        }
+    }
+
+    Err(match device_name {
+        Some(device_name) => InvalidOperationError::new(format!(
+            "Device with graphics support which name contains `{}` was not found.",
+            device_name
+        )),
+        None => InvalidOperationError::with_str("Device with graphics support was not found."),
+    }
+    .into())
+}
+
+struct ReplayTarget<'init: 'ma, 'ma> {
+    framebuffer: Box<Framebuffer<'init, 'ma>>,
+    color: Arc<VulkanImage<'init, 'ma>>,
+    _depth: Option<Arc<VulkanImage<'init, 'ma>>>,
+}
+
+#[allow(clippy::redundant_allocation)]
+struct Replay<'init, 'cap> {
+    device: &'init Arc<VulkanDevice<'init>>,
+    capture: &'cap CaptureFile,
+    buffers: HashMap<u64, Box<Arc<VulkanBuffer<'init, 'init>>>>,
+    images: HashMap<u64, Box<Arc<VulkanImage<'init, 'init>>>>,
+    clear_colors: HashMap<u64, Box<[u32; 4]>>,
+    pipelines: HashMap<u64, Box<Pipeline<'init>>>,
+    descriptor_sets: HashMap<u64, Box<DescriptorSet<'init>>>,
+    targets: Vec<ReplayTarget<'init, 'init>>,
+    target_indices: HashMap<(u64, u64), usize>,
+}
+
+fn replay<'init>(
+    device: &'init Arc<VulkanDevice<'init>>,
+    capture: &CaptureFile,
+    output_directory: &Path,
+) -> Result<Vec<PathBuf>, VulkanUniversalError> {
+    let initialized = device.initialized()?;
+
+    let mut replay = Replay {
+        device,
+        capture,
+        buffers: HashMap::new(),
+        images: HashMap::new(),
+        clear_colors: HashMap::new(),
+        pipelines: HashMap::new(),
+        descriptor_sets: HashMap::new(),
+        targets: Vec::new(),
+        target_indices: HashMap::new(),
+    };
+
+    for (id, resource) in &capture.resources {
+        if let CaptureResource::Buffer {
+            description,
+            contents,
+        } = resource
+        {
+            let buffer = VulkanBuffer::new(
+                device,
+                description.usage | vk::BufferUsageFlags::TRANSFER_DST,
+                description.size,
+                true,
+            )?;
+            if let Some(contents) = contents {
+                buffer.memory().write(contents, 0)?;
+            }
+            replay.buffers.insert(*id, Box::new(Arc::new(buffer)));
+        }
+    }
+
+    for (id, resource) in &capture.resources {
+        match resource {
+            CaptureResource::Image {
+                create_info,
+                contents,
+            } => {
+                let image = Arc::new(VulkanImage::new(
+                    device,
+                    VulkanImageCreateInfo {
+                        usage: create_info.usage | vk::ImageUsageFlags::TRANSFER_DST,
+                        ..*create_info
+                    },
+                )?);
+
+                match contents {
+                    Some(contents) => transfer::write_image(initialized, &image, contents)?,
+                    None => log::warning(
+                        format!("Contents of image {} were not captured.", id).as_str(),
+                    ),
+                }
+
+                replay.images.insert(*id, Box::new(image));
+            }
+            CaptureResource::ClearColor { value } => {
+                replay.clear_colors.insert(*id, Box::new(*value));
+            }
+            CaptureResource::Pipeline(pipeline) => {
+                let pipeline = replay.create_pipeline(pipeline)?;
+                replay.pipelines.insert(*id, Box::new(pipeline));
+            }
+            CaptureResource::DescriptorSet { layout, writes } => {
+                let descriptor_set = replay.create_descriptor_set(layout, writes)?;
+                replay.descriptor_sets.insert(*id, Box::new(descriptor_set));
+            }
+            _ => (),
+        }
+    }
+
+    let stream = replay.encode_stream()?;
+
+    let command_buffer = VulkanCommandBuffer::new(
+        device,
+        SerializationReader::new(&stream),
+        VulkanDeviceSupport {
+            graphics: true,
+            computing: true,
+            transfer: false,
+            dedicated: false,
+        },
+        false,
+    )?;
+    command_buffer.execute(&[], &[])?.wait(u64::MAX)?;
+
+    if let Err(err) = fs::create_dir_all(output_directory) {
+        return Err(InvalidOperationError::new(format!(
+            "Unable to create directory {}: {}",
+            output_directory.display(),
+            err
+        ))
+        .into());
+    }
+
+    let mut paths = Vec::with_capacity(replay.targets.len());
+    for (i, target) in replay.targets.iter().enumerate() {
+        let name = format!("attachment_{}", i);
+        match transfer::read_image(initialized, &target.color)? {
+            Some(contents) => paths.push(write_attachment(
+                output_directory,
+                &name,
+                target.color.create_info(),
+                contents,
+            )?),
+            None => log::warning(
+                format!(
+                    "Attachment {} cannot be read back, so it was not written.",
+                    i
+                )
+                .as_str(),
+            ),
+        }
+    }
+
+    Ok(paths)
+}
+
+impl<'init> Replay<'init, '_> {
+    fn create_pipeline(
+        &self,
+        captured: &CapturedPipeline,
+    ) -> Result<Pipeline<'init>, VulkanUniversalError> {
+        let set_layouts = captured
+            .set_layouts
+            .iter()
+            .map(|layout| create_set_layout(self.device, layout))
+            .collect::<Result<Vec<_>, _>>()?;
+        let layout = Arc::new(PipelineLayout::new(
+            &set_layouts.iter().collect::<Vec<_>>(),
+            &captured.push_constant_ranges,
+        )?);
+
+        // Modules are used only while the pipeline is created.
+        let modules = captured
+            .stages
+            .iter()
+            .map(|stage| ShaderModule::new(self.device, &stage.code).map(Arc::new))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut stages = captured
+            .stages
+            .iter()
+            .zip(&modules)
+            .map(|(stage, module)| PipelineShaderStage {
+                stage: stage.stage,
+                module,
+                name: stage.name.clone().into(),
+                specialization: PipelineShaderStageSpecialization {
+                    map_entries: stage.specialization_map_entries.as_slice().into(),
+                    data: stage.specialization_data.as_slice().into(),
+                },
+            })
+            .collect::<Vec<_>>();
+
+        match &captured.graphics {
+            Some((render_pass, create_info)) => {
+                let render_pass = Arc::new(RenderPass::new(
+                    self.device,
+                    replay_render_pass_create_info(*render_pass),
+                )?);
+                Pipeline::with_graphics(
+                    &render_pass,
+                    &layout,
+                    &stages,
+                    captured.flags,
+                    create_info.as_create_info(),
+                )
+            }
+            None => match stages.pop() {
+                Some(stage) if stages.is_empty() => {
+                    Pipeline::with_compute(&layout, stage, captured.flags)
+                }
+                _ => Err(InvalidOperationError::with_str(
+                    "Compute pipeline must have a single shader stage.",
+                )
+                .into()),
+            },
+        }
+    }
+
+    fn create_descriptor_set(
+        &self,
+        layout: &CapturedDescriptorSetLayout,
+        writes: &[CapturedDescriptorWrite],
+    ) -> Result<DescriptorSet<'init>, VulkanUniversalError> {
+        let layout = create_set_layout(self.device, layout)?;
+
+        // Descriptor set borrows its layout only while it is created, and then owns its clone.
+        let descriptor_set =
+            DescriptorSet::new(unsafe { &*(&layout as *const Arc<DescriptorSetLayout>) })?;
+
+        let stride = mem::size_of::<vk::DescriptorBufferInfo>();
+        let mut entries = Vec::with_capacity(writes.len());
+        let mut infos = Vec::with_capacity(writes.len());
+        for write in writes {
+            let buffer = match self.buffers.get(&write.buffer) {
+                Some(buffer) => buffer.inner(),
+                None => {
+                    return Err(InvalidOperationError::new(format!(
+                        "Buffer {} used by descriptor set was not captured.",
+                        write.buffer
+                    ))
+                    .into())
+                }
+            };
+
+            entries.push(vk::DescriptorUpdateTemplateEntry {
+                dst_binding: write.binding,
+                dst_array_element: write.array_element,
+                descriptor_count: 1,
+                descriptor_type: write.descriptor_type,
+                offset: infos.len() * stride,
+                stride,
+            });
+            infos.push(vk::DescriptorBufferInfo {
+                buffer,
+                offset: write.offset,
+                range: write.range,
+            });
+        }
+
+        if !entries.is_empty() {
+            let template = DescriptorUpdateTemplate::new(&layout, &entries)?;
+            let data =
+                unsafe { slice::from_raw_parts(infos.as_ptr() as *const u8, infos.len() * stride) };
+            descriptor_set.update(&template, data);
+        }
+
+        Ok(descriptor_set)
+    }
+
+    fn encode_stream(&mut self) -> Result<Vec<u8>, VulkanUniversalError> {
+        let data = &self.capture.stream;
+        let commands = stream::decode(data)?;
+        super::ensure_replayable(&commands)?;
+
+        let mut stream = Vec::with_capacity(data.len());
+        for (i, command) in commands.iter().enumerate() {
+            let end = match commands.get(i + 1) {
+                Some(next) => next.offset,
+                None => data.len(),
+            };
+
+            // Camera window is replaced by camera texture, which takes framebuffer instead of
+            // render pass and swapchain.
+            let start = match command.command {
+                GraphicsCommandBufferCommand::AttachCameraWindow => {
+                    let framebuffer =
+                        self.get_target(command, field_id(command, 0), field_id(command, 1))?;
+
+                    stream.extend_from_slice(
+                        &(GraphicsCommandBufferCommand::AttachCameraTexture as u16).to_ne_bytes(),
+                    );
+                    stream.extend_from_slice(&framebuffer.to_ne_bytes());
+                    command.fields[2].offset
+                }
+                _ => command.offset,
+            };
+
+            let stream_start = stream.len();
+            stream.extend_from_slice(&data[start..end]);
+
+            for field in command.fields.iter().filter(|field| field.offset >= start) {
+                if let Some(value) = self.resolve(command, field)? {
+                    let offset = stream_start + field.offset - start;
+                    stream[offset..offset + value.len()].copy_from_slice(&value);
+                }
+            }
+        }
+
+        Ok(stream)
+    }
+
+    /// Returns bytes which replace id stored in the field.
+    fn resolve(
+        &mut self,
+        command: &StreamCommand,
+        field: &StreamField,
+    ) -> Result<Option<Vec<u8>>, VulkanUniversalError> {
+        let value = &field.value;
+        let (kind, id) = match *value {
+            StreamValue::Reference(kind, id) => (kind, id as u64),
+            StreamValue::Handle(kind, id) => (kind, id),
+            _ => return Ok(None),
+        };
+
+        let resolved = match (kind, value) {
+            (StreamResourceKind::Buffer, StreamValue::Reference(..)) => {
+                self.buffers.get(&id).map(|buffer| {
+                    (&**buffer as *const Arc<VulkanBuffer> as usize)
+                        .to_ne_bytes()
+                        .to_vec()
+                })
+            }
+            (StreamResourceKind::Buffer, _) => self
+                .buffers
+                .get(&id)
+                .map(|buffer| buffer.inner().as_raw().to_ne_bytes().to_vec()),
+            (StreamResourceKind::Image, _) => self.images.get(&id).map(|image| {
+                (&**image as *const Arc<VulkanImage> as usize)
+                    .to_ne_bytes()
+                    .to_vec()
+            }),
+            (StreamResourceKind::ClearColor, _) => self.clear_colors.get(&id).map(|value| {
+                (&**value as *const [u32; 4] as usize)
+                    .to_ne_bytes()
+                    .to_vec()
+            }),
+            (StreamResourceKind::Pipeline, _) => self.pipelines.get(&id).map(|pipeline| {
+                (&**pipeline as *const Pipeline as usize)
+                    .to_ne_bytes()
+                    .to_vec()
+            }),
+            (StreamResourceKind::DescriptorSet, _) => {
+                self.descriptor_sets.get(&id).map(|descriptor_set| {
+                    (&**descriptor_set as *const DescriptorSet as usize)
+                        .to_ne_bytes()
+                        .to_vec()
+                })
+            }
+            (StreamResourceKind::Framebuffer, _) => {
+                Some(self.get_target(command, id, 0)?.to_ne_bytes().to_vec())
+            }
+            _ => None,
+        };
+
+        match resolved {
+            Some(resolved) => Ok(Some(resolved)),
+            None => Err(InvalidOperationError::new(format!(
+                "Resource {} used by `{}` of command {} was not captured.",
+                id, field.name, command.index
+            ))
+            .into()),
+        }
+    }
+
+    /// Returns pointer to offscreen framebuffer which replaces captured framebuffer, or swapchain
+    /// when `swapchain` is not zero.
+    fn get_target(
+        &mut self,
+        command: &StreamCommand,
+        id: u64,
+        swapchain: u64,
+    ) -> Result<usize, VulkanUniversalError> {
+        let index = match self.target_indices.get(&(id, swapchain)) {
+            Some(index) => *index,
+            None => {
+                let (extent, create_info) = match (
+                    self.capture.get_resource(id),
+                    self.capture.get_resource(swapchain),
+                ) {
+                    (
+                        Some(CaptureResource::Framebuffer {
+                            extent,
+                            render_pass,
+                        }),
+                        _,
+                    ) => (*extent, *render_pass),
+                    (
+                        Some(CaptureResource::RenderPass { create_info }),
+                        Some(CaptureResource::Swapchain { extent, .. }),
+                    ) => (*extent, *create_info),
+                    _ => {
+                        return Err(InvalidOperationError::new(format!(
+                            "Camera target used by command {} was not captured.",
+                            command.index
+                        ))
+                        .into())
+                    }
+                };
+
+                self.targets
+                    .push(create_target(self.device, extent, create_info)?);
+                self.target_indices
+                    .insert((id, swapchain), self.targets.len() - 1);
+                self.targets.len() - 1
+            }
+        };
+
+        Ok(&*self.targets[index].framebuffer as *const Framebuffer as usize)
+    }
+}
+
+fn field_id(command: &StreamCommand, index: usize) -> u64 {
+    match command.fields[index].value {
+        StreamValue::Reference(_, id) => id as u64,
+        StreamValue::Handle(_, id) => id,
+        _ => 0,
+    }
+}
+
+fn create_set_layout<'init>(
+    device: &'init Arc<VulkanDevice<'init>>,
+    layout: &CapturedDescriptorSetLayout,
+) -> Result<Arc<DescriptorSetLayout<'init>>, VulkanUniversalError> {
+    let bindings = layout
+        .bindings
+        .iter()
+        .map(|binding| vk::DescriptorSetLayoutBinding {
+            binding: binding.binding,
+            descriptor_type: binding.descriptor_type,
+            descriptor_count: binding.descriptor_count,
+            stage_flags: binding.stage_flags,
+            p_immutable_samplers: ptr::null(),
+        })
+        .collect::<Vec<_>>();
+
+    Ok(Arc::new(DescriptorSetLayout::new(
+        device,
+        layout.flags,
+        &bindings,
+    )?))
+}
+
+/// Returns create info of render pass used by the replay instead of captured one. Render passes of
+/// graphics pipelines are created from the same info as targets, so they stay compatible.
+fn replay_render_pass_create_info(create_info: RenderPassCreateInfo) -> RenderPassCreateInfo {
+    // Target is rendered without multisampling, which only changes edges of the result.
+    RenderPassCreateInfo {
+        depth_stencil_sample_count: match create_info.multisampled() {
+            true => vk::SampleCountFlags::TYPE_1,
+            false => create_info.depth_stencil_sample_count,
+        },
+        multisample_count: vk::SampleCountFlags::TYPE_1,
+        // Depth stencil image is created for the replay, so it has no content to load.
+        stencil_load_op: match create_info.stencil_load_op {
+            vk::AttachmentLoadOp::LOAD => vk::AttachmentLoadOp::CLEAR,
+            op => op,
+        },
+        ..create_info
+    }
+}
+
+fn create_target<'init>(
+    device: &'init Arc<VulkanDevice<'init>>,
+    extent: vk::Extent2D,
+    create_info: RenderPassCreateInfo,
+) -> Result<ReplayTarget<'init, 'init>, VulkanUniversalError> {
+    let create_info = replay_render_pass_create_info(create_info);
+    let render_pass = Arc::new(RenderPass::new(
+        device,
+        RenderPassCreateInfo {
+            final_layout: vk::ImageLayout::TRANSFER_SRC_OPTIMAL,
+            ..create_info
+        },
+    )?);
+
+    let color = Arc::new(VulkanImage::new(
+        device,
+        target_image_create_info(
+            extent,
+            create_info.format,
+            create_info.sample_count,
+            vk::ImageUsageFlags::COLOR_ATTACHMENT | vk::ImageUsageFlags::TRANSFER_SRC,
+            vk::ImageLayout::TRANSFER_SRC_OPTIMAL,
+        ),
+    )?);
+
+    let depth = match create_info.depth_testing {
+        true => Some(Arc::new(VulkanImage::new(
+            device,
+            target_image_create_info(
+                extent,
+                create_info.depth_stencil_format,
+                create_info.depth_stencil_sample_count,
+                vk::ImageUsageFlags::DEPTH_STENCIL_ATTACHMENT,
+                vk::ImageLayout::UNDEFINED,
+            ),
+        )?)),
+        false => None,
+    };
+
+    // Framebuffer borrows attachments only while creating image views, which own their images.
+    let mut attachments = vec![FramebufferAttachment::new(
+        unsafe { &*(&color as *const Arc<VulkanImage>) },
+        target_view_create_info(vk::ImageAspectFlags::COLOR),
+    )];
+    if let Some(depth) = &depth {
+        attachments.push(FramebufferAttachment::new(
+            unsafe { &*(depth as *const Arc<VulkanImage>) },
+            target_view_create_info(vk::ImageAspectFlags::DEPTH),
+        ));
+    }
+
+    let framebuffer = Box::new(Framebuffer::new(
+        &render_pass,
+        vk::FramebufferCreateFlags::empty(),
+        extent.width,
+        extent.height,
+        1,
+        &attachments,
+    )?);
+
+    Ok(ReplayTarget {
+        framebuffer,
+        color,
+        _depth: depth,
+    })
+}
+
+fn target_image_create_info(
+    extent: vk::Extent2D,
+    format: vk::Format,
+    sample_count: vk::SampleCountFlags,
+    usage: vk::ImageUsageFlags,
+    layout: vk::ImageLayout,
+) -> VulkanImageCreateInfo {
+    VulkanImageCreateInfo {
+        flags: vk::ImageCreateFlags::empty(),
+        image_type: vk::ImageType::TYPE_2D,
+        extent: vk::Extent3D {
+            width: extent.width,
+            height: extent.height,
+            depth: 1,
+        },
+        format,
+        mip_levels: 1,
+        array_layers: 1,
+        sample_count: sample_count.as_raw(),
+        linear: false,
+        usage,
+        concurrent: false,
+        layout,
+    }
+}
+
+fn target_view_create_info(aspect_mask: vk::ImageAspectFlags) -> VulkanImageViewCreateInfo {
+    VulkanImageViewCreateInfo {
+        flags: vk::ImageViewCreateFlags::empty(),
+        view_type: vk::ImageViewType::TYPE_2D,
+        components: vk::ComponentMapping::default(),
+        subresource_range: vk::ImageSubresourceRange {
+            aspect_mask,
+            base_mip_level: 0,
+            level_count: 1,
+            base_array_layer: 0,
+            layer_count: 1,
+        },
+    }
+}
+
+/// Writes 8 bit RGBA and BGRA attachments as PNG, and other formats as raw texels.
+fn write_attachment(
+    output_directory: &Path,
+    name: &str,
+    create_info: &VulkanImageCreateInfo,
+    mut contents: Vec<u8>,
+) -> Result<PathBuf, VulkanUniversalError> {
+    let is_bgra = matches!(
+        create_info.format,
+        vk::Format::B8G8R8A8_UNORM | vk::Format::B8G8R8A8_SRGB
+    );
+    let is_rgba = matches!(
+        create_info.format,
+        vk::Format::R8G8B8A8_UNORM | vk::Format::R8G8B8A8_SRGB
+    );
+
+    let path;
+    let result = if is_bgra || is_rgba {
+        if is_bgra {
+            for texel in contents.chunks_exact_mut(4) {
+                texel.swap(0, 2);
+            }
+        }
+
+        path = output_directory.join(format!("{}.png", name));
+        image::save_buffer(
+            &path,
+            &contents,
+            create_info.extent.width,
+            create_info.extent.height,
+            image::ColorType::Rgba8,
+        )
+        .map_err(|err| err.to_string())
+    } else {
+        path = output_directory.join(format!("{}_{:?}.bin", name, create_info.format));
+        fs::write(&path, &contents).map_err(|err| err.to_string())
+    };
+
+    match result {
+        Ok(()) => Ok(path),
+        Err(err) => Err(InvalidOperationError::new(format!(
+            "Unable to write attachment {}: {}",
+            path.display(),
+            err
+        ))
+        .into()),
+    }
+}
+
+unsafe extern "C" fn log_to_stderr(data: LogData) {
+    let level = match data.level {
+        LogLevel::Debug => "Debug",
+        LogLevel::Trace => "Trace",
+        LogLevel::Info => "Info",
+        LogLevel::Warning => "Warning",
+        LogLevel::Error => "Error",
+        LogLevel::Fatal => "Fatal",
+    };
+
+    let message: &[u8] = data.message.into();
+    eprintln!("[{}] {}", level, String::from_utf8_lossy(message));
+}
+
+// Text of validation messages is already logged by `log_to_stderr`.
+unsafe extern "C" fn ignore_validation(_data: ValidationLogData) {}
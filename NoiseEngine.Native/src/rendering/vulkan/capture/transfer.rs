@@ -0,0 +1,375 @@
+use std::ptr;
+
+use ash::vk;
+
+use crate::rendering::vulkan::{
+    device::VulkanDeviceInitialized, device_support::VulkanDeviceSupport,
+    errors::universal::VulkanUniversalError, image::VulkanImage, memory_allocator::MemoryBlock,
+};
+
+struct StagingBuffer<'init> {
+    inner: vk::Buffer,
+    memory: MemoryBlock<'init>,
+    initialized: &'init VulkanDeviceInitialized<'init>,
+}
+
+impl<'init> StagingBuffer<'init> {
+    fn new(
+        initialized: &'init VulkanDeviceInitialized<'init>,
+        size: u64,
+    ) -> Result<Self, VulkanUniversalError> {
+        let buffer_info = vk::BufferCreateInfo {
+            s_type: vk::StructureType::BUFFER_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: vk::BufferCreateFlags::empty(),
+            size,
+            usage: vk::BufferUsageFlags::TRANSFER_SRC | vk::BufferUsageFlags::TRANSFER_DST,
+            sharing_mode: vk::SharingMode::EXCLUSIVE,
+            queue_family_index_count: 0,
+            p_queue_family_indices: ptr::null(),
+        };
+
+        let alloc_info = vma::AllocationCreateInfo {
+            flags: vma::AllocationCreateFlags::HOST_ACCESS_RANDOM,
+            usage: vma::MemoryUsage::AutoPreferHost,
+            required_flags: vk::MemoryPropertyFlags::HOST_VISIBLE
+                | vk::MemoryPropertyFlags::HOST_COHERENT,
+            ..Default::default()
+        };
+
+        let (inner, memory) = initialized
+            .allocator()
+            .create_buffer(&buffer_info, &alloc_info)?;
+
+        Ok(Self {
+            inner,
+            memory,
+            initialized,
+        })
+    }
+}
+
+impl Drop for StagingBuffer<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            self.initialized
+                .vulkan_device()
+                .destroy_buffer(self.inner, None)
+        }
+    }
+}
+
+/// Records commands into temporary command buffer and waits for its execution.
+pub fn submit_once<'init, F>(
+    initialized: &'init VulkanDeviceInitialized<'init>,
+    record: F,
+) -> Result<(), VulkanUniversalError>
+where
+    F: FnOnce(&ash::Device, vk::CommandBuffer),
+{
+    let queue_family = initialized.get_family(VulkanDeviceSupport {
+        graphics: true,
+        computing: false,
+        transfer: true,
+        dedicated: false,
+    })?;
+    let command_pool = queue_family.get_command_pool()?;
+    let vulkan_device = initialized.vulkan_device();
+
+    let allocate_info = vk::CommandBufferAllocateInfo {
+        s_type: vk::StructureType::COMMAND_BUFFER_ALLOCATE_INFO,
+        p_next: ptr::null(),
+        command_pool: command_pool.inner(),
+        level: vk::CommandBufferLevel::PRIMARY,
+        command_buffer_count: 1,
+    };
+    let command_buffer = unsafe { vulkan_device.allocate_command_buffers(&allocate_info) }?[0];
+
+    let result = submit_once_worker(
+        initialized,
+        queue_family.get_queue().queue,
+        command_buffer,
+        record,
+    );
+
+    unsafe { vulkan_device.free_command_buffers(command_pool.inner(), &[command_buffer]) };
+    result
+}
+
+fn submit_once_worker<F>(
+    initialized: &VulkanDeviceInitialized,
+    queue: vk::Queue,
+    command_buffer: vk::CommandBuffer,
+    record: F,
+) -> Result<(), VulkanUniversalError>
+where
+    F: FnOnce(&ash::Device, vk::CommandBuffer),
+{
+    let vulkan_device = initialized.vulkan_device();
+
+    let begin_info = vk::CommandBufferBeginInfo {
+        s_type: vk::StructureType::COMMAND_BUFFER_BEGIN_INFO,
+        p_next: ptr::null(),
+        flags: vk::CommandBufferUsageFlags::ONE_TIME_SUBMIT,
+        p_inheritance_info: ptr::null(),
+    };
+
+    unsafe { vulkan_device.begin_command_buffer(command_buffer, &begin_info) }?;
+    record(vulkan_device, command_buffer);
+    unsafe { vulkan_device.end_command_buffer(command_buffer) }?;
+
+    let fence_info = vk::FenceCreateInfo {
+        s_type: vk::StructureType::FENCE_CREATE_INFO,
+        p_next: ptr::null(),
+        flags: vk::FenceCreateFlags::empty(),
+    };
+    let fence = unsafe { vulkan_device.create_fence(&fence_info, None) }?;
+
+    let submit_info = vk::SubmitInfo {
+        s_type: vk::StructureType::SUBMIT_INFO,
+        p_next: ptr::null(),
+        wait_semaphore_count: 0,
+        p_wait_semaphores: ptr::null(),
+        p_wait_dst_stage_mask: ptr::null(),
+        command_buffer_count: 1,
+        p_command_buffers: &command_buffer as *const vk::CommandBuffer,
+        signal_semaphore_count: 0,
+        p_signal_semaphores: ptr::null(),
+    };
+
+    let result = unsafe {
+        match vulkan_device.queue_submit(queue, &[submit_info], fence) {
+            Ok(()) => vulkan_device.wait_for_fences(&[fence], true, u64::MAX),
+            Err(err) => Err(err),
+        }
+    };
+
+    unsafe { vulkan_device.destroy_fence(fence, None) };
+    Ok(result?)
+}
+
+/// Returns size in bytes of single texel of color format, if it is known.
+pub fn format_size(format: vk::Format) -> Option<u64> {
+    match format {
+        vk::Format::R8_UNORM | vk::Format::R8_SNORM | vk::Format::R8_UINT | vk::Format::R8_SRGB => {
+            Some(1)
+        }
+        vk::Format::R8G8_UNORM | vk::Format::R8G8_SNORM | vk::Format::R16_SFLOAT => Some(2),
+        vk::Format::R8G8B8A8_UNORM
+        | vk::Format::R8G8B8A8_SNORM
+        | vk::Format::R8G8B8A8_UINT
+        | vk::Format::R8G8B8A8_SRGB
+        | vk::Format::B8G8R8A8_UNORM
+        | vk::Format::B8G8R8A8_SRGB
+        | vk::Format::R16G16_SFLOAT
+        | vk::Format::R32_SFLOAT
+        | vk::Format::R32_UINT => Some(4),
+        vk::Format::R16G16B16A16_SFLOAT | vk::Format::R32G32_SFLOAT => Some(8),
+        vk::Format::R32G32B32A32_SFLOAT => Some(16),
+        _ => None,
+    }
+}
+
+pub fn read_buffer<'init>(
+    initialized: &'init VulkanDeviceInitialized<'init>,
+    buffer: vk::Buffer,
+    size: u64,
+) -> Result<Vec<u8>, VulkanUniversalError> {
+    let staging = StagingBuffer::new(initialized, size)?;
+
+    submit_once(initialized, |vulkan_device, command_buffer| unsafe {
+        vulkan_device.cmd_copy_buffer(
+            command_buffer,
+            buffer,
+            staging.inner,
+            &[vk::BufferCopy {
+                src_offset: 0,
+                dst_offset: 0,
+                size,
+            }],
+        )
+    })?;
+
+    let mut contents = vec![0; size as usize];
+    staging.memory.read(&mut contents, 0)?;
+    Ok(contents)
+}
+
+/// Reads first mip level of all layers of color image. Returns [`None`] when image cannot be
+/// read back.
+pub fn read_image<'init>(
+    initialized: &'init VulkanDeviceInitialized<'init>,
+    image: &VulkanImage,
+) -> Result<Option<Vec<u8>>, VulkanUniversalError> {
+    let create_info = image.create_info();
+    let layout = image.layout();
+
+    let texel_size = match format_size(create_info.format) {
+        Some(size) => size,
+        None => return Ok(None),
+    };
+    if !create_info
+        .usage
+        .contains(vk::ImageUsageFlags::TRANSFER_SRC)
+        || create_info.sample_count != vk::SampleCountFlags::TYPE_1.as_raw()
+        || layout == vk::ImageLayout::UNDEFINED
+        || layout == vk::ImageLayout::PREINITIALIZED
+    {
+        return Ok(None);
+    }
+
+    let size = image_size(create_info.extent, create_info.array_layers, texel_size);
+    let staging = StagingBuffer::new(initialized, size)?;
+
+    let transition =
+        layout != vk::ImageLayout::GENERAL && layout != vk::ImageLayout::TRANSFER_SRC_OPTIMAL;
+    let copy_layout = match transition {
+        true => vk::ImageLayout::TRANSFER_SRC_OPTIMAL,
+        false => layout,
+    };
+
+    submit_once(initialized, |vulkan_device, command_buffer| unsafe {
+        image_barrier(
+            vulkan_device,
+            command_buffer,
+            image,
+            layout,
+            copy_layout,
+            vk::AccessFlags::MEMORY_WRITE,
+            vk::AccessFlags::TRANSFER_READ,
+        );
+
+        vulkan_device.cmd_copy_image_to_buffer(
+            command_buffer,
+            image.inner(),
+            copy_layout,
+            staging.inner,
+            &[buffer_image_copy(
+                create_info.extent,
+                create_info.array_layers,
+            )],
+        );
+
+        image_barrier(
+            vulkan_device,
+            command_buffer,
+            image,
+            copy_layout,
+            layout,
+            vk::AccessFlags::TRANSFER_READ,
+            vk::AccessFlags::MEMORY_READ | vk::AccessFlags::MEMORY_WRITE,
+        );
+    })?;
+
+    let mut contents = vec![0; size as usize];
+    staging.memory.read(&mut contents, 0)?;
+    Ok(Some(contents))
+}
+
+/// Writes first mip level of all layers of color image, and leaves it in its layout.
+pub fn write_image<'init>(
+    initialized: &'init VulkanDeviceInitialized<'init>,
+    image: &VulkanImage,
+    contents: &[u8],
+) -> Result<(), VulkanUniversalError> {
+    let create_info = image.create_info();
+
+    let staging = StagingBuffer::new(initialized, contents.len() as u64)?;
+    staging.memory.write(contents, 0)?;
+
+    submit_once(initialized, |vulkan_device, command_buffer| unsafe {
+        image_barrier(
+            vulkan_device,
+            command_buffer,
+            image,
+            vk::ImageLayout::UNDEFINED,
+            vk::ImageLayout::TRANSFER_DST_OPTIMAL,
+            vk::AccessFlags::empty(),
+            vk::AccessFlags::TRANSFER_WRITE,
+        );
+
+        vulkan_device.cmd_copy_buffer_to_image(
+            command_buffer,
+            staging.inner,
+            image.inner(),
+            vk::ImageLayout::TRANSFER_DST_OPTIMAL,
+            &[buffer_image_copy(
+                create_info.extent,
+                create_info.array_layers,
+            )],
+        );
+
+        image_barrier(
+            vulkan_device,
+            command_buffer,
+            image,
+            vk::ImageLayout::TRANSFER_DST_OPTIMAL,
+            image.layout(),
+            vk::AccessFlags::TRANSFER_WRITE,
+            vk::AccessFlags::MEMORY_READ | vk::AccessFlags::MEMORY_WRITE,
+        );
+    })
+}
+
+fn image_size(extent: vk::Extent3D, array_layers: u32, texel_size: u64) -> u64 {
+    extent.width as u64
+        * extent.height as u64
+        * extent.depth as u64
+        * array_layers as u64
+        * texel_size
+}
+
+fn buffer_image_copy(extent: vk::Extent3D, array_layers: u32) -> vk::BufferImageCopy {
+    vk::BufferImageCopy {
+        buffer_offset: 0,
+        buffer_row_length: 0,
+        buffer_image_height: 0,
+        image_subresource: vk::ImageSubresourceLayers {
+            aspect_mask: vk::ImageAspectFlags::COLOR,
+            mip_level: 0,
+            base_array_layer: 0,
+            layer_count: array_layers,
+        },
+        image_offset: vk::Offset3D { x: 0, y: 0, z: 0 },
+        image_extent: extent,
+    }
+}
+
+unsafe fn image_barrier(
+    vulkan_device: &ash::Device,
+    command_buffer: vk::CommandBuffer,
+    image: &VulkanImage,
+    old_layout: vk::ImageLayout,
+    new_layout: vk::ImageLayout,
+    src_access_mask: vk::AccessFlags,
+    dst_access_mask: vk::AccessFlags,
+) {
+    let barrier = vk::ImageMemoryBarrier {
+        s_type: vk::StructureType::IMAGE_MEMORY_BARRIER,
+        p_next: ptr::null(),
+        src_access_mask,
+        dst_access_mask,
+        old_layout,
+        new_layout,
+        src_queue_family_index: vk::QUEUE_FAMILY_IGNORED,
+        dst_queue_family_index: vk::QUEUE_FAMILY_IGNORED,
+        image: image.inner(),
+        subresource_range: vk::ImageSubresourceRange {
+            aspect_mask: vk::ImageAspectFlags::COLOR,
+            base_mip_level: 0,
+            level_count: 1,
+            base_array_layer: 0,
+            layer_count: image.create_info().array_layers,
+        },
+    };
+
+    vulkan_device.cmd_pipeline_barrier(
+        command_buffer,
+        vk::PipelineStageFlags::ALL_COMMANDS,
+        vk::PipelineStageFlags::ALL_COMMANDS,
+        vk::DependencyFlags::empty(),
+        &[],
+        &[],
+        &[barrier],
+    );
+}
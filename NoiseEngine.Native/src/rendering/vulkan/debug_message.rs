@@ -0,0 +1,222 @@
+use std::{
+    ffi::CStr,
+    fmt::Display,
+    os::raw::c_char,
+    slice,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+
+use ash::vk;
+
+use crate::{
+    interop::prelude::InteropString,
+    logging::{
+        log_level::LogLevel,
+        validation_log_data::{ValidationLogData, ValidationLogObject},
+    },
+};
+
+use super::errors::validation::VulkanValidationError;
+
+static CAPTURE_ERRORS: AtomicBool = AtomicBool::new(false);
+static CAPTURED_ERRORS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Returns `true` when validation errors are captured and returned as failure of the next
+/// interop call.
+pub fn capture_errors() -> bool {
+    CAPTURE_ERRORS.load(Ordering::Relaxed)
+}
+
+/// Enables or disables capturing of validation errors. Disabling discards already captured errors.
+pub fn set_capture_errors(capture: bool) {
+    CAPTURE_ERRORS.store(capture, Ordering::Relaxed);
+    if !capture {
+        CAPTURED_ERRORS
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .clear();
+    }
+}
+
+/// Returns and clears errors captured since the previous call. Messages are not assigned to
+/// threads, so errors reported by other threads are also returned.
+pub(crate) fn take_captured_error() -> Option<VulkanValidationError> {
+    if !capture_errors() {
+        return None;
+    }
+
+    let mut captured = CAPTURED_ERRORS
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    match captured.is_empty() {
+        true => None,
+        false => Some(VulkanValidationError::new(captured.drain(..).collect())),
+    }
+}
+
+pub struct VulkanDebugObject {
+    pub object_type: vk::ObjectType,
+    pub handle: u64,
+    pub name: Option<String>,
+}
+
+/// Message of the debug messenger, copied from callback data.
+pub struct VulkanDebugMessage {
+    pub severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+    pub message_type: vk::DebugUtilsMessageTypeFlagsEXT,
+    pub message_id_name: Option<String>,
+    pub message_id_number: i32,
+    pub message: String,
+    pub objects: Vec<VulkanDebugObject>,
+    pub queue_labels: Vec<String>,
+    pub command_buffer_labels: Vec<String>,
+}
+
+impl VulkanDebugMessage {
+    /// # Safety
+    /// `data` must be valid callback data passed to the debug messenger.
+    pub unsafe fn from_raw(
+        severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+        message_type: vk::DebugUtilsMessageTypeFlagsEXT,
+        data: &vk::DebugUtilsMessengerCallbackDataEXT,
+    ) -> Self {
+        let objects = raw_slice(data.p_objects, data.object_count)
+            .iter()
+            .map(|object| VulkanDebugObject {
+                object_type: object.object_type,
+                handle: object.object_handle,
+                name: raw_string(object.p_object_name),
+            })
+            .collect();
+
+        Self {
+            severity,
+            message_type,
+            message_id_name: raw_string(data.p_message_id_name),
+            message_id_number: data.message_id_number,
+            message: raw_string(data.p_message).unwrap_or_default(),
+            objects,
+            queue_labels: raw_labels(data.p_queue_labels, data.queue_label_count),
+            command_buffer_labels: raw_labels(data.p_cmd_buf_labels, data.cmd_buf_label_count),
+        }
+    }
+
+    pub fn level(&self) -> LogLevel {
+        match self.severity {
+            vk::DebugUtilsMessageSeverityFlagsEXT::ERROR => LogLevel::Error,
+            vk::DebugUtilsMessageSeverityFlagsEXT::WARNING => LogLevel::Warning,
+            vk::DebugUtilsMessageSeverityFlagsEXT::INFO => LogLevel::Info,
+            _ => LogLevel::Debug,
+        }
+    }
+
+    pub fn is_validation_error(&self) -> bool {
+        self.severity == vk::DebugUtilsMessageSeverityFlagsEXT::ERROR
+            && self
+                .message_type
+                .contains(vk::DebugUtilsMessageTypeFlagsEXT::VALIDATION)
+    }
+
+    /// Stores this message when it is a validation error and capturing is enabled.
+    pub fn capture(&self) {
+        if self.is_validation_error() && capture_errors() {
+            CAPTURED_ERRORS
+                .lock()
+                .unwrap_or_else(|err| err.into_inner())
+                .push(self.to_string());
+        }
+    }
+
+    /// Calls `f` with borrowed structured representation of this message.
+    pub fn with_log_data(&self, f: impl FnOnce(ValidationLogData)) {
+        let objects = self
+            .objects
+            .iter()
+            .map(|object| ValidationLogObject {
+                object_type: format!("{:?}", object.object_type).into(),
+                handle: object.handle,
+                name: object.name.as_deref().unwrap_or_default().into(),
+            })
+            .collect::<Vec<_>>();
+        let queue_labels = to_interop_strings(&self.queue_labels);
+        let command_buffer_labels = to_interop_strings(&self.command_buffer_labels);
+
+        f(ValidationLogData {
+            level: self.level(),
+            message_type: self.message_type.as_raw(),
+            message_id_number: self.message_id_number,
+            message_id_name: self
+                .message_id_name
+                .as_deref()
+                .unwrap_or_default()
+                .as_bytes()
+                .into(),
+            message: self.message.as_bytes().into(),
+            objects: objects.as_slice().into(),
+            queue_labels: queue_labels.as_slice().into(),
+            command_buffer_labels: command_buffer_labels.as_slice().into(),
+        });
+    }
+}
+
+impl Display for VulkanDebugMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.message_type {
+            vk::DebugUtilsMessageTypeFlagsEXT::GENERAL => (),
+            vk::DebugUtilsMessageTypeFlagsEXT::VALIDATION => write!(f, "Validation: ")?,
+            vk::DebugUtilsMessageTypeFlagsEXT::PERFORMANCE => write!(f, "Performance: ")?,
+            _ => write!(f, "Unknown: ")?,
+        }
+        write!(f, "{}", self.message)?;
+
+        // Message itself contains only raw handles of objects.
+        let mut separator = " Objects: ";
+        for object in &self.objects {
+            if let Some(name) = &object.name {
+                write!(
+                    f,
+                    "{}{:?} \"{}\" ({:#x})",
+                    separator, object.object_type, name, object.handle
+                )?;
+                separator = ", ";
+            }
+        }
+
+        if !self.command_buffer_labels.is_empty() {
+            write!(f, " Labels: {}", self.command_buffer_labels.join(" > "))?;
+        }
+
+        Ok(())
+    }
+}
+
+unsafe fn raw_string(pointer: *const c_char) -> Option<String> {
+    match pointer.is_null() {
+        true => None,
+        false => Some(CStr::from_ptr(pointer).to_string_lossy().into_owned()),
+    }
+}
+
+unsafe fn raw_slice<'a, T>(pointer: *const T, count: u32) -> &'a [T] {
+    match pointer.is_null() {
+        true => &[],
+        false => slice::from_raw_parts(pointer, count as usize),
+    }
+}
+
+unsafe fn raw_labels(pointer: *const vk::DebugUtilsLabelEXT, count: u32) -> Vec<String> {
+    raw_slice(pointer, count)
+        .iter()
+        .map(|label| raw_string(label.p_label_name).unwrap_or_default())
+        .collect()
+}
+
+fn to_interop_strings(strings: &[String]) -> Vec<InteropString> {
+    strings
+        .iter()
+        .map(|string| InteropString::from(string.as_str()))
+        .collect()
+}
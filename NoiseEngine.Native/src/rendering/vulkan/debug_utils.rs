@@ -0,0 +1,77 @@
+use std::{ffi::CString, ptr};
+
+use ash::{extensions::ext, vk};
+
+use crate::errors::invalid_operation::InvalidOperationError;
+
+use super::errors::universal::VulkanUniversalError;
+
+/// Names objects and labels regions of command buffers, which are visible in validation messages
+/// and in captures of graphics debuggers.
+pub struct VulkanDebugUtils {
+    inner: ext::DebugUtils,
+    device: vk::Device,
+}
+
+impl VulkanDebugUtils {
+    pub fn new(library: &ash::Entry, instance: &ash::Instance, device: &ash::Device) -> Self {
+        Self {
+            inner: ext::DebugUtils::new(library, instance),
+            device: device.handle(),
+        }
+    }
+
+    pub fn set_object_name(
+        &self,
+        object_type: vk::ObjectType,
+        handle: u64,
+        name: &str,
+    ) -> Result<(), VulkanUniversalError> {
+        let name = Self::create_name(name)?;
+        let name_info = vk::DebugUtilsObjectNameInfoEXT {
+            s_type: vk::StructureType::DEBUG_UTILS_OBJECT_NAME_INFO_EXT,
+            p_next: ptr::null(),
+            object_type,
+            object_handle: handle,
+            p_object_name: name.as_ptr(),
+        };
+
+        unsafe {
+            self.inner
+                .set_debug_utils_object_name(self.device, &name_info)
+        }?;
+        Ok(())
+    }
+
+    pub fn begin_label(&self, command_buffer: vk::CommandBuffer, name: &CString, color: [f32; 4]) {
+        unsafe {
+            self.inner
+                .cmd_begin_debug_utils_label(command_buffer, &Self::create_label(name, color))
+        }
+    }
+
+    pub fn end_label(&self, command_buffer: vk::CommandBuffer) {
+        unsafe { self.inner.cmd_end_debug_utils_label(command_buffer) }
+    }
+
+    pub fn insert_label(&self, command_buffer: vk::CommandBuffer, name: &CString, color: [f32; 4]) {
+        unsafe {
+            self.inner
+                .cmd_insert_debug_utils_label(command_buffer, &Self::create_label(name, color))
+        }
+    }
+
+    fn create_name(name: &str) -> Result<CString, InvalidOperationError> {
+        CString::new(name)
+            .map_err(|_| InvalidOperationError::with_str("Name contains null character."))
+    }
+
+    fn create_label(name: &CString, color: [f32; 4]) -> vk::DebugUtilsLabelEXT {
+        vk::DebugUtilsLabelEXT {
+            s_type: vk::StructureType::DEBUG_UTILS_LABEL_EXT,
+            p_next: ptr::null(),
+            p_label_name: name.as_ptr(),
+            color,
+        }
+    }
+}
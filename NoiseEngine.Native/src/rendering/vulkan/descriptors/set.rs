@@ -1,4 +1,8 @@
-use std::{mem::ManuallyDrop, ptr, sync::Arc};
+use std::{
+    mem::{self, ManuallyDrop},
+    ptr,
+    sync::{Arc, Mutex},
+};
 
 use ash::vk;
 use libc::c_void;
@@ -12,10 +16,21 @@ use crate::{
 
 use super::{set_layout::DescriptorSetLayout, update_template::DescriptorUpdateTemplate};
 
+/// Descriptor written by the last update of its binding element, retained for capture of command
+/// streams. Only buffer descriptors keep their value.
+#[derive(Clone, Copy, Debug)]
+pub struct DescriptorWrite {
+    pub binding: u32,
+    pub array_element: u32,
+    pub descriptor_type: vk::DescriptorType,
+    pub buffer: Option<vk::DescriptorBufferInfo>,
+}
+
 pub struct DescriptorSet<'init> {
     layout: Arc<DescriptorSetLayout<'init>>,
     inner: vk::DescriptorSet,
     pool: ManuallyDrop<PoolItem<'init, VulkanDescriptorPool<'init>>>,
+    writes: Mutex<Vec<DescriptorWrite>>,
 }
 
 impl<'init> DescriptorSet<'init> {
@@ -46,6 +61,7 @@ impl<'init> DescriptorSet<'init> {
             layout: layout.clone(),
             inner,
             pool: ManuallyDrop::new(pool),
+            writes: Mutex::new(Vec::new()),
         })
     }
 
@@ -53,6 +69,14 @@ impl<'init> DescriptorSet<'init> {
         self.inner
     }
 
+    pub fn layout(&self) -> &Arc<DescriptorSetLayout<'init>> {
+        &self.layout
+    }
+
+    pub fn writes(&self) -> Vec<DescriptorWrite> {
+        self.writes.lock().unwrap().clone()
+    }
+
     pub fn update(&self, template: &DescriptorUpdateTemplate, data: &[u8]) {
         unsafe {
             self.layout
@@ -66,6 +90,50 @@ impl<'init> DescriptorSet<'init> {
                     data.as_ptr() as *const c_void,
                 );
         }
+
+        let mut writes = self.writes.lock().unwrap();
+        for entry in template.entries() {
+            for i in 0..entry.descriptor_count {
+                let write = DescriptorWrite {
+                    binding: entry.dst_binding,
+                    array_element: entry.dst_array_element.saturating_add(i),
+                    descriptor_type: entry.descriptor_type,
+                    buffer: read_buffer_info(entry, i as usize, data),
+                };
+
+                match writes.iter_mut().find(|other| {
+                    other.binding == write.binding && other.array_element == write.array_element
+                }) {
+                    Some(other) => *other = write,
+                    None => writes.push(write),
+                }
+            }
+        }
+    }
+}
+
+fn read_buffer_info(
+    entry: &vk::DescriptorUpdateTemplateEntry,
+    index: usize,
+    data: &[u8],
+) -> Option<vk::DescriptorBufferInfo> {
+    if !matches!(
+        entry.descriptor_type,
+        vk::DescriptorType::UNIFORM_BUFFER
+            | vk::DescriptorType::STORAGE_BUFFER
+            | vk::DescriptorType::UNIFORM_BUFFER_DYNAMIC
+            | vk::DescriptorType::STORAGE_BUFFER_DYNAMIC
+    ) {
+        return None;
+    }
+
+    let offset = entry.offset.checked_add(entry.stride.checked_mul(index)?)?;
+    let end = offset.checked_add(mem::size_of::<vk::DescriptorBufferInfo>())?;
+    match end <= data.len() {
+        true => Some(unsafe {
+            ptr::read_unaligned(data[offset..].as_ptr() as *const vk::DescriptorBufferInfo)
+        }),
+        false => None,
     }
 }
 
@@ -75,14 +143,14 @@ impl Drop for DescriptorSet<'_> {
             ManuallyDrop::drop(&mut self.pool);
         }
 
-        unsafe {
+        // Reset fails only on lost device, whose pools are not reused after recreation.
+        _ = unsafe {
             self.layout
                 .device()
                 .initialized()
                 .unwrap()
                 .vulkan_device()
                 .reset_descriptor_pool(self.pool.inner(), vk::DescriptorPoolResetFlags::default())
-        }
-        .unwrap();
+        };
     }
 }
@@ -10,8 +10,21 @@ use crate::rendering::vulkan::{device::VulkanDevice, errors::universal::VulkanUn
 
 use super::pool_sizes::DescriptorPoolSizes;
 
+/// Binding of [`DescriptorSetLayout`], retained for capture of command streams.
+#[derive(Clone, Copy, Debug)]
+pub struct DescriptorSetLayoutBinding {
+    pub binding: u32,
+    pub descriptor_type: vk::DescriptorType,
+    pub descriptor_count: u32,
+    pub stage_flags: vk::ShaderStageFlags,
+    /// Immutable samplers are not retained, so layouts which use them cannot be rebuilt.
+    pub immutable_samplers: bool,
+}
+
 pub struct DescriptorSetLayout<'init> {
     inner: vk::DescriptorSetLayout,
+    flags: vk::DescriptorSetLayoutCreateFlags,
+    bindings: Vec<DescriptorSetLayoutBinding>,
     pool_sizes: Arc<DescriptorPoolSizes>,
     device: Arc<VulkanDevice<'init>>,
 }
@@ -52,6 +65,17 @@ impl<'dev: 'init, 'init> DescriptorSetLayout<'init> {
 
         Ok(Self {
             inner,
+            flags,
+            bindings: bindings
+                .iter()
+                .map(|binding| DescriptorSetLayoutBinding {
+                    binding: binding.binding,
+                    descriptor_type: binding.descriptor_type,
+                    descriptor_count: binding.descriptor_count,
+                    stage_flags: binding.stage_flags,
+                    immutable_samplers: !binding.p_immutable_samplers.is_null(),
+                })
+                .collect(),
             pool_sizes: Arc::new(DescriptorPoolSizes {
                 map: pool_sizes,
                 count: sum_count,
@@ -64,6 +88,14 @@ impl<'dev: 'init, 'init> DescriptorSetLayout<'init> {
         self.inner
     }
 
+    pub fn flags(&self) -> vk::DescriptorSetLayoutCreateFlags {
+        self.flags
+    }
+
+    pub fn bindings(&self) -> &[DescriptorSetLayoutBinding] {
+        &self.bindings
+    }
+
     pub fn pool_sizes(&self) -> &Arc<DescriptorPoolSizes> {
         &self.pool_sizes
     }
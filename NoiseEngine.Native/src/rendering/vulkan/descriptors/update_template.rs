@@ -9,6 +9,7 @@ use super::set_layout::DescriptorSetLayout;
 pub struct DescriptorUpdateTemplate<'init> {
     layout: Arc<DescriptorSetLayout<'init>>,
     inner: vk::DescriptorUpdateTemplate,
+    entries: Vec<vk::DescriptorUpdateTemplateEntry>,
 }
 
 impl<'init> DescriptorUpdateTemplate<'init> {
@@ -39,12 +40,17 @@ impl<'init> DescriptorUpdateTemplate<'init> {
         Ok(Self {
             layout: layout.clone(),
             inner,
+            entries: entries.to_vec(),
         })
     }
 
     pub fn inner(&self) -> vk::DescriptorUpdateTemplate {
         self.inner
     }
+
+    pub fn entries(&self) -> &[vk::DescriptorUpdateTemplateEntry] {
+        &self.entries
+    }
 }
 
 impl Drop for DescriptorUpdateTemplate<'_> {
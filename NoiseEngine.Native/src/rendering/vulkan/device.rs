@@ -1,7 +1,20 @@
-use std::{ffi::CString, mem::ManuallyDrop, ptr, rc::Rc, sync::Arc};
+use std::{
+    collections::HashMap,
+    ffi::{c_void, CStr, CString},
+    mem::ManuallyDrop,
+    ptr,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
 
-use ash::vk::{self, QueueFlags};
-use lockfree::stack::Stack;
+use ash::{
+    extensions::khr,
+    prelude::VkResult,
+    vk::{self, QueueFlags},
+};
+use lockfree::queue::Queue;
 use rsevents::{AutoResetEvent, Awaitable, EventState};
 
 use crate::{
@@ -12,15 +25,43 @@ use crate::{
 };
 
 use super::{
-    device_pool::VulkanDevicePool, device_support::VulkanDeviceSupport,
-    errors::universal::VulkanUniversalError, instance::VulkanInstance,
-    memory_allocator::MemoryAllocator, pool_wrappers::VulkanCommandPool,
+    buffers::buffer::VulkanBufferDescription,
+    debug_utils::VulkanDebugUtils,
+    device_fault::VulkanDeviceFault,
+    device_features::VulkanDeviceFeatures,
+    device_limits::VulkanDeviceLimits,
+    device_lost_handler::{VulkanDeviceLostData, VulkanDeviceLostHandler},
+    device_pool::VulkanDevicePool,
+    device_support::VulkanDeviceSupport,
+    dynamic_rendering::{DynamicRendering, DynamicRenderingSupport},
+    errors::universal::VulkanUniversalError,
+    instance::VulkanInstance,
+    memory_allocator::MemoryAllocator,
+    pipeline_cache::PipelineCache,
+    pool_wrappers::VulkanCommandPool,
+    timeline_semaphore::VulkanTimelineSemaphore,
 };
 
+/// Count of queues created in each family, when queues are not configured explicitly.
+const DEFAULT_QUEUES_PER_FAMILY: u32 = 4;
+
+/// Queue created by device initialization.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct VulkanQueueConfiguration {
+    pub family_index: u32,
+    /// Priority between 0 and 1, which is a hint for scheduling of queues from the same family.
+    pub priority: f32,
+}
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
 pub struct VulkanDevice<'init> {
     initialized: Option<VulkanDeviceInitialized<'init>>,
     physical_device: vk::PhysicalDevice,
     instance: Arc<VulkanInstance>,
+    id: u64,
+    lost: AtomicBool,
 }
 
 impl<'init> VulkanDevice<'init> {
@@ -29,6 +70,8 @@ impl<'init> VulkanDevice<'init> {
             initialized: None,
             physical_device,
             instance: instance.clone(),
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            lost: AtomicBool::new(false),
         }
     }
 
@@ -39,6 +82,8 @@ impl<'init> VulkanDevice<'init> {
     pub fn initialize(
         &mut self,
         enabled_extensions: &[InteropString],
+        enabled_features: &[InteropString],
+        queues: &[VulkanQueueConfiguration],
     ) -> Result<(), VulkanUniversalError> {
         if self.initialized.is_some() {
             return Err(
@@ -46,11 +91,47 @@ impl<'init> VulkanDevice<'init> {
             );
         }
 
-        let (queue_create_infos, _queue_create_info_priorities) = self.create_queue_create_infos();
-        let physical_device_features = vk::PhysicalDeviceFeatures {
+        let queue_priorities = self.queue_priorities(queues)?;
+        let queue_create_infos: Vec<_> = queue_priorities
+            .iter()
+            .zip(0u32..)
+            .filter(|(priorities, _)| !priorities.is_empty())
+            .map(
+                |(priorities, queue_family_index)| vk::DeviceQueueCreateInfo {
+                    s_type: vk::StructureType::DEVICE_QUEUE_CREATE_INFO,
+                    p_next: ptr::null(),
+                    flags: vk::DeviceQueueCreateFlags::empty(),
+                    queue_family_index,
+                    queue_count: priorities.len() as u32,
+                    p_queue_priorities: priorities.as_ptr(),
+                },
+            )
+            .collect();
+
+        let supported_features =
+            VulkanDeviceFeatures::query(self.instance().inner(), self.physical_device);
+        let mut features = supported_features.to_empty();
+
+        // Optional pipeline state and query features are enabled when supported, usage of them is
+        // validated during pipeline and query pool creation.
+        features.core = vk::PhysicalDeviceFeatures {
+            depth_clamp: supported_features.core.depth_clamp,
+            depth_bias_clamp: supported_features.core.depth_bias_clamp,
+            depth_bounds: supported_features.core.depth_bounds,
+            fill_mode_non_solid: supported_features.core.fill_mode_non_solid,
+            wide_lines: supported_features.core.wide_lines,
+            independent_blend: supported_features.core.independent_blend,
+            dual_src_blend: supported_features.core.dual_src_blend,
+            sample_rate_shading: supported_features.core.sample_rate_shading,
+            pipeline_statistics_query: supported_features.core.pipeline_statistics_query,
+            occlusion_query_precise: supported_features.core.occlusion_query_precise,
             ..Default::default()
         };
 
+        for feature in enabled_features {
+            features.enable(feature.into(), &supported_features)?;
+        }
+
         let mut enabled_extensions_c = Vec::new();
         let mut enabled_extensions_result = Vec::new();
 
@@ -68,28 +149,79 @@ impl<'init> VulkanDevice<'init> {
             enabled_extensions_c.push(c);
         }
 
+        // Dynamic rendering is used instead of render pass objects when supported.
+        let dynamic_rendering_support =
+            DynamicRenderingSupport::query(self.instance().inner(), self.physical_device);
+        let mut dynamic_rendering_features = vk::PhysicalDeviceDynamicRenderingFeatures {
+            dynamic_rendering: vk::TRUE,
+            ..Default::default()
+        };
+        let mut required_extensions = Vec::new();
+        match dynamic_rendering_support {
+            Some(DynamicRenderingSupport::Core) => {
+                features.enable("dynamicRendering", &supported_features)?
+            }
+            Some(DynamicRenderingSupport::Extension) => {
+                required_extensions.push(khr::DynamicRendering::name())
+            }
+            None => (),
+        }
+
+        // Timeline semaphores are used for dependencies between submissions to different queues.
+        let timeline_semaphore =
+            VulkanTimelineSemaphore::is_supported(self.instance().inner(), self.physical_device);
+        if timeline_semaphore {
+            features.enable("timelineSemaphore", &supported_features)?;
+        }
+
+        // Fault information is used only for diagnostics of lost device.
+        let device_fault = supported_features.get("deviceFault") == Some(true);
+        if device_fault {
+            features.enable("deviceFault", &supported_features)?;
+        }
+
+        required_extensions.extend(features.required_extensions());
+        for extension in required_extensions {
+            if !enabled_extensions_c
+                .iter()
+                .any(|enabled| enabled.as_c_str() == extension)
+            {
+                enabled_extensions_result.push(extension.as_ptr());
+            }
+        }
+
+        let mut p_next = features.chain();
+        if dynamic_rendering_support == Some(DynamicRenderingSupport::Extension) {
+            dynamic_rendering_features.p_next = p_next;
+            p_next = &mut dynamic_rendering_features as *mut _ as *mut c_void;
+        }
+
         let create_info = vk::DeviceCreateInfo {
             s_type: vk::StructureType::DEVICE_CREATE_INFO,
-            p_next: ptr::null(),
+            p_next,
             flags: vk::DeviceCreateFlags::empty(),
             queue_create_info_count: queue_create_infos.len() as u32,
             p_queue_create_infos: queue_create_infos.as_ptr(),
             enabled_extension_count: enabled_extensions_result.len() as u32,
             pp_enabled_extension_names: enabled_extensions_result.as_ptr(),
-            p_enabled_features: &physical_device_features,
+            p_enabled_features: &features.core,
             ..Default::default()
         };
 
         let device = unsafe {
-            Rc::new(self.instance().inner().create_device(
+            Arc::new(self.instance().inner().create_device(
                 self.physical_device,
                 &create_info,
                 None,
             )?)
         };
 
-        let queue_families =
-            Self::create_queue_families(&self.instance, self.physical_device, device.clone());
+        let queue_families = Self::create_queue_families(
+            &self.instance,
+            self.physical_device,
+            device.clone(),
+            &queue_priorities,
+        );
 
         self.initialized = Some(VulkanDeviceInitialized {
             device: device.clone(),
@@ -100,12 +232,35 @@ impl<'init> VulkanDevice<'init> {
                 self.physical_device(),
             )?),
             pool: ManuallyDrop::new(VulkanDevicePool::new(device.clone())),
+            pipeline_cache: ManuallyDrop::new(PipelineCache::new(device.clone(), &unsafe {
+                self.instance()
+                    .inner()
+                    .get_physical_device_properties(self.physical_device)
+            })?),
+            buffers: Mutex::new(HashMap::new()),
+            enabled_features: features,
+            dynamic_rendering: dynamic_rendering_support
+                .map(|support| DynamicRendering::new(support, self.instance().inner(), &device)),
+            timeline_semaphore,
+            debug_utils: match self.instance().debug_utils() {
+                true => Some(VulkanDebugUtils::new(
+                    self.instance().library(),
+                    self.instance().inner(),
+                    &device,
+                )),
+                false => None,
+            },
+            device_fault: match device_fault {
+                true => Some(VulkanDeviceFault::new(self.instance().inner(), &device)),
+                false => None,
+            },
         });
 
         log::info(
             format!(
-                "Initialized VulkanDevice {{ InnerHandle = {:p} }}.",
-                device.handle()
+                "Initialized VulkanDevice {{ InnerHandle = {:p}, DynamicRendering = {:?} }}.",
+                device.handle(),
+                dynamic_rendering_support
             )
             .as_str(),
         );
@@ -120,6 +275,138 @@ impl<'init> VulkanDevice<'init> {
         self.physical_device
     }
 
+    /// Returns identifier, which is unique for each created device, including recreated ones.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns `true` when any operation of this device returned `VK_ERROR_DEVICE_LOST`. Lost
+    /// device keeps its objects, which still can be destroyed, but it must be recreated to be used
+    /// again.
+    pub fn is_lost(&self) -> bool {
+        self.lost.load(Ordering::Acquire)
+    }
+
+    /// Returns `VK_ERROR_DEVICE_LOST` when this device is lost, which prevents submissions to it.
+    pub fn ensure_not_lost(&self) -> VkResult<()> {
+        match self.is_lost() {
+            true => Err(vk::Result::ERROR_DEVICE_LOST),
+            false => Ok(()),
+        }
+    }
+
+    /// Marks this device as lost when `result` is `VK_ERROR_DEVICE_LOST`, and returns `result`.
+    pub fn check_lost<T>(&self, result: VkResult<T>) -> VkResult<T> {
+        if result.as_ref().err() == Some(&vk::Result::ERROR_DEVICE_LOST) {
+            self.mark_lost();
+        }
+        result
+    }
+
+    /// Reports loss of this device, only for the first time.
+    fn mark_lost(&self) {
+        if self.lost.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        let fault = self
+            .initialized
+            .as_ref()
+            .and_then(|initialized| initialized.device_fault.as_ref())
+            .and_then(|device_fault| device_fault.describe())
+            .unwrap_or_default();
+
+        let properties = unsafe {
+            self.instance()
+                .inner()
+                .get_physical_device_properties(self.physical_device)
+        };
+        let message = format!(
+            "VulkanDevice {{ Name = {:?}, Id = {} }} was lost.",
+            unsafe { CStr::from_ptr(properties.device_name.as_ptr()) },
+            self.id
+        );
+
+        match fault.is_empty() {
+            true => log::error(&message),
+            false => log::error(&format!("{} Fault: {}", message, fault)),
+        }
+
+        if let Some(handler) = VulkanDeviceLostHandler::get() {
+            unsafe {
+                (handler.lost)(
+                    self.id,
+                    VulkanDeviceLostData {
+                        message: message.as_bytes().into(),
+                        fault: fault.as_bytes().into(),
+                    },
+                )
+            };
+        }
+    }
+
+    /// Returns sample counts supported by framebuffer color attachments, and when `depth_stencil`
+    /// is `true` also by depth stencil attachments.
+    pub fn supported_sample_counts(&self, depth_stencil: bool) -> vk::SampleCountFlags {
+        let limits = unsafe {
+            self.instance()
+                .inner()
+                .get_physical_device_properties(self.physical_device)
+        }
+        .limits;
+
+        match depth_stencil {
+            true => limits.framebuffer_color_sample_counts & limits.framebuffer_depth_sample_counts,
+            false => limits.framebuffer_color_sample_counts,
+        }
+    }
+
+    /// Returns features supported by this device.
+    pub fn supported_features(&self) -> VulkanDeviceFeatures {
+        VulkanDeviceFeatures::query(self.instance().inner(), self.physical_device)
+    }
+
+    pub fn limits(&self) -> VulkanDeviceLimits {
+        VulkanDeviceLimits::query(self.instance().inner(), self.physical_device)
+    }
+
+    /// Checks if optimal tiling 2D images with `format` can be created with `usage`.
+    pub fn is_image_format_supported(
+        &self,
+        format: vk::Format,
+        usage: vk::ImageUsageFlags,
+    ) -> bool {
+        unsafe {
+            self.instance()
+                .inner()
+                .get_physical_device_image_format_properties(
+                    self.physical_device,
+                    format,
+                    vk::ImageType::TYPE_2D,
+                    vk::ImageTiling::OPTIMAL,
+                    usage,
+                    vk::ImageCreateFlags::empty(),
+                )
+        }
+        .is_ok()
+    }
+
+    pub fn queue_family_properties(&self) -> Vec<vk::QueueFamilyProperties> {
+        unsafe {
+            self.instance()
+                .inner()
+                .get_physical_device_queue_family_properties(self.physical_device)
+        }
+    }
+
+    pub fn memory_properties(&self) -> vk::PhysicalDeviceMemoryProperties {
+        unsafe {
+            self.instance()
+                .inner()
+                .get_physical_device_memory_properties(self.physical_device)
+        }
+    }
+
     pub fn get_queue<'dev: 'init>(
         &'dev self,
         support: VulkanDeviceSupport,
@@ -139,47 +426,67 @@ impl<'init> VulkanDevice<'init> {
         }
     }
 
-    fn create_queue_create_infos(&self) -> (Vec<vk::DeviceQueueCreateInfo>, Vec<f32>) {
-        let queue_families = unsafe {
-            self.instance()
-                .inner()
-                .get_physical_device_queue_family_properties(self.physical_device)
-        };
+    /// Returns priorities of queues created in each family, grouped by family index.
+    fn queue_priorities(
+        &self,
+        queues: &[VulkanQueueConfiguration],
+    ) -> Result<Vec<Vec<f32>>, InvalidOperationError> {
+        let queue_families = self.queue_family_properties();
+
+        if queues.is_empty() {
+            return Ok(queue_families
+                .iter()
+                .map(|family| vec![1.0; family.queue_count.min(DEFAULT_QUEUES_PER_FAMILY) as usize])
+                .collect());
+        }
 
-        let mut queue_create_infos = Vec::with_capacity(queue_families.len());
+        let mut priorities = vec![Vec::new(); queue_families.len()];
+        for queue in queues {
+            let family = match queue_families.get(queue.family_index as usize) {
+                Some(family) => family,
+                None => {
+                    return Err(InvalidOperationError::new(format!(
+                        "Queue family {} does not exist.",
+                        queue.family_index
+                    )))
+                }
+            };
 
-        let mut queues = Vec::new();
-        for (queue_family_index, queue_family) in (0u32..).zip(queue_families.into_iter()) {
-            for _ in queues.len()..queue_family.queue_count as usize {
-                queues.push(1.0)
+            if !(0.0..=1.0).contains(&queue.priority) {
+                return Err(InvalidOperationError::new(format!(
+                    "Queue priority {} is not between 0 and 1.",
+                    queue.priority
+                )));
             }
 
-            queue_create_infos.push(vk::DeviceQueueCreateInfo {
-                s_type: vk::StructureType::DEVICE_QUEUE_CREATE_INFO,
-                p_next: ptr::null(),
-                flags: vk::DeviceQueueCreateFlags::empty(),
-                queue_family_index,
-                queue_count: queue_family.queue_count,
-                p_queue_priorities: queues.as_ptr(),
-            });
+            let family_priorities = &mut priorities[queue.family_index as usize];
+            if family_priorities.len() >= family.queue_count as usize {
+                return Err(InvalidOperationError::new(format!(
+                    "Queue family {} has only {} queues.",
+                    queue.family_index, family.queue_count
+                )));
+            }
+            family_priorities.push(queue.priority);
         }
 
-        (queue_create_infos, queues)
+        Ok(priorities)
     }
 
     fn create_queue_families(
         instance: &Arc<VulkanInstance>,
         physical_device: vk::PhysicalDevice,
-        device: Rc<ash::Device>,
+        device: Arc<ash::Device>,
+        priorities: &[Vec<f32>],
     ) -> Vec<VulkanQueueFamily<'init>> {
-        let mut queue_family_index = 0;
         let mut families: Vec<_> = unsafe {
             instance
                 .inner()
                 .get_physical_device_queue_family_properties(physical_device)
         }
         .into_iter()
-        .map(|family| {
+        .zip(0u32..)
+        .filter(|(_, queue_family_index)| !priorities[*queue_family_index as usize].is_empty())
+        .map(|(family, queue_family_index)| {
             let result = VulkanQueueFamily {
                 vulkan_device: device.clone(),
                 index: queue_family_index,
@@ -187,18 +494,18 @@ impl<'init> VulkanDevice<'init> {
                     graphics: family.queue_flags.contains(QueueFlags::GRAPHICS),
                     computing: family.queue_flags.contains(QueueFlags::COMPUTE),
                     transfer: family.queue_flags.contains(QueueFlags::TRANSFER),
+                    dedicated: false,
                 },
-                queues: Stack::new(),
+                timestamp_valid_bits: family.timestamp_valid_bits,
+                queues: Queue::new(),
                 reset_event: AutoResetEvent::new(EventState::Unset),
                 command_pools: Pool::default(),
             };
 
-            for i in 0..family.queue_count {
+            for i in 0..priorities[queue_family_index as usize].len() as u32 {
                 result.push_queue(unsafe { device.get_device_queue(queue_family_index, i) });
             }
 
-            queue_family_index += 1;
-
             result
         })
         .collect();
@@ -209,10 +516,17 @@ impl<'init> VulkanDevice<'init> {
 }
 
 pub(crate) struct VulkanDeviceInitialized<'init> {
-    device: Rc<ash::Device>,
+    device: Arc<ash::Device>,
     queue_families: ManuallyDrop<Vec<VulkanQueueFamily<'init>>>,
     allocator: ManuallyDrop<MemoryAllocator>,
     pool: ManuallyDrop<VulkanDevicePool<'init>>,
+    pipeline_cache: ManuallyDrop<PipelineCache>,
+    buffers: Mutex<HashMap<vk::Buffer, VulkanBufferDescription>>,
+    enabled_features: VulkanDeviceFeatures,
+    dynamic_rendering: Option<DynamicRendering>,
+    timeline_semaphore: bool,
+    debug_utils: Option<VulkanDebugUtils>,
+    device_fault: Option<VulkanDeviceFault>,
 }
 
 impl<'init> VulkanDeviceInitialized<'init> {
@@ -228,6 +542,55 @@ impl<'init> VulkanDeviceInitialized<'init> {
         &self.pool
     }
 
+    pub fn pipeline_cache(&self) -> &PipelineCache {
+        &self.pipeline_cache
+    }
+
+    pub fn enabled_features(&self) -> &vk::PhysicalDeviceFeatures {
+        &self.enabled_features.core
+    }
+
+    /// Returns all enabled features, including the ones of Vulkan 1.1 and newer.
+    pub fn features(&self) -> &VulkanDeviceFeatures {
+        &self.enabled_features
+    }
+
+    /// Returns entry points of dynamic rendering, or `None` when device supports only render
+    /// pass objects.
+    pub fn dynamic_rendering(&self) -> Option<&DynamicRendering> {
+        self.dynamic_rendering.as_ref()
+    }
+
+    pub fn timeline_semaphore(&self) -> bool {
+        self.timeline_semaphore
+    }
+
+    /// Returns `None` when `VK_EXT_debug_utils` is not enabled.
+    pub fn debug_utils(&self) -> Option<&VulkanDebugUtils> {
+        self.debug_utils.as_ref()
+    }
+
+    /// Sets name of the object, which does nothing when `VK_EXT_debug_utils` is not enabled.
+    pub fn set_object_name<T: vk::Handle>(
+        &self,
+        handle: T,
+        name: &str,
+    ) -> Result<(), VulkanUniversalError> {
+        self.set_object_name_raw(T::TYPE, handle.as_raw(), name)
+    }
+
+    pub fn set_object_name_raw(
+        &self,
+        object_type: vk::ObjectType,
+        handle: u64,
+        name: &str,
+    ) -> Result<(), VulkanUniversalError> {
+        match &self.debug_utils {
+            Some(debug_utils) => debug_utils.set_object_name(object_type, handle, name),
+            None => Ok(()),
+        }
+    }
+
     pub fn queue_families_count(&self) -> usize {
         self.queue_families.len()
     }
@@ -236,6 +599,8 @@ impl<'init> VulkanDeviceInitialized<'init> {
         &self.queue_families
     }
 
+    /// Returns first family suitable to `support`. When `support.dedicated` is `true` and device
+    /// does not have dedicated family, falls back to shared one.
     pub fn get_family(
         &self,
         support: VulkanDeviceSupport,
@@ -246,16 +611,38 @@ impl<'init> VulkanDeviceInitialized<'init> {
             }
         }
 
+        if support.dedicated {
+            return self.get_family(VulkanDeviceSupport {
+                dedicated: false,
+                ..support
+            });
+        }
+
         Err(InvalidOperationError::with_str(
             "This VulkanDevice does not have suitable families.",
         ))
     }
+
+    /// Returns description of alive buffer created from this device, which is referenced only by
+    /// raw handle.
+    pub fn get_buffer_description(&self, buffer: vk::Buffer) -> Option<VulkanBufferDescription> {
+        self.buffers.lock().unwrap().get(&buffer).copied()
+    }
+
+    pub(super) fn register_buffer(&self, buffer: vk::Buffer, description: VulkanBufferDescription) {
+        self.buffers.lock().unwrap().insert(buffer, description);
+    }
+
+    pub(super) fn unregister_buffer(&self, buffer: vk::Buffer) {
+        self.buffers.lock().unwrap().remove(&buffer);
+    }
 }
 
 impl Drop for VulkanDeviceInitialized<'_> {
     fn drop(&mut self) {
         unsafe {
             ManuallyDrop::drop(&mut self.pool);
+            ManuallyDrop::drop(&mut self.pipeline_cache);
             ManuallyDrop::drop(&mut self.queue_families);
             ManuallyDrop::drop(&mut self.allocator);
 
@@ -273,10 +660,11 @@ impl Drop for VulkanDeviceInitialized<'_> {
 }
 
 pub struct VulkanQueueFamily<'fam> {
-    vulkan_device: Rc<ash::Device>,
+    vulkan_device: Arc<ash::Device>,
     index: u32,
     support: VulkanDeviceSupport,
-    queues: Stack<vk::Queue>,
+    timestamp_valid_bits: u32,
+    queues: Queue<vk::Queue>,
     reset_event: AutoResetEvent,
     command_pools: Pool<VulkanCommandPool<'fam>>,
 }
@@ -290,6 +678,12 @@ impl<'fam> VulkanQueueFamily<'fam> {
         &self.support
     }
 
+    /// Returns count of meaningful bits of timestamps written on this family, which is zero when
+    /// timestamps are not supported.
+    pub fn timestamp_valid_bits(&self) -> u32 {
+        self.timestamp_valid_bits
+    }
+
     pub fn try_get_queue(&self) -> Option<VulkanQueue<'fam, '_>> {
         self.queues.pop().map(|queue| VulkanQueue {
             family: self,
@@ -0,0 +1,77 @@
+use std::{ffi::CStr, fmt::Write, mem, ptr};
+
+use ash::vk;
+
+/// Entry points of `VK_EXT_device_fault`, which describes why the device was lost.
+pub struct VulkanDeviceFault {
+    fp: vk::ExtDeviceFaultFn,
+    device: vk::Device,
+}
+
+impl VulkanDeviceFault {
+    pub fn new(instance: &ash::Instance, device: &ash::Device) -> Self {
+        let handle = device.handle();
+        Self {
+            fp: vk::ExtDeviceFaultFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            }),
+            device: handle,
+        }
+    }
+
+    /// Returns human readable description of the fault, or `None` when driver did not report any.
+    /// Must be called only after the device was lost.
+    pub fn describe(&self) -> Option<String> {
+        let mut counts = vk::DeviceFaultCountsEXT::default();
+        let result = unsafe {
+            (self.fp.get_device_fault_info_ext)(self.device, &mut counts, ptr::null_mut())
+        };
+        if result != vk::Result::SUCCESS {
+            return None;
+        }
+
+        let mut address_infos =
+            vec![vk::DeviceFaultAddressInfoEXT::default(); counts.address_info_count as usize];
+        let mut vendor_infos =
+            vec![vk::DeviceFaultVendorInfoEXT::default(); counts.vendor_info_count as usize];
+
+        // Vendor binary is not human readable, so it is not requested.
+        counts.vendor_binary_size = 0;
+        let mut info = vk::DeviceFaultInfoEXT {
+            p_address_infos: address_infos.as_mut_ptr(),
+            p_vendor_infos: vendor_infos.as_mut_ptr(),
+            ..Default::default()
+        };
+
+        match unsafe { (self.fp.get_device_fault_info_ext)(self.device, &mut counts, &mut info) } {
+            vk::Result::SUCCESS | vk::Result::INCOMPLETE => (),
+            _ => return None,
+        }
+
+        let mut description = unsafe { CStr::from_ptr(info.description.as_ptr()) }
+            .to_string_lossy()
+            .into_owned();
+
+        for address in &address_infos[..counts.address_info_count as usize] {
+            write!(
+                description,
+                "\nAddress {:?}: 0x{:x} (precision 0x{:x})",
+                address.address_type, address.reported_address, address.address_precision
+            )
+            .unwrap();
+        }
+
+        for vendor in &vendor_infos[..counts.vendor_info_count as usize] {
+            write!(
+                description,
+                "\nVendor: {} (code 0x{:x}, data 0x{:x})",
+                unsafe { CStr::from_ptr(vendor.description.as_ptr()) }.to_string_lossy(),
+                vendor.vendor_fault_code,
+                vendor.vendor_fault_data
+            )
+            .unwrap();
+        }
+
+        Some(description)
+    }
+}
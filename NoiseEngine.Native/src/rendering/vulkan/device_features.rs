@@ -0,0 +1,396 @@
+use std::{
+    ffi::{c_void, CStr},
+    ptr,
+};
+
+use ash::vk;
+
+use crate::errors::invalid_operation::InvalidOperationError;
+
+macro_rules! features {
+    ($($group:ident($member:ident) { $($field:ident => $name:literal,)* })*) => {
+        const FEATURES: &[VulkanFeature] = &[$($(VulkanFeature {
+            name: $name,
+            group: VulkanFeatureGroup::$group,
+            get: |features| features.$member.$field != vk::FALSE,
+            enable: |features| features.$member.$field = vk::TRUE,
+        },)*)*];
+    };
+}
+
+features! {
+    Core(core) {
+        robust_buffer_access => "robustBufferAccess",
+        full_draw_index_uint32 => "fullDrawIndexUint32",
+        image_cube_array => "imageCubeArray",
+        independent_blend => "independentBlend",
+        geometry_shader => "geometryShader",
+        tessellation_shader => "tessellationShader",
+        sample_rate_shading => "sampleRateShading",
+        dual_src_blend => "dualSrcBlend",
+        logic_op => "logicOp",
+        multi_draw_indirect => "multiDrawIndirect",
+        draw_indirect_first_instance => "drawIndirectFirstInstance",
+        depth_clamp => "depthClamp",
+        depth_bias_clamp => "depthBiasClamp",
+        fill_mode_non_solid => "fillModeNonSolid",
+        depth_bounds => "depthBounds",
+        wide_lines => "wideLines",
+        large_points => "largePoints",
+        alpha_to_one => "alphaToOne",
+        multi_viewport => "multiViewport",
+        sampler_anisotropy => "samplerAnisotropy",
+        texture_compression_etc2 => "textureCompressionETC2",
+        texture_compression_astc_ldr => "textureCompressionASTC_LDR",
+        texture_compression_bc => "textureCompressionBC",
+        occlusion_query_precise => "occlusionQueryPrecise",
+        pipeline_statistics_query => "pipelineStatisticsQuery",
+        vertex_pipeline_stores_and_atomics => "vertexPipelineStoresAndAtomics",
+        fragment_stores_and_atomics => "fragmentStoresAndAtomics",
+        shader_tessellation_and_geometry_point_size => "shaderTessellationAndGeometryPointSize",
+        shader_image_gather_extended => "shaderImageGatherExtended",
+        shader_storage_image_extended_formats => "shaderStorageImageExtendedFormats",
+        shader_storage_image_multisample => "shaderStorageImageMultisample",
+        shader_storage_image_read_without_format => "shaderStorageImageReadWithoutFormat",
+        shader_storage_image_write_without_format => "shaderStorageImageWriteWithoutFormat",
+        shader_uniform_buffer_array_dynamic_indexing => "shaderUniformBufferArrayDynamicIndexing",
+        shader_sampled_image_array_dynamic_indexing => "shaderSampledImageArrayDynamicIndexing",
+        shader_storage_buffer_array_dynamic_indexing => "shaderStorageBufferArrayDynamicIndexing",
+        shader_storage_image_array_dynamic_indexing => "shaderStorageImageArrayDynamicIndexing",
+        shader_clip_distance => "shaderClipDistance",
+        shader_cull_distance => "shaderCullDistance",
+        shader_float64 => "shaderFloat64",
+        shader_int64 => "shaderInt64",
+        shader_int16 => "shaderInt16",
+        shader_resource_residency => "shaderResourceResidency",
+        shader_resource_min_lod => "shaderResourceMinLod",
+        sparse_binding => "sparseBinding",
+        sparse_residency_buffer => "sparseResidencyBuffer",
+        sparse_residency_image2_d => "sparseResidencyImage2D",
+        sparse_residency_image3_d => "sparseResidencyImage3D",
+        sparse_residency2_samples => "sparseResidency2Samples",
+        sparse_residency4_samples => "sparseResidency4Samples",
+        sparse_residency8_samples => "sparseResidency8Samples",
+        sparse_residency16_samples => "sparseResidency16Samples",
+        sparse_residency_aliased => "sparseResidencyAliased",
+        variable_multisample_rate => "variableMultisampleRate",
+        inherited_queries => "inheritedQueries",
+    }
+    Vulkan11(vulkan11) {
+        storage_buffer16_bit_access => "storageBuffer16BitAccess",
+        uniform_and_storage_buffer16_bit_access => "uniformAndStorageBuffer16BitAccess",
+        storage_push_constant16 => "storagePushConstant16",
+        storage_input_output16 => "storageInputOutput16",
+        multiview => "multiview",
+        multiview_geometry_shader => "multiviewGeometryShader",
+        multiview_tessellation_shader => "multiviewTessellationShader",
+        variable_pointers_storage_buffer => "variablePointersStorageBuffer",
+        variable_pointers => "variablePointers",
+        protected_memory => "protectedMemory",
+        sampler_ycbcr_conversion => "samplerYcbcrConversion",
+        shader_draw_parameters => "shaderDrawParameters",
+    }
+    Vulkan12(vulkan12) {
+        sampler_mirror_clamp_to_edge => "samplerMirrorClampToEdge",
+        draw_indirect_count => "drawIndirectCount",
+        storage_buffer8_bit_access => "storageBuffer8BitAccess",
+        uniform_and_storage_buffer8_bit_access => "uniformAndStorageBuffer8BitAccess",
+        storage_push_constant8 => "storagePushConstant8",
+        shader_buffer_int64_atomics => "shaderBufferInt64Atomics",
+        shader_shared_int64_atomics => "shaderSharedInt64Atomics",
+        shader_float16 => "shaderFloat16",
+        shader_int8 => "shaderInt8",
+        descriptor_indexing => "descriptorIndexing",
+        shader_input_attachment_array_dynamic_indexing => "shaderInputAttachmentArrayDynamicIndexing",
+        shader_uniform_texel_buffer_array_dynamic_indexing => "shaderUniformTexelBufferArrayDynamicIndexing",
+        shader_storage_texel_buffer_array_dynamic_indexing => "shaderStorageTexelBufferArrayDynamicIndexing",
+        shader_uniform_buffer_array_non_uniform_indexing => "shaderUniformBufferArrayNonUniformIndexing",
+        shader_sampled_image_array_non_uniform_indexing => "shaderSampledImageArrayNonUniformIndexing",
+        shader_storage_buffer_array_non_uniform_indexing => "shaderStorageBufferArrayNonUniformIndexing",
+        shader_storage_image_array_non_uniform_indexing => "shaderStorageImageArrayNonUniformIndexing",
+        shader_input_attachment_array_non_uniform_indexing => "shaderInputAttachmentArrayNonUniformIndexing",
+        shader_uniform_texel_buffer_array_non_uniform_indexing => "shaderUniformTexelBufferArrayNonUniformIndexing",
+        shader_storage_texel_buffer_array_non_uniform_indexing => "shaderStorageTexelBufferArrayNonUniformIndexing",
+        descriptor_binding_uniform_buffer_update_after_bind => "descriptorBindingUniformBufferUpdateAfterBind",
+        descriptor_binding_sampled_image_update_after_bind => "descriptorBindingSampledImageUpdateAfterBind",
+        descriptor_binding_storage_image_update_after_bind => "descriptorBindingStorageImageUpdateAfterBind",
+        descriptor_binding_storage_buffer_update_after_bind => "descriptorBindingStorageBufferUpdateAfterBind",
+        descriptor_binding_uniform_texel_buffer_update_after_bind => "descriptorBindingUniformTexelBufferUpdateAfterBind",
+        descriptor_binding_storage_texel_buffer_update_after_bind => "descriptorBindingStorageTexelBufferUpdateAfterBind",
+        descriptor_binding_update_unused_while_pending => "descriptorBindingUpdateUnusedWhilePending",
+        descriptor_binding_partially_bound => "descriptorBindingPartiallyBound",
+        descriptor_binding_variable_descriptor_count => "descriptorBindingVariableDescriptorCount",
+        runtime_descriptor_array => "runtimeDescriptorArray",
+        sampler_filter_minmax => "samplerFilterMinmax",
+        scalar_block_layout => "scalarBlockLayout",
+        imageless_framebuffer => "imagelessFramebuffer",
+        uniform_buffer_standard_layout => "uniformBufferStandardLayout",
+        shader_subgroup_extended_types => "shaderSubgroupExtendedTypes",
+        separate_depth_stencil_layouts => "separateDepthStencilLayouts",
+        host_query_reset => "hostQueryReset",
+        timeline_semaphore => "timelineSemaphore",
+        buffer_device_address => "bufferDeviceAddress",
+        buffer_device_address_capture_replay => "bufferDeviceAddressCaptureReplay",
+        buffer_device_address_multi_device => "bufferDeviceAddressMultiDevice",
+        vulkan_memory_model => "vulkanMemoryModel",
+        vulkan_memory_model_device_scope => "vulkanMemoryModelDeviceScope",
+        vulkan_memory_model_availability_visibility_chains => "vulkanMemoryModelAvailabilityVisibilityChains",
+        shader_output_viewport_index => "shaderOutputViewportIndex",
+        shader_output_layer => "shaderOutputLayer",
+        subgroup_broadcast_dynamic_id => "subgroupBroadcastDynamicId",
+    }
+    Vulkan13(vulkan13) {
+        robust_image_access => "robustImageAccess",
+        inline_uniform_block => "inlineUniformBlock",
+        descriptor_binding_inline_uniform_block_update_after_bind => "descriptorBindingInlineUniformBlockUpdateAfterBind",
+        pipeline_creation_cache_control => "pipelineCreationCacheControl",
+        private_data => "privateData",
+        shader_demote_to_helper_invocation => "shaderDemoteToHelperInvocation",
+        shader_terminate_invocation => "shaderTerminateInvocation",
+        subgroup_size_control => "subgroupSizeControl",
+        compute_full_subgroups => "computeFullSubgroups",
+        synchronization2 => "synchronization2",
+        texture_compression_astc_hdr => "textureCompressionASTC_HDR",
+        shader_zero_initialize_workgroup_memory => "shaderZeroInitializeWorkgroupMemory",
+        dynamic_rendering => "dynamicRendering",
+        shader_integer_dot_product => "shaderIntegerDotProduct",
+        maintenance4 => "maintenance4",
+    }
+    ExtendedDynamicState(extended_dynamic_state) {
+        extended_dynamic_state => "extendedDynamicState",
+    }
+    MeshShader(mesh_shader) {
+        task_shader => "taskShader",
+        mesh_shader => "meshShader",
+        multiview_mesh_shader => "multiviewMeshShader",
+        primitive_fragment_shading_rate_mesh_shader => "primitiveFragmentShadingRateMeshShader",
+        mesh_shader_queries => "meshShaderQueries",
+    }
+    FragmentShadingRate(fragment_shading_rate) {
+        pipeline_fragment_shading_rate => "pipelineFragmentShadingRate",
+        primitive_fragment_shading_rate => "primitiveFragmentShadingRate",
+        attachment_fragment_shading_rate => "attachmentFragmentShadingRate",
+    }
+    DeviceFault(device_fault) {
+        device_fault => "deviceFault",
+        device_fault_vendor_binary => "deviceFaultVendorBinary",
+    }
+}
+
+/// Vulkan structure which holds a feature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum VulkanFeatureGroup {
+    Core,
+    Vulkan11,
+    Vulkan12,
+    Vulkan13,
+    ExtendedDynamicState,
+    MeshShader,
+    FragmentShadingRate,
+    DeviceFault,
+}
+
+impl VulkanFeatureGroup {
+    const ALL: [Self; 8] = [
+        Self::Core,
+        Self::Vulkan11,
+        Self::Vulkan12,
+        Self::Vulkan13,
+        Self::ExtendedDynamicState,
+        Self::MeshShader,
+        Self::FragmentShadingRate,
+        Self::DeviceFault,
+    ];
+
+    /// Returns extension which must be enabled to use features of this group.
+    fn extension(self) -> Option<&'static CStr> {
+        match self {
+            Self::ExtendedDynamicState => Some(vk::ExtExtendedDynamicStateFn::name()),
+            Self::MeshShader => Some(vk::ExtMeshShaderFn::name()),
+            Self::FragmentShadingRate => Some(vk::KhrFragmentShadingRateFn::name()),
+            Self::DeviceFault => Some(vk::ExtDeviceFaultFn::name()),
+            _ => None,
+        }
+    }
+
+    fn mask(self) -> u32 {
+        1 << self as u32
+    }
+}
+
+struct VulkanFeature {
+    name: &'static str,
+    group: VulkanFeatureGroup,
+    get: fn(&VulkanDeviceFeatures) -> bool,
+    enable: fn(&mut VulkanDeviceFeatures),
+}
+
+/// Features of the physical device, identified by names of members of Vulkan feature structures,
+/// e.g. `samplerAnisotropy`. Features of Vulkan 1.1 and 1.2 are available only on Vulkan 1.2
+/// devices, because of structures which hold them, and features of extensions only when the
+/// extension is supported.
+#[derive(Clone, Copy)]
+pub struct VulkanDeviceFeatures {
+    pub core: vk::PhysicalDeviceFeatures,
+    pub vulkan11: vk::PhysicalDeviceVulkan11Features,
+    pub vulkan12: vk::PhysicalDeviceVulkan12Features,
+    pub vulkan13: vk::PhysicalDeviceVulkan13Features,
+    pub extended_dynamic_state: vk::PhysicalDeviceExtendedDynamicStateFeaturesEXT,
+    pub mesh_shader: vk::PhysicalDeviceMeshShaderFeaturesEXT,
+    pub fragment_shading_rate: vk::PhysicalDeviceFragmentShadingRateFeaturesKHR,
+    pub device_fault: vk::PhysicalDeviceFaultFeaturesEXT,
+    groups: u32,
+}
+
+// Pointers of structures are linked only temporarily by `chain`, and are never dereferenced
+// through a shared reference.
+unsafe impl Send for VulkanDeviceFeatures {}
+unsafe impl Sync for VulkanDeviceFeatures {}
+
+impl VulkanDeviceFeatures {
+    /// Returns features supported by `physical_device`.
+    pub fn query(instance: &ash::Instance, physical_device: vk::PhysicalDevice) -> Self {
+        let api_version =
+            unsafe { instance.get_physical_device_properties(physical_device) }.api_version;
+        let extensions = unsafe { instance.enumerate_device_extension_properties(physical_device) }
+            .unwrap_or_default();
+
+        let mut groups = 0;
+        for group in VulkanFeatureGroup::ALL {
+            let available = match group {
+                VulkanFeatureGroup::Core => true,
+                VulkanFeatureGroup::Vulkan11 | VulkanFeatureGroup::Vulkan12 => {
+                    api_version >= vk::API_VERSION_1_2
+                }
+                VulkanFeatureGroup::Vulkan13 => api_version >= vk::API_VERSION_1_3,
+                // Dependencies of supported extensions are part of Vulkan 1.2.
+                _ => {
+                    api_version >= vk::API_VERSION_1_2
+                        && extensions.iter().any(|extension| {
+                            let name = unsafe { CStr::from_ptr(extension.extension_name.as_ptr()) };
+                            Some(name) == group.extension()
+                        })
+                }
+            };
+
+            if available {
+                groups |= group.mask();
+            }
+        }
+
+        let mut features = Self::empty(groups);
+        let mut features2 = vk::PhysicalDeviceFeatures2 {
+            s_type: vk::StructureType::PHYSICAL_DEVICE_FEATURES_2,
+            p_next: features.chain(),
+            features: vk::PhysicalDeviceFeatures::default(),
+        };
+        unsafe { instance.get_physical_device_features2(physical_device, &mut features2) };
+
+        features.core = features2.features;
+        features
+    }
+
+    /// Returns features with the same available structures as `self`, but with none enabled.
+    pub fn to_empty(&self) -> Self {
+        Self::empty(self.groups)
+    }
+
+    /// Returns `None` when `name` is not a known feature. Features which are not available on the
+    /// device are not supported.
+    pub fn get(&self, name: &str) -> Option<bool> {
+        let feature = Self::find(name)?;
+        Some(self.has_group(feature.group) && (feature.get)(self))
+    }
+
+    /// Returns names of all known features with their values.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, bool)> + '_ {
+        FEATURES.iter().map(|feature| {
+            (
+                feature.name,
+                self.has_group(feature.group) && (feature.get)(self),
+            )
+        })
+    }
+
+    /// Enables feature with `name`, which must be supported by `supported`.
+    pub fn enable(&mut self, name: &str, supported: &Self) -> Result<(), InvalidOperationError> {
+        let feature = match Self::find(name) {
+            Some(feature) => feature,
+            None => {
+                return Err(InvalidOperationError::new(format!(
+                    "Unknown device feature `{}`.",
+                    name
+                )))
+            }
+        };
+
+        if !supported.has_group(feature.group) || !(feature.get)(supported) {
+            return Err(InvalidOperationError::new(format!(
+                "Device feature `{}` is not supported.",
+                name
+            )));
+        }
+
+        (feature.enable)(self);
+        Ok(())
+    }
+
+    /// Returns extensions required by enabled features.
+    pub fn required_extensions(&self) -> impl Iterator<Item = &'static CStr> + '_ {
+        VulkanFeatureGroup::ALL
+            .into_iter()
+            .filter(|group| {
+                FEATURES
+                    .iter()
+                    .any(|feature| feature.group == *group && (feature.get)(self))
+            })
+            .filter_map(|group| group.extension())
+    }
+
+    /// Links available structures, except of Vulkan 1.0 core one, and returns pointer to the
+    /// first of them. Returned pointer is valid until `self` is moved.
+    pub fn chain(&mut self) -> *mut c_void {
+        let mut p_next: *mut c_void = ptr::null_mut();
+
+        macro_rules! link {
+            ($group:ident, $member:ident) => {
+                if self.has_group(VulkanFeatureGroup::$group) {
+                    self.$member.p_next = p_next;
+                    p_next = &mut self.$member as *mut _ as *mut c_void;
+                }
+            };
+        }
+
+        link!(Vulkan11, vulkan11);
+        link!(Vulkan12, vulkan12);
+        link!(Vulkan13, vulkan13);
+        link!(ExtendedDynamicState, extended_dynamic_state);
+        link!(MeshShader, mesh_shader);
+        link!(FragmentShadingRate, fragment_shading_rate);
+        link!(DeviceFault, device_fault);
+
+        p_next
+    }
+
+    fn empty(groups: u32) -> Self {
+        Self {
+            core: vk::PhysicalDeviceFeatures::default(),
+            vulkan11: vk::PhysicalDeviceVulkan11Features::default(),
+            vulkan12: vk::PhysicalDeviceVulkan12Features::default(),
+            vulkan13: vk::PhysicalDeviceVulkan13Features::default(),
+            extended_dynamic_state: vk::PhysicalDeviceExtendedDynamicStateFeaturesEXT::default(),
+            mesh_shader: vk::PhysicalDeviceMeshShaderFeaturesEXT::default(),
+            fragment_shading_rate: vk::PhysicalDeviceFragmentShadingRateFeaturesKHR::default(),
+            device_fault: vk::PhysicalDeviceFaultFeaturesEXT::default(),
+            groups,
+        }
+    }
+
+    fn find(name: &str) -> Option<&'static VulkanFeature> {
+        FEATURES.iter().find(|feature| feature.name == name)
+    }
+
+    fn has_group(&self, group: VulkanFeatureGroup) -> bool {
+        self.groups & group.mask() != 0
+    }
+}
@@ -0,0 +1,138 @@
+use std::{ffi::c_void, ptr};
+
+use ash::vk;
+
+/// Limits of the physical device. Limits introduced after Vulkan 1.0 are zero when they are not
+/// supported by the device.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct VulkanDeviceLimits {
+    pub limits: vk::PhysicalDeviceLimits,
+    // Vulkan 1.1.
+    pub subgroup_size: u32,
+    pub max_multiview_view_count: u32,
+    pub max_multiview_instance_index: u32,
+    pub max_per_set_descriptors: u32,
+    pub max_memory_allocation_size: vk::DeviceSize,
+    // Vulkan 1.2.
+    pub max_update_after_bind_descriptors_in_all_pools: u32,
+    pub max_per_stage_descriptor_update_after_bind_samplers: u32,
+    pub max_per_stage_descriptor_update_after_bind_uniform_buffers: u32,
+    pub max_per_stage_descriptor_update_after_bind_storage_buffers: u32,
+    pub max_per_stage_descriptor_update_after_bind_sampled_images: u32,
+    pub max_per_stage_descriptor_update_after_bind_storage_images: u32,
+    pub max_per_stage_descriptor_update_after_bind_input_attachments: u32,
+    pub max_per_stage_update_after_bind_resources: u32,
+    pub max_descriptor_set_update_after_bind_samplers: u32,
+    pub max_descriptor_set_update_after_bind_uniform_buffers: u32,
+    pub max_descriptor_set_update_after_bind_uniform_buffers_dynamic: u32,
+    pub max_descriptor_set_update_after_bind_storage_buffers: u32,
+    pub max_descriptor_set_update_after_bind_storage_buffers_dynamic: u32,
+    pub max_descriptor_set_update_after_bind_sampled_images: u32,
+    pub max_descriptor_set_update_after_bind_storage_images: u32,
+    pub max_descriptor_set_update_after_bind_input_attachments: u32,
+    pub max_timeline_semaphore_value_difference: u64,
+    // Vulkan 1.3.
+    pub min_subgroup_size: u32,
+    pub max_subgroup_size: u32,
+    pub max_compute_workgroup_subgroups: u32,
+    pub max_inline_uniform_block_size: u32,
+    pub max_per_stage_descriptor_inline_uniform_blocks: u32,
+    pub max_per_stage_descriptor_update_after_bind_inline_uniform_blocks: u32,
+    pub max_descriptor_set_inline_uniform_blocks: u32,
+    pub max_descriptor_set_update_after_bind_inline_uniform_blocks: u32,
+    pub max_inline_uniform_total_size: u32,
+    pub storage_texel_buffer_offset_alignment_bytes: vk::DeviceSize,
+    pub uniform_texel_buffer_offset_alignment_bytes: vk::DeviceSize,
+    pub max_buffer_size: vk::DeviceSize,
+}
+
+impl VulkanDeviceLimits {
+    pub fn query(instance: &ash::Instance, physical_device: vk::PhysicalDevice) -> Self {
+        let api_version =
+            unsafe { instance.get_physical_device_properties(physical_device) }.api_version;
+
+        let mut vulkan11 = vk::PhysicalDeviceVulkan11Properties::default();
+        let mut vulkan12 = vk::PhysicalDeviceVulkan12Properties::default();
+        let mut vulkan13 = vk::PhysicalDeviceVulkan13Properties::default();
+
+        let mut p_next: *mut c_void = ptr::null_mut();
+        if api_version >= vk::API_VERSION_1_2 {
+            vulkan11.p_next = p_next;
+            vulkan12.p_next = &mut vulkan11 as *mut _ as *mut c_void;
+            p_next = &mut vulkan12 as *mut _ as *mut c_void;
+        }
+        if api_version >= vk::API_VERSION_1_3 {
+            vulkan13.p_next = p_next;
+            p_next = &mut vulkan13 as *mut _ as *mut c_void;
+        }
+
+        let mut properties2 = vk::PhysicalDeviceProperties2 {
+            s_type: vk::StructureType::PHYSICAL_DEVICE_PROPERTIES_2,
+            p_next,
+            properties: vk::PhysicalDeviceProperties::default(),
+        };
+        unsafe { instance.get_physical_device_properties2(physical_device, &mut properties2) };
+
+        Self {
+            limits: properties2.properties.limits,
+            subgroup_size: vulkan11.subgroup_size,
+            max_multiview_view_count: vulkan11.max_multiview_view_count,
+            max_multiview_instance_index: vulkan11.max_multiview_instance_index,
+            max_per_set_descriptors: vulkan11.max_per_set_descriptors,
+            max_memory_allocation_size: vulkan11.max_memory_allocation_size,
+            max_update_after_bind_descriptors_in_all_pools: vulkan12
+                .max_update_after_bind_descriptors_in_all_pools,
+            max_per_stage_descriptor_update_after_bind_samplers: vulkan12
+                .max_per_stage_descriptor_update_after_bind_samplers,
+            max_per_stage_descriptor_update_after_bind_uniform_buffers: vulkan12
+                .max_per_stage_descriptor_update_after_bind_uniform_buffers,
+            max_per_stage_descriptor_update_after_bind_storage_buffers: vulkan12
+                .max_per_stage_descriptor_update_after_bind_storage_buffers,
+            max_per_stage_descriptor_update_after_bind_sampled_images: vulkan12
+                .max_per_stage_descriptor_update_after_bind_sampled_images,
+            max_per_stage_descriptor_update_after_bind_storage_images: vulkan12
+                .max_per_stage_descriptor_update_after_bind_storage_images,
+            max_per_stage_descriptor_update_after_bind_input_attachments: vulkan12
+                .max_per_stage_descriptor_update_after_bind_input_attachments,
+            max_per_stage_update_after_bind_resources: vulkan12
+                .max_per_stage_update_after_bind_resources,
+            max_descriptor_set_update_after_bind_samplers: vulkan12
+                .max_descriptor_set_update_after_bind_samplers,
+            max_descriptor_set_update_after_bind_uniform_buffers: vulkan12
+                .max_descriptor_set_update_after_bind_uniform_buffers,
+            max_descriptor_set_update_after_bind_uniform_buffers_dynamic: vulkan12
+                .max_descriptor_set_update_after_bind_uniform_buffers_dynamic,
+            max_descriptor_set_update_after_bind_storage_buffers: vulkan12
+                .max_descriptor_set_update_after_bind_storage_buffers,
+            max_descriptor_set_update_after_bind_storage_buffers_dynamic: vulkan12
+                .max_descriptor_set_update_after_bind_storage_buffers_dynamic,
+            max_descriptor_set_update_after_bind_sampled_images: vulkan12
+                .max_descriptor_set_update_after_bind_sampled_images,
+            max_descriptor_set_update_after_bind_storage_images: vulkan12
+                .max_descriptor_set_update_after_bind_storage_images,
+            max_descriptor_set_update_after_bind_input_attachments: vulkan12
+                .max_descriptor_set_update_after_bind_input_attachments,
+            max_timeline_semaphore_value_difference: vulkan12
+                .max_timeline_semaphore_value_difference,
+            min_subgroup_size: vulkan13.min_subgroup_size,
+            max_subgroup_size: vulkan13.max_subgroup_size,
+            max_compute_workgroup_subgroups: vulkan13.max_compute_workgroup_subgroups,
+            max_inline_uniform_block_size: vulkan13.max_inline_uniform_block_size,
+            max_per_stage_descriptor_inline_uniform_blocks: vulkan13
+                .max_per_stage_descriptor_inline_uniform_blocks,
+            max_per_stage_descriptor_update_after_bind_inline_uniform_blocks: vulkan13
+                .max_per_stage_descriptor_update_after_bind_inline_uniform_blocks,
+            max_descriptor_set_inline_uniform_blocks: vulkan13
+                .max_descriptor_set_inline_uniform_blocks,
+            max_descriptor_set_update_after_bind_inline_uniform_blocks: vulkan13
+                .max_descriptor_set_update_after_bind_inline_uniform_blocks,
+            max_inline_uniform_total_size: vulkan13.max_inline_uniform_total_size,
+            storage_texel_buffer_offset_alignment_bytes: vulkan13
+                .storage_texel_buffer_offset_alignment_bytes,
+            uniform_texel_buffer_offset_alignment_bytes: vulkan13
+                .uniform_texel_buffer_offset_alignment_bytes,
+            max_buffer_size: vulkan13.max_buffer_size,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use once_cell::sync::OnceCell;
+
+use crate::{
+    errors::invalid_operation::InvalidOperationError, interop::prelude::InteropReadOnlySpan,
+};
+
+/// Information about lost device. Strings are owned by the native side and are valid only during
+/// the handler call.
+#[repr(C)]
+pub(crate) struct VulkanDeviceLostData<'a> {
+    pub message: InteropReadOnlySpan<'a, u8>,
+    /// Description from `VK_EXT_device_fault`, which is empty when it is not available.
+    pub fault: InteropReadOnlySpan<'a, u8>,
+}
+
+#[repr(C)]
+pub(crate) struct VulkanDeviceLostHandler {
+    pub lost: unsafe extern "C" fn(id: u64, data: VulkanDeviceLostData),
+}
+
+static INSTANCE: OnceCell<VulkanDeviceLostHandler> = OnceCell::new();
+
+pub(crate) fn initialize(handler: VulkanDeviceLostHandler) -> Result<(), InvalidOperationError> {
+    INSTANCE.set(handler).map_err(|_| {
+        InvalidOperationError::with_str("VulkanDeviceLostHandler already initialized.")
+    })
+}
+
+impl VulkanDeviceLostHandler {
+    /// Returns `None` when handler is not initialized, e.g. when native code is used without
+    /// managed side.
+    pub(crate) fn get() -> Option<&'static VulkanDeviceLostHandler> {
+        INSTANCE.get()
+    }
+}
@@ -1,4 +1,4 @@
-use std::{ptr, rc::Rc, sync::Arc};
+use std::{ptr, sync::Arc};
 
 use ash::vk;
 
@@ -11,12 +11,12 @@ use super::{
 };
 
 pub struct VulkanDevicePool<'devpool> {
-    vulkan_device: Rc<ash::Device>,
+    vulkan_device: Arc<ash::Device>,
     descriptor_pools: Pool<VulkanDescriptorPool<'devpool>>,
 }
 
 impl<'init: 'devpool, 'devpool> VulkanDevicePool<'devpool> {
-    pub(super) fn new(device: Rc<ash::Device>) -> Self {
+    pub(super) fn new(device: Arc<ash::Device>) -> Self {
         Self {
             vulkan_device: device,
             descriptor_pools: Pool::default(),
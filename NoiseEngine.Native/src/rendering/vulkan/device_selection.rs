@@ -0,0 +1,123 @@
+use std::{cmp::Reverse, ffi::CStr};
+
+use ash::{extensions::khr, vk};
+
+use crate::errors::invalid_operation::InvalidOperationError;
+
+use super::{
+    device::VulkanDevice, errors::universal::VulkanUniversalError, surface::VulkanSurface,
+};
+
+/// Requirements, which must be met by devices returned by [`rank`].
+pub struct VulkanDeviceRequirements<'a> {
+    pub extensions: &'a [&'a str],
+    pub features: &'a [&'a str],
+    /// When `Some`, device must be able to present to this surface.
+    pub surface: Option<&'a VulkanSurface>,
+}
+
+/// Returns indices of `devices` which meet `requirements`, ordered from the best one.
+pub fn rank(
+    devices: &[&VulkanDevice],
+    requirements: &VulkanDeviceRequirements,
+) -> Result<Vec<usize>, VulkanUniversalError> {
+    let mut scores = Vec::with_capacity(devices.len());
+    for (index, device) in devices.iter().enumerate() {
+        if let Some(score) = score(device, requirements)? {
+            scores.push((index, score));
+        }
+    }
+
+    // Stable sort keeps order of enumeration for devices with equal scores.
+    scores.sort_by_key(|(_, score)| Reverse(*score));
+    Ok(scores.into_iter().map(|(index, _)| index).collect())
+}
+
+/// Returns score of `device`, or `None` when it does not meet `requirements`. Device type is
+/// compared first, from discrete to CPU devices, and then size of device local memory.
+pub fn score(
+    device: &VulkanDevice,
+    requirements: &VulkanDeviceRequirements,
+) -> Result<Option<u64>, VulkanUniversalError> {
+    let instance = device.instance().inner();
+    let physical_device = device.physical_device();
+
+    // Features.
+    let features = device.supported_features();
+    for name in requirements.features {
+        match features.get(name) {
+            Some(true) => (),
+            Some(false) => return Ok(None),
+            None => {
+                return Err(InvalidOperationError::new(format!(
+                    "Unknown device feature `{}`.",
+                    name
+                ))
+                .into())
+            }
+        }
+    }
+
+    // Extensions.
+    let extensions = unsafe { instance.enumerate_device_extension_properties(physical_device) }?;
+    let has_extension = |required: &str| {
+        extensions.iter().any(|extension| {
+            let name = unsafe { CStr::from_ptr(extension.extension_name.as_ptr()) };
+            name.to_str() == Ok(required)
+        })
+    };
+
+    if !requirements
+        .extensions
+        .iter()
+        .all(|name| has_extension(name))
+    {
+        return Ok(None);
+    }
+
+    // Presentation.
+    if let Some(surface) = requirements.surface {
+        if !has_extension(khr::Swapchain::name().to_str().unwrap()) {
+            return Ok(None);
+        }
+
+        let family_count =
+            unsafe { instance.get_physical_device_queue_family_properties(physical_device) }.len();
+        let mut supports = false;
+        for family_index in 0..family_count as u32 {
+            supports |= unsafe {
+                surface.ash_surface().get_physical_device_surface_support(
+                    physical_device,
+                    family_index,
+                    surface.inner(),
+                )
+            }?;
+        }
+
+        if !supports {
+            return Ok(None);
+        }
+    }
+
+    // Score.
+    let device_type =
+        match unsafe { instance.get_physical_device_properties(physical_device) }.device_type {
+            vk::PhysicalDeviceType::DISCRETE_GPU => 4,
+            vk::PhysicalDeviceType::INTEGRATED_GPU => 3,
+            vk::PhysicalDeviceType::VIRTUAL_GPU => 2,
+            vk::PhysicalDeviceType::CPU => 1,
+            _ => 0,
+        };
+
+    let memory = device.memory_properties();
+    let device_local_memory: u64 = memory.memory_heaps[..memory.memory_heap_count as usize]
+        .iter()
+        .filter(|heap| heap.flags.contains(vk::MemoryHeapFlags::DEVICE_LOCAL))
+        .map(|heap| heap.size)
+        .sum();
+
+    // Memory is counted in MiB, which leaves the highest bits for device type.
+    Ok(Some(
+        (device_type << 48) | (device_local_memory >> 20).min((1 << 48) - 1),
+    ))
+}
@@ -1,10 +1,14 @@
 use std::cmp::Ordering;
 
 #[repr(C)]
+#[derive(Clone, Copy)]
 pub struct VulkanDeviceSupport {
     pub graphics: bool,
     pub computing: bool,
     pub transfer: bool,
+    /// Only meaningful for requested usage. When `true`, families which support graphics or
+    /// computing without it being requested are rejected, e.g. to select an async compute family.
+    pub dedicated: bool,
 }
 
 impl VulkanDeviceSupport {
@@ -12,6 +16,8 @@ impl VulkanDeviceSupport {
         (!self.graphics || main.graphics)
             && (!self.computing || main.computing)
             && (!self.transfer || main.transfer)
+            && (!self.dedicated
+                || ((self.graphics || !main.graphics) && (self.computing || !main.computing)))
     }
 
     pub(crate) fn family_cmp(&self, other: &Self) -> Ordering {
@@ -0,0 +1,264 @@
+use std::{
+    ffi::{c_void, CStr},
+    ptr,
+};
+
+use ash::{extensions::khr, vk};
+
+/// How dynamic rendering is provided by the physical device.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DynamicRenderingSupport {
+    Core,
+    Extension,
+}
+
+impl DynamicRenderingSupport {
+    /// Returns how dynamic rendering can be enabled on `physical_device`, or `None` when render
+    /// pass objects must be used instead.
+    pub fn query(instance: &ash::Instance, physical_device: vk::PhysicalDevice) -> Option<Self> {
+        let api_version =
+            unsafe { instance.get_physical_device_properties(physical_device) }.api_version;
+
+        let support = if api_version >= vk::API_VERSION_1_3 {
+            Self::Core
+        } else if api_version >= vk::API_VERSION_1_2 {
+            // Dependencies of the extension are part of Vulkan 1.2.
+            let extensions =
+                unsafe { instance.enumerate_device_extension_properties(physical_device) }
+                    .unwrap_or_default();
+            let has_extension = extensions.iter().any(|extension| {
+                let name = unsafe { CStr::from_ptr(extension.extension_name.as_ptr()) };
+                name == khr::DynamicRendering::name()
+            });
+
+            match has_extension {
+                true => Self::Extension,
+                false => return None,
+            }
+        } else {
+            return None;
+        };
+
+        let mut features = vk::PhysicalDeviceDynamicRenderingFeatures::default();
+        let mut features2 = vk::PhysicalDeviceFeatures2 {
+            s_type: vk::StructureType::PHYSICAL_DEVICE_FEATURES_2,
+            p_next: &mut features as *mut _ as *mut c_void,
+            features: vk::PhysicalDeviceFeatures::default(),
+        };
+        unsafe { instance.get_physical_device_features2(physical_device, &mut features2) };
+
+        match features.dynamic_rendering {
+            vk::FALSE => None,
+            _ => Some(support),
+        }
+    }
+}
+
+/// Entry points of dynamic rendering, which replaces render pass and framebuffer objects.
+pub enum DynamicRendering {
+    Core,
+    Extension(khr::DynamicRendering),
+}
+
+impl DynamicRendering {
+    pub fn new(
+        support: DynamicRenderingSupport,
+        instance: &ash::Instance,
+        device: &ash::Device,
+    ) -> Self {
+        match support {
+            DynamicRenderingSupport::Core => Self::Core,
+            DynamicRenderingSupport::Extension => {
+                Self::Extension(khr::DynamicRendering::new(instance, device))
+            }
+        }
+    }
+
+    /// # Safety
+    /// Same as of `vkCmdBeginRendering`.
+    pub unsafe fn cmd_begin_rendering(
+        &self,
+        device: &ash::Device,
+        command_buffer: vk::CommandBuffer,
+        rendering_info: &vk::RenderingInfo,
+    ) {
+        match self {
+            Self::Core => device.cmd_begin_rendering(command_buffer, rendering_info),
+            Self::Extension(extension) => {
+                extension.cmd_begin_rendering(command_buffer, rendering_info)
+            }
+        }
+    }
+
+    /// # Safety
+    /// Same as of `vkCmdEndRendering`.
+    pub unsafe fn cmd_end_rendering(
+        &self,
+        device: &ash::Device,
+        command_buffer: vk::CommandBuffer,
+    ) {
+        match self {
+            Self::Core => device.cmd_end_rendering(command_buffer),
+            Self::Extension(extension) => extension.cmd_end_rendering(command_buffer),
+        }
+    }
+}
+
+/// Maximum count of color attachments rendered without render pass object. Passes with more color
+/// attachments use render pass objects instead.
+pub const MAX_COLOR_ATTACHMENTS: usize = 8;
+
+/// Formats of attachments rendered without render pass object, which are given to pipelines and
+/// secondary command buffers instead of the render pass.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RenderingFormats {
+    color_formats: [vk::Format; MAX_COLOR_ATTACHMENTS],
+    color_attachment_count: u32,
+    pub depth_format: vk::Format,
+    pub stencil_format: vk::Format,
+    pub sample_count: vk::SampleCountFlags,
+}
+
+impl RenderingFormats {
+    /// # Panics
+    /// When there are more than [`MAX_COLOR_ATTACHMENTS`] color formats.
+    pub fn new(
+        color_formats: &[vk::Format],
+        depth_stencil_format: Option<vk::Format>,
+        sample_count: vk::SampleCountFlags,
+    ) -> Self {
+        let depth_stencil_format = depth_stencil_format.unwrap_or(vk::Format::UNDEFINED);
+
+        let mut formats = [vk::Format::UNDEFINED; MAX_COLOR_ATTACHMENTS];
+        formats[..color_formats.len()].copy_from_slice(color_formats);
+
+        Self {
+            color_formats: formats,
+            color_attachment_count: color_formats.len() as u32,
+            depth_format: match depth_stencil_format {
+                vk::Format::S8_UINT => vk::Format::UNDEFINED,
+                format => format,
+            },
+            stencil_format: match has_stencil_component(depth_stencil_format) {
+                true => depth_stencil_format,
+                false => vk::Format::UNDEFINED,
+            },
+            sample_count,
+        }
+    }
+
+    /// Returns formats of color attachments, which are [`vk::Format::UNDEFINED`] for unused ones.
+    pub fn color_formats(&self) -> &[vk::Format] {
+        &self.color_formats[..self.color_attachment_count as usize]
+    }
+
+    /// Returns info which must be chained to pipeline create info. Returned value points to
+    /// `self`.
+    pub fn pipeline_rendering_create_info(&self) -> vk::PipelineRenderingCreateInfo {
+        vk::PipelineRenderingCreateInfo {
+            s_type: vk::StructureType::PIPELINE_RENDERING_CREATE_INFO,
+            p_next: ptr::null(),
+            view_mask: 0,
+            color_attachment_count: self.color_attachment_count,
+            p_color_attachment_formats: self.color_formats.as_ptr(),
+            depth_attachment_format: self.depth_format,
+            stencil_attachment_format: self.stencil_format,
+        }
+    }
+
+    /// Returns info which must be chained to inheritance info of secondary command buffers.
+    /// Returned value points to `self`.
+    pub fn inheritance_rendering_info(&self) -> vk::CommandBufferInheritanceRenderingInfo {
+        vk::CommandBufferInheritanceRenderingInfo {
+            s_type: vk::StructureType::COMMAND_BUFFER_INHERITANCE_RENDERING_INFO,
+            p_next: ptr::null(),
+            flags: vk::RenderingFlags::empty(),
+            view_mask: 0,
+            color_attachment_count: self.color_attachment_count,
+            p_color_attachment_formats: self.color_formats.as_ptr(),
+            depth_attachment_format: self.depth_format,
+            stencil_attachment_format: self.stencil_format,
+            rasterization_samples: self.sample_count,
+        }
+    }
+}
+
+/// Attachments of the only subpass of render pass rendered without render pass object, given as
+/// indices of render pass attachments.
+#[derive(Clone, Debug)]
+pub struct RenderingSubpass {
+    /// Contains [`vk::ATTACHMENT_UNUSED`] for unused color attachments.
+    pub color_attachments: Vec<u32>,
+    /// Empty, or with entry for each color attachment, which is [`vk::ATTACHMENT_UNUSED`] when the
+    /// color attachment is not resolved.
+    pub resolve_attachments: Vec<u32>,
+    pub depth_stencil_attachment: Option<u32>,
+}
+
+/// Image view of framebuffer attachment, which is used directly by dynamic rendering.
+#[derive(Clone, Copy)]
+pub struct RenderingAttachmentView {
+    pub image: vk::Image,
+    pub view: vk::ImageView,
+    pub subresource_range: vk::ImageSubresourceRange,
+}
+
+pub fn has_stencil_component(format: vk::Format) -> bool {
+    matches!(
+        format,
+        vk::Format::S8_UINT
+            | vk::Format::D16_UNORM_S8_UINT
+            | vk::Format::D24_UNORM_S8_UINT
+            | vk::Format::D32_SFLOAT_S8_UINT
+    )
+}
+
+/// Returns `true` for formats with unsigned or signed integer components, which cannot be resolved
+/// by averaging samples.
+pub fn is_integer_format(format: vk::Format) -> bool {
+    matches!(
+        format,
+        vk::Format::R8_UINT
+            | vk::Format::R8_SINT
+            | vk::Format::R8G8_UINT
+            | vk::Format::R8G8_SINT
+            | vk::Format::R8G8B8_UINT
+            | vk::Format::R8G8B8_SINT
+            | vk::Format::B8G8R8_UINT
+            | vk::Format::B8G8R8_SINT
+            | vk::Format::R8G8B8A8_UINT
+            | vk::Format::R8G8B8A8_SINT
+            | vk::Format::B8G8R8A8_UINT
+            | vk::Format::B8G8R8A8_SINT
+            | vk::Format::A8B8G8R8_UINT_PACK32
+            | vk::Format::A8B8G8R8_SINT_PACK32
+            | vk::Format::A2R10G10B10_UINT_PACK32
+            | vk::Format::A2R10G10B10_SINT_PACK32
+            | vk::Format::A2B10G10R10_UINT_PACK32
+            | vk::Format::A2B10G10R10_SINT_PACK32
+            | vk::Format::R16_UINT
+            | vk::Format::R16_SINT
+            | vk::Format::R16G16_UINT
+            | vk::Format::R16G16_SINT
+            | vk::Format::R16G16B16_UINT
+            | vk::Format::R16G16B16_SINT
+            | vk::Format::R16G16B16A16_UINT
+            | vk::Format::R16G16B16A16_SINT
+            | vk::Format::R32_UINT
+            | vk::Format::R32_SINT
+            | vk::Format::R32G32_UINT
+            | vk::Format::R32G32_SINT
+            | vk::Format::R32G32B32_UINT
+            | vk::Format::R32G32B32_SINT
+            | vk::Format::R32G32B32A32_UINT
+            | vk::Format::R32G32B32A32_SINT
+            | vk::Format::R64_UINT
+            | vk::Format::R64_SINT
+            | vk::Format::R64G64_UINT
+            | vk::Format::R64G64_SINT
+            | vk::Format::R64G64B64_UINT
+            | vk::Format::R64G64B64_SINT
+            | vk::Format::R64G64B64A64_UINT
+            | vk::Format::R64G64B64A64_SINT
+    )
+}
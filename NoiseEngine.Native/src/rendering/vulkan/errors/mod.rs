@@ -1,2 +1,3 @@
 pub mod swapchain_accquire_next_image;
 pub mod universal;
+pub mod validation;
@@ -5,10 +5,13 @@ use ash::vk;
 use crate::{
     errors::{
         invalid_operation::InvalidOperationError, null_reference::NullReferenceError,
-        overflow::OverflowError,
+        overflow::OverflowError, serialization::SerializationError,
     },
     interop::prelude::ResultError,
-    rendering::errors::window_not_supported::WindowNotSupportedError,
+    rendering::errors::{
+        invalid_command_stream::InvalidCommandStreamError,
+        window_not_supported::WindowNotSupportedError,
+    },
 };
 
 #[derive(Debug)]
@@ -19,6 +22,8 @@ pub enum VulkanUniversalError {
     Utf8(Utf8Error),
     Vulkan(vk::Result),
     WindowNotSupported(WindowNotSupportedError),
+    Serialization(SerializationError),
+    InvalidCommandStream(InvalidCommandStreamError),
 }
 
 impl Error for VulkanUniversalError {
@@ -30,6 +35,8 @@ impl Error for VulkanUniversalError {
             VulkanUniversalError::Utf8(err) => err.source(),
             VulkanUniversalError::Vulkan(err) => err.source(),
             VulkanUniversalError::WindowNotSupported(err) => err.source(),
+            VulkanUniversalError::Serialization(err) => err.source(),
+            VulkanUniversalError::InvalidCommandStream(err) => err.source(),
         }
     }
 }
@@ -46,6 +53,8 @@ impl Display for VulkanUniversalError {
                 VulkanUniversalError::Utf8(err) => err.to_string(),
                 VulkanUniversalError::Vulkan(err) => err.to_string(),
                 VulkanUniversalError::WindowNotSupported(err) => err.to_string(),
+                VulkanUniversalError::Serialization(err) => err.to_string(),
+                VulkanUniversalError::InvalidCommandStream(err) => err.to_string(),
             }
         )
     }
@@ -87,6 +96,18 @@ impl From<WindowNotSupportedError> for VulkanUniversalError {
     }
 }
 
+impl From<SerializationError> for VulkanUniversalError {
+    fn from(err: SerializationError) -> Self {
+        Self::Serialization(err)
+    }
+}
+
+impl From<InvalidCommandStreamError> for VulkanUniversalError {
+    fn from(err: InvalidCommandStreamError) -> Self {
+        Self::InvalidCommandStream(err)
+    }
+}
+
 impl From<VulkanUniversalError> for ResultError {
     fn from(err: VulkanUniversalError) -> Self {
         match err {
@@ -96,6 +117,8 @@ impl From<VulkanUniversalError> for ResultError {
             VulkanUniversalError::Utf8(err) => err.into(),
             VulkanUniversalError::Vulkan(err) => err.into(),
             VulkanUniversalError::WindowNotSupported(err) => err.into(),
+            VulkanUniversalError::Serialization(err) => err.into(),
+            VulkanUniversalError::InvalidCommandStream(err) => err.into(),
         }
     }
 }
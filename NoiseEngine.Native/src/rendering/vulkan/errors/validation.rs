@@ -0,0 +1,45 @@
+use std::{error::Error, fmt::Display};
+
+use crate::interop::prelude::{ResultError, ResultErrorKind};
+
+/// Validation errors captured since the previous interop call.
+#[derive(Debug)]
+pub struct VulkanValidationError {
+    messages: Vec<String>,
+}
+
+impl VulkanValidationError {
+    pub fn new(messages: Vec<String>) -> Self {
+        Self { messages }
+    }
+
+    pub fn messages(&self) -> &[String] {
+        &self.messages
+    }
+}
+
+impl Error for VulkanValidationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+impl Display for VulkanValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} validation error(s) were reported:",
+            self.messages.len()
+        )?;
+        for message in &self.messages {
+            write!(f, "\n{}", message)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<VulkanValidationError> for ResultError {
+    fn from(err: VulkanValidationError) -> Self {
+        ResultError::with_kind(&err, ResultErrorKind::GraphicsValidation)
+    }
+}
@@ -30,6 +30,15 @@ impl<'init> VulkanFence<'init> {
         unsafe { self.wait_inner(&[self.inner], false, timeout) }
     }
 
+    pub fn is_signaled(&self) -> Result<bool, VulkanUniversalError> {
+        Ok(self.device.check_lost(unsafe {
+            self.device
+                .initialized()?
+                .vulkan_device()
+                .get_fence_status(self.inner)
+        })?)
+    }
+
     /// # Safety
     /// All fences must be from the same device.
     unsafe fn wait_inner(
@@ -38,13 +47,12 @@ impl<'init> VulkanFence<'init> {
         wait_all: bool,
         timeout: u64,
     ) -> Result<bool, VulkanUniversalError> {
-        match self
-            .device
-            .initialized()
-            .unwrap()
-            .vulkan_device()
-            .wait_for_fences(fences, wait_all, timeout)
-        {
+        match self.device.check_lost(
+            self.device
+                .initialized()?
+                .vulkan_device()
+                .wait_for_fences(fences, wait_all, timeout),
+        ) {
             Ok(()) => Ok(true),
             Err(err) => match err {
                 vk::Result::TIMEOUT => Ok(false),
@@ -75,13 +83,7 @@ impl GraphicsFence for VulkanFence<'_> {
     }
 
     fn is_signaled(&self) -> InteropResult<bool> {
-        match unsafe {
-            self.device
-                .initialized()
-                .unwrap()
-                .vulkan_device()
-                .get_fence_status(self.inner)
-        } {
+        match self.is_signaled() {
             Ok(i) => InteropResult::with_ok(i),
             Err(err) => InteropResult::with_err(err.into()),
         }
@@ -2,7 +2,10 @@ use std::{ptr, sync::Arc};
 
 use ash::vk;
 
+use crate::errors::invalid_operation::InvalidOperationError;
+
 use super::{
+    dynamic_rendering::RenderingAttachmentView,
     errors::universal::VulkanUniversalError,
     image::VulkanImage,
     image_view::{VulkanImageView, VulkanImageViewCreateInfo},
@@ -15,10 +18,20 @@ pub struct FramebufferAttachment<'init: 'ma, 'ma> {
     create_info: VulkanImageViewCreateInfo,
 }
 
+impl<'init: 'ma, 'ma> FramebufferAttachment<'init, 'ma> {
+    pub fn new(
+        image: &'init Arc<VulkanImage<'init, 'ma>>,
+        create_info: VulkanImageViewCreateInfo,
+    ) -> Self {
+        Self { image, create_info }
+    }
+}
+
 pub struct Framebuffer<'init: 'ma, 'ma> {
     inner: vk::Framebuffer,
     extent: vk::Extent2D,
     render_pass: Arc<RenderPass<'init>>,
+    attachment_views: Vec<RenderingAttachmentView>,
     _attachments: Vec<VulkanImageView<'init, 'ma>>,
 }
 
@@ -31,16 +44,42 @@ impl<'init: 'ma, 'ma> Framebuffer<'init, 'ma> {
         layers: u32,
         attachments: &[FramebufferAttachment<'init, 'ma>],
     ) -> Result<Self, VulkanUniversalError> {
+        if attachments.len() != render_pass.attachments().len() {
+            return Err(InvalidOperationError::new(format!(
+                "Framebuffer was given {} attachments, but render pass has {} attachments.",
+                attachments.len(),
+                render_pass.attachments().len()
+            ))
+            .into());
+        }
+
         let mut inner_attachments = Vec::with_capacity(attachments.len());
+        let mut attachment_views = Vec::with_capacity(attachments.len());
         let mut constructed_attachments = Vec::with_capacity(attachments.len());
 
         for attachment in attachments {
             let image_view = VulkanImageView::new(attachment.image, &attachment.create_info)?;
 
             inner_attachments.push(image_view.inner());
+            attachment_views.push(RenderingAttachmentView {
+                image: attachment.image.inner(),
+                view: image_view.inner(),
+                subresource_range: attachment.create_info.subresource_range,
+            });
             constructed_attachments.push(image_view);
         }
 
+        // Dynamic rendering uses attachment views directly.
+        if render_pass.is_dynamic() {
+            return Ok(Self {
+                inner: vk::Framebuffer::null(),
+                extent: vk::Extent2D { width, height },
+                render_pass: render_pass.clone(),
+                attachment_views,
+                _attachments: constructed_attachments,
+            });
+        }
+
         let vk_create_info = vk::FramebufferCreateInfo {
             s_type: vk::StructureType::FRAMEBUFFER_CREATE_INFO,
             p_next: ptr::null(),
@@ -64,10 +103,12 @@ impl<'init: 'ma, 'ma> Framebuffer<'init, 'ma> {
             inner,
             extent: vk::Extent2D { width, height },
             render_pass: render_pass.clone(),
+            attachment_views,
             _attachments: constructed_attachments,
         })
     }
 
+    /// Returns framebuffer object, which is null when render pass is rendered by dynamic rendering.
     pub fn inner(&self) -> vk::Framebuffer {
         self.inner
     }
@@ -79,10 +120,18 @@ impl<'init: 'ma, 'ma> Framebuffer<'init, 'ma> {
     pub fn render_pass(&self) -> &Arc<RenderPass<'init>> {
         &self.render_pass
     }
+
+    pub fn attachment_views(&self) -> &[RenderingAttachmentView] {
+        &self.attachment_views
+    }
 }
 
 impl Drop for Framebuffer<'_, '_> {
     fn drop(&mut self) {
+        if self.render_pass.is_dynamic() {
+            return;
+        }
+
         unsafe {
             self.render_pass
                 .device()
@@ -1,6 +1,9 @@
 use ash::vk;
 
-use crate::interop::interop_read_only_span::InteropReadOnlySpan;
+use crate::{
+    errors::invalid_operation::InvalidOperationError,
+    interop::interop_read_only_span::InteropReadOnlySpan,
+};
 
 #[repr(C)]
 pub struct GraphicsPipelineCreateInfo<'a> {
@@ -9,4 +12,258 @@ pub struct GraphicsPipelineCreateInfo<'a> {
     pub vertex_input_attribute_descriptions:
         InteropReadOnlySpan<'a, vk::VertexInputAttributeDescription>,
     pub primitive_topology: vk::PrimitiveTopology,
+    pub rasterization: PipelineRasterizationState,
+    pub depth_stencil: PipelineDepthStencilState,
+    pub multisample: PipelineMultisampleState,
+    /// Blend state of each color attachment of the subpass. When empty, blending is disabled and
+    /// all components are written.
+    pub color_blend_attachments: InteropReadOnlySpan<'a, PipelineColorBlendAttachmentState>,
+    pub blend_constants: [f32; 4],
+    /// Index of the render pass subpass in which the pipeline is used.
+    pub subpass: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct PipelineRasterizationState {
+    pub depth_clamp: bool,
+    pub polygon_mode: vk::PolygonMode,
+    pub cull_mode: vk::CullModeFlags,
+    pub front_face: vk::FrontFace,
+    pub depth_bias: bool,
+    pub depth_bias_constant_factor: f32,
+    pub depth_bias_clamp: f32,
+    pub depth_bias_slope_factor: f32,
+    pub line_width: f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct PipelineDepthStencilState {
+    pub depth_test: bool,
+    pub depth_write: bool,
+    pub depth_compare_op: vk::CompareOp,
+    pub depth_bounds_test: bool,
+    pub stencil_test: bool,
+    pub front: vk::StencilOpState,
+    pub back: vk::StencilOpState,
+    pub min_depth_bounds: f32,
+    pub max_depth_bounds: f32,
+}
+
+/// Sample count is taken from the subpass, so only shading of samples is configured.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct PipelineMultisampleState {
+    /// Runs fragment shader for at least `min_sample_shading` fraction of samples, instead of
+    /// once per pixel.
+    pub sample_shading: bool,
+    pub min_sample_shading: f32,
+    pub alpha_to_coverage: bool,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct PipelineColorBlendAttachmentState {
+    pub blend: bool,
+    pub src_color_blend_factor: vk::BlendFactor,
+    pub dst_color_blend_factor: vk::BlendFactor,
+    pub color_blend_op: vk::BlendOp,
+    pub src_alpha_blend_factor: vk::BlendFactor,
+    pub dst_alpha_blend_factor: vk::BlendFactor,
+    pub alpha_blend_op: vk::BlendOp,
+    pub color_write_mask: vk::ColorComponentFlags,
+}
+
+impl GraphicsPipelineCreateInfo<'_> {
+    /// Checks that used state is supported by enabled `features` and that blend state is given
+    /// for each of `color_attachment_count` attachments.
+    pub fn validate(
+        &self,
+        features: &vk::PhysicalDeviceFeatures,
+        color_attachment_count: usize,
+    ) -> Result<(), InvalidOperationError> {
+        let rasterization = &self.rasterization;
+        if rasterization.depth_clamp {
+            require_feature(features.depth_clamp, "depthClamp", "Depth clamp")?;
+        }
+        if rasterization.polygon_mode != vk::PolygonMode::FILL {
+            require_feature(
+                features.fill_mode_non_solid,
+                "fillModeNonSolid",
+                "Polygon mode other than fill",
+            )?;
+        }
+        if rasterization.depth_bias && rasterization.depth_bias_clamp != 0.0 {
+            require_feature(
+                features.depth_bias_clamp,
+                "depthBiasClamp",
+                "Depth bias clamp",
+            )?;
+        }
+        if rasterization.line_width != 1.0 {
+            require_feature(features.wide_lines, "wideLines", "Line width other than 1")?;
+        }
+
+        let multisample = &self.multisample;
+        if multisample.sample_shading {
+            require_feature(
+                features.sample_rate_shading,
+                "sampleRateShading",
+                "Sample shading",
+            )?;
+
+            if !(0.0..=1.0).contains(&multisample.min_sample_shading) {
+                return Err(InvalidOperationError::new(format!(
+                    "Minimum sample shading {} is not in range from 0 to 1.",
+                    multisample.min_sample_shading
+                )));
+            }
+        }
+
+        if self.depth_stencil.depth_bounds_test {
+            require_feature(features.depth_bounds, "depthBounds", "Depth bounds test")?;
+        }
+
+        let attachments: &[PipelineColorBlendAttachmentState] = self.color_blend_attachments.into();
+        if !attachments.is_empty() && attachments.len() != color_attachment_count {
+            return Err(InvalidOperationError::new(format!(
+                "Color blend state was given for {} attachments, but subpass has {} color attachments.",
+                attachments.len(),
+                color_attachment_count
+            )));
+        }
+
+        for attachment in attachments {
+            for op in [attachment.color_blend_op, attachment.alpha_blend_op] {
+                if op.as_raw() < vk::BlendOp::ADD.as_raw()
+                    || op.as_raw() > vk::BlendOp::MAX.as_raw()
+                {
+                    return Err(InvalidOperationError::new(format!(
+                        "Blend operation {:?} is not supported.",
+                        op
+                    )));
+                }
+            }
+
+            let uses_second_source = [
+                attachment.src_color_blend_factor,
+                attachment.dst_color_blend_factor,
+                attachment.src_alpha_blend_factor,
+                attachment.dst_alpha_blend_factor,
+            ]
+            .into_iter()
+            .any(|factor| {
+                matches!(
+                    factor,
+                    vk::BlendFactor::SRC1_COLOR
+                        | vk::BlendFactor::ONE_MINUS_SRC1_COLOR
+                        | vk::BlendFactor::SRC1_ALPHA
+                        | vk::BlendFactor::ONE_MINUS_SRC1_ALPHA
+                )
+            });
+            if attachment.blend && uses_second_source {
+                require_feature(
+                    features.dual_src_blend,
+                    "dualSrcBlend",
+                    "Dual source blending",
+                )?;
+            }
+        }
+
+        if attachments.windows(2).any(|pair| pair[0] != pair[1]) {
+            require_feature(
+                features.independent_blend,
+                "independentBlend",
+                "Different blend state per attachment",
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Copy of [`GraphicsPipelineCreateInfo`] which does not borrow its spans.
+#[derive(Clone)]
+pub struct OwnedGraphicsPipelineCreateInfo {
+    pub vertex_input_binding_descriptions: Vec<vk::VertexInputBindingDescription>,
+    pub vertex_input_attribute_descriptions: Vec<vk::VertexInputAttributeDescription>,
+    pub primitive_topology: vk::PrimitiveTopology,
+    pub rasterization: PipelineRasterizationState,
+    pub depth_stencil: PipelineDepthStencilState,
+    pub multisample: PipelineMultisampleState,
+    pub color_blend_attachments: Vec<PipelineColorBlendAttachmentState>,
+    pub blend_constants: [f32; 4],
+    pub subpass: u32,
+}
+
+impl OwnedGraphicsPipelineCreateInfo {
+    pub fn new(create_info: &GraphicsPipelineCreateInfo) -> Self {
+        Self {
+            vertex_input_binding_descriptions: <&[_]>::from(
+                create_info.vertex_input_binding_descriptions,
+            )
+            .to_vec(),
+            vertex_input_attribute_descriptions: <&[_]>::from(
+                create_info.vertex_input_attribute_descriptions,
+            )
+            .to_vec(),
+            primitive_topology: create_info.primitive_topology,
+            rasterization: create_info.rasterization,
+            depth_stencil: create_info.depth_stencil,
+            multisample: create_info.multisample,
+            color_blend_attachments: <&[_]>::from(create_info.color_blend_attachments).to_vec(),
+            blend_constants: create_info.blend_constants,
+            subpass: create_info.subpass,
+        }
+    }
+
+    pub fn as_create_info(&self) -> GraphicsPipelineCreateInfo<'_> {
+        GraphicsPipelineCreateInfo {
+            vertex_input_binding_descriptions: self
+                .vertex_input_binding_descriptions
+                .as_slice()
+                .into(),
+            vertex_input_attribute_descriptions: self
+                .vertex_input_attribute_descriptions
+                .as_slice()
+                .into(),
+            primitive_topology: self.primitive_topology,
+            rasterization: self.rasterization,
+            depth_stencil: self.depth_stencil,
+            multisample: self.multisample,
+            color_blend_attachments: self.color_blend_attachments.as_slice().into(),
+            blend_constants: self.blend_constants,
+            subpass: self.subpass,
+        }
+    }
+}
+
+impl From<&PipelineColorBlendAttachmentState> for vk::PipelineColorBlendAttachmentState {
+    fn from(state: &PipelineColorBlendAttachmentState) -> Self {
+        vk::PipelineColorBlendAttachmentState {
+            blend_enable: state.blend as vk::Bool32,
+            src_color_blend_factor: state.src_color_blend_factor,
+            dst_color_blend_factor: state.dst_color_blend_factor,
+            color_blend_op: state.color_blend_op,
+            src_alpha_blend_factor: state.src_alpha_blend_factor,
+            dst_alpha_blend_factor: state.dst_alpha_blend_factor,
+            alpha_blend_op: state.alpha_blend_op,
+            color_write_mask: state.color_write_mask,
+        }
+    }
+}
+
+fn require_feature(
+    enabled: vk::Bool32,
+    feature: &str,
+    usage: &str,
+) -> Result<(), InvalidOperationError> {
+    match enabled {
+        vk::FALSE => Err(InvalidOperationError::new(format!(
+            "{} requires {} feature, which is not supported by the device.",
+            usage, feature
+        ))),
+        _ => Ok(()),
+    }
 }
@@ -9,6 +9,7 @@ use super::{
 };
 
 #[repr(C)]
+#[derive(Clone, Copy)]
 pub struct VulkanImageCreateInfo {
     pub flags: vk::ImageCreateFlags,
     pub image_type: vk::ImageType,
@@ -25,8 +26,7 @@ pub struct VulkanImageCreateInfo {
 
 pub struct VulkanImage<'init: 'ma, 'ma> {
     inner: vk::Image,
-    format: vk::Format,
-    layout: vk::ImageLayout,
+    create_info: VulkanImageCreateInfo,
     _memory: MemoryBlock<'ma>,
     device: Arc<VulkanDevice<'init>>,
 }
@@ -91,19 +91,22 @@ impl<'init: 'ma, 'ma> VulkanImage<'init, 'ma> {
 
         Ok(Self {
             inner,
-            format: create_info.format,
-            layout: create_info.layout,
+            create_info,
             _memory: memory,
             device: device.clone(),
         })
     }
 
     pub fn format(&self) -> vk::Format {
-        self.format
+        self.create_info.format
     }
 
     pub fn layout(&self) -> vk::ImageLayout {
-        self.layout
+        self.create_info.layout
+    }
+
+    pub fn create_info(&self) -> &VulkanImageCreateInfo {
+        &self.create_info
     }
 
     pub fn inner(&self) -> vk::Image {
@@ -4,7 +4,7 @@ use std::{
     sync::Arc,
 };
 
-use ash::vk;
+use ash::{extensions::ext, vk};
 use libc::c_void;
 use uuid::Uuid;
 
@@ -16,14 +16,18 @@ use crate::{
             application_info::VulkanApplicationInfo, device_value::VulkanDeviceValue,
         },
     },
-    logging::{log, log_level::LogLevel, logger},
+    logging::{log, logger},
 };
 
-use super::{device::VulkanDevice, errors::universal::VulkanUniversalError};
+use super::{
+    debug_message::VulkanDebugMessage, device::VulkanDevice,
+    errors::universal::VulkanUniversalError,
+};
 
 pub struct VulkanInstance {
     inner: ash::Instance,
     library: Arc<ash::Entry>,
+    debug_utils: bool,
 }
 
 impl VulkanInstance {
@@ -42,12 +46,27 @@ impl VulkanInstance {
         let mut enabled_extensions_result = Vec::new();
         let mut enabled_layers_result: Vec<*const i8> = Vec::new();
 
+        // Debug utils are enabled whenever available, so object names and labels are also visible
+        // in graphics debuggers which inject them.
+        let debug_utils_name = ext::DebugUtils::name();
+        let debug_utils = validation
+            || library
+                .enumerate_instance_extension_properties(None)?
+                .iter()
+                .any(|extension| {
+                    let name = unsafe { CStr::from_ptr(extension.extension_name.as_ptr()) };
+                    name == debug_utils_name
+                });
+        if debug_utils
+            && !enabled_extensions
+                .iter()
+                .any(|extension| <&str>::from(extension).as_bytes() == debug_utils_name.to_bytes())
+        {
+            enabled_extensions_result.push(debug_utils_name.as_ptr());
+        }
+
         let validation_layer;
         if validation {
-            let debug_utils = CString::new("VK_EXT_debug_utils").unwrap();
-            enabled_extensions_result.push(debug_utils.as_ptr());
-            enabled_extensions_c.push(debug_utils);
-
             validation_layer = CString::new("VK_LAYER_KHRONOS_validation").unwrap();
             enabled_layers_result.push(validation_layer.as_ptr());
         }
@@ -101,6 +120,7 @@ impl VulkanInstance {
             Ok(instance) => Ok(Self {
                 inner: instance,
                 library: library.clone(),
+                debug_utils,
             }),
             Err(err) => Err(err.into()),
         }
@@ -138,6 +158,7 @@ impl VulkanInstance {
 
             let mut supports_graphics = false;
             let mut supports_computing = false;
+            let mut supports_async_computing = false;
             for queue_family_properties in queue_family_properties {
                 supports_graphics |= queue_family_properties
                     .queue_flags
@@ -145,6 +166,12 @@ impl VulkanInstance {
                 supports_computing |= queue_family_properties
                     .queue_flags
                     .contains(vk::QueueFlags::COMPUTE);
+                supports_async_computing |= queue_family_properties
+                    .queue_flags
+                    .contains(vk::QueueFlags::COMPUTE)
+                    && !queue_family_properties
+                        .queue_flags
+                        .contains(vk::QueueFlags::GRAPHICS);
             }
 
             // Presentation.
@@ -177,6 +204,7 @@ impl VulkanInstance {
                 guid: Uuid::from_bytes_le(id_properties.device_uuid),
                 supports_graphics,
                 supports_computing,
+                supports_async_computing,
                 supports_presentation,
                 handle: Box::new(Arc::new(VulkanDevice::new(instance, physical_device))),
             });
@@ -192,6 +220,11 @@ impl VulkanInstance {
     pub fn library(&self) -> &Arc<ash::Entry> {
         &self.library
     }
+
+    /// Returns `true` when `VK_EXT_debug_utils` is enabled.
+    pub fn debug_utils(&self) -> bool {
+        self.debug_utils
+    }
 }
 
 impl Drop for VulkanInstance {
@@ -216,40 +249,11 @@ unsafe extern "system" fn log_callback(
     p_callback_data: *const vk::DebugUtilsMessengerCallbackDataEXT,
     _p_user_data: *mut c_void,
 ) -> vk::Bool32 {
-    let level = match message_severity {
-        vk::DebugUtilsMessageSeverityFlagsEXT::WARNING => LogLevel::Warning,
-        vk::DebugUtilsMessageSeverityFlagsEXT::ERROR => LogLevel::Error,
-        vk::DebugUtilsMessageSeverityFlagsEXT::INFO => LogLevel::Info,
-        vk::DebugUtilsMessageSeverityFlagsEXT::VERBOSE => LogLevel::Debug,
-        _ => {
-            log::warning("Vulkan log severity was not handled.");
-            LogLevel::Info
-        }
-    };
-
-    let prefix = match message_type {
-        vk::DebugUtilsMessageTypeFlagsEXT::GENERAL => "",
-        vk::DebugUtilsMessageTypeFlagsEXT::VALIDATION => "Validation",
-        vk::DebugUtilsMessageTypeFlagsEXT::PERFORMANCE => "Performance",
-        _ => {
-            log::warning("Vulkan log type was not handled.");
-            "Unknown"
-        }
-    };
+    let message = VulkanDebugMessage::from_raw(message_severity, message_type, &*p_callback_data);
 
-    let message = match CStr::from_ptr((*p_callback_data).p_message).to_str() {
-        Ok(m) => m,
-        Err(_) => {
-            log::error("Vulkan log message throws Utf8Error.");
-            return vk::FALSE;
-        }
-    };
-
-    if prefix.is_empty() {
-        logger::log(level, message);
-    } else {
-        logger::log(level, format!("{}: {}", prefix, message).as_str());
-    }
+    logger::log(message.level(), message.to_string().as_str());
+    message.with_log_data(logger::log_validation);
+    message.capture();
 
     vk::FALSE
 }
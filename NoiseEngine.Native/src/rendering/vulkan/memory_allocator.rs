@@ -1,7 +1,4 @@
-use std::{
-    rc::Rc,
-    sync::{Arc, Mutex},
-};
+use std::sync::{Arc, Mutex};
 
 use ash::vk;
 use vma::{Alloc, AllocationCreateInfo};
@@ -15,7 +12,7 @@ pub(crate) struct MemoryAllocator {
 impl MemoryAllocator {
     pub fn new(
         instance: &Arc<VulkanInstance>,
-        device: Rc<ash::Device>,
+        device: Arc<ash::Device>,
         physical_device: vk::PhysicalDevice,
     ) -> Result<Self, VulkanUniversalError> {
         let create_info = vma::AllocatorCreateInfo::new(instance.inner(), &device, physical_device);
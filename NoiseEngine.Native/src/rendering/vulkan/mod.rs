@@ -1,10 +1,19 @@
 pub mod buffers;
+pub mod capture;
 pub mod descriptors;
 pub mod errors;
 
+pub mod debug_message;
+pub mod debug_utils;
 pub mod device;
+pub mod device_fault;
+pub mod device_features;
+pub mod device_limits;
+pub mod device_lost_handler;
 pub mod device_pool;
+pub mod device_selection;
 pub mod device_support;
+pub mod dynamic_rendering;
 pub mod fence;
 pub mod framebuffer;
 pub mod graphics_pipeline_create_info;
@@ -16,16 +25,22 @@ pub mod log_severity;
 pub mod log_type;
 pub mod memory_allocator;
 pub mod pipeline;
+pub mod pipeline_cache;
+pub mod pipeline_compilation;
 pub mod pipeline_layout;
 pub mod pipeline_shader_stage;
 pub mod pool_wrappers;
+pub mod query_pool;
 pub mod render_pass;
 pub mod sampler;
 pub mod semaphore;
 pub mod shader_module;
+pub mod shader_reflection;
 pub mod surface;
 pub mod swapchain;
 pub mod swapchain_framebuffer;
 pub mod swapchain_image_view;
 pub mod swapchain_support;
 pub mod synchronized_fence;
+pub mod timeline_semaphore;
+pub mod upload_manager;
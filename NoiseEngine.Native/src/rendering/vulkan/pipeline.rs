@@ -1,4 +1,8 @@
-use std::{ffi::CString, ptr, sync::Arc};
+use std::{
+    ffi::{c_void, CString},
+    ptr,
+    sync::Arc,
+};
 
 use ash::vk;
 
@@ -6,14 +10,22 @@ use crate::errors::invalid_operation::InvalidOperationError;
 
 use super::{
     errors::universal::VulkanUniversalError,
-    graphics_pipeline_create_info::GraphicsPipelineCreateInfo, pipeline_layout::PipelineLayout,
-    pipeline_shader_stage::PipelineShaderStage, render_pass::RenderPass,
+    graphics_pipeline_create_info::{
+        GraphicsPipelineCreateInfo, OwnedGraphicsPipelineCreateInfo,
+        PipelineColorBlendAttachmentState,
+    },
+    pipeline_layout::PipelineLayout,
+    pipeline_shader_stage::{PipelineShaderStage, PipelineShaderStageInfo},
+    render_pass::RenderPass,
 };
 
 pub struct Pipeline<'init> {
     inner: vk::Pipeline,
-    _render_pass: Option<Arc<RenderPass<'init>>>,
+    render_pass: Option<Arc<RenderPass<'init>>>,
     layout: Arc<PipelineLayout<'init>>,
+    stages: Vec<PipelineShaderStageInfo>,
+    flags: vk::PipelineCreateFlags,
+    graphics_create_info: Option<OwnedGraphicsPipelineCreateInfo>,
 }
 
 impl<'init> Pipeline<'init> {
@@ -24,11 +36,22 @@ impl<'init> Pipeline<'init> {
         flags: vk::PipelineCreateFlags,
         create_info: GraphicsPipelineCreateInfo,
     ) -> Result<Self, VulkanUniversalError> {
+        let initialized = layout.device().initialized()?;
+        let subpass = render_pass.subpass(create_info.subpass)?;
+        create_info.validate(
+            initialized.enabled_features(),
+            subpass.color_attachment_count as usize,
+        )?;
+
         // Stages.
         let mut stage_names = Vec::with_capacity(stages.len());
         let mut final_stages = Vec::with_capacity(stages.len());
+        let specializations = stages
+            .iter()
+            .map(|stage| stage.specialization.info())
+            .collect::<Result<Vec<_>, _>>()?;
 
-        for stage in stages {
+        for (stage, specialization) in stages.iter().zip(&specializations) {
             let stage_name = match CString::new(&stage.name) {
                 Ok(s) => s,
                 Err(_) => {
@@ -46,7 +69,7 @@ impl<'init> Pipeline<'init> {
                 stage: stage.stage,
                 module: stage.module.inner(),
                 p_name: stage_name.as_ptr(),
-                p_specialization_info: ptr::null(),
+                p_specialization_info: specialization.as_ref().map_or(ptr::null(), |info| info),
             });
             stage_names.push(stage_name);
         }
@@ -88,20 +111,21 @@ impl<'init> Pipeline<'init> {
         };
 
         // Rasterization.
+        let rasterization_state = &create_info.rasterization;
         let rasterization = vk::PipelineRasterizationStateCreateInfo {
             s_type: vk::StructureType::PIPELINE_RASTERIZATION_STATE_CREATE_INFO,
             p_next: ptr::null(),
             flags: vk::PipelineRasterizationStateCreateFlags::empty(),
-            depth_clamp_enable: vk::FALSE,
+            depth_clamp_enable: rasterization_state.depth_clamp as vk::Bool32,
             rasterizer_discard_enable: vk::FALSE,
-            polygon_mode: vk::PolygonMode::FILL,
-            cull_mode: vk::CullModeFlags::BACK,
-            front_face: vk::FrontFace::CLOCKWISE,
-            depth_bias_enable: vk::FALSE,
-            depth_bias_constant_factor: 0.0,
-            depth_bias_clamp: 0.0,
-            depth_bias_slope_factor: 0.0,
-            line_width: 1.0,
+            polygon_mode: rasterization_state.polygon_mode,
+            cull_mode: rasterization_state.cull_mode,
+            front_face: rasterization_state.front_face,
+            depth_bias_enable: rasterization_state.depth_bias as vk::Bool32,
+            depth_bias_constant_factor: rasterization_state.depth_bias_constant_factor,
+            depth_bias_clamp: rasterization_state.depth_bias_clamp,
+            depth_bias_slope_factor: rasterization_state.depth_bias_slope_factor,
+            line_width: rasterization_state.line_width,
         };
 
         // Multisample.
@@ -109,44 +133,56 @@ impl<'init> Pipeline<'init> {
             s_type: vk::StructureType::PIPELINE_MULTISAMPLE_STATE_CREATE_INFO,
             p_next: ptr::null(),
             flags: vk::PipelineMultisampleStateCreateFlags::empty(),
-            rasterization_samples: vk::SampleCountFlags::TYPE_1,
-            sample_shading_enable: vk::FALSE,
-            min_sample_shading: 1.0,
+            rasterization_samples: subpass.sample_count,
+            sample_shading_enable: create_info.multisample.sample_shading as vk::Bool32,
+            min_sample_shading: match create_info.multisample.sample_shading {
+                true => create_info.multisample.min_sample_shading,
+                false => 1.0,
+            },
             p_sample_mask: ptr::null(),
-            alpha_to_coverage_enable: vk::FALSE,
+            alpha_to_coverage_enable: create_info.multisample.alpha_to_coverage as vk::Bool32,
             alpha_to_one_enable: vk::FALSE,
         };
 
         // Depth stencil.
-        let depth_stencil = if render_pass.depth_testing() {
+        let depth_stencil = if subpass.depth_stencil {
+            let depth_stencil_state = &create_info.depth_stencil;
             vk::PipelineDepthStencilStateCreateInfo {
                 s_type: vk::StructureType::PIPELINE_DEPTH_STENCIL_STATE_CREATE_INFO,
                 p_next: ptr::null(),
                 flags: vk::PipelineDepthStencilStateCreateFlags::empty(),
-                depth_test_enable: vk::TRUE,
-                depth_write_enable: vk::TRUE,
-                depth_compare_op: vk::CompareOp::LESS,
-                depth_bounds_test_enable: vk::FALSE,
-                stencil_test_enable: vk::FALSE,
-                front: vk::StencilOpState::default(),
-                back: vk::StencilOpState::default(),
-                min_depth_bounds: 0.0,
-                max_depth_bounds: 1.0,
+                depth_test_enable: depth_stencil_state.depth_test as vk::Bool32,
+                depth_write_enable: depth_stencil_state.depth_write as vk::Bool32,
+                depth_compare_op: depth_stencil_state.depth_compare_op,
+                depth_bounds_test_enable: depth_stencil_state.depth_bounds_test as vk::Bool32,
+                stencil_test_enable: depth_stencil_state.stencil_test as vk::Bool32,
+                front: depth_stencil_state.front,
+                back: depth_stencil_state.back,
+                min_depth_bounds: depth_stencil_state.min_depth_bounds,
+                max_depth_bounds: depth_stencil_state.max_depth_bounds,
             }
         } else {
             vk::PipelineDepthStencilStateCreateInfo::default()
         };
 
         // Color blend.
-        let color_blend_attachment = vk::PipelineColorBlendAttachmentState {
-            blend_enable: vk::FALSE,
-            src_color_blend_factor: vk::BlendFactor::ONE,
-            dst_color_blend_factor: vk::BlendFactor::ZERO,
-            color_blend_op: vk::BlendOp::ADD,
-            src_alpha_blend_factor: vk::BlendFactor::ONE,
-            dst_alpha_blend_factor: vk::BlendFactor::ZERO,
-            alpha_blend_op: vk::BlendOp::ADD,
-            color_write_mask: vk::ColorComponentFlags::RGBA,
+        let color_blend_attachments: &[PipelineColorBlendAttachmentState] =
+            create_info.color_blend_attachments.into();
+        let color_blend_attachments = match color_blend_attachments.is_empty() {
+            true => vec![
+                vk::PipelineColorBlendAttachmentState {
+                    blend_enable: vk::FALSE,
+                    src_color_blend_factor: vk::BlendFactor::ONE,
+                    dst_color_blend_factor: vk::BlendFactor::ZERO,
+                    color_blend_op: vk::BlendOp::ADD,
+                    src_alpha_blend_factor: vk::BlendFactor::ONE,
+                    dst_alpha_blend_factor: vk::BlendFactor::ZERO,
+                    alpha_blend_op: vk::BlendOp::ADD,
+                    color_write_mask: vk::ColorComponentFlags::RGBA,
+                };
+                subpass.color_attachment_count as usize
+            ],
+            false => color_blend_attachments.iter().map(Into::into).collect(),
         };
 
         let color_blend = vk::PipelineColorBlendStateCreateInfo {
@@ -155,9 +191,9 @@ impl<'init> Pipeline<'init> {
             flags: vk::PipelineColorBlendStateCreateFlags::empty(),
             logic_op_enable: vk::FALSE,
             logic_op: vk::LogicOp::COPY,
-            attachment_count: 1,
-            p_attachments: &color_blend_attachment,
-            blend_constants: [0.0, 0.0, 0.0, 0.0],
+            attachment_count: color_blend_attachments.len() as u32,
+            p_attachments: color_blend_attachments.as_ptr(),
+            blend_constants: create_info.blend_constants,
         };
 
         // Dynamic.
@@ -171,10 +207,17 @@ impl<'init> Pipeline<'init> {
             p_dynamic_states: dynamic_states.as_ptr(),
         };
 
+        // Dynamic rendering describes attachments instead of render pass object.
+        let rendering = render_pass
+            .rendering_formats()
+            .map(|formats| formats.pipeline_rendering_create_info());
+
         // Construct.
         let create_info_final = vk::GraphicsPipelineCreateInfo {
             s_type: vk::StructureType::GRAPHICS_PIPELINE_CREATE_INFO,
-            p_next: ptr::null(),
+            p_next: rendering.as_ref().map_or(ptr::null(), |rendering| {
+                rendering as *const _ as *const c_void
+            }),
             flags,
             stage_count: final_stages.len() as u32,
             p_stages: final_stages.as_ptr(),
@@ -189,15 +232,14 @@ impl<'init> Pipeline<'init> {
             p_dynamic_state: &dynamic,
             layout: layout.inner(),
             render_pass: render_pass.inner(),
-            subpass: 0,
+            subpass: create_info.subpass,
             base_pipeline_handle: vk::Pipeline::null(),
             base_pipeline_index: 0,
         };
 
-        let initialized = layout.device().initialized()?;
         let inner = match unsafe {
             initialized.vulkan_device().create_graphics_pipelines(
-                vk::PipelineCache::null(),
+                *initialized.pipeline_cache().inner(),
                 &[create_info_final],
                 None,
             )
@@ -208,8 +250,11 @@ impl<'init> Pipeline<'init> {
 
         Ok(Self {
             inner,
-            _render_pass: Some(render_pass.clone()),
+            render_pass: Some(render_pass.clone()),
             layout: layout.clone(),
+            stages: stages.iter().map(PipelineShaderStageInfo::new).collect(),
+            flags,
+            graphics_create_info: Some(OwnedGraphicsPipelineCreateInfo::new(&create_info)),
         })
     }
 
@@ -218,6 +263,7 @@ impl<'init> Pipeline<'init> {
         stage: PipelineShaderStage,
         flags: vk::PipelineCreateFlags,
     ) -> Result<Self, VulkanUniversalError> {
+        let stage_info = PipelineShaderStageInfo::new(&stage);
         let stage_name = match CString::new(String::from(stage.name)) {
             Ok(s) => s,
             Err(_) => {
@@ -228,6 +274,8 @@ impl<'init> Pipeline<'init> {
             }
         };
 
+        let specialization = stage.specialization.info()?;
+
         let final_stage = vk::PipelineShaderStageCreateInfo {
             s_type: vk::StructureType::PIPELINE_SHADER_STAGE_CREATE_INFO,
             p_next: ptr::null(),
@@ -235,7 +283,7 @@ impl<'init> Pipeline<'init> {
             stage: stage.stage,
             module: stage.module.inner(),
             p_name: stage_name.as_ptr(),
-            p_specialization_info: ptr::null(),
+            p_specialization_info: specialization.as_ref().map_or(ptr::null(), |info| info),
         };
 
         let create_info = vk::ComputePipelineCreateInfo {
@@ -251,7 +299,7 @@ impl<'init> Pipeline<'init> {
         let initialized = layout.device().initialized()?;
         let inner = match unsafe {
             initialized.vulkan_device().create_compute_pipelines(
-                vk::PipelineCache::null(),
+                *initialized.pipeline_cache().inner(),
                 &[create_info],
                 None,
             )
@@ -262,8 +310,11 @@ impl<'init> Pipeline<'init> {
 
         Ok(Self {
             inner,
-            _render_pass: None,
+            render_pass: None,
             layout: layout.clone(),
+            stages: vec![stage_info],
+            flags,
+            graphics_create_info: None,
         })
     }
 
@@ -274,6 +325,24 @@ impl<'init> Pipeline<'init> {
     pub fn layout(&self) -> &Arc<PipelineLayout<'init>> {
         &self.layout
     }
+
+    /// Returns render pass of graphics pipeline, or `None` for compute pipeline.
+    pub fn render_pass(&self) -> Option<&Arc<RenderPass<'init>>> {
+        self.render_pass.as_ref()
+    }
+
+    pub fn stages(&self) -> &[PipelineShaderStageInfo] {
+        &self.stages
+    }
+
+    pub fn flags(&self) -> vk::PipelineCreateFlags {
+        self.flags
+    }
+
+    /// Returns state of graphics pipeline, or `None` for compute pipeline.
+    pub fn graphics_create_info(&self) -> Option<&OwnedGraphicsPipelineCreateInfo> {
+        self.graphics_create_info.as_ref()
+    }
 }
 
 impl Drop for Pipeline<'_> {
@@ -0,0 +1,120 @@
+use std::{
+    ptr,
+    sync::{Arc, RwLock, RwLockReadGuard},
+};
+
+use ash::vk;
+
+use crate::logging::log;
+
+use super::errors::universal::VulkanUniversalError;
+
+const HEADER_SIZE: usize = 16 + vk::UUID_SIZE;
+
+pub struct PipelineCache {
+    vulkan_device: Arc<ash::Device>,
+    inner: RwLock<vk::PipelineCache>,
+    vendor_id: u32,
+    device_id: u32,
+    pipeline_cache_uuid: [u8; vk::UUID_SIZE],
+}
+
+impl PipelineCache {
+    pub(super) fn new(
+        device: Arc<ash::Device>,
+        properties: &vk::PhysicalDeviceProperties,
+    ) -> Result<Self, VulkanUniversalError> {
+        let inner = Self::create(&device, &[])?;
+
+        Ok(Self {
+            vulkan_device: device,
+            inner: RwLock::new(inner),
+            vendor_id: properties.vendor_id,
+            device_id: properties.device_id,
+            pipeline_cache_uuid: properties.pipeline_cache_uuid,
+        })
+    }
+
+    /// Returns cache which can be passed to pipeline creation. Cache cannot be used after the guard
+    /// is dropped, because loading merges other caches into it, which must be externally
+    /// synchronized with pipeline creation.
+    pub fn inner(&self) -> RwLockReadGuard<'_, vk::PipelineCache> {
+        self.inner.read().unwrap()
+    }
+
+    /// Merges previously serialized `data` into this cache. Returns `false` when `data` was not
+    /// created by the same device and driver, in which case it is ignored.
+    pub fn load(&self, data: &[u8]) -> Result<bool, VulkanUniversalError> {
+        if let Err(reason) = self.validate_header(data) {
+            log::warning(&format!("Pipeline cache was ignored, {}.", reason));
+            return Ok(false);
+        }
+
+        let source = Self::create(&self.vulkan_device, data)?;
+        // Destination cache of the merge must be externally synchronized with pipeline creation.
+        #[allow(clippy::readonly_write_lock)]
+        let inner = self.inner.write().unwrap();
+
+        let result = unsafe { self.vulkan_device.merge_pipeline_caches(*inner, &[source]) };
+        unsafe {
+            self.vulkan_device.destroy_pipeline_cache(source, None);
+        }
+
+        result?;
+        Ok(true)
+    }
+
+    /// Serializes content of this cache, which can be later passed to [`PipelineCache::load`].
+    pub fn data(&self) -> Result<Vec<u8>, VulkanUniversalError> {
+        Ok(unsafe { self.vulkan_device.get_pipeline_cache_data(*self.inner()) }?)
+    }
+
+    fn create(
+        device: &ash::Device,
+        data: &[u8],
+    ) -> Result<vk::PipelineCache, VulkanUniversalError> {
+        let create_info = vk::PipelineCacheCreateInfo {
+            s_type: vk::StructureType::PIPELINE_CACHE_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: vk::PipelineCacheCreateFlags::empty(),
+            initial_data_size: data.len(),
+            p_initial_data: data.as_ptr() as *const _,
+        };
+
+        Ok(unsafe { device.create_pipeline_cache(&create_info, None) }?)
+    }
+
+    fn validate_header(&self, data: &[u8]) -> Result<(), &'static str> {
+        if data.len() < HEADER_SIZE {
+            return Err("data is shorter than header");
+        }
+
+        // Header fields are always written with the least significant byte first.
+        let read_u32 =
+            |offset: usize| u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+
+        if (read_u32(0) as usize) < HEADER_SIZE {
+            return Err("header size is invalid");
+        }
+        if read_u32(4) != vk::PipelineCacheHeaderVersion::ONE.as_raw() as u32 {
+            return Err("header version is not supported");
+        }
+        if read_u32(8) != self.vendor_id || read_u32(12) != self.device_id {
+            return Err("it was created by other device");
+        }
+        if data[16..HEADER_SIZE] != self.pipeline_cache_uuid {
+            return Err("it was created by other driver version");
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for PipelineCache {
+    fn drop(&mut self) {
+        unsafe {
+            self.vulkan_device
+                .destroy_pipeline_cache(*self.inner.get_mut().unwrap(), None);
+        }
+    }
+}
@@ -0,0 +1,314 @@
+use std::{
+    mem,
+    panic::{self, AssertUnwindSafe},
+    sync::{Arc, Condvar, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use ash::vk;
+
+use crate::{common::worker_pool::WorkerPool, errors::invalid_operation::InvalidOperationError};
+
+use super::{
+    errors::universal::VulkanUniversalError,
+    graphics_pipeline_create_info::{GraphicsPipelineCreateInfo, OwnedGraphicsPipelineCreateInfo},
+    pipeline::Pipeline,
+    pipeline_layout::PipelineLayout,
+    pipeline_shader_stage::{PipelineShaderStage, PipelineShaderStageSpecialization},
+    render_pass::RenderPass,
+    shader_module::ShaderModule,
+};
+
+/// Collects descriptions of pipelines for [`PipelineCompilation`]. Descriptions are copied and
+/// retain used device objects, so the caller may release them before the compilation is finished.
+#[derive(Default)]
+pub struct PipelineCompilationBuilder<'init> {
+    descriptions: Vec<OwnedPipelineDescription<'init>>,
+}
+
+impl<'init> PipelineCompilationBuilder<'init> {
+    pub fn add_graphics(
+        &mut self,
+        render_pass: &Arc<RenderPass<'init>>,
+        layout: &Arc<PipelineLayout<'init>>,
+        stages: &[PipelineShaderStage<'init, '_>],
+        flags: vk::PipelineCreateFlags,
+        create_info: &GraphicsPipelineCreateInfo,
+    ) {
+        self.descriptions.push(OwnedPipelineDescription::Graphics {
+            render_pass: render_pass.clone(),
+            layout: layout.clone(),
+            stages: stages.iter().map(OwnedPipelineShaderStage::new).collect(),
+            flags,
+            create_info: OwnedGraphicsPipelineCreateInfo::new(create_info),
+        });
+    }
+
+    pub fn add_compute(
+        &mut self,
+        layout: &Arc<PipelineLayout<'init>>,
+        stage: &PipelineShaderStage<'init, '_>,
+        flags: vk::PipelineCreateFlags,
+    ) {
+        self.descriptions.push(OwnedPipelineDescription::Compute {
+            layout: layout.clone(),
+            stage: OwnedPipelineShaderStage::new(stage),
+            flags,
+        });
+    }
+}
+
+impl PipelineCompilationBuilder<'static> {
+    /// Starts compilation of added pipelines, results are returned in the order of adding.
+    pub fn start(self) -> Arc<PipelineCompilation<'static>> {
+        let count = self.descriptions.len();
+        let compilation = Arc::new(PipelineCompilation {
+            descriptions: self.descriptions,
+            state: Mutex::new(match count {
+                0 => PipelineCompilationState::Finished(Vec::new()),
+                _ => PipelineCompilationState::Compiling {
+                    results: (0..count).map(|_| None).collect(),
+                    remaining: count,
+                },
+            }),
+            finished: Condvar::new(),
+        });
+
+        WorkerPool::get().spawn((0..count).map(|index| {
+            let compilation = compilation.clone();
+            Box::new(move || compilation.compile(index)) as Box<dyn FnOnce() + Send>
+        }));
+
+        compilation
+    }
+}
+
+/// Lock and condition variable which are notified when any compilation is finished, used by
+/// [`PipelineCompilation::wait_multiple`].
+static FINISHED_LOCK: Mutex<()> = Mutex::new(());
+static FINISHED: Condvar = Condvar::new();
+
+/// Batch of pipelines compiled on worker threads.
+pub struct PipelineCompilation<'init> {
+    descriptions: Vec<OwnedPipelineDescription<'init>>,
+    state: Mutex<PipelineCompilationState<'init>>,
+    finished: Condvar,
+}
+
+enum PipelineCompilationState<'init> {
+    Compiling {
+        results: Vec<Option<Result<Pipeline<'init>, VulkanUniversalError>>>,
+        remaining: usize,
+    },
+    Finished(Vec<Result<Pipeline<'init>, VulkanUniversalError>>),
+    Taken,
+}
+
+impl<'init> PipelineCompilation<'init> {
+    pub fn is_signaled(&self) -> bool {
+        !matches!(
+            *self.lock_state(),
+            PipelineCompilationState::Compiling { .. }
+        )
+    }
+
+    /// Waits for compilation of all pipelines. Returns `false` when `timeout` in nanoseconds
+    /// elapsed before.
+    pub fn wait(&self, timeout: u64) -> bool {
+        let deadline = Self::deadline(timeout);
+        let mut state = self.lock_state();
+
+        while let PipelineCompilationState::Compiling { .. } = *state {
+            state = match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return false;
+                    }
+                    self.finished.wait_timeout(state, deadline - now).unwrap().0
+                }
+                None => self.finished.wait(state).unwrap(),
+            };
+        }
+
+        true
+    }
+
+    /// Waits for all or any of `compilations`. Returns `false` when `timeout` in nanoseconds
+    /// elapsed before.
+    pub fn wait_multiple(compilations: &[&Arc<Self>], wait_all: bool, timeout: u64) -> bool {
+        if wait_all {
+            let start = Instant::now();
+            return compilations.iter().all(|compilation| {
+                let elapsed = start.elapsed().as_nanos().min(u64::MAX as u128) as u64;
+                compilation.wait(match timeout {
+                    u64::MAX => u64::MAX,
+                    _ => timeout.saturating_sub(elapsed),
+                })
+            });
+        }
+
+        let deadline = Self::deadline(timeout);
+        let mut lock = FINISHED_LOCK.lock().unwrap();
+
+        while !compilations
+            .iter()
+            .any(|compilation| compilation.is_signaled())
+        {
+            lock = match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return false;
+                    }
+                    FINISHED.wait_timeout(lock, deadline - now).unwrap().0
+                }
+                None => FINISHED.wait(lock).unwrap(),
+            };
+        }
+
+        true
+    }
+
+    /// Takes results of all pipelines. Fails when compilation is not finished or results were
+    /// already taken.
+    pub fn take_results(
+        &self,
+    ) -> Result<Vec<Result<Pipeline<'init>, VulkanUniversalError>>, InvalidOperationError> {
+        let mut state = self.lock_state();
+        match mem::replace(&mut *state, PipelineCompilationState::Taken) {
+            PipelineCompilationState::Finished(results) => Ok(results),
+            PipelineCompilationState::Taken => Err(InvalidOperationError::with_str(
+                "Pipeline compilation results were already taken.",
+            )),
+            compiling => {
+                *state = compiling;
+                Err(InvalidOperationError::with_str(
+                    "Pipeline compilation is not finished.",
+                ))
+            }
+        }
+    }
+
+    fn deadline(timeout: u64) -> Option<Instant> {
+        match timeout {
+            u64::MAX => None,
+            _ => Instant::now().checked_add(Duration::from_nanos(timeout)),
+        }
+    }
+
+    fn lock_state(&self) -> MutexGuard<'_, PipelineCompilationState<'init>> {
+        self.state.lock().unwrap()
+    }
+
+    fn compile(&self, index: usize) {
+        // Panic is reported as the result of its pipeline, so the compilation is always finished.
+        let result = panic::catch_unwind(AssertUnwindSafe(|| self.descriptions[index].compile()))
+            .unwrap_or_else(|_| {
+                Err(InvalidOperationError::with_str("Pipeline compilation panicked.").into())
+            });
+
+        let mut state = self.lock_state();
+        if let PipelineCompilationState::Compiling { results, remaining } = &mut *state {
+            results[index] = Some(result);
+            *remaining -= 1;
+
+            if *remaining == 0 {
+                let results = mem::take(results).into_iter().map(Option::unwrap).collect();
+                *state = PipelineCompilationState::Finished(results);
+                drop(state);
+
+                self.finished.notify_all();
+
+                // Lock is taken after the state is changed, so waiters in `wait_multiple` either
+                // see the new state or already wait for the notification.
+                drop(FINISHED_LOCK.lock().unwrap());
+                FINISHED.notify_all();
+            }
+        }
+    }
+}
+
+enum OwnedPipelineDescription<'init> {
+    Graphics {
+        render_pass: Arc<RenderPass<'init>>,
+        layout: Arc<PipelineLayout<'init>>,
+        stages: Vec<OwnedPipelineShaderStage<'init>>,
+        flags: vk::PipelineCreateFlags,
+        create_info: OwnedGraphicsPipelineCreateInfo,
+    },
+    Compute {
+        layout: Arc<PipelineLayout<'init>>,
+        stage: OwnedPipelineShaderStage<'init>,
+        flags: vk::PipelineCreateFlags,
+    },
+}
+
+impl<'init> OwnedPipelineDescription<'init> {
+    fn compile(&self) -> Result<Pipeline<'init>, VulkanUniversalError> {
+        match self {
+            Self::Graphics {
+                render_pass,
+                layout,
+                stages,
+                flags,
+                create_info,
+            } => {
+                let stages: Vec<_> = stages.iter().map(|stage| stage.as_stage()).collect();
+                Pipeline::with_graphics(
+                    render_pass,
+                    layout,
+                    &stages,
+                    *flags,
+                    create_info.as_create_info(),
+                )
+            }
+            Self::Compute {
+                layout,
+                stage,
+                flags,
+            } => Pipeline::with_compute(layout, stage.as_stage(), *flags),
+        }
+    }
+}
+
+struct OwnedPipelineShaderStage<'init> {
+    stage: vk::ShaderStageFlags,
+    module: Arc<ShaderModule<'init>>,
+    name: String,
+    specialization_map_entries: Vec<vk::SpecializationMapEntry>,
+    specialization_data: Vec<u8>,
+}
+
+impl<'init> OwnedPipelineShaderStage<'init> {
+    fn new(stage: &PipelineShaderStage<'init, '_>) -> Self {
+        let specialization = &stage.specialization;
+        let (map_entries, data) = match specialization.map_entries.is_empty() {
+            true => (Vec::new(), Vec::new()),
+            false => (
+                <&[_]>::from(specialization.map_entries).to_vec(),
+                <&[_]>::from(specialization.data).to_vec(),
+            ),
+        };
+
+        Self {
+            stage: stage.stage,
+            module: stage.module.clone(),
+            name: <&str>::from(&stage.name).to_owned(),
+            specialization_map_entries: map_entries,
+            specialization_data: data,
+        }
+    }
+
+    fn as_stage(&self) -> PipelineShaderStage<'init, '_> {
+        PipelineShaderStage {
+            stage: self.stage,
+            module: &self.module,
+            name: self.name.clone().into(),
+            specialization: PipelineShaderStageSpecialization {
+                map_entries: self.specialization_map_entries.as_slice().into(),
+                data: self.specialization_data.as_slice().into(),
+            },
+        }
+    }
+}
@@ -8,6 +8,8 @@ use super::{descriptors::set_layout::DescriptorSetLayout, device::VulkanDevice};
 
 pub struct PipelineLayout<'init> {
     inner: vk::PipelineLayout,
+    set_layouts: Vec<Arc<DescriptorSetLayout<'init>>>,
+    push_constant_ranges: Arc<[vk::PushConstantRange]>,
     device: Arc<VulkanDevice<'init>>,
 }
 
@@ -41,6 +43,8 @@ impl<'init: 'setl, 'setl> PipelineLayout<'init> {
 
         Ok(Self {
             inner,
+            set_layouts: layouts.iter().map(|layout| (*layout).clone()).collect(),
+            push_constant_ranges: push_constant_ranges.into(),
             device: device.clone(),
         })
     }
@@ -49,6 +53,14 @@ impl<'init: 'setl, 'setl> PipelineLayout<'init> {
         self.inner
     }
 
+    pub fn set_layouts(&self) -> &[Arc<DescriptorSetLayout<'init>>] {
+        &self.set_layouts
+    }
+
+    pub fn push_constant_ranges(&self) -> &Arc<[vk::PushConstantRange]> {
+        &self.push_constant_ranges
+    }
+
     pub fn device(&self) -> &Arc<VulkanDevice<'init>> {
         &self.device
     }
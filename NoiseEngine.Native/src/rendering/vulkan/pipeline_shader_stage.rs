@@ -1,12 +1,95 @@
+use std::sync::Arc;
+
 use ash::vk;
 
-use crate::interop::prelude::InteropString;
+use crate::{
+    errors::invalid_operation::InvalidOperationError,
+    interop::{interop_read_only_span::InteropReadOnlySpan, prelude::InteropString},
+};
 
 use super::shader_module::ShaderModule;
 
 #[repr(C)]
 pub struct PipelineShaderStage<'init: 'shm, 'shm> {
     pub stage: vk::ShaderStageFlags,
-    pub module: &'shm ShaderModule<'init>,
+    pub module: &'shm Arc<ShaderModule<'init>>,
     pub name: InteropString,
+    pub specialization: PipelineShaderStageSpecialization<'shm>,
+}
+
+/// Copy of [`PipelineShaderStage`] with code of its module, retained for capture of command
+/// streams.
+#[derive(Clone)]
+pub struct PipelineShaderStageInfo {
+    pub stage: vk::ShaderStageFlags,
+    pub code: Arc<[u8]>,
+    pub name: String,
+    pub specialization_map_entries: Vec<vk::SpecializationMapEntry>,
+    pub specialization_data: Vec<u8>,
+}
+
+impl PipelineShaderStageInfo {
+    pub fn new(stage: &PipelineShaderStage) -> Self {
+        let specialization = &stage.specialization;
+        let (map_entries, data) = match specialization.map_entries.is_empty() {
+            true => (Vec::new(), Vec::new()),
+            false => (
+                <&[_]>::from(specialization.map_entries).to_vec(),
+                <&[_]>::from(specialization.data).to_vec(),
+            ),
+        };
+
+        Self {
+            stage: stage.stage,
+            code: stage.module.code().clone(),
+            name: <&str>::from(&stage.name).to_owned(),
+            specialization_map_entries: map_entries,
+            specialization_data: data,
+        }
+    }
+}
+
+/// Values of specialization constants. Each map entry points to a range of `data` with value of the
+/// constant. When there are no map entries, default values from the shader module are used.
+#[repr(C)]
+pub struct PipelineShaderStageSpecialization<'a> {
+    pub map_entries: InteropReadOnlySpan<'a, vk::SpecializationMapEntry>,
+    pub data: InteropReadOnlySpan<'a, u8>,
+}
+
+impl PipelineShaderStageSpecialization<'_> {
+    /// Returns [`vk::SpecializationInfo`] which points to this specialization, or `None` when
+    /// there are no specialization constants.
+    pub fn info(&self) -> Result<Option<vk::SpecializationInfo>, InvalidOperationError> {
+        if self.map_entries.is_empty() {
+            return Ok(None);
+        }
+
+        let map_entries: &[vk::SpecializationMapEntry] = self.map_entries.into();
+        for (i, entry) in map_entries.iter().enumerate() {
+            if entry.offset as usize + entry.size > self.data.len() {
+                return Err(InvalidOperationError::new(format!(
+                    "Specialization map entry of constant {} is out of data bounds.",
+                    entry.constant_id
+                )));
+            }
+
+            if map_entries[..i]
+                .iter()
+                .any(|other| other.constant_id == entry.constant_id)
+            {
+                return Err(InvalidOperationError::new(format!(
+                    "Specialization constant {} is specified multiple times.",
+                    entry.constant_id
+                )));
+            }
+        }
+
+        Ok(Some(vk::SpecializationInfo {
+            map_entry_count: map_entries.len() as u32,
+            p_map_entries: map_entries.as_ptr(),
+            data_size: self.data.len(),
+            p_data: self.data.as_ptr() as *const _,
+        }))
+    }
 }
@@ -0,0 +1,214 @@
+use std::{
+    ffi::c_void,
+    mem, ptr,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+};
+
+use ash::vk;
+
+use crate::errors::invalid_operation::InvalidOperationError;
+
+use super::{device::VulkanDevice, errors::universal::VulkanUniversalError};
+
+pub struct VulkanQueryPool<'init> {
+    inner: vk::QueryPool,
+    device: Arc<VulkanDevice<'init>>,
+    query_type: vk::QueryType,
+    count: u32,
+    pipeline_statistics: vk::QueryPipelineStatisticFlags,
+    timestamp_period: f32,
+    timestamp_valid_bits: AtomicU32,
+}
+
+impl<'init> VulkanQueryPool<'init> {
+    pub fn new(
+        device: &Arc<VulkanDevice<'init>>,
+        query_type: vk::QueryType,
+        count: u32,
+        pipeline_statistics: vk::QueryPipelineStatisticFlags,
+    ) -> Result<Self, VulkanUniversalError> {
+        let initialized = device.initialized()?;
+
+        if count == 0 {
+            return Err(InvalidOperationError::with_str("Query pool cannot be empty.").into());
+        }
+
+        let pipeline_statistics = match query_type {
+            vk::QueryType::OCCLUSION | vk::QueryType::TIMESTAMP => {
+                vk::QueryPipelineStatisticFlags::empty()
+            }
+            vk::QueryType::PIPELINE_STATISTICS => {
+                if initialized.enabled_features().pipeline_statistics_query == vk::FALSE {
+                    return Err(InvalidOperationError::with_str(
+                        "Pipeline statistics queries are not supported by the device.",
+                    )
+                    .into());
+                }
+                if pipeline_statistics.is_empty() {
+                    return Err(InvalidOperationError::with_str(
+                        "Pipeline statistics query pool must collect at least one statistic.",
+                    )
+                    .into());
+                }
+                pipeline_statistics
+            }
+            _ => {
+                return Err(InvalidOperationError::new(format!(
+                    "Query type {:?} is not supported.",
+                    query_type
+                ))
+                .into())
+            }
+        };
+
+        let limits = unsafe {
+            device
+                .instance()
+                .inner()
+                .get_physical_device_properties(device.physical_device())
+        }
+        .limits;
+
+        let create_info = vk::QueryPoolCreateInfo {
+            s_type: vk::StructureType::QUERY_POOL_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: vk::QueryPoolCreateFlags::empty(),
+            query_type,
+            query_count: count,
+            pipeline_statistics,
+        };
+
+        let inner = unsafe {
+            initialized
+                .vulkan_device()
+                .create_query_pool(&create_info, None)
+        }?;
+
+        Ok(Self {
+            inner,
+            device: device.clone(),
+            query_type,
+            count,
+            pipeline_statistics,
+            timestamp_period: limits.timestamp_period,
+            timestamp_valid_bits: AtomicU32::new(u64::BITS),
+        })
+    }
+
+    pub fn inner(&self) -> vk::QueryPool {
+        self.inner
+    }
+
+    pub fn query_type(&self) -> vk::QueryType {
+        self.query_type
+    }
+
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Returns count of values written by single query.
+    pub fn values_per_query(&self) -> u32 {
+        match self.query_type {
+            vk::QueryType::PIPELINE_STATISTICS => self.pipeline_statistics.as_raw().count_ones(),
+            _ => 1,
+        }
+    }
+
+    /// Registers count of valid bits of timestamps written to this pool. Results are masked to the
+    /// lowest count, because the pool can be written by queue families with different counts.
+    pub fn use_timestamp_valid_bits(&self, valid_bits: u32) {
+        self.timestamp_valid_bits
+            .fetch_min(valid_bits, Ordering::Relaxed);
+    }
+
+    /// Returns `Err` with message when queries from `first` to `first + count` are not in this
+    /// pool.
+    pub fn check_range(&self, first: u32, count: u32) -> Result<(), String> {
+        match first.checked_add(count) {
+            Some(end) if end <= self.count => Ok(()),
+            _ => Err(format!(
+                "Queries from {} to {} are out of range of the pool with {} queries.",
+                first,
+                first as u64 + count as u64,
+                self.count
+            )),
+        }
+    }
+
+    /// Writes results of queries from `first` to `first + count` to `results`. Timestamps are
+    /// masked to their valid bits and converted to nanoseconds. When `wait` is `false` and some result is not available yet,
+    /// returns `false` and contents of `results` are undefined.
+    pub fn get_results(
+        &self,
+        first: u32,
+        count: u32,
+        results: &mut [u64],
+        wait: bool,
+    ) -> Result<bool, VulkanUniversalError> {
+        if let Err(message) = self.check_range(first, count) {
+            return Err(InvalidOperationError::new(message).into());
+        }
+
+        let stride = self.values_per_query() as usize;
+        if results.len() != count as usize * stride {
+            return Err(InvalidOperationError::new(format!(
+                "Results must have exactly {} values.",
+                count as usize * stride
+            ))
+            .into());
+        }
+
+        let mut flags = vk::QueryResultFlags::TYPE_64;
+        if wait {
+            flags |= vk::QueryResultFlags::WAIT;
+        }
+
+        let vulkan_device = self.device.initialized()?.vulkan_device();
+        let result = unsafe {
+            (vulkan_device.fp_v1_0().get_query_pool_results)(
+                vulkan_device.handle(),
+                self.inner,
+                first,
+                count,
+                mem::size_of_val(results),
+                results.as_mut_ptr() as *mut c_void,
+                (stride * mem::size_of::<u64>()) as vk::DeviceSize,
+                flags,
+            )
+        };
+
+        match self.device.check_lost(result.result()) {
+            Ok(()) => (),
+            Err(vk::Result::NOT_READY) => return Ok(false),
+            Err(err) => return Err(err.into()),
+        }
+
+        if self.query_type == vk::QueryType::TIMESTAMP {
+            let mask = match self.timestamp_valid_bits.load(Ordering::Relaxed) {
+                u64::BITS => u64::MAX,
+                valid_bits => (1 << valid_bits) - 1,
+            };
+            for value in results.iter_mut() {
+                *value = ((*value & mask) as f64 * self.timestamp_period as f64) as u64;
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+impl Drop for VulkanQueryPool<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            self.device
+                .initialized()
+                .unwrap()
+                .vulkan_device()
+                .destroy_query_pool(self.inner, None);
+        }
+    }
+}
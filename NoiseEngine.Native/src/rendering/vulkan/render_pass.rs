@@ -2,27 +2,83 @@ use std::{ptr, sync::Arc};
 
 use ash::vk;
 
-use crate::rendering::camera_clear::CameraClearFlags;
+use crate::{
+    errors::invalid_operation::InvalidOperationError,
+    interop::interop_read_only_span::InteropReadOnlySpan,
+    rendering::camera_clear::CameraClearFlags,
+};
 
-use super::{device::VulkanDevice, errors::universal::VulkanUniversalError};
+use super::{
+    device::VulkanDevice,
+    dynamic_rendering::{self, RenderingFormats, RenderingSubpass},
+    errors::universal::VulkanUniversalError,
+};
 
 #[repr(C)]
+#[derive(Clone, Copy)]
 pub struct RenderPassCreateInfo {
-    format: vk::Format,
-    sample_count: vk::SampleCountFlags,
-    clear_flags: CameraClearFlags,
-    final_layout: vk::ImageLayout,
-    depth_testing: bool,
-    depth_stencil_format: vk::Format,
-    depth_stencil_sample_count: vk::SampleCountFlags,
+    pub format: vk::Format,
+    pub sample_count: vk::SampleCountFlags,
+    pub clear_flags: CameraClearFlags,
+    pub final_layout: vk::ImageLayout,
+    pub depth_testing: bool,
+    pub depth_stencil_format: vk::Format,
+    pub depth_stencil_sample_count: vk::SampleCountFlags,
+    /// When greater than one, rendering is done to multisampled color attachment which is
+    /// resolved into the target attachment at the end of the pass. Depth stencil attachment must
+    /// then have the same sample count.
+    pub multisample_count: vk::SampleCountFlags,
+    /// Store of depth, which is required to sample depth stencil attachment after the pass.
+    pub depth_store_op: vk::AttachmentStoreOp,
+    /// Load of stencil requires depth stencil attachment to be in depth stencil attachment layout,
+    /// e.g. after previous render pass which stored it.
+    pub stencil_load_op: vk::AttachmentLoadOp,
+    pub stencil_store_op: vk::AttachmentStoreOp,
+}
+
+impl RenderPassCreateInfo {
+    pub fn multisampled(&self) -> bool {
+        self.multisample_count.as_raw() > 1
+    }
+}
+
+/// Render pass with any number of attachments and subpasses, e.g. for G-buffer and input
+/// attachments of deferred shading.
+#[repr(C)]
+pub struct RenderPassDescription<'a> {
+    pub attachments: InteropReadOnlySpan<'a, vk::AttachmentDescription>,
+    pub subpasses: InteropReadOnlySpan<'a, SubpassDescription<'a>>,
+    pub dependencies: InteropReadOnlySpan<'a, vk::SubpassDependency>,
+}
+
+#[repr(C)]
+pub struct SubpassDescription<'a> {
+    pub input_attachments: InteropReadOnlySpan<'a, vk::AttachmentReference>,
+    pub color_attachments: InteropReadOnlySpan<'a, vk::AttachmentReference>,
+    /// Single sampled attachments into which color attachments are resolved. Empty, or with entry
+    /// for each color attachment.
+    pub resolve_attachments: InteropReadOnlySpan<'a, vk::AttachmentReference>,
+    /// Reference to [`vk::ATTACHMENT_UNUSED`] when subpass has no depth stencil attachment.
+    pub depth_stencil_attachment: vk::AttachmentReference,
+    pub preserve_attachments: InteropReadOnlySpan<'a, u32>,
+}
+
+/// Attachments used by a subpass, which pipelines created for it must match.
+#[derive(Clone, Copy, Debug)]
+pub struct RenderPassSubpass {
+    pub color_attachment_count: u32,
+    pub depth_stencil: bool,
+    pub sample_count: vk::SampleCountFlags,
 }
 
 pub struct RenderPass<'init> {
     inner: vk::RenderPass,
     device: Arc<VulkanDevice<'init>>,
-    depth_testing: bool,
-    depth_stencil_format: vk::Format,
-    depth_stencil_sample_count: vk::SampleCountFlags,
+    create_info: RenderPassCreateInfo,
+    attachments: Vec<vk::AttachmentDescription>,
+    subpasses: Vec<RenderPassSubpass>,
+    rendering_formats: Option<RenderingFormats>,
+    rendering_subpass: Option<RenderingSubpass>,
 }
 
 impl<'init> RenderPass<'init> {
@@ -30,49 +86,81 @@ impl<'init> RenderPass<'init> {
         device: &Arc<VulkanDevice<'init>>,
         create_info: RenderPassCreateInfo,
     ) -> Result<Self, VulkanUniversalError> {
-        let color_attachment = vk::AttachmentDescription {
+        let multisampled = create_info.multisampled();
+        if multisampled {
+            Self::check_multisample_count(device, &create_info)?;
+        }
+
+        let load_op = match create_info.clear_flags {
+            CameraClearFlags::Undefined => vk::AttachmentLoadOp::DONT_CARE,
+            CameraClearFlags::Nothing => vk::AttachmentLoadOp::LOAD,
+            CameraClearFlags::SolidColor => vk::AttachmentLoadOp::CLEAR,
+        };
+
+        // Target is always the first attachment, so swapchain images keep their place in
+        // framebuffers. Multisampled color attachment follows it and is resolved into it.
+        let mut attachments = vec![vk::AttachmentDescription {
             flags: vk::AttachmentDescriptionFlags::default(),
             format: create_info.format,
             samples: create_info.sample_count,
-            load_op: match create_info.clear_flags {
-                CameraClearFlags::Undefined => vk::AttachmentLoadOp::DONT_CARE,
-                CameraClearFlags::Nothing => vk::AttachmentLoadOp::LOAD,
-                CameraClearFlags::SolidColor => vk::AttachmentLoadOp::CLEAR,
+            load_op: match multisampled {
+                true => vk::AttachmentLoadOp::DONT_CARE,
+                false => load_op,
             },
             store_op: vk::AttachmentStoreOp::STORE,
             stencil_load_op: vk::AttachmentLoadOp::DONT_CARE,
             stencil_store_op: vk::AttachmentStoreOp::DONT_CARE,
             initial_layout: vk::ImageLayout::UNDEFINED,
             final_layout: create_info.final_layout,
+        }];
+
+        let resolve_attachment_reference = vk::AttachmentReference {
+            attachment: 0,
+            layout: vk::ImageLayout::COLOR_ATTACHMENT_OPTIMAL,
         };
 
+        if multisampled {
+            attachments.push(vk::AttachmentDescription {
+                flags: vk::AttachmentDescriptionFlags::default(),
+                format: create_info.format,
+                samples: create_info.multisample_count,
+                load_op,
+                store_op: vk::AttachmentStoreOp::DONT_CARE,
+                stencil_load_op: vk::AttachmentLoadOp::DONT_CARE,
+                stencil_store_op: vk::AttachmentStoreOp::DONT_CARE,
+                initial_layout: vk::ImageLayout::UNDEFINED,
+                final_layout: vk::ImageLayout::COLOR_ATTACHMENT_OPTIMAL,
+            });
+        }
         let color_attachment_reference = vk::AttachmentReference {
-            attachment: 0,
+            attachment: attachments.len() as u32 - 1,
             layout: vk::ImageLayout::COLOR_ATTACHMENT_OPTIMAL,
         };
 
-        let depth_attachment;
         let depth_attachment_reference;
 
         let dependency;
 
         if create_info.depth_testing {
-            depth_attachment = vk::AttachmentDescription {
+            depth_attachment_reference = vk::AttachmentReference {
+                attachment: attachments.len() as u32,
+                layout: vk::ImageLayout::DEPTH_STENCIL_ATTACHMENT_OPTIMAL,
+            };
+
+            attachments.push(vk::AttachmentDescription {
                 flags: vk::AttachmentDescriptionFlags::default(),
                 format: create_info.depth_stencil_format,
                 samples: create_info.depth_stencil_sample_count,
                 load_op: vk::AttachmentLoadOp::CLEAR,
-                store_op: vk::AttachmentStoreOp::DONT_CARE,
-                stencil_load_op: vk::AttachmentLoadOp::DONT_CARE,
-                stencil_store_op: vk::AttachmentStoreOp::DONT_CARE,
-                initial_layout: vk::ImageLayout::UNDEFINED,
+                store_op: create_info.depth_store_op,
+                stencil_load_op: create_info.stencil_load_op,
+                stencil_store_op: create_info.stencil_store_op,
+                initial_layout: match create_info.stencil_load_op {
+                    vk::AttachmentLoadOp::LOAD => vk::ImageLayout::DEPTH_STENCIL_ATTACHMENT_OPTIMAL,
+                    _ => vk::ImageLayout::UNDEFINED,
+                },
                 final_layout: vk::ImageLayout::DEPTH_STENCIL_ATTACHMENT_OPTIMAL,
-            };
-
-            depth_attachment_reference = vk::AttachmentReference {
-                attachment: 1,
-                layout: vk::ImageLayout::DEPTH_STENCIL_ATTACHMENT_OPTIMAL,
-            };
+            });
 
             dependency = vk::SubpassDependency {
                 src_subpass: vk::SUBPASS_EXTERNAL,
@@ -87,7 +175,6 @@ impl<'init> RenderPass<'init> {
                 dependency_flags: vk::DependencyFlags::empty(),
             };
         } else {
-            depth_attachment = vk::AttachmentDescription::default();
             depth_attachment_reference = vk::AttachmentReference::default();
 
             dependency = vk::SubpassDependency {
@@ -108,7 +195,10 @@ impl<'init> RenderPass<'init> {
             p_input_attachments: ptr::null(),
             color_attachment_count: 1,
             p_color_attachments: &color_attachment_reference,
-            p_resolve_attachments: ptr::null(),
+            p_resolve_attachments: match multisampled {
+                true => &resolve_attachment_reference,
+                false => ptr::null(),
+            },
             p_depth_stencil_attachment: match create_info.depth_testing {
                 true => &depth_attachment_reference,
                 false => ptr::null(),
@@ -117,20 +207,311 @@ impl<'init> RenderPass<'init> {
             p_preserve_attachments: ptr::null(),
         };
 
-        let p_attachments = [color_attachment, depth_attachment];
+        let sample_count = attachments[color_attachment_reference.attachment as usize].samples;
+        let subpasses = vec![RenderPassSubpass {
+            color_attachment_count: 1,
+            depth_stencil: create_info.depth_testing,
+            sample_count,
+        }];
+
+        // Single subpass is expressible by dynamic rendering, which then replaces the render pass
+        // object.
+        let rendering = device.initialized()?.dynamic_rendering().map(|_| {
+            let formats = RenderingFormats::new(
+                &[create_info.format],
+                create_info
+                    .depth_testing
+                    .then_some(create_info.depth_stencil_format),
+                sample_count,
+            );
+            let subpass = RenderingSubpass {
+                color_attachments: vec![color_attachment_reference.attachment],
+                resolve_attachments: match multisampled {
+                    true => vec![resolve_attachment_reference.attachment],
+                    false => Vec::new(),
+                },
+                depth_stencil_attachment: create_info
+                    .depth_testing
+                    .then_some(depth_attachment_reference.attachment),
+            };
+            (formats, subpass)
+        });
+
+        Self::create(
+            device,
+            create_info,
+            attachments,
+            &[subpass],
+            subpasses,
+            &[dependency],
+            rendering,
+        )
+    }
+
+    pub fn with_description(
+        device: &Arc<VulkanDevice<'init>>,
+        description: &RenderPassDescription,
+    ) -> Result<Self, VulkanUniversalError> {
+        let attachments: &[vk::AttachmentDescription] = description.attachments.into();
+        let subpasses: &[SubpassDescription] = description.subpasses.into();
+        let dependencies: &[vk::SubpassDependency] = description.dependencies.into();
+
+        if subpasses.is_empty() {
+            return Err(InvalidOperationError::with_str(
+                "Render pass must have at least one subpass.",
+            )
+            .into());
+        }
+
+        let check_attachment = |reference: &vk::AttachmentReference, allow_unused: bool| {
+            let valid = (reference.attachment as usize) < attachments.len()
+                || (allow_unused && reference.attachment == vk::ATTACHMENT_UNUSED);
+            match valid {
+                true => Ok(()),
+                false => Err(InvalidOperationError::new(format!(
+                    "Attachment {} is not one of {} render pass attachments.",
+                    reference.attachment,
+                    attachments.len()
+                ))),
+            }
+        };
+
+        let mut vk_subpasses = Vec::with_capacity(subpasses.len());
+        let mut subpass_infos = Vec::with_capacity(subpasses.len());
+        for subpass in subpasses {
+            let input_attachments: &[vk::AttachmentReference] = subpass.input_attachments.into();
+            let color_attachments: &[vk::AttachmentReference] = subpass.color_attachments.into();
+            let resolve_attachments: &[vk::AttachmentReference] =
+                subpass.resolve_attachments.into();
+            let preserve_attachments: &[u32] = subpass.preserve_attachments.into();
+
+            for reference in input_attachments
+                .iter()
+                .chain(color_attachments)
+                .chain(resolve_attachments)
+            {
+                check_attachment(reference, true)?;
+            }
+            check_attachment(&subpass.depth_stencil_attachment, true)?;
+            for &attachment in preserve_attachments {
+                check_attachment(
+                    &vk::AttachmentReference {
+                        attachment,
+                        layout: vk::ImageLayout::UNDEFINED,
+                    },
+                    false,
+                )?;
+            }
+
+            let depth_stencil =
+                subpass.depth_stencil_attachment.attachment != vk::ATTACHMENT_UNUSED;
+            let sample_count = Self::subpass_sample_count(attachments, color_attachments, subpass)?;
+
+            if !resolve_attachments.is_empty() {
+                if resolve_attachments.len() != color_attachments.len() {
+                    return Err(InvalidOperationError::new(format!(
+                        "Subpass has {} resolve attachments, but {} color attachments.",
+                        resolve_attachments.len(),
+                        color_attachments.len()
+                    ))
+                    .into());
+                }
+
+                for (color, resolve) in color_attachments.iter().zip(resolve_attachments) {
+                    if resolve.attachment == vk::ATTACHMENT_UNUSED {
+                        continue;
+                    }
+                    if color.attachment == vk::ATTACHMENT_UNUSED
+                        || attachments[color.attachment as usize].samples
+                            == vk::SampleCountFlags::TYPE_1
+                        || attachments[resolve.attachment as usize].samples
+                            != vk::SampleCountFlags::TYPE_1
+                    {
+                        return Err(InvalidOperationError::new(format!(
+                            "Attachment {} must be single sampled and resolved from multisampled attachment.",
+                            resolve.attachment
+                        ))
+                        .into());
+                    }
+                }
+            }
+
+            vk_subpasses.push(vk::SubpassDescription {
+                flags: vk::SubpassDescriptionFlags::default(),
+                pipeline_bind_point: vk::PipelineBindPoint::GRAPHICS,
+                input_attachment_count: input_attachments.len() as u32,
+                p_input_attachments: input_attachments.as_ptr(),
+                color_attachment_count: color_attachments.len() as u32,
+                p_color_attachments: color_attachments.as_ptr(),
+                p_resolve_attachments: match resolve_attachments.is_empty() {
+                    true => ptr::null(),
+                    false => resolve_attachments.as_ptr(),
+                },
+                p_depth_stencil_attachment: match depth_stencil {
+                    true => &subpass.depth_stencil_attachment,
+                    false => ptr::null(),
+                },
+                preserve_attachment_count: preserve_attachments.len() as u32,
+                p_preserve_attachments: preserve_attachments.as_ptr(),
+            });
+            subpass_infos.push(RenderPassSubpass {
+                color_attachment_count: color_attachments.len() as u32,
+                depth_stencil,
+                sample_count,
+            });
+        }
+
+        for dependency in dependencies {
+            for subpass in [dependency.src_subpass, dependency.dst_subpass] {
+                if subpass != vk::SUBPASS_EXTERNAL && subpass as usize >= subpasses.len() {
+                    return Err(InvalidOperationError::new(format!(
+                        "Subpass dependency references subpass {}, but render pass has {} subpasses.",
+                        subpass,
+                        subpasses.len()
+                    ))
+                    .into());
+                }
+            }
+        }
+
+        // Single subpass without input attachments is expressible by dynamic rendering, which then
+        // replaces the render pass object.
+        let subpass = &subpasses[0];
+        let color_attachments: &[vk::AttachmentReference] = subpass.color_attachments.into();
+        let resolve_attachments: &[vk::AttachmentReference] = subpass.resolve_attachments.into();
+        let is_dynamic = device.initialized()?.dynamic_rendering().is_some()
+            && subpasses.len() == 1
+            && subpass.input_attachments.is_empty()
+            && color_attachments.len() <= dynamic_rendering::MAX_COLOR_ATTACHMENTS;
+
+        let rendering = is_dynamic.then(|| {
+            let format = |reference: &vk::AttachmentReference| match reference.attachment {
+                vk::ATTACHMENT_UNUSED => vk::Format::UNDEFINED,
+                attachment => attachments[attachment as usize].format,
+            };
+            let color_formats: Vec<_> = color_attachments.iter().map(format).collect();
+            let depth_stencil_attachment = subpass_infos[0]
+                .depth_stencil
+                .then_some(subpass.depth_stencil_attachment.attachment);
+
+            let formats = RenderingFormats::new(
+                &color_formats,
+                depth_stencil_attachment.map(|_| format(&subpass.depth_stencil_attachment)),
+                subpass_infos[0].sample_count,
+            );
+            let subpass = RenderingSubpass {
+                color_attachments: color_attachments
+                    .iter()
+                    .map(|reference| reference.attachment)
+                    .collect(),
+                resolve_attachments: resolve_attachments
+                    .iter()
+                    .map(|reference| reference.attachment)
+                    .collect(),
+                depth_stencil_attachment,
+            };
+            (formats, subpass)
+        });
+
+        Self::create(
+            device,
+            Self::summarize(attachments, subpass),
+            attachments.to_vec(),
+            &vk_subpasses,
+            subpass_infos,
+            dependencies,
+            rendering,
+        )
+    }
+
+    fn check_multisample_count(
+        device: &Arc<VulkanDevice<'init>>,
+        create_info: &RenderPassCreateInfo,
+    ) -> Result<(), InvalidOperationError> {
+        let supported = device.supported_sample_counts(create_info.depth_testing);
+        if !supported.contains(create_info.multisample_count)
+            || !create_info.multisample_count.as_raw().is_power_of_two()
+        {
+            return Err(InvalidOperationError::new(format!(
+                "Sample count {} is not supported by the device, supported are {:?}.",
+                create_info.multisample_count.as_raw(),
+                supported
+            )));
+        }
+
+        if create_info.depth_testing
+            && create_info.depth_stencil_sample_count != create_info.multisample_count
+        {
+            return Err(InvalidOperationError::new(format!(
+                "Depth stencil sample count {} does not match multisample count {}.",
+                create_info.depth_stencil_sample_count.as_raw(),
+                create_info.multisample_count.as_raw()
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Returns sample count shared by color and depth stencil attachments of `subpass`.
+    fn subpass_sample_count(
+        attachments: &[vk::AttachmentDescription],
+        color_attachments: &[vk::AttachmentReference],
+        subpass: &SubpassDescription,
+    ) -> Result<vk::SampleCountFlags, InvalidOperationError> {
+        let mut sample_count = None;
+        for reference in color_attachments
+            .iter()
+            .chain([&subpass.depth_stencil_attachment])
+        {
+            if reference.attachment == vk::ATTACHMENT_UNUSED {
+                continue;
+            }
+
+            let samples = attachments[reference.attachment as usize].samples;
+            match sample_count {
+                Some(sample_count) if sample_count != samples => {
+                    return Err(InvalidOperationError::with_str(
+                        "Color and depth stencil attachments of subpass must have the same sample count.",
+                    ))
+                }
+                _ => sample_count = Some(samples),
+            }
+        }
+
+        Ok(sample_count.unwrap_or(vk::SampleCountFlags::TYPE_1))
+    }
+
+    fn create(
+        device: &Arc<VulkanDevice<'init>>,
+        create_info: RenderPassCreateInfo,
+        attachments: Vec<vk::AttachmentDescription>,
+        vk_subpasses: &[vk::SubpassDescription],
+        subpasses: Vec<RenderPassSubpass>,
+        dependencies: &[vk::SubpassDependency],
+        rendering: Option<(RenderingFormats, RenderingSubpass)>,
+    ) -> Result<Self, VulkanUniversalError> {
+        if let Some((rendering_formats, rendering_subpass)) = rendering {
+            return Ok(Self {
+                inner: vk::RenderPass::null(),
+                device: device.clone(),
+                create_info,
+                attachments,
+                subpasses,
+                rendering_formats: Some(rendering_formats),
+                rendering_subpass: Some(rendering_subpass),
+            });
+        }
+
         let vk_create_info = vk::RenderPassCreateInfo {
             s_type: vk::StructureType::RENDER_PASS_CREATE_INFO,
             p_next: ptr::null(),
             flags: vk::RenderPassCreateFlags::default(),
-            attachment_count: match create_info.depth_testing {
-                true => 2,
-                false => 1,
-            },
-            p_attachments: p_attachments.as_ptr(),
-            subpass_count: 1,
-            p_subpasses: &subpass,
-            dependency_count: 1,
-            p_dependencies: &dependency,
+            attachment_count: attachments.len() as u32,
+            p_attachments: attachments.as_ptr(),
+            subpass_count: vk_subpasses.len() as u32,
+            p_subpasses: vk_subpasses.as_ptr(),
+            dependency_count: dependencies.len() as u32,
+            p_dependencies: dependencies.as_ptr(),
         };
 
         let initialized = device.initialized()?;
@@ -143,35 +524,143 @@ impl<'init> RenderPass<'init> {
         Ok(Self {
             inner,
             device: device.clone(),
-            depth_testing: create_info.depth_testing,
-            depth_stencil_format: create_info.depth_stencil_format,
-            depth_stencil_sample_count: create_info.depth_stencil_sample_count,
+            create_info,
+            attachments,
+            subpasses,
+            rendering_formats: None,
+            rendering_subpass: None,
         })
     }
 
+    /// Describes first color and depth stencil attachments of `subpass` in form of single target
+    /// render pass, which is used where only such passes are supported, e.g. by captures.
+    fn summarize(
+        attachments: &[vk::AttachmentDescription],
+        subpass: &SubpassDescription,
+    ) -> RenderPassCreateInfo {
+        let color_attachments: &[vk::AttachmentReference] = subpass.color_attachments.into();
+        let resolve_attachments: &[vk::AttachmentReference] = subpass.resolve_attachments.into();
+        let attachment = |references: &[vk::AttachmentReference]| {
+            references
+                .first()
+                .and_then(|reference| attachments.get(reference.attachment as usize))
+                .copied()
+        };
+
+        let multisample = attachment(color_attachments).unwrap_or_default();
+        let (color, multisample_count) = match attachment(resolve_attachments) {
+            Some(resolve) => (resolve, multisample.samples),
+            None => (multisample, vk::SampleCountFlags::TYPE_1),
+        };
+        let depth_stencil = attachments
+            .get(subpass.depth_stencil_attachment.attachment as usize)
+            .copied();
+
+        RenderPassCreateInfo {
+            format: color.format,
+            sample_count: color.samples,
+            clear_flags: match multisample.load_op {
+                vk::AttachmentLoadOp::CLEAR => CameraClearFlags::SolidColor,
+                vk::AttachmentLoadOp::LOAD => CameraClearFlags::Nothing,
+                _ => CameraClearFlags::Undefined,
+            },
+            final_layout: color.final_layout,
+            depth_testing: depth_stencil.is_some(),
+            depth_stencil_format: depth_stencil.map_or(vk::Format::UNDEFINED, |d| d.format),
+            depth_stencil_sample_count: depth_stencil
+                .map_or(vk::SampleCountFlags::TYPE_1, |d| d.samples),
+            multisample_count,
+            depth_store_op: depth_stencil.map_or(vk::AttachmentStoreOp::DONT_CARE, |d| d.store_op),
+            stencil_load_op: depth_stencil
+                .map_or(vk::AttachmentLoadOp::DONT_CARE, |d| d.stencil_load_op),
+            stencil_store_op: depth_stencil
+                .map_or(vk::AttachmentStoreOp::DONT_CARE, |d| d.stencil_store_op),
+        }
+    }
+
+    /// Returns render pass object, which is null when pass is rendered by dynamic rendering.
     pub fn inner(&self) -> vk::RenderPass {
         self.inner
     }
 
+    /// Returns formats of attachments when pass is rendered by dynamic rendering instead of render
+    /// pass object.
+    pub fn rendering_formats(&self) -> Option<&RenderingFormats> {
+        self.rendering_formats.as_ref()
+    }
+
+    /// Returns attachments of the only subpass when pass is rendered by dynamic rendering instead of
+    /// render pass object.
+    pub fn rendering_subpass(&self) -> Option<&RenderingSubpass> {
+        self.rendering_subpass.as_ref()
+    }
+
+    pub fn is_dynamic(&self) -> bool {
+        self.rendering_formats.is_some()
+    }
+
+    /// Returns `true` when both passes are rendered by dynamic rendering into attachments of the
+    /// same formats, so framebuffers of one can be used by the other.
+    pub fn is_dynamic_compatible(&self, other: &RenderPass) -> bool {
+        self.is_dynamic()
+            && other.is_dynamic()
+            && self.attachments.len() == other.attachments.len()
+            && self
+                .attachments
+                .iter()
+                .zip(&other.attachments)
+                .all(|(a, b)| a.format == b.format && a.samples == b.samples)
+    }
+
     pub fn device(&self) -> &Arc<VulkanDevice<'init>> {
         &self.device
     }
 
+    pub fn create_info(&self) -> &RenderPassCreateInfo {
+        &self.create_info
+    }
+
+    pub fn attachments(&self) -> &[vk::AttachmentDescription] {
+        &self.attachments
+    }
+
+    pub fn subpasses(&self) -> &[RenderPassSubpass] {
+        &self.subpasses
+    }
+
+    /// Returns attachments used by `subpass`, or error when render pass has no such subpass.
+    pub fn subpass(&self, subpass: u32) -> Result<RenderPassSubpass, InvalidOperationError> {
+        self.subpasses
+            .get(subpass as usize)
+            .copied()
+            .ok_or_else(|| {
+                InvalidOperationError::new(format!(
+                    "Render pass has {} subpasses, subpass {} does not exist.",
+                    self.subpasses.len(),
+                    subpass
+                ))
+            })
+    }
+
     pub fn depth_testing(&self) -> bool {
-        self.depth_testing
+        self.create_info.depth_testing
     }
 
     pub fn depth_stencil_format(&self) -> vk::Format {
-        self.depth_stencil_format
+        self.create_info.depth_stencil_format
     }
 
     pub fn depth_stencil_sample_count(&self) -> vk::SampleCountFlags {
-        self.depth_stencil_sample_count
+        self.create_info.depth_stencil_sample_count
     }
 }
 
 impl Drop for RenderPass<'_> {
     fn drop(&mut self) {
+        if self.is_dynamic() {
+            return;
+        }
+
         unsafe {
             self.device
                 .initialized()
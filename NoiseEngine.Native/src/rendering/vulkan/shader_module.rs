@@ -2,16 +2,19 @@ use std::{mem, ptr, sync::Arc};
 
 use ash::vk;
 
-use crate::errors::invalid_operation::InvalidOperationError;
+use crate::{errors::invalid_operation::InvalidOperationError, logging::log};
 
 use super::{
     device::{VulkanDevice, VulkanDeviceInitialized},
     errors::universal::VulkanUniversalError,
+    shader_reflection::ShaderReflection,
 };
 
 pub struct ShaderModule<'init> {
     initialized: &'init VulkanDeviceInitialized<'init>,
     inner: vk::ShaderModule,
+    code: Arc<[u8]>,
+    reflection: Option<ShaderReflection>,
     _device: Arc<VulkanDevice<'init>>,
 }
 
@@ -29,6 +32,16 @@ impl<'dev: 'init, 'init> ShaderModule<'init> {
             .into());
         }
 
+        // Reflection is only used to check and complete pipeline layouts, so modules which it
+        // cannot read are still passed to the driver.
+        let reflection = match ShaderReflection::new(code) {
+            Ok(reflection) => Some(reflection),
+            Err(err) => {
+                log::warning(&format!("Shader module reflection is unavailable, {}", err));
+                None
+            }
+        };
+
         let create_info = vk::ShaderModuleCreateInfo {
             s_type: vk::StructureType::SHADER_MODULE_CREATE_INFO,
             p_next: ptr::null(),
@@ -47,6 +60,8 @@ impl<'dev: 'init, 'init> ShaderModule<'init> {
         Ok(Self {
             initialized,
             inner,
+            code: code.into(),
+            reflection,
             _device: device.clone(),
         })
     }
@@ -54,6 +69,16 @@ impl<'dev: 'init, 'init> ShaderModule<'init> {
     pub fn inner(&self) -> vk::ShaderModule {
         self.inner
     }
+
+    /// SPIR-V code from which the module was created, retained for capture of command streams.
+    pub fn code(&self) -> &Arc<[u8]> {
+        &self.code
+    }
+
+    /// Returns resource interface of the module, or `None` when its code could not be reflected.
+    pub fn reflection(&self) -> Option<&ShaderReflection> {
+        self.reflection.as_ref()
+    }
 }
 
 impl Drop for ShaderModule<'_> {
@@ -0,0 +1,785 @@
+use std::collections::{HashMap, HashSet};
+
+use ash::vk;
+
+use crate::errors::invalid_operation::InvalidOperationError;
+
+const MAGIC_NUMBER: u32 = 0x07230203;
+const HEADER_WORDS: usize = 5;
+/// Maximum nesting of types whose size is computed, which bounds recursion on self-referential
+/// types of malformed modules.
+const MAX_TYPE_DEPTH: u32 = 64;
+/// Size of physical storage buffer pointers.
+const POINTER_SIZE: u32 = 8;
+
+mod op {
+    pub const ENTRY_POINT: u32 = 15;
+    pub const EXECUTION_MODE: u32 = 16;
+    pub const TYPE_BOOL: u32 = 20;
+    pub const TYPE_INT: u32 = 21;
+    pub const TYPE_FLOAT: u32 = 22;
+    pub const TYPE_VECTOR: u32 = 23;
+    pub const TYPE_MATRIX: u32 = 24;
+    pub const TYPE_IMAGE: u32 = 25;
+    pub const TYPE_SAMPLER: u32 = 26;
+    pub const TYPE_SAMPLED_IMAGE: u32 = 27;
+    pub const TYPE_ARRAY: u32 = 28;
+    pub const TYPE_RUNTIME_ARRAY: u32 = 29;
+    pub const TYPE_STRUCT: u32 = 30;
+    pub const TYPE_POINTER: u32 = 32;
+    pub const CONSTANT: u32 = 43;
+    pub const CONSTANT_COMPOSITE: u32 = 44;
+    pub const SPEC_CONSTANT: u32 = 50;
+    pub const SPEC_CONSTANT_COMPOSITE: u32 = 51;
+    pub const FUNCTION: u32 = 54;
+    pub const FUNCTION_END: u32 = 56;
+    pub const FUNCTION_CALL: u32 = 57;
+    pub const VARIABLE: u32 = 59;
+    pub const DECORATE: u32 = 71;
+    pub const MEMBER_DECORATE: u32 = 72;
+    pub const EXECUTION_MODE_ID: u32 = 331;
+    pub const TYPE_ACCELERATION_STRUCTURE: u32 = 5341;
+}
+
+mod decoration {
+    pub const BUFFER_BLOCK: u32 = 3;
+    pub const ARRAY_STRIDE: u32 = 6;
+    pub const MATRIX_STRIDE: u32 = 7;
+    pub const BUILT_IN: u32 = 11;
+    pub const LOCATION: u32 = 30;
+    pub const BINDING: u32 = 33;
+    pub const DESCRIPTOR_SET: u32 = 34;
+    pub const OFFSET: u32 = 35;
+}
+
+mod storage_class {
+    pub const UNIFORM_CONSTANT: u32 = 0;
+    pub const INPUT: u32 = 1;
+    pub const UNIFORM: u32 = 2;
+    pub const PUSH_CONSTANT: u32 = 9;
+    pub const STORAGE_BUFFER: u32 = 12;
+}
+
+const EXECUTION_MODE_LOCAL_SIZE: u32 = 17;
+const EXECUTION_MODE_LOCAL_SIZE_ID: u32 = 38;
+const BUILT_IN_WORKGROUP_SIZE: u32 = 25;
+const IMAGE_DIM_BUFFER: u32 = 5;
+const IMAGE_DIM_SUBPASS_DATA: u32 = 6;
+
+/// Resource interface of SPIR-V module, used to generate and check descriptor set layouts, push
+/// constant ranges and vertex input of pipelines which use the module.
+#[derive(Debug, Default)]
+pub struct ShaderReflection {
+    pub entry_points: Vec<ShaderEntryPoint>,
+    /// Bindings sorted by set and binding number.
+    pub bindings: Vec<ShaderBinding>,
+    pub push_constant_ranges: Vec<vk::PushConstantRange>,
+    /// Inputs of vertex entry points sorted by location.
+    pub vertex_inputs: Vec<ShaderVertexInput>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShaderEntryPoint {
+    pub name: String,
+    pub stage: vk::ShaderStageFlags,
+    /// Local workgroup size of compute entry points.
+    pub workgroup_size: Option<[u32; 3]>,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShaderBinding {
+    pub set: u32,
+    pub binding: u32,
+    pub descriptor_type: vk::DescriptorType,
+    /// Number of descriptors in the binding, zero for runtime sized arrays.
+    pub count: u32,
+    /// Stages of entry points which statically use the binding.
+    pub stage_flags: vk::ShaderStageFlags,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShaderVertexInput {
+    /// Index of entry point in [`ShaderReflection::entry_points`].
+    pub entry_point: u32,
+    pub location: u32,
+    /// Format matching the input type, or [`vk::Format::UNDEFINED`] when it has no equivalent.
+    pub format: vk::Format,
+}
+
+impl ShaderReflection {
+    pub fn new(code: &[u8]) -> Result<Self, InvalidOperationError> {
+        let words = code
+            .chunks_exact(4)
+            .map(|chunk| u32::from_ne_bytes(chunk.try_into().unwrap()))
+            .collect::<Vec<_>>();
+
+        if words.len() < HEADER_WORDS || words[0] != MAGIC_NUMBER {
+            return Err(InvalidOperationError::with_str(
+                "Given code is not a valid SPIR-V module.",
+            ));
+        }
+
+        let mut module = Module::default();
+        let mut offset = HEADER_WORDS;
+        while offset < words.len() {
+            let word_count = (words[offset] >> 16) as usize;
+            if word_count == 0 || offset + word_count > words.len() {
+                return Err(InvalidOperationError::new(format!(
+                    "SPIR-V instruction at word {} has invalid length.",
+                    offset
+                )));
+            }
+
+            module.parse_instruction(
+                words[offset] & 0xffff,
+                &words[offset + 1..offset + word_count],
+            );
+            offset += word_count;
+        }
+
+        module.reflect()
+    }
+
+    /// Returns bindings of `set` in form accepted by descriptor set layout creation.
+    pub fn descriptor_set_layout_bindings(&self, set: u32) -> Vec<vk::DescriptorSetLayoutBinding> {
+        self.bindings
+            .iter()
+            .filter(|binding| binding.set == set)
+            .map(|binding| vk::DescriptorSetLayoutBinding {
+                binding: binding.binding,
+                descriptor_type: binding.descriptor_type,
+                descriptor_count: binding.count,
+                stage_flags: binding.stage_flags,
+                p_immutable_samplers: std::ptr::null(),
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum ScalarKind {
+    Bool,
+    Int { signed: bool },
+    Float,
+}
+
+enum Type {
+    Scalar { kind: ScalarKind, width: u32 },
+    Vector { component: u32, count: u32 },
+    Matrix { column: u32, count: u32 },
+    Image { dim: u32, sampled: u32 },
+    Sampler,
+    SampledImage,
+    AccelerationStructure,
+    Array { element: u32, length: u32 },
+    RuntimeArray { element: u32 },
+    Struct { members: Vec<u32> },
+    Pointer { pointee: u32 },
+}
+
+struct EntryPoint {
+    stage: vk::ShaderStageFlags,
+    function: u32,
+    name: String,
+    interface: Vec<u32>,
+    workgroup_size: Option<[u32; 3]>,
+    workgroup_size_ids: Option<[u32; 3]>,
+}
+
+#[derive(Default)]
+struct Function {
+    used_ids: HashSet<u32>,
+    calls: Vec<u32>,
+}
+
+#[derive(Default)]
+struct Module {
+    entry_points: Vec<EntryPoint>,
+    types: HashMap<u32, Type>,
+    constants: HashMap<u32, u32>,
+    decorations: HashMap<(u32, u32), u32>,
+    member_decorations: HashMap<(u32, u32, u32), u32>,
+    /// Global variables with their pointer type and storage class.
+    variables: Vec<(u32, u32, u32)>,
+    functions: HashMap<u32, Function>,
+    current_function: Option<u32>,
+    workgroup_size: Option<[u32; 3]>,
+}
+
+impl Module {
+    fn parse_instruction(&mut self, opcode: u32, operands: &[u32]) {
+        // Missing operands are read as zero, the driver reports invalid modules anyway.
+        let operand = |index: usize| operands.get(index).copied().unwrap_or_default();
+
+        if let Some(id) = self.current_function {
+            let function = self.functions.get_mut(&id).unwrap();
+            if opcode == op::FUNCTION_CALL {
+                function.calls.push(operand(2));
+            }
+
+            // Literal operands are not distinguished from ids, which can only add a stage to
+            // bindings of a variable whose id collides with a literal.
+            function.used_ids.extend(operands.iter().copied());
+
+            if opcode == op::FUNCTION_END {
+                self.current_function = None;
+            }
+            return;
+        }
+
+        match opcode {
+            op::ENTRY_POINT => {
+                let (name, name_words) = parse_string(&operands[operands.len().min(2)..]);
+                if let Some(stage) = execution_model_stage(operand(0)) {
+                    self.entry_points.push(EntryPoint {
+                        stage,
+                        function: operand(1),
+                        name,
+                        interface: operands[operands.len().min(2 + name_words)..].to_vec(),
+                        workgroup_size: None,
+                        workgroup_size_ids: None,
+                    });
+                }
+            }
+            op::EXECUTION_MODE | op::EXECUTION_MODE_ID if operands.len() >= 5 => {
+                let size = [operand(2), operand(3), operand(4)];
+                for entry_point in self.entry_points.iter_mut() {
+                    if entry_point.function != operand(0) {
+                        continue;
+                    }
+                    match operand(1) {
+                        EXECUTION_MODE_LOCAL_SIZE => entry_point.workgroup_size = Some(size),
+                        EXECUTION_MODE_LOCAL_SIZE_ID => entry_point.workgroup_size_ids = Some(size),
+                        _ => {}
+                    }
+                }
+            }
+            op::TYPE_BOOL => self.add_type(
+                operand(0),
+                Type::Scalar {
+                    kind: ScalarKind::Bool,
+                    width: 32,
+                },
+            ),
+            op::TYPE_INT => self.add_type(
+                operand(0),
+                Type::Scalar {
+                    kind: ScalarKind::Int {
+                        signed: operand(2) != 0,
+                    },
+                    width: operand(1),
+                },
+            ),
+            op::TYPE_FLOAT => self.add_type(
+                operand(0),
+                Type::Scalar {
+                    kind: ScalarKind::Float,
+                    width: operand(1),
+                },
+            ),
+            op::TYPE_VECTOR => self.add_type(
+                operand(0),
+                Type::Vector {
+                    component: operand(1),
+                    count: operand(2),
+                },
+            ),
+            op::TYPE_MATRIX => self.add_type(
+                operand(0),
+                Type::Matrix {
+                    column: operand(1),
+                    count: operand(2),
+                },
+            ),
+            op::TYPE_IMAGE => self.add_type(
+                operand(0),
+                Type::Image {
+                    dim: operand(2),
+                    sampled: operand(6),
+                },
+            ),
+            op::TYPE_SAMPLER => self.add_type(operand(0), Type::Sampler),
+            op::TYPE_SAMPLED_IMAGE => self.add_type(operand(0), Type::SampledImage),
+            op::TYPE_ACCELERATION_STRUCTURE => {
+                self.add_type(operand(0), Type::AccelerationStructure)
+            }
+            op::TYPE_ARRAY => self.add_type(
+                operand(0),
+                Type::Array {
+                    element: operand(1),
+                    length: operand(2),
+                },
+            ),
+            op::TYPE_RUNTIME_ARRAY => self.add_type(
+                operand(0),
+                Type::RuntimeArray {
+                    element: operand(1),
+                },
+            ),
+            op::TYPE_STRUCT => self.add_type(
+                operand(0),
+                Type::Struct {
+                    members: operands[operands.len().min(1)..].to_vec(),
+                },
+            ),
+            op::TYPE_POINTER => self.add_type(
+                operand(0),
+                Type::Pointer {
+                    pointee: operand(2),
+                },
+            ),
+            // Only the low-order word is used, which is enough for array lengths and sizes.
+            // Specialization constants are reflected with their default values.
+            op::CONSTANT | op::SPEC_CONSTANT => {
+                self.constants.insert(operand(1), operand(2));
+            }
+            // Constant decorated as workgroup size overrides sizes given by execution modes.
+            op::CONSTANT_COMPOSITE | op::SPEC_CONSTANT_COMPOSITE
+                if self.decoration(operand(1), decoration::BUILT_IN)
+                    == Some(BUILT_IN_WORKGROUP_SIZE) =>
+            {
+                self.workgroup_size = Some([
+                    self.constant(operand(2)),
+                    self.constant(operand(3)),
+                    self.constant(operand(4)),
+                ]);
+            }
+            op::VARIABLE => self.variables.push((operand(1), operand(0), operand(2))),
+            op::DECORATE => {
+                self.decorations
+                    .insert((operand(0), operand(1)), operand(2));
+            }
+            op::MEMBER_DECORATE => {
+                self.member_decorations
+                    .insert((operand(0), operand(1), operand(2)), operand(3));
+            }
+            op::FUNCTION => {
+                self.functions.insert(operand(1), Function::default());
+                self.current_function = Some(operand(1));
+            }
+            _ => {}
+        }
+    }
+
+    fn add_type(&mut self, id: u32, ty: Type) {
+        self.types.insert(id, ty);
+    }
+
+    fn decoration(&self, id: u32, decoration: u32) -> Option<u32> {
+        self.decorations.get(&(id, decoration)).copied()
+    }
+
+    fn constant(&self, id: u32) -> u32 {
+        self.constants.get(&id).copied().unwrap_or_default()
+    }
+
+    fn get_type(&self, id: u32) -> Result<&Type, InvalidOperationError> {
+        self.types.get(&id).ok_or_else(|| {
+            InvalidOperationError::new(format!("SPIR-V type %{} is not defined.", id))
+        })
+    }
+
+    fn reflect(self) -> Result<ShaderReflection, InvalidOperationError> {
+        let entry_point_variables = self.entry_point_variables();
+        let stages_of = |variable: u32| {
+            self.entry_points
+                .iter()
+                .zip(&entry_point_variables)
+                .filter(|(_, variables)| variables.contains(&variable))
+                .fold(vk::ShaderStageFlags::empty(), |stages, (entry_point, _)| {
+                    stages | entry_point.stage
+                })
+        };
+
+        let mut reflection = ShaderReflection::default();
+
+        for entry_point in &self.entry_points {
+            let workgroup_size = match entry_point.stage {
+                vk::ShaderStageFlags::COMPUTE => self
+                    .workgroup_size
+                    .or(entry_point
+                        .workgroup_size_ids
+                        .map(|ids| ids.map(|id| self.constant(id))))
+                    .or(entry_point.workgroup_size),
+                _ => None,
+            };
+
+            reflection.entry_points.push(ShaderEntryPoint {
+                name: entry_point.name.clone(),
+                stage: entry_point.stage,
+                workgroup_size,
+            });
+        }
+
+        for &(id, pointer_type, storage_class) in &self.variables {
+            let pointee = match self.get_type(pointer_type)? {
+                Type::Pointer { pointee } => *pointee,
+                _ => {
+                    return Err(InvalidOperationError::new(format!(
+                        "Type of SPIR-V variable %{} is not a pointer.",
+                        id
+                    )))
+                }
+            };
+
+            match storage_class {
+                storage_class::UNIFORM_CONSTANT
+                | storage_class::UNIFORM
+                | storage_class::STORAGE_BUFFER => {
+                    let (Some(set), Some(binding)) = (
+                        self.decoration(id, decoration::DESCRIPTOR_SET),
+                        self.decoration(id, decoration::BINDING),
+                    ) else {
+                        continue;
+                    };
+
+                    let (element, count) = match self.get_type(pointee)? {
+                        Type::Array { element, length } => (*element, self.constant(*length)),
+                        Type::RuntimeArray { element } => (*element, 0),
+                        _ => (pointee, 1),
+                    };
+
+                    let binding = ShaderBinding {
+                        set,
+                        binding,
+                        descriptor_type: self.descriptor_type(element, storage_class)?,
+                        count,
+                        stage_flags: stages_of(id),
+                    };
+                    add_binding(&mut reflection.bindings, binding)?;
+                }
+                storage_class::PUSH_CONSTANT => {
+                    let stage_flags = stages_of(id);
+                    if stage_flags.is_empty() {
+                        continue;
+                    }
+
+                    let (offset, end) = self.struct_extent(pointee, 0)?;
+                    reflection.push_constant_ranges.push(vk::PushConstantRange {
+                        stage_flags,
+                        offset,
+                        size: end - offset,
+                    });
+                }
+                storage_class::INPUT => {
+                    let Some(location) = self.decoration(id, decoration::LOCATION) else {
+                        continue;
+                    };
+
+                    for (index, (entry_point, variables)) in self
+                        .entry_points
+                        .iter()
+                        .zip(&entry_point_variables)
+                        .enumerate()
+                    {
+                        if entry_point.stage == vk::ShaderStageFlags::VERTEX
+                            && variables.contains(&id)
+                        {
+                            self.add_vertex_inputs(
+                                &mut reflection.vertex_inputs,
+                                index as u32,
+                                location,
+                                pointee,
+                            )?;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        reflection
+            .bindings
+            .sort_by_key(|binding| (binding.set, binding.binding));
+        reflection
+            .vertex_inputs
+            .sort_by_key(|input| (input.entry_point, input.location));
+
+        Ok(reflection)
+    }
+
+    /// Returns global variables referenced by interface or static call tree of each entry point.
+    fn entry_point_variables(&self) -> Vec<HashSet<u32>> {
+        let variables = self
+            .variables
+            .iter()
+            .map(|(id, ..)| *id)
+            .collect::<HashSet<_>>();
+
+        self.entry_points
+            .iter()
+            .map(|entry_point| {
+                let mut visited = HashSet::new();
+                let mut pending = vec![entry_point.function];
+                let mut used = entry_point
+                    .interface
+                    .iter()
+                    .copied()
+                    .collect::<HashSet<_>>();
+
+                while let Some(function) = pending.pop() {
+                    if !visited.insert(function) {
+                        continue;
+                    }
+                    if let Some(function) = self.functions.get(&function) {
+                        used.extend(function.used_ids.iter().copied());
+                        pending.extend(function.calls.iter().copied());
+                    }
+                }
+
+                used.retain(|id| variables.contains(id));
+                used
+            })
+            .collect()
+    }
+
+    fn descriptor_type(
+        &self,
+        ty: u32,
+        storage_class: u32,
+    ) -> Result<vk::DescriptorType, InvalidOperationError> {
+        let descriptor_type = match (storage_class, self.get_type(ty)?) {
+            (storage_class::STORAGE_BUFFER, _) => vk::DescriptorType::STORAGE_BUFFER,
+            (storage_class::UNIFORM, _) => match self.decoration(ty, decoration::BUFFER_BLOCK) {
+                Some(_) => vk::DescriptorType::STORAGE_BUFFER,
+                None => vk::DescriptorType::UNIFORM_BUFFER,
+            },
+            (_, Type::Sampler) => vk::DescriptorType::SAMPLER,
+            (_, Type::SampledImage) => vk::DescriptorType::COMBINED_IMAGE_SAMPLER,
+            (_, Type::AccelerationStructure) => vk::DescriptorType::ACCELERATION_STRUCTURE_KHR,
+            (_, Type::Image { dim, sampled }) => match (*dim, *sampled) {
+                (IMAGE_DIM_SUBPASS_DATA, _) => vk::DescriptorType::INPUT_ATTACHMENT,
+                (IMAGE_DIM_BUFFER, 2) => vk::DescriptorType::STORAGE_TEXEL_BUFFER,
+                (IMAGE_DIM_BUFFER, _) => vk::DescriptorType::UNIFORM_TEXEL_BUFFER,
+                (_, 2) => vk::DescriptorType::STORAGE_IMAGE,
+                _ => vk::DescriptorType::SAMPLED_IMAGE,
+            },
+            _ => {
+                return Err(InvalidOperationError::new(format!(
+                    "SPIR-V type %{} cannot be used as a descriptor.",
+                    ty
+                )))
+            }
+        };
+
+        Ok(descriptor_type)
+    }
+
+    /// Returns offset of the first member and end of the last member of block `ty`.
+    fn struct_extent(&self, ty: u32, depth: u32) -> Result<(u32, u32), InvalidOperationError> {
+        let Type::Struct { members } = self.get_type(ty)? else {
+            return Err(InvalidOperationError::new(format!(
+                "SPIR-V type %{} is not a block.",
+                ty
+            )));
+        };
+
+        let mut start = u32::MAX;
+        let mut end = 0;
+        for (index, member) in members.iter().enumerate() {
+            let index = index as u32;
+            let offset = self
+                .member_decorations
+                .get(&(ty, index, decoration::OFFSET))
+                .copied()
+                .unwrap_or(end);
+            let matrix_stride = self
+                .member_decorations
+                .get(&(ty, index, decoration::MATRIX_STRIDE))
+                .copied();
+
+            let member_end = offset
+                .checked_add(self.size_of(*member, matrix_stride, depth + 1)?)
+                .ok_or_else(|| Self::size_overflow(ty))?;
+
+            start = start.min(offset);
+            end = end.max(member_end);
+        }
+
+        Ok((start.min(end), end))
+    }
+
+    fn size_of(
+        &self,
+        ty: u32,
+        matrix_stride: Option<u32>,
+        depth: u32,
+    ) -> Result<u32, InvalidOperationError> {
+        if depth > MAX_TYPE_DEPTH {
+            return Err(InvalidOperationError::new(format!(
+                "SPIR-V type %{} is nested too deeply.",
+                ty
+            )));
+        }
+
+        let size = match self.get_type(ty)? {
+            Type::Scalar { width, .. } => Some(width / 8),
+            Type::Vector { component, count } => {
+                count.checked_mul(self.size_of(*component, None, depth + 1)?)
+            }
+            Type::Matrix { column, count } => match matrix_stride {
+                Some(stride) => count.checked_mul(stride),
+                None => count.checked_mul(self.size_of(*column, None, depth + 1)?),
+            },
+            Type::Array { element, length } => {
+                let stride = match self.decoration(ty, decoration::ARRAY_STRIDE) {
+                    Some(stride) => stride,
+                    None => self.size_of(*element, matrix_stride, depth + 1)?,
+                };
+                self.constant(*length).checked_mul(stride)
+            }
+            Type::Struct { .. } => Some(self.struct_extent(ty, depth)?.1),
+            Type::Pointer { .. } => Some(POINTER_SIZE),
+            _ => {
+                return Err(InvalidOperationError::new(format!(
+                    "SPIR-V type %{} has no size.",
+                    ty
+                )))
+            }
+        };
+
+        size.ok_or_else(|| Self::size_overflow(ty))
+    }
+
+    fn size_overflow(ty: u32) -> InvalidOperationError {
+        InvalidOperationError::new(format!("Size of SPIR-V type %{} overflows.", ty))
+    }
+
+    fn add_vertex_inputs(
+        &self,
+        inputs: &mut Vec<ShaderVertexInput>,
+        entry_point: u32,
+        location: u32,
+        ty: u32,
+    ) -> Result<(), InvalidOperationError> {
+        // Matrices and arrays consume one location per column or element.
+        let (element, count) = match self.get_type(ty)? {
+            Type::Matrix { column, count } => (*column, *count),
+            Type::Array { element, length } => (*element, self.constant(*length)),
+            _ => (ty, 1),
+        };
+
+        if location.checked_add(count).is_none() {
+            return Err(InvalidOperationError::new(format!(
+                "Vertex input at location {} occupies locations out of range.",
+                location
+            )));
+        }
+
+        let format = self.vertex_format(element)?;
+        for index in 0..count {
+            inputs.push(ShaderVertexInput {
+                entry_point,
+                location: location + index,
+                format,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn vertex_format(&self, ty: u32) -> Result<vk::Format, InvalidOperationError> {
+        let (component, count) = match self.get_type(ty)? {
+            Type::Vector { component, count } => (*component, *count),
+            Type::Scalar { .. } => (ty, 1),
+            _ => return Ok(vk::Format::UNDEFINED),
+        };
+        let Type::Scalar { kind, width } = self.get_type(component)? else {
+            return Ok(vk::Format::UNDEFINED);
+        };
+
+        let formats = match (kind, width) {
+            (ScalarKind::Float, 16) => [
+                vk::Format::R16_SFLOAT,
+                vk::Format::R16G16_SFLOAT,
+                vk::Format::R16G16B16_SFLOAT,
+                vk::Format::R16G16B16A16_SFLOAT,
+            ],
+            (ScalarKind::Float, 32) => [
+                vk::Format::R32_SFLOAT,
+                vk::Format::R32G32_SFLOAT,
+                vk::Format::R32G32B32_SFLOAT,
+                vk::Format::R32G32B32A32_SFLOAT,
+            ],
+            (ScalarKind::Float, 64) => [
+                vk::Format::R64_SFLOAT,
+                vk::Format::R64G64_SFLOAT,
+                vk::Format::R64G64B64_SFLOAT,
+                vk::Format::R64G64B64A64_SFLOAT,
+            ],
+            (ScalarKind::Int { signed: true }, 32) => [
+                vk::Format::R32_SINT,
+                vk::Format::R32G32_SINT,
+                vk::Format::R32G32B32_SINT,
+                vk::Format::R32G32B32A32_SINT,
+            ],
+            (ScalarKind::Int { signed: false }, 32) => [
+                vk::Format::R32_UINT,
+                vk::Format::R32G32_UINT,
+                vk::Format::R32G32B32_UINT,
+                vk::Format::R32G32B32A32_UINT,
+            ],
+            _ => return Ok(vk::Format::UNDEFINED),
+        };
+
+        Ok(formats
+            .get((count as usize).wrapping_sub(1))
+            .copied()
+            .unwrap_or(vk::Format::UNDEFINED))
+    }
+}
+
+/// Merges `binding` with binding declared by other variable at the same location.
+fn add_binding(
+    bindings: &mut Vec<ShaderBinding>,
+    binding: ShaderBinding,
+) -> Result<(), InvalidOperationError> {
+    let Some(existing) = bindings
+        .iter_mut()
+        .find(|b| b.set == binding.set && b.binding == binding.binding)
+    else {
+        bindings.push(binding);
+        return Ok(());
+    };
+
+    if existing.descriptor_type != binding.descriptor_type {
+        return Err(InvalidOperationError::new(format!(
+            "Binding {} of set {} is declared as both {:?} and {:?}.",
+            binding.binding, binding.set, existing.descriptor_type, binding.descriptor_type
+        )));
+    }
+
+    existing.count = existing.count.max(binding.count);
+    existing.stage_flags |= binding.stage_flags;
+    Ok(())
+}
+
+fn execution_model_stage(execution_model: u32) -> Option<vk::ShaderStageFlags> {
+    Some(match execution_model {
+        0 => vk::ShaderStageFlags::VERTEX,
+        1 => vk::ShaderStageFlags::TESSELLATION_CONTROL,
+        2 => vk::ShaderStageFlags::TESSELLATION_EVALUATION,
+        3 => vk::ShaderStageFlags::GEOMETRY,
+        4 => vk::ShaderStageFlags::FRAGMENT,
+        5 => vk::ShaderStageFlags::COMPUTE,
+        5364 => vk::ShaderStageFlags::TASK_EXT,
+        5365 => vk::ShaderStageFlags::MESH_EXT,
+        _ => return None,
+    })
+}
+
+/// Decodes null terminated literal string, returns it with count of words it occupies.
+fn parse_string(words: &[u32]) -> (String, usize) {
+    let mut bytes = Vec::new();
+    for (index, word) in words.iter().enumerate() {
+        for byte in word.to_le_bytes() {
+            if byte == 0 {
+                return (String::from_utf8_lossy(&bytes).into_owned(), index + 1);
+            }
+            bytes.push(byte);
+        }
+    }
+    (String::from_utf8_lossy(&bytes).into_owned(), words.len())
+}
@@ -1,12 +1,10 @@
 use std::{
-    cell::Cell,
     cmp,
     mem::{self, ManuallyDrop},
     ops::Deref,
     ptr,
-    rc::Rc,
     sync::{
-        atomic::{AtomicUsize, Ordering},
+        atomic::{AtomicBool, AtomicUsize, Ordering},
         Arc, Mutex, MutexGuard, Weak,
     },
 };
@@ -135,8 +133,8 @@ impl<'init: 'fam, 'fam> Swapchain<'init, 'fam> {
             shared: shared.clone(),
             dynamic: ArcCell::new(Arc::new(SwapchainSharedDynamic {
                 shared: shared.clone(),
-                is_old: Cell::new(false),
-                must_be_recreated: Cell::new(false),
+                is_old: AtomicBool::new(false),
+                must_be_recreated: AtomicBool::new(false),
                 inner: unsafe { mem::zeroed() },
                 extent: vk::Extent2D::default(),
                 used_min_image_count: 0,
@@ -187,6 +185,10 @@ impl<'init: 'fam, 'fam> Swapchain<'init, 'fam> {
         self.format
     }
 
+    pub fn extent(&self) -> vk::Extent2D {
+        self.dynamic.get().extent
+    }
+
     pub fn device(&self) -> &Arc<VulkanDevice<'init>> {
         &self.device
     }
@@ -235,8 +237,11 @@ impl<'init: 'fam, 'fam> Swapchain<'init, 'fam> {
                 Ok(i) => return Ok((swapchain_pass, synchronized_fence, frame_index, i)),
                 Err(result) => match result {
                     SwapchainAccquireNextImageError::Suboptimal(i) => {
-                        swapchain_pass.dynamic.is_old.set(true);
-                        swapchain_pass.dynamic.must_be_recreated.set(true);
+                        swapchain_pass.dynamic.is_old.store(true, Ordering::Relaxed);
+                        swapchain_pass
+                            .dynamic
+                            .must_be_recreated
+                            .store(true, Ordering::Relaxed);
                         return Ok((swapchain_pass, synchronized_fence, frame_index, i));
                     }
                     SwapchainAccquireNextImageError::OutOfDate => {
@@ -245,7 +250,11 @@ impl<'init: 'fam, 'fam> Swapchain<'init, 'fam> {
                     }
                     SwapchainAccquireNextImageError::Recreated => {
                         synchronized_fence.retire();
-                        if swapchain_pass.dynamic.must_be_recreated.take() {
+                        if swapchain_pass
+                            .dynamic
+                            .must_be_recreated
+                            .swap(false, Ordering::Relaxed)
+                        {
                             self.recreate(None)?;
                         }
                     }
@@ -379,12 +388,12 @@ impl<'init: 'fam, 'fam> Swapchain<'init, 'fam> {
         }
 
         // Construct.
-        old_dynamic.is_old.set(true);
+        old_dynamic.is_old.store(true, Ordering::Relaxed);
 
         self.dynamic.set(Arc::new(SwapchainSharedDynamic {
             shared: self.shared.clone(),
-            is_old: Cell::new(false),
-            must_be_recreated: Cell::new(false),
+            is_old: AtomicBool::new(false),
+            must_be_recreated: AtomicBool::new(false),
             inner,
             extent,
             used_min_image_count,
@@ -417,61 +426,37 @@ impl<'init: 'fam, 'fam> Swapchain<'init, 'fam> {
         let dynamic = self.dynamic.get();
 
         let mut framebuffers = Vec::with_capacity(dynamic.image_views.len());
-        let device = self.shared.device();
-
+        let create_info = render_pass.create_info();
         for image_view in dynamic.image_views.deref() {
-            if render_pass.depth_testing() {
-                let image = Arc::new(VulkanImage::new(
-                    device,
-                    VulkanImageCreateInfo {
-                        flags: vk::ImageCreateFlags::empty(),
-                        image_type: vk::ImageType::TYPE_2D,
-                        extent: vk::Extent3D {
-                            width: dynamic.extent.width,
-                            height: dynamic.extent.height,
-                            depth: 1,
-                        },
-                        format: render_pass.depth_stencil_format(),
-                        mip_levels: 1,
-                        array_layers: 1,
-                        sample_count: render_pass.depth_stencil_sample_count().as_raw(),
-                        linear: false,
-                        usage: vk::ImageUsageFlags::DEPTH_STENCIL_ATTACHMENT,
-                        concurrent: true,
-                        layout: vk::ImageLayout::UNDEFINED,
-                    },
-                )?);
+            let mut attachments = Vec::with_capacity(2);
 
-                let attachments = &[SwapchainFramebufferAttachment {
-                    image,
-                    create_info: VulkanImageViewCreateInfo {
-                        flags: vk::ImageViewCreateFlags::empty(),
-                        view_type: vk::ImageViewType::TYPE_2D,
-                        components: vk::ComponentMapping::default(),
-                        subresource_range: vk::ImageSubresourceRange {
-                            aspect_mask: vk::ImageAspectFlags::DEPTH,
-                            base_mip_level: 0,
-                            level_count: 1,
-                            base_array_layer: 0,
-                            layer_count: 1,
-                        },
-                    },
-                }];
-
-                framebuffers.push(SwapchainFramebuffer::new(
-                    render_pass,
-                    image_view,
+            if create_info.multisampled() {
+                attachments.push(self.create_pass_attachment(
                     dynamic.extent,
-                    attachments,
+                    create_info.format,
+                    create_info.multisample_count,
+                    vk::ImageUsageFlags::COLOR_ATTACHMENT
+                        | vk::ImageUsageFlags::TRANSIENT_ATTACHMENT,
+                    vk::ImageAspectFlags::COLOR,
                 )?);
-            } else {
-                framebuffers.push(SwapchainFramebuffer::new(
-                    render_pass,
-                    image_view,
+            }
+
+            if create_info.depth_testing {
+                attachments.push(self.create_pass_attachment(
                     dynamic.extent,
-                    &[],
+                    create_info.depth_stencil_format,
+                    create_info.depth_stencil_sample_count,
+                    vk::ImageUsageFlags::DEPTH_STENCIL_ATTACHMENT,
+                    vk::ImageAspectFlags::DEPTH,
                 )?);
             }
+
+            framebuffers.push(SwapchainFramebuffer::new(
+                render_pass,
+                image_view,
+                dynamic.extent,
+                &attachments,
+            )?);
         }
 
         let in_flight_fences_length = dynamic.image_available_semaphores.len();
@@ -488,7 +473,7 @@ impl<'init: 'fam, 'fam> Swapchain<'init, 'fam> {
         };
 
         while in_flight_fences.len() != in_flight_fences_length {
-            in_flight_fences.push(Rc::new(WeakCell::new(Weak::new())));
+            in_flight_fences.push(Arc::new(WeakCell::new(Weak::new())));
         }
 
         let new_pass = Arc::new(SwapchainPass {
@@ -504,12 +489,61 @@ impl<'init: 'fam, 'fam> Swapchain<'init, 'fam> {
         Ok(new_pass)
     }
 
+    fn create_pass_attachment(
+        &'init self,
+        extent: vk::Extent2D,
+        format: vk::Format,
+        sample_count: vk::SampleCountFlags,
+        usage: vk::ImageUsageFlags,
+        aspect_mask: vk::ImageAspectFlags,
+    ) -> Result<SwapchainFramebufferAttachment<'init, 'fam>, VulkanUniversalError> {
+        let image = Arc::new(VulkanImage::new(
+            self.shared.device(),
+            VulkanImageCreateInfo {
+                flags: vk::ImageCreateFlags::empty(),
+                image_type: vk::ImageType::TYPE_2D,
+                extent: vk::Extent3D {
+                    width: extent.width,
+                    height: extent.height,
+                    depth: 1,
+                },
+                format,
+                mip_levels: 1,
+                array_layers: 1,
+                sample_count: sample_count.as_raw(),
+                linear: false,
+                usage,
+                concurrent: true,
+                layout: vk::ImageLayout::UNDEFINED,
+            },
+        )?);
+
+        Ok(SwapchainFramebufferAttachment {
+            image,
+            create_info: VulkanImageViewCreateInfo {
+                flags: vk::ImageViewCreateFlags::empty(),
+                view_type: vk::ImageViewType::TYPE_2D,
+                components: vk::ComponentMapping::default(),
+                subresource_range: vk::ImageSubresourceRange {
+                    aspect_mask,
+                    base_mip_level: 0,
+                    level_count: 1,
+                    base_array_layer: 0,
+                    layer_count: 1,
+                },
+            },
+        })
+    }
+
     fn compare_render_pass(
         &self,
         current_pass: &Arc<SwapchainPass<'init, 'fam>>,
         render_pass: &Arc<RenderPass<'init>>,
     ) -> bool {
-        Arc::ptr_eq(&current_pass.render_pass, render_pass) && !current_pass.dynamic.is_old.get()
+        // Framebuffers without render pass object are shared by all compatible passes.
+        (Arc::ptr_eq(&current_pass.render_pass, render_pass)
+            || current_pass.render_pass.is_dynamic_compatible(render_pass))
+            && !current_pass.dynamic.is_old.load(Ordering::Relaxed)
     }
 
     fn lock_pass_creation_mutex(&self) -> Result<MutexGuard<()>, InvalidOperationError> {
@@ -556,8 +590,8 @@ impl<'init: 'fam, 'fam> SwapchainShared<'init, 'fam> {
 
 struct SwapchainSharedDynamic<'init: 'fam, 'fam> {
     shared: Arc<SwapchainShared<'init, 'fam>>,
-    is_old: Cell<bool>,
-    must_be_recreated: Cell<bool>,
+    is_old: AtomicBool,
+    must_be_recreated: AtomicBool,
     inner: vk::SwapchainKHR,
     extent: vk::Extent2D,
     used_min_image_count: u32,
@@ -581,7 +615,7 @@ pub struct SwapchainPass<'init: 'fam, 'fam> {
     shared: Arc<SwapchainShared<'init, 'fam>>,
     dynamic: Arc<SwapchainSharedDynamic<'init, 'fam>>,
     framebuffers: ManuallyDrop<Vec<SwapchainFramebuffer<'init, 'fam>>>,
-    in_flight_fences: Vec<Rc<WeakCell<VulkanSynchronizedFence<'init>>>>,
+    in_flight_fences: Vec<Arc<WeakCell<VulkanSynchronizedFence<'init>>>>,
     frame_index: AtomicUsize,
     render_pass: Arc<RenderPass<'init>>,
 }
@@ -613,18 +647,18 @@ impl<'init: 'fam, 'fam> SwapchainPass<'init, 'fam> {
             // Lock mutex.
             let _swapchain_lock = self.lock_ash_swapchain()?;
 
-            if self.dynamic.is_old.get() {
+            if self.dynamic.is_old.load(Ordering::Relaxed) {
                 return Err(SwapchainAccquireNextImageError::Recreated);
             }
 
-            unsafe {
+            self.shared.device.check_lost(unsafe {
                 self.shared.ash_swapchain.acquire_next_image(
                     self.inner(),
                     u64::MAX,
                     semaphore,
                     vk::Fence::null(),
                 )
-            }
+            })
         };
 
         match result {
@@ -3,6 +3,7 @@ use std::{ptr, sync::Arc};
 use ash::vk;
 
 use super::{
+    dynamic_rendering::RenderingAttachmentView,
     errors::universal::VulkanUniversalError,
     image::VulkanImage,
     image_view::{VulkanImageView, VulkanImageViewCreateInfo},
@@ -20,6 +21,7 @@ pub struct SwapchainFramebuffer<'init: 'ma, 'ma> {
     _attachment: Arc<SwapchainImageView<'init>>,
     render_pass: Arc<RenderPass<'init>>,
     extent: vk::Extent2D,
+    attachment_views: Vec<RenderingAttachmentView>,
     _attachments: Vec<VulkanImageView<'init, 'ma>>,
 }
 
@@ -31,17 +33,40 @@ impl<'init: 'ma, 'ma> SwapchainFramebuffer<'init, 'ma> {
         attachments: &[SwapchainFramebufferAttachment<'init, 'ma>],
     ) -> Result<Self, VulkanUniversalError> {
         let mut inner_attachments = Vec::with_capacity(attachments.len() + 1);
+        let mut attachment_views = Vec::with_capacity(attachments.len() + 1);
         let mut constructed_attachments = Vec::with_capacity(attachments.len());
 
         inner_attachments.push(image_view.inner());
+        attachment_views.push(RenderingAttachmentView {
+            image: image_view.image(),
+            view: image_view.inner(),
+            subresource_range: image_view.subresource_range(),
+        });
 
         for attachment in attachments {
             let image_view = VulkanImageView::new(&attachment.image, &attachment.create_info)?;
 
             inner_attachments.push(image_view.inner());
+            attachment_views.push(RenderingAttachmentView {
+                image: attachment.image.inner(),
+                view: image_view.inner(),
+                subresource_range: attachment.create_info.subresource_range,
+            });
             constructed_attachments.push(image_view);
         }
 
+        // Dynamic rendering uses attachment views directly.
+        if render_pass.is_dynamic() {
+            return Ok(Self {
+                inner: vk::Framebuffer::null(),
+                _attachment: image_view.clone(),
+                render_pass: render_pass.clone(),
+                extent,
+                attachment_views,
+                _attachments: constructed_attachments,
+            });
+        }
+
         let vk_create_info = vk::FramebufferCreateInfo {
             s_type: vk::StructureType::FRAMEBUFFER_CREATE_INFO,
             p_next: ptr::null(),
@@ -66,6 +91,7 @@ impl<'init: 'ma, 'ma> SwapchainFramebuffer<'init, 'ma> {
             _attachment: image_view.clone(),
             render_pass: render_pass.clone(),
             extent,
+            attachment_views,
             _attachments: constructed_attachments,
         })
     }
@@ -77,10 +103,22 @@ impl<'init: 'ma, 'ma> SwapchainFramebuffer<'init, 'ma> {
     pub fn extent(&self) -> vk::Extent2D {
         self.extent
     }
+
+    pub fn render_pass(&self) -> &Arc<RenderPass<'init>> {
+        &self.render_pass
+    }
+
+    pub fn attachment_views(&self) -> &[RenderingAttachmentView] {
+        &self.attachment_views
+    }
 }
 
 impl Drop for SwapchainFramebuffer<'_, '_> {
     fn drop(&mut self) {
+        if self.render_pass.is_dynamic() {
+            return;
+        }
+
         unsafe {
             self.render_pass
                 .device()
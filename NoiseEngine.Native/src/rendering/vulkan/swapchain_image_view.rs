@@ -4,8 +4,17 @@ use ash::vk;
 
 use super::{device::VulkanDevice, errors::universal::VulkanUniversalError, swapchain::Swapchain};
 
+const SUBRESOURCE_RANGE: vk::ImageSubresourceRange = vk::ImageSubresourceRange {
+    aspect_mask: vk::ImageAspectFlags::COLOR,
+    base_mip_level: 0,
+    level_count: 1,
+    base_array_layer: 0,
+    layer_count: 1,
+};
+
 pub struct SwapchainImageView<'init> {
     inner: vk::ImageView,
+    image: vk::Image,
     device: Arc<VulkanDevice<'init>>,
 }
 
@@ -27,13 +36,7 @@ impl<'init> SwapchainImageView<'init> {
                 b: vk::ComponentSwizzle::IDENTITY,
                 a: vk::ComponentSwizzle::IDENTITY,
             },
-            subresource_range: vk::ImageSubresourceRange {
-                aspect_mask: vk::ImageAspectFlags::COLOR,
-                base_mip_level: 0,
-                level_count: 1,
-                base_array_layer: 0,
-                layer_count: 1,
-            },
+            subresource_range: SUBRESOURCE_RANGE,
         };
 
         let initialized = swapchain.device().initialized()?;
@@ -45,6 +48,7 @@ impl<'init> SwapchainImageView<'init> {
 
         Ok(Self {
             inner,
+            image,
             device: swapchain.device().clone(),
         })
     }
@@ -52,6 +56,14 @@ impl<'init> SwapchainImageView<'init> {
     pub fn inner(&self) -> vk::ImageView {
         self.inner
     }
+
+    pub fn image(&self) -> vk::Image {
+        self.image
+    }
+
+    pub fn subresource_range(&self) -> vk::ImageSubresourceRange {
+        SUBRESOURCE_RANGE
+    }
 }
 
 impl Drop for SwapchainImageView<'_> {
@@ -0,0 +1,222 @@
+use std::{ffi::c_void, ptr, sync::Arc};
+
+use ash::vk;
+
+use crate::{
+    errors::invalid_operation::InvalidOperationError,
+    interop::prelude::{InteropResult, ResultError},
+    rendering::timeline_semaphore::{GraphicsTimelineSemaphore, GraphicsTimelineSemaphoreSubmit},
+};
+
+use super::{device::VulkanDevice, errors::universal::VulkanUniversalError};
+
+pub struct VulkanTimelineSemaphore<'init> {
+    inner: vk::Semaphore,
+    device: Arc<VulkanDevice<'init>>,
+}
+
+impl<'init> VulkanTimelineSemaphore<'init> {
+    pub fn new(
+        device: &Arc<VulkanDevice<'init>>,
+        initial_value: u64,
+    ) -> Result<Self, VulkanUniversalError> {
+        let initialized = device.initialized()?;
+        if !initialized.timeline_semaphore() {
+            return Err(InvalidOperationError::with_str(
+                "Timeline semaphores are not supported by the device.",
+            )
+            .into());
+        }
+
+        let type_create_info = vk::SemaphoreTypeCreateInfo {
+            s_type: vk::StructureType::SEMAPHORE_TYPE_CREATE_INFO,
+            p_next: ptr::null(),
+            semaphore_type: vk::SemaphoreType::TIMELINE,
+            initial_value,
+        };
+        let create_info = vk::SemaphoreCreateInfo {
+            s_type: vk::StructureType::SEMAPHORE_CREATE_INFO,
+            p_next: &type_create_info as *const _ as *const c_void,
+            flags: vk::SemaphoreCreateFlags::empty(),
+        };
+
+        let inner = unsafe {
+            initialized
+                .vulkan_device()
+                .create_semaphore(&create_info, None)
+        }?;
+
+        Ok(Self {
+            inner,
+            device: device.clone(),
+        })
+    }
+
+    /// Returns `true` when `physical_device` supports timeline semaphores, which are part of
+    /// Vulkan 1.2.
+    pub fn is_supported(instance: &ash::Instance, physical_device: vk::PhysicalDevice) -> bool {
+        let api_version =
+            unsafe { instance.get_physical_device_properties(physical_device) }.api_version;
+        if api_version < vk::API_VERSION_1_2 {
+            return false;
+        }
+
+        let mut features = vk::PhysicalDeviceTimelineSemaphoreFeatures::default();
+        let mut features2 = vk::PhysicalDeviceFeatures2 {
+            s_type: vk::StructureType::PHYSICAL_DEVICE_FEATURES_2,
+            p_next: &mut features as *mut _ as *mut c_void,
+            features: vk::PhysicalDeviceFeatures::default(),
+        };
+        unsafe { instance.get_physical_device_features2(physical_device, &mut features2) };
+
+        features.timeline_semaphore != vk::FALSE
+    }
+
+    /// Returns semaphore of `submit`, which must be created by this API and `device`.
+    /// # Safety
+    /// Semaphore of `submit` must be [`VulkanTimelineSemaphore`].
+    pub unsafe fn from_submit<'a>(
+        device: &Arc<VulkanDevice<'init>>,
+        submit: &GraphicsTimelineSemaphoreSubmit<'a, 'init>,
+    ) -> Result<&'a Self, InvalidOperationError> {
+        let semaphore = &*(Arc::as_ptr(submit.semaphore) as *const Self);
+        match Arc::ptr_eq(&semaphore.device, device) {
+            true => Ok(semaphore),
+            false => Err(InvalidOperationError::with_str(
+                "Timeline semaphore is from other device.",
+            )),
+        }
+    }
+
+    pub fn inner(&self) -> vk::Semaphore {
+        self.inner
+    }
+
+    pub fn value(&self) -> Result<u64, VulkanUniversalError> {
+        Ok(self.device.check_lost(unsafe {
+            self.device
+                .initialized()?
+                .vulkan_device()
+                .get_semaphore_counter_value(self.inner)
+        })?)
+    }
+
+    pub fn wait(&self, value: u64, timeout: u64) -> Result<bool, VulkanUniversalError> {
+        unsafe { self.wait_inner(&[self.inner], &[value], true, timeout) }
+    }
+
+    pub fn signal(&self, value: u64) -> Result<(), VulkanUniversalError> {
+        let signal_info = vk::SemaphoreSignalInfo {
+            s_type: vk::StructureType::SEMAPHORE_SIGNAL_INFO,
+            p_next: ptr::null(),
+            semaphore: self.inner,
+            value,
+        };
+
+        Ok(unsafe {
+            self.device
+                .initialized()?
+                .vulkan_device()
+                .signal_semaphore(&signal_info)
+        }?)
+    }
+
+    /// # Safety
+    /// All semaphores must be from the same device.
+    unsafe fn wait_inner(
+        &self,
+        semaphores: &[vk::Semaphore],
+        values: &[u64],
+        wait_all: bool,
+        timeout: u64,
+    ) -> Result<bool, VulkanUniversalError> {
+        let wait_info = vk::SemaphoreWaitInfo {
+            s_type: vk::StructureType::SEMAPHORE_WAIT_INFO,
+            p_next: ptr::null(),
+            flags: match wait_all {
+                true => vk::SemaphoreWaitFlags::empty(),
+                false => vk::SemaphoreWaitFlags::ANY,
+            },
+            semaphore_count: semaphores.len() as u32,
+            p_semaphores: semaphores.as_ptr(),
+            p_values: values.as_ptr(),
+        };
+
+        match self.device.check_lost(
+            self.device
+                .initialized()?
+                .vulkan_device()
+                .wait_semaphores(&wait_info, timeout),
+        ) {
+            Ok(()) => Ok(true),
+            Err(vk::Result::TIMEOUT) => Ok(false),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+impl Drop for VulkanTimelineSemaphore<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            self.device
+                .initialized()
+                .unwrap()
+                .vulkan_device()
+                .destroy_semaphore(self.inner, None);
+        }
+    }
+}
+
+impl GraphicsTimelineSemaphore for VulkanTimelineSemaphore<'_> {
+    fn value(&self) -> InteropResult<u64> {
+        match self.value() {
+            Ok(value) => InteropResult::with_ok(value),
+            Err(err) => InteropResult::with_err(err.into()),
+        }
+    }
+
+    fn wait(&self, value: u64, timeout: u64) -> InteropResult<bool> {
+        match self.wait(value, timeout) {
+            Ok(is_reached) => InteropResult::with_ok(is_reached),
+            Err(err) => InteropResult::with_err(err.into()),
+        }
+    }
+
+    fn signal(&self, value: u64) -> InteropResult<()> {
+        match self.signal(value) {
+            Ok(()) => InteropResult::with_ok(()),
+            Err(err) => InteropResult::with_err(err.into()),
+        }
+    }
+
+    unsafe fn wait_multiple(
+        &self,
+        semaphores: &[&Arc<dyn GraphicsTimelineSemaphore>],
+        values: &[u64],
+        wait_all: bool,
+        timeout: u64,
+    ) -> Result<bool, ResultError> {
+        if semaphores.len() != values.len() {
+            return Err(InvalidOperationError::new(format!(
+                "Given {} timeline semaphores, but {} values.",
+                semaphores.len(),
+                values.len()
+            ))
+            .into());
+        }
+
+        let mut inner = Vec::with_capacity(semaphores.len());
+        for semaphore in semaphores {
+            let semaphore = &*(Arc::as_ptr(semaphore) as *const Self);
+            if !Arc::ptr_eq(&semaphore.device, &self.device) {
+                return Err(InvalidOperationError::with_str(
+                    "Timeline semaphore is from other device.",
+                )
+                .into());
+            }
+            inner.push(semaphore.inner);
+        }
+
+        Ok(self.wait_inner(&inner, values, wait_all, timeout)?)
+    }
+}
@@ -0,0 +1,770 @@
+use std::{
+    collections::VecDeque,
+    ptr,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use ash::vk;
+
+use crate::errors::invalid_operation::InvalidOperationError;
+
+use super::{
+    buffers::buffer::VulkanBuffer,
+    device::{VulkanDevice, VulkanDeviceInitialized, VulkanQueueFamily},
+    device_support::VulkanDeviceSupport,
+    errors::universal::VulkanUniversalError,
+    fence::VulkanFence,
+    image::VulkanImage,
+    semaphore::VulkanSemaphore,
+};
+
+/// Alignment of data in the staging buffer, which satisfies offset requirements of copies to
+/// buffers and to images of all color formats.
+const STAGING_ALIGNMENT: u64 = 16;
+
+/// Region of the image written by single upload.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct VulkanUploadImageRegion {
+    pub image_subresource: vk::ImageSubresourceLayers,
+    pub image_offset: vk::Offset3D,
+    pub image_extent: vk::Extent3D,
+}
+
+/// Uploads data to device local buffers and images through ring staging buffer. Copies are
+/// batched and executed on the dedicated transfer family when the device has one. Exclusive images
+/// are then released by the graphics family before the copies, and acquired back after them.
+pub struct VulkanUploadManager<'init: 'ma, 'ma> {
+    device: Arc<VulkanDevice<'init>>,
+    staging: VulkanBuffer<'init, 'ma>,
+    capacity: u64,
+    transfer_family: u32,
+    destination_family: u32,
+    transfer_pool: vk::CommandPool,
+    destination_pool: Option<vk::CommandPool>,
+    state: Mutex<UploadState<'init, 'ma>>,
+}
+
+struct UploadState<'init: 'ma, 'ma> {
+    ring: StagingRing,
+    buffer_copies: Vec<(Arc<VulkanBuffer<'init, 'ma>>, vk::BufferCopy)>,
+    image_copies: Vec<(Arc<VulkanImage<'init, 'ma>>, vk::BufferImageCopy)>,
+    in_flight: VecDeque<UploadBatch<'init, 'ma>>,
+    free_command_buffers: Vec<UploadCommandBuffers>,
+}
+
+/// Command buffer of the transfer family, and command buffers of the graphics family which release
+/// and acquire exclusive images when families differ.
+type UploadCommandBuffers = (vk::CommandBuffer, Option<[vk::CommandBuffer; 2]>);
+
+struct UploadBatch<'init: 'ma, 'ma> {
+    fence: Arc<VulkanFence<'init>>,
+    command_buffers: UploadCommandBuffers,
+    _semaphores: Vec<VulkanSemaphore<'init>>,
+    _buffers: Vec<Arc<VulkanBuffer<'init, 'ma>>>,
+    _images: Vec<Arc<VulkanImage<'init, 'ma>>>,
+    end: u64,
+    lap: u64,
+}
+
+/// Ring allocator of the staging buffer. Data between `tail` and `head` is used by pending or
+/// executing batches, and laps distinguish full ring from empty one.
+#[derive(Default)]
+struct StagingRing {
+    head: u64,
+    head_lap: u64,
+    tail: u64,
+    tail_lap: u64,
+}
+
+impl StagingRing {
+    fn try_allocate(&mut self, size: u64, capacity: u64) -> Option<u64> {
+        let start = (self.head + STAGING_ALIGNMENT - 1) & !(STAGING_ALIGNMENT - 1);
+
+        if self.head_lap == self.tail_lap {
+            if start + size <= capacity {
+                self.head = start + size;
+                return Some(start);
+            }
+            if size <= self.tail {
+                self.head = size;
+                self.head_lap += 1;
+                return Some(0);
+            }
+        } else if start + size <= self.tail {
+            self.head = start + size;
+            return Some(start);
+        }
+
+        None
+    }
+}
+
+impl<'init: 'ma, 'ma> VulkanUploadManager<'init, 'ma> {
+    pub fn new<'dev: 'init>(
+        device: &'dev Arc<VulkanDevice<'init>>,
+        capacity: u64,
+    ) -> Result<Self, VulkanUniversalError> {
+        if capacity == 0 {
+            return Err(InvalidOperationError::with_str(
+                "Capacity of the staging buffer cannot be zero.",
+            )
+            .into());
+        }
+
+        let initialized = device.initialized()?;
+
+        // Families are sorted by count of supported operations, so dedicated transfer family is
+        // returned first.
+        let transfer_family = initialized
+            .get_family(VulkanDeviceSupport {
+                graphics: false,
+                computing: false,
+                transfer: true,
+                dedicated: false,
+            })?
+            .index();
+        let destination_family = match initialized.get_family(VulkanDeviceSupport {
+            graphics: true,
+            computing: false,
+            transfer: false,
+            dedicated: false,
+        }) {
+            Ok(family) => family.index(),
+            Err(_) => transfer_family,
+        };
+
+        let staging =
+            VulkanBuffer::new(device, vk::BufferUsageFlags::TRANSFER_SRC, capacity, true)?;
+
+        let transfer_pool = Self::create_command_pool(initialized, transfer_family)?;
+        let destination_pool = match destination_family == transfer_family {
+            true => None,
+            false => match Self::create_command_pool(initialized, destination_family) {
+                Ok(pool) => Some(pool),
+                Err(err) => {
+                    unsafe {
+                        initialized
+                            .vulkan_device()
+                            .destroy_command_pool(transfer_pool, None)
+                    };
+                    return Err(err);
+                }
+            },
+        };
+
+        Ok(Self {
+            device: device.clone(),
+            staging,
+            capacity,
+            transfer_family,
+            destination_family,
+            transfer_pool,
+            destination_pool,
+            state: Mutex::new(UploadState {
+                ring: StagingRing::default(),
+                buffer_copies: Vec::new(),
+                image_copies: Vec::new(),
+                in_flight: VecDeque::new(),
+                free_command_buffers: Vec::new(),
+            }),
+        })
+    }
+
+    /// Returns index of the queue family on which copies are executed.
+    pub fn transfer_family(&self) -> u32 {
+        self.transfer_family
+    }
+
+    /// Writes `data` to the staging buffer, and queues its copy to `buffer` at `offset`.
+    pub(crate) fn upload_buffer(
+        &self,
+        buffer: &Arc<VulkanBuffer<'init, 'ma>>,
+        offset: u64,
+        data: &[u8],
+    ) -> Result<(), VulkanUniversalError> {
+        let description = buffer.description();
+        if !description
+            .usage
+            .contains(vk::BufferUsageFlags::TRANSFER_DST)
+        {
+            return Err(InvalidOperationError::with_str(
+                "Buffer without transfer destination usage cannot be uploaded.",
+            )
+            .into());
+        }
+        if offset
+            .checked_add(data.len() as u64)
+            .is_none_or(|end| end > description.size)
+        {
+            return Err(InvalidOperationError::new(format!(
+                "Upload of {} bytes at offset {} exceeds buffer size, which is {} bytes.",
+                data.len(),
+                offset,
+                description.size
+            ))
+            .into());
+        }
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        let mut state = self.lock_state();
+        let src_offset = self.allocate(&mut state, data)?;
+
+        state.buffer_copies.push((
+            buffer.clone(),
+            vk::BufferCopy {
+                src_offset,
+                dst_offset: offset,
+                size: data.len() as u64,
+            },
+        ));
+        Ok(())
+    }
+
+    /// Writes tightly packed `data` to the staging buffer, and queues its copy to `region` of
+    /// `image`.
+    pub fn upload_image(
+        &self,
+        image: &Arc<VulkanImage<'init, 'ma>>,
+        region: &VulkanUploadImageRegion,
+        data: &[u8],
+    ) -> Result<(), VulkanUniversalError> {
+        if matches!(
+            image.layout(),
+            vk::ImageLayout::UNDEFINED | vk::ImageLayout::PREINITIALIZED
+        ) {
+            return Err(InvalidOperationError::with_str(
+                "Image with undefined or preinitialized layout cannot be uploaded.",
+            )
+            .into());
+        }
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        let mut state = self.lock_state();
+        let buffer_offset = self.allocate(&mut state, data)?;
+
+        state.image_copies.push((
+            image.clone(),
+            vk::BufferImageCopy {
+                buffer_offset,
+                buffer_row_length: 0,
+                buffer_image_height: 0,
+                image_subresource: region.image_subresource,
+                image_offset: region.image_offset,
+                image_extent: region.image_extent,
+            },
+        ));
+        Ok(())
+    }
+
+    /// Submits queued copies. Returned fence is signaled when uploaded data can be used by the
+    /// graphics family.
+    pub fn flush(&self) -> Result<Arc<VulkanFence<'init>>, VulkanUniversalError> {
+        let mut state = self.lock_state();
+        self.retire(&mut state, false)?;
+
+        if state.buffer_copies.is_empty() && state.image_copies.is_empty() {
+            if let Some(batch) = state.in_flight.back() {
+                return Ok(batch.fence.clone());
+            }
+
+            let initialized = self.device.initialized()?;
+            let create_info = vk::FenceCreateInfo {
+                s_type: vk::StructureType::FENCE_CREATE_INFO,
+                p_next: ptr::null(),
+                flags: vk::FenceCreateFlags::SIGNALED,
+            };
+            let fence = unsafe { initialized.vulkan_device().create_fence(&create_info, None) }?;
+            return Ok(Arc::new(VulkanFence::new(&self.device, fence)));
+        }
+
+        self.submit(&mut state)
+    }
+
+    /// Waits for execution of all submitted copies.
+    pub fn wait_idle(&self) -> Result<(), VulkanUniversalError> {
+        let mut state = self.lock_state();
+        self.retire(&mut state, true)
+    }
+
+    fn lock_state(&self) -> MutexGuard<'_, UploadState<'init, 'ma>> {
+        self.state.lock().unwrap()
+    }
+
+    fn create_command_pool(
+        initialized: &VulkanDeviceInitialized,
+        queue_family_index: u32,
+    ) -> Result<vk::CommandPool, VulkanUniversalError> {
+        let pool_info = vk::CommandPoolCreateInfo {
+            s_type: vk::StructureType::COMMAND_POOL_CREATE_INFO,
+            p_next: ptr::null(),
+            flags: vk::CommandPoolCreateFlags::RESET_COMMAND_BUFFER
+                | vk::CommandPoolCreateFlags::TRANSIENT,
+            queue_family_index,
+        };
+
+        Ok(unsafe {
+            initialized
+                .vulkan_device()
+                .create_command_pool(&pool_info, None)
+        }?)
+    }
+
+    fn get_family<'a>(
+        initialized: &'a VulkanDeviceInitialized<'init>,
+        index: u32,
+    ) -> &'a VulkanQueueFamily<'init> {
+        initialized
+            .get_families()
+            .iter()
+            .find(|family| family.index() == index)
+            .unwrap()
+    }
+
+    /// Returns offset of `data` written to the staging buffer. When the ring is full, queued
+    /// copies are submitted and the oldest batches are waited.
+    fn allocate(
+        &self,
+        state: &mut UploadState<'init, 'ma>,
+        data: &[u8],
+    ) -> Result<u64, VulkanUniversalError> {
+        let size = data.len() as u64;
+        if size > self.capacity {
+            return Err(InvalidOperationError::new(format!(
+                "Upload of {} bytes exceeds capacity of the staging buffer, which is {} bytes.",
+                size, self.capacity
+            ))
+            .into());
+        }
+
+        self.retire(state, false)?;
+
+        let offset = loop {
+            if let Some(offset) = state.ring.try_allocate(size, self.capacity) {
+                break offset;
+            }
+
+            if !state.buffer_copies.is_empty() || !state.image_copies.is_empty() {
+                self.submit(state)?;
+            }
+
+            match state.in_flight.front() {
+                Some(batch) => {
+                    batch.fence.wait(u64::MAX)?;
+                    self.retire(state, false)?;
+                }
+                None => state.ring = StagingRing::default(),
+            }
+        };
+
+        self.staging.memory().write(data, offset)?;
+        Ok(offset)
+    }
+
+    /// Removes finished batches and releases their part of the ring. When `wait` is `true`, all
+    /// batches are waited.
+    fn retire(
+        &self,
+        state: &mut UploadState<'init, 'ma>,
+        wait: bool,
+    ) -> Result<(), VulkanUniversalError> {
+        while let Some(batch) = state.in_flight.front() {
+            if wait {
+                batch.fence.wait(u64::MAX)?;
+            } else if !batch.fence.is_signaled()? {
+                break;
+            }
+
+            let batch = state.in_flight.pop_front().unwrap();
+            state.ring.tail = batch.end;
+            state.ring.tail_lap = batch.lap;
+            state.free_command_buffers.push(batch.command_buffers);
+        }
+
+        if state.in_flight.is_empty()
+            && state.buffer_copies.is_empty()
+            && state.image_copies.is_empty()
+        {
+            state.ring = StagingRing::default();
+        }
+
+        Ok(())
+    }
+
+    fn get_command_buffers(
+        &self,
+        state: &mut UploadState<'init, 'ma>,
+        vulkan_device: &ash::Device,
+    ) -> Result<UploadCommandBuffers, VulkanUniversalError> {
+        if let Some(command_buffers) = state.free_command_buffers.pop() {
+            return Ok(command_buffers);
+        }
+
+        let allocate = |pool, count| -> Result<Vec<vk::CommandBuffer>, VulkanUniversalError> {
+            let allocate_info = vk::CommandBufferAllocateInfo {
+                s_type: vk::StructureType::COMMAND_BUFFER_ALLOCATE_INFO,
+                p_next: ptr::null(),
+                command_pool: pool,
+                level: vk::CommandBufferLevel::PRIMARY,
+                command_buffer_count: count,
+            };
+            Ok(unsafe { vulkan_device.allocate_command_buffers(&allocate_info) }?)
+        };
+
+        let transfer = allocate(self.transfer_pool, 1)?[0];
+        let destination = match self.destination_pool {
+            Some(pool) => match allocate(pool, 2) {
+                Ok(command_buffers) => Some([command_buffers[0], command_buffers[1]]),
+                Err(err) => {
+                    unsafe { vulkan_device.free_command_buffers(self.transfer_pool, &[transfer]) };
+                    return Err(err);
+                }
+            },
+            None => None,
+        };
+
+        Ok((transfer, destination))
+    }
+
+    fn submit(
+        &self,
+        state: &mut UploadState<'init, 'ma>,
+    ) -> Result<Arc<VulkanFence<'init>>, VulkanUniversalError> {
+        self.device.ensure_not_lost()?;
+        let initialized = self.device.initialized()?;
+        let vulkan_device = initialized.vulkan_device();
+
+        let command_buffers = self.get_command_buffers(state, vulkan_device)?;
+        match self.submit_worker(state, initialized, command_buffers) {
+            Ok(fence) => Ok(fence),
+            Err(err) => {
+                state.free_command_buffers.push(command_buffers);
+                Err(err)
+            }
+        }
+    }
+
+    fn submit_worker(
+        &self,
+        state: &mut UploadState<'init, 'ma>,
+        initialized: &VulkanDeviceInitialized<'init>,
+        command_buffers: UploadCommandBuffers,
+    ) -> Result<Arc<VulkanFence<'init>>, VulkanUniversalError> {
+        let vulkan_device = initialized.vulkan_device();
+        let (transfer_command_buffer, destination_command_buffers) = command_buffers;
+
+        let begin_info = vk::CommandBufferBeginInfo {
+            s_type: vk::StructureType::COMMAND_BUFFER_BEGIN_INFO,
+            p_next: ptr::null(),
+            flags: vk::CommandBufferUsageFlags::ONE_TIME_SUBMIT,
+            p_inheritance_info: ptr::null(),
+        };
+
+        let mut graphics_release_barriers = Vec::new();
+        let mut copy_barriers = Vec::with_capacity(state.image_copies.len());
+        let mut release_barriers = Vec::with_capacity(state.image_copies.len());
+        let mut graphics_acquire_barriers = Vec::new();
+
+        for (image, copy) in &state.image_copies {
+            let layout = image.layout();
+            let copy_layout = Self::copy_layout(layout);
+            let subresource_range = vk::ImageSubresourceRange {
+                aspect_mask: copy.image_subresource.aspect_mask,
+                base_mip_level: copy.image_subresource.mip_level,
+                level_count: 1,
+                base_array_layer: copy.image_subresource.base_array_layer,
+                layer_count: copy.image_subresource.layer_count,
+            };
+
+            // Exclusive images are owned by the graphics family, which releases them to the
+            // transfer family before the copy and acquires them back after it. Each release and
+            // acquire pair does the same layout transition.
+            let ownership_transfer =
+                destination_command_buffers.is_some() && !image.create_info().concurrent;
+            let (to_transfer, to_graphics) = match ownership_transfer {
+                true => (
+                    (self.destination_family, self.transfer_family),
+                    (self.transfer_family, self.destination_family),
+                ),
+                false => (
+                    (vk::QUEUE_FAMILY_IGNORED, vk::QUEUE_FAMILY_IGNORED),
+                    (vk::QUEUE_FAMILY_IGNORED, vk::QUEUE_FAMILY_IGNORED),
+                ),
+            };
+
+            let copy_barrier = vk::ImageMemoryBarrier {
+                s_type: vk::StructureType::IMAGE_MEMORY_BARRIER,
+                p_next: ptr::null(),
+                src_access_mask: vk::AccessFlags::empty(),
+                dst_access_mask: vk::AccessFlags::TRANSFER_WRITE,
+                old_layout: layout,
+                new_layout: copy_layout,
+                src_queue_family_index: to_transfer.0,
+                dst_queue_family_index: to_transfer.1,
+                image: image.inner(),
+                subresource_range,
+            };
+            copy_barriers.push(copy_barrier);
+
+            let barrier = vk::ImageMemoryBarrier {
+                src_access_mask: vk::AccessFlags::TRANSFER_WRITE,
+                dst_access_mask: match ownership_transfer {
+                    true => vk::AccessFlags::empty(),
+                    false => vk::AccessFlags::MEMORY_READ | vk::AccessFlags::MEMORY_WRITE,
+                },
+                old_layout: copy_layout,
+                new_layout: layout,
+                src_queue_family_index: to_graphics.0,
+                dst_queue_family_index: to_graphics.1,
+                ..copy_barrier
+            };
+            release_barriers.push(barrier);
+
+            if ownership_transfer {
+                graphics_release_barriers.push(vk::ImageMemoryBarrier {
+                    src_access_mask: vk::AccessFlags::MEMORY_WRITE,
+                    dst_access_mask: vk::AccessFlags::empty(),
+                    ..copy_barrier
+                });
+                graphics_acquire_barriers.push(vk::ImageMemoryBarrier {
+                    src_access_mask: vk::AccessFlags::empty(),
+                    dst_access_mask: vk::AccessFlags::MEMORY_READ | vk::AccessFlags::MEMORY_WRITE,
+                    ..barrier
+                });
+            }
+        }
+
+        unsafe {
+            if let (Some([command_buffer, _]), false) = (
+                destination_command_buffers,
+                graphics_release_barriers.is_empty(),
+            ) {
+                vulkan_device.begin_command_buffer(command_buffer, &begin_info)?;
+                vulkan_device.cmd_pipeline_barrier(
+                    command_buffer,
+                    vk::PipelineStageFlags::ALL_COMMANDS,
+                    vk::PipelineStageFlags::BOTTOM_OF_PIPE,
+                    vk::DependencyFlags::empty(),
+                    &[],
+                    &[],
+                    &graphics_release_barriers,
+                );
+                vulkan_device.end_command_buffer(command_buffer)?;
+            }
+
+            vulkan_device.begin_command_buffer(transfer_command_buffer, &begin_info)?;
+
+            if !copy_barriers.is_empty() {
+                vulkan_device.cmd_pipeline_barrier(
+                    transfer_command_buffer,
+                    vk::PipelineStageFlags::ALL_COMMANDS,
+                    vk::PipelineStageFlags::TRANSFER,
+                    vk::DependencyFlags::empty(),
+                    &[],
+                    &[],
+                    &copy_barriers,
+                );
+            }
+
+            for (buffer, copy) in &state.buffer_copies {
+                vulkan_device.cmd_copy_buffer(
+                    transfer_command_buffer,
+                    self.staging.inner(),
+                    buffer.inner(),
+                    &[*copy],
+                );
+            }
+            for (image, copy) in &state.image_copies {
+                vulkan_device.cmd_copy_buffer_to_image(
+                    transfer_command_buffer,
+                    self.staging.inner(),
+                    image.inner(),
+                    Self::copy_layout(image.layout()),
+                    &[*copy],
+                );
+            }
+
+            let memory_barrier = vk::MemoryBarrier {
+                s_type: vk::StructureType::MEMORY_BARRIER,
+                p_next: ptr::null(),
+                src_access_mask: vk::AccessFlags::TRANSFER_WRITE,
+                dst_access_mask: vk::AccessFlags::MEMORY_READ | vk::AccessFlags::MEMORY_WRITE,
+            };
+            vulkan_device.cmd_pipeline_barrier(
+                transfer_command_buffer,
+                vk::PipelineStageFlags::TRANSFER,
+                vk::PipelineStageFlags::ALL_COMMANDS,
+                vk::DependencyFlags::empty(),
+                &[memory_barrier],
+                &[],
+                &release_barriers,
+            );
+
+            vulkan_device.end_command_buffer(transfer_command_buffer)?;
+
+            if let Some([_, command_buffer]) = destination_command_buffers {
+                vulkan_device.begin_command_buffer(command_buffer, &begin_info)?;
+                vulkan_device.cmd_pipeline_barrier(
+                    command_buffer,
+                    vk::PipelineStageFlags::ALL_COMMANDS,
+                    vk::PipelineStageFlags::ALL_COMMANDS,
+                    vk::DependencyFlags::empty(),
+                    &[vk::MemoryBarrier {
+                        src_access_mask: vk::AccessFlags::empty(),
+                        ..memory_barrier
+                    }],
+                    &[],
+                    &graphics_acquire_barriers,
+                );
+                vulkan_device.end_command_buffer(command_buffer)?;
+            }
+        }
+
+        let fence = Arc::new(initialized.pool().get_fence(&self.device)?);
+        let released = match (
+            destination_command_buffers,
+            graphics_release_barriers.is_empty(),
+        ) {
+            (Some(_), false) => Some(initialized.pool().get_semaphore(&self.device)?),
+            _ => None,
+        };
+        let copied = match destination_command_buffers {
+            Some(_) => Some(initialized.pool().get_semaphore(&self.device)?),
+            None => None,
+        };
+        let released_inner = released.as_ref().map(|semaphore| semaphore.inner());
+        let copied_inner = copied.as_ref().map(|semaphore| semaphore.inner());
+
+        let queue = |family| Self::get_family(initialized, family).get_queue().queue;
+        let wait_stage = vk::PipelineStageFlags::ALL_COMMANDS;
+
+        if let (Some([command_buffer, _]), Some(released)) =
+            (destination_command_buffers, &released_inner)
+        {
+            let release_submit_info = vk::SubmitInfo {
+                s_type: vk::StructureType::SUBMIT_INFO,
+                p_next: ptr::null(),
+                wait_semaphore_count: 0,
+                p_wait_semaphores: ptr::null(),
+                p_wait_dst_stage_mask: ptr::null(),
+                command_buffer_count: 1,
+                p_command_buffers: &command_buffer,
+                signal_semaphore_count: 1,
+                p_signal_semaphores: released,
+            };
+
+            self.device.check_lost(unsafe {
+                vulkan_device.queue_submit(
+                    queue(self.destination_family),
+                    &[release_submit_info],
+                    vk::Fence::null(),
+                )
+            })?;
+        }
+
+        let transfer_submit_info = vk::SubmitInfo {
+            s_type: vk::StructureType::SUBMIT_INFO,
+            p_next: ptr::null(),
+            wait_semaphore_count: released_inner.is_some() as u32,
+            p_wait_semaphores: match &released_inner {
+                Some(semaphore) => semaphore,
+                None => ptr::null(),
+            },
+            p_wait_dst_stage_mask: &wait_stage,
+            command_buffer_count: 1,
+            p_command_buffers: &transfer_command_buffer,
+            signal_semaphore_count: copied_inner.is_some() as u32,
+            p_signal_semaphores: match &copied_inner {
+                Some(semaphore) => semaphore,
+                None => ptr::null(),
+            },
+        };
+
+        self.device.check_lost(unsafe {
+            vulkan_device.queue_submit(
+                queue(self.transfer_family),
+                &[transfer_submit_info],
+                match destination_command_buffers {
+                    Some(_) => vk::Fence::null(),
+                    None => fence.inner(),
+                },
+            )
+        })?;
+
+        if let (Some([_, command_buffer]), Some(copied)) =
+            (destination_command_buffers, &copied_inner)
+        {
+            let acquire_submit_info = vk::SubmitInfo {
+                s_type: vk::StructureType::SUBMIT_INFO,
+                p_next: ptr::null(),
+                wait_semaphore_count: 1,
+                p_wait_semaphores: copied,
+                p_wait_dst_stage_mask: &wait_stage,
+                command_buffer_count: 1,
+                p_command_buffers: &command_buffer,
+                signal_semaphore_count: 0,
+                p_signal_semaphores: ptr::null(),
+            };
+
+            self.device.check_lost(unsafe {
+                vulkan_device.queue_submit(
+                    queue(self.destination_family),
+                    &[acquire_submit_info],
+                    fence.inner(),
+                )
+            })?;
+        }
+
+        state.in_flight.push_back(UploadBatch {
+            fence: fence.clone(),
+            command_buffers,
+            _semaphores: released.into_iter().chain(copied).collect(),
+            _buffers: state
+                .buffer_copies
+                .drain(..)
+                .map(|(buffer, _)| buffer)
+                .collect(),
+            _images: state
+                .image_copies
+                .drain(..)
+                .map(|(image, _)| image)
+                .collect(),
+            end: state.ring.head,
+            lap: state.ring.head_lap,
+        });
+
+        Ok(fence)
+    }
+
+    fn copy_layout(layout: vk::ImageLayout) -> vk::ImageLayout {
+        match layout {
+            vk::ImageLayout::GENERAL => vk::ImageLayout::GENERAL,
+            _ => vk::ImageLayout::TRANSFER_DST_OPTIMAL,
+        }
+    }
+}
+
+impl Drop for VulkanUploadManager<'_, '_> {
+    fn drop(&mut self) {
+        let mut state = self.lock_state();
+        for batch in &state.in_flight {
+            let _ = batch.fence.wait(u64::MAX);
+        }
+        state.in_flight.clear();
+        drop(state);
+
+        let initialized = self.device.initialized().unwrap();
+        unsafe {
+            initialized
+                .vulkan_device()
+                .destroy_command_pool(self.transfer_pool, None);
+            if let Some(pool) = self.destination_pool {
+                initialized.vulkan_device().destroy_command_pool(pool, None);
+            }
+        }
+    }
+}
@@ -1,13 +1,24 @@
 use std::{mem, ptr, slice};
 
+use crate::errors::serialization::SerializationError;
+
 pub struct SerializationReader<'a> {
     pub index: usize,
     pub data: &'a [u8],
+    pub checked: bool,
 }
 
 impl<'a> SerializationReader<'a> {
     pub fn new(data: &'a [u8]) -> SerializationReader {
-        SerializationReader { index: 0, data }
+        SerializationReader {
+            index: 0,
+            data,
+            checked: false,
+        }
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.data.len().saturating_sub(self.index)
     }
 
     pub fn read<T>(&mut self) -> Option<T> {
@@ -41,4 +52,83 @@ impl<'a> SerializationReader<'a> {
 
         unsafe { slice::from_raw_parts(ptr, length) }
     }
+
+    /// Reads value with bounds check when this reader is checked, otherwise same as `read_unchecked`.
+    pub fn try_read<T>(&mut self) -> Result<T, SerializationError> {
+        if self.checked {
+            self.assert_remaining(mem::size_of::<T>())?;
+        }
+        Ok(self.read_unchecked())
+    }
+
+    /// Reads bytes with bounds check when this reader is checked, otherwise same as
+    /// `read_bytes_unchecked`.
+    pub fn try_read_bytes(&mut self, length: usize) -> Result<&[u8], SerializationError> {
+        if self.checked {
+            self.assert_remaining(length)?;
+        }
+        Ok(self.read_bytes_unchecked(length))
+    }
+
+    /// Reads pointer written by the managed side. When this reader is checked, pointer must be
+    /// non-null and aligned.
+    pub fn try_read_ref<'b, T>(&mut self) -> Result<&'b T, SerializationError> {
+        let offset = self.index;
+        let pointer = self.try_read::<*const T>()?;
+
+        if self.checked {
+            if pointer.is_null() {
+                return Err(SerializationError::with_str(
+                    "Reference cannot be null.",
+                    offset,
+                ));
+            }
+            if pointer as usize & (mem::align_of::<T>() - 1) != 0 {
+                return Err(SerializationError::with_str(
+                    "Reference is not aligned.",
+                    offset,
+                ));
+            }
+        }
+
+        Ok(unsafe { &*pointer })
+    }
+
+    /// Reads i32 count of elements. Count cannot be negative, and when this reader is checked,
+    /// it cannot exceed remaining elements of `element_size`.
+    pub fn try_read_count(&mut self, element_size: usize) -> Result<usize, SerializationError> {
+        let offset = self.index;
+        let count = self.try_read::<i32>()?;
+
+        if count < 0 {
+            return Err(SerializationError::new(
+                format!("Count {} cannot be negative.", count),
+                offset,
+            ));
+        }
+
+        let count = count as usize;
+        if self.checked && count.saturating_mul(element_size) > self.remaining() {
+            return Err(SerializationError::new(
+                format!("Count {} exceeds remaining data.", count),
+                offset,
+            ));
+        }
+
+        Ok(count)
+    }
+
+    fn assert_remaining(&self, length: usize) -> Result<(), SerializationError> {
+        match length > self.remaining() {
+            true => Err(SerializationError::new(
+                format!(
+                    "Unexpected end of data, {} bytes expected but {} remaining.",
+                    length,
+                    self.remaining()
+                ),
+                self.index,
+            )),
+            false => Ok(()),
+        }
+    }
 }
@@ -0,0 +1,43 @@
+use std::path::Path;
+
+use noise_engine_native::{
+    errors::invalid_operation::InvalidOperationError,
+    interop::prelude::{InteropResult, InteropSpan, InteropString},
+    rendering::vulkan::capture::replay,
+};
+
+/// Replays capture and copies RGBA texels of its first written attachment into `texels`.
+#[no_mangle]
+extern "C" fn rendering_vulkan_capture_test_replay(
+    capture_path: InteropString,
+    output_directory: InteropString,
+    texels: InteropSpan<u8>,
+) -> InteropResult<()> {
+    let paths = match replay::run(
+        Path::new(<&str>::from(&capture_path)),
+        Path::new(<&str>::from(&output_directory)),
+        None,
+    ) {
+        Ok(paths) => paths,
+        Err(err) => return InteropResult::with_err(err.into()),
+    };
+
+    let image = match paths.first().map(image::open) {
+        Some(Ok(image)) => image.into_rgba8(),
+        _ => {
+            return InteropResult::with_err(
+                InvalidOperationError::with_str("Replay did not write readable attachment.").into(),
+            )
+        }
+    };
+
+    let texels: &mut [u8] = texels.into();
+    if image.as_raw().len() != texels.len() {
+        return InteropResult::with_err(
+            InvalidOperationError::with_str("Attachment has different size.").into(),
+        );
+    }
+
+    texels.copy_from_slice(image.as_raw());
+    InteropResult::with_ok(())
+}
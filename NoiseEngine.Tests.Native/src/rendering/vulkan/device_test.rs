@@ -1,5 +1,7 @@
 use std::sync::Arc;
 
+use ash::vk;
+
 use noise_engine_native::{
     interop::prelude::InteropResult,
     rendering::vulkan::{device::VulkanDevice, device_support::VulkanDeviceSupport},
@@ -13,8 +15,17 @@ extern "C" fn rendering_vulkan_device_test_get_queue<'dev: 'init, 'init>(
         graphics: false,
         computing: false,
         transfer: false,
+        dedicated: false,
     }) {
         Ok(_) => InteropResult::with_ok(()),
         Err(err) => InteropResult::with_err(err.into()),
     }
 }
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_device_test_mark_lost(device: &Arc<VulkanDevice>) -> bool {
+    device
+        .check_lost::<()>(Err(vk::Result::ERROR_DEVICE_LOST))
+        .is_err()
+        && device.is_lost()
+}
@@ -0,0 +1,16 @@
+use ash::vk;
+
+use noise_engine_native::{
+    interop::prelude::InteropResult,
+    rendering::vulkan::graphics_pipeline_create_info::GraphicsPipelineCreateInfo,
+};
+
+#[no_mangle]
+extern "C" fn rendering_vulkan_graphics_pipeline_create_info_test_validate_without_features(
+    create_info: GraphicsPipelineCreateInfo,
+) -> InteropResult<()> {
+    match create_info.validate(&vk::PhysicalDeviceFeatures::default(), 1) {
+        Ok(()) => InteropResult::with_ok(()),
+        Err(err) => InteropResult::with_err(err.into()),
+    }
+}
@@ -1 +1,3 @@
+mod capture_test;
 mod device_test;
+mod graphics_pipeline_create_info_test;
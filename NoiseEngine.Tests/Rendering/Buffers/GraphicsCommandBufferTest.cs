@@ -1,12 +1,22 @@
-﻿using NoiseEngine.Mathematics;
+﻿using NoiseEngine.Components;
+using NoiseEngine.Interop;
+using NoiseEngine.Interop.InteropMarshalling;
+using NoiseEngine.Interop.Rendering.Buffers;
+using NoiseEngine.Mathematics;
 using NoiseEngine.Nesl.Default;
 using NoiseEngine.Nesl.Emit;
 using NoiseEngine.Nesl.Emit.Attributes;
+using NoiseEngine.Primitives;
 using NoiseEngine.Rendering;
 using NoiseEngine.Rendering.Buffers;
+using NoiseEngine.Rendering.Buffers.CommandBuffers;
+using NoiseEngine.Serialization;
 using NoiseEngine.Tests.Environments;
 using NoiseEngine.Tests.Fixtures;
 using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
 
 namespace NoiseEngine.Tests.Rendering.Buffers;
 
@@ -63,6 +73,71 @@ public class GraphicsCommandBufferTest : GraphicsTestEnvironment {
         }
     }
 
+    [FactRequire(TestRequirements.Graphics)]
+    public void ExecuteReRecorded() {
+        int i = 0;
+        foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
+            GraphicsHostBuffer<int> hostBufferC = new GraphicsHostBuffer<int>(
+                device, GraphicsBufferUsage.TransferAll, Size
+            );
+
+            int[] data = GetRandomData();
+            hostBufferA[i].SetData(data);
+
+            commandBuffer[i].Copy(hostBufferA[i], hostBufferB[i], Size);
+            commandBuffer[i].Execute().Wait();
+
+            hostBufferB[i].GetData(readInt);
+            Assert.Equal(data, readInt);
+
+            // Adding new command records the same native command buffer again.
+            data = GetRandomData();
+            hostBufferA[i].SetData(data);
+
+            commandBuffer[i].Copy(hostBufferB[i], hostBufferC, Size);
+            commandBuffer[i].Execute();
+            commandBuffer[i].Clear();
+
+            hostBufferC.GetData(readInt);
+            Assert.Equal(data, readInt);
+
+            i++;
+        }
+    }
+
+    [FactRequire(TestRequirements.Graphics)]
+    public void ExecuteClearedReRecorded() {
+        int i = 0;
+        foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
+            GraphicsHostBuffer<int> hostBufferC = new GraphicsHostBuffer<int>(
+                device, GraphicsBufferUsage.TransferAll, Size
+            );
+
+            commandBuffer[i].Copy(hostBufferA[i], hostBufferB[i], Size);
+            commandBuffer[i].Execute();
+            commandBuffer[i].Clear();
+            InteropHandle<GraphicsCommandBuffer> handle = commandBuffer[i].Handle;
+
+            // Recording the same amount of different commands after clear records the same native handle again.
+            for (int j = 0; j < 4; j++) {
+                GraphicsHostBuffer<int> destination = j % 2 == 0 ? hostBufferC : hostBufferB[i];
+
+                int[] data = GetRandomData();
+                hostBufferA[i].SetData(data);
+
+                commandBuffer[i].Copy(hostBufferA[i], destination, Size);
+                commandBuffer[i].Execute();
+                commandBuffer[i].Clear();
+
+                Assert.Equal(handle, commandBuffer[i].Handle);
+                destination.GetData(readInt);
+                Assert.Equal(data, readInt);
+            }
+
+            i++;
+        }
+    }
+
     [FactRequire(TestRequirements.Graphics)]
     public void Dispatch() {
         const float Value = 18.64f;
@@ -107,6 +182,280 @@ public class GraphicsCommandBufferTest : GraphicsTestEnvironment {
         }
     }
 
+    [FactRequire(TestRequirements.Graphics)]
+    public void CopyBufferWithDebugNamesAndLabels() {
+        int i = 0;
+        foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
+            hostBufferA[i].DebugName = "Source";
+            hostBufferB[i].DebugName = "Destination";
+            commandBuffer[i].DebugName = nameof(CopyBufferWithDebugNamesAndLabels);
+
+            int[] data = GetRandomData();
+            hostBufferA[i].SetData(data);
+
+            commandBuffer[i].BeginLabel("Copy", Color.Green);
+            commandBuffer[i].InsertLabel("Before copy");
+            commandBuffer[i].Copy(hostBufferA[i], hostBufferB[i], Size);
+            commandBuffer[i].EndLabel();
+            Assert.Throws<InvalidOperationException>(commandBuffer[i].EndLabel);
+
+            Assert.Contains(
+                "BeginLabel color_r=0, color_g=1, color_b=0, color_a=1, name=\"Copy\"\n",
+                commandBuffer[i].Disassemble()
+            );
+
+            commandBuffer[i].Execute();
+            commandBuffer[i].Clear();
+            commandBuffer[i].DebugName = null;
+
+            hostBufferB[i].GetData(readInt);
+            Assert.Equal(data, readInt);
+
+            i++;
+        }
+    }
+
+    [FactRequire(TestRequirements.Graphics)]
+    public void ConstructInvalidCommandStream() {
+        bool checkedDecoding = GraphicsCommandBuffer.CheckedDecoding;
+        GraphicsCommandBuffer.CheckedDecoding = true;
+
+        try {
+            foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
+                GraphicsCommandBufferUsage usage = new GraphicsCommandBufferUsage(false, true, false, false);
+
+                // Unknown command.
+                ArgumentException exception = Assert.Throws<ArgumentException>(() => device.CreateCommandBuffer(
+                    new byte[] { 0xff, 0xff }, usage, false
+                ));
+                Assert.Contains("at byte offset 0 in command 0.", exception.Message);
+
+                // Unknown command after insert label command with empty name.
+                byte[] data = new byte[24];
+                data[0] = 17;
+                data[22] = 0xff;
+                data[23] = 0xff;
+                exception = Assert.Throws<ArgumentException>(() => device.CreateCommandBuffer(data, usage, false));
+                Assert.Contains("at byte offset 22 in command 1.", exception.Message);
+
+                // Dispatch command without group count.
+                exception = Assert.Throws<ArgumentException>(() => device.CreateCommandBuffer(
+                    new byte[] { 3, 0, 1, 0, 0, 0 }, usage, false
+                ));
+                Assert.Contains("at byte offset 6 in command 0.", exception.Message);
+
+                // Push constants without attached pipeline.
+                exception = Assert.Throws<ArgumentException>(() => device.CreateCommandBuffer(
+                    new byte[] { 0x12, 0x27, 0x20, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 1, 2, 3, 4 }, usage, false
+                ));
+                Assert.Contains("at byte offset 2 in command 0.", exception.Message);
+
+                // Next subpass without attached camera.
+                exception = Assert.Throws<ArgumentException>(() => device.CreateCommandBuffer(
+                    new byte[] { 9, 0, 0, 0, 0, 0 }, usage, false
+                ));
+                Assert.Contains("Next subpass requires attached camera", exception.Message);
+                Assert.Contains("at byte offset 2 in command 0.", exception.Message);
+
+                // Detach camera without attached camera.
+                exception = Assert.Throws<ArgumentException>(() => device.CreateCommandBuffer(
+                    new byte[] { 6, 0 }, usage, false
+                ));
+                Assert.Contains("at byte offset 2 in command 0.", exception.Message);
+            }
+        } finally {
+            GraphicsCommandBuffer.CheckedDecoding = checkedDecoding;
+        }
+    }
+
+    [FactRequire(TestRequirements.Graphics)]
+    public void DisassembleCopyBuffer() {
+        int i = 0;
+        foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
+            commandBuffer[i].Copy(hostBufferA[i], hostBufferB[i], Size);
+            string listing = commandBuffer[i].Disassemble();
+            commandBuffer[i].Clear();
+
+            Assert.StartsWith("0x0000 #0 CopyBuffer source=Buffer@", listing);
+            Assert.Contains(
+                $"region_count=1, regions[0]={{source_offset=0, destination_offset=0, size={Size * sizeof(int)}}}\n",
+                listing
+            );
+
+            i++;
+        }
+    }
+
+    [FactRequire(TestRequirements.Graphics)]
+    public void CaptureCopyBuffer() {
+        string directory = Path.Combine(Path.GetTempPath(), Guid.NewGuid().ToString());
+        Directory.CreateDirectory(directory);
+
+        string? captureDirectory = GraphicsCommandBuffer.CaptureDirectory;
+        GraphicsCommandBuffer.CaptureDirectory = directory;
+
+        try {
+            Assert.Equal(Path.GetFullPath(directory), GraphicsCommandBuffer.CaptureDirectory);
+
+            int i = 0;
+            foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
+                hostBufferA[i].SetData(GetRandomData());
+
+                commandBuffer[i].Copy(hostBufferA[i], hostBufferB[i], Size);
+                commandBuffer[i].Execute();
+                commandBuffer[i].Clear();
+
+                i++;
+            }
+        } finally {
+            GraphicsCommandBuffer.CaptureDirectory = captureDirectory;
+        }
+
+        Assert.True(Directory.GetFiles(directory, "*.necapture").Length >= Fixture.GraphicsDevices.Count);
+        Directory.Delete(directory, true);
+    }
+
+    [FactRequire(TestRequirements.Graphics)]
+    public unsafe void DisassembleEveryCommand() {
+        // Compute shader.
+        NeslAssemblyBuilder assembly = NeslAssemblyBuilder.DefineAssembly(nameof(DisassembleEveryCommand));
+
+        NeslTypeBuilder shaderType = assembly.DefineType("Shader");
+
+        NeslFieldBuilder buffer = shaderType.DefineField("buffer", BuiltInTypes.Float32);
+        buffer.AddAttribute(UniformAttribute.Create());
+
+        NeslMethodBuilder main = shaderType.DefineMethod("Main");
+        main.AddAttribute(KernelAttribute.Create(Vector3<uint>.One));
+        IlGenerator il = main.IlGenerator;
+
+        il.Emit(OpCode.LoadFloat32, 0u, 1f);
+        il.Emit(OpCode.Return);
+
+        // Window cameras and subpasses cannot be recorded headless, so only the disassembler decodes them.
+        CommandBufferCommand[] streamOnly = {
+            CommandBufferCommand.AttachCameraWindow, CommandBufferCommand.NextSubpass
+        };
+
+        bool checkedDecoding = GraphicsCommandBuffer.CheckedDecoding;
+        GraphicsCommandBuffer.CheckedDecoding = true;
+
+        try {
+            foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
+                GraphicsHostBuffer<float> uniform =
+                    new GraphicsHostBuffer<float>(device, GraphicsBufferUsage.TransferSource, 1);
+                GraphicsHostBuffer<int> source =
+                    new GraphicsHostBuffer<int>(device, GraphicsBufferUsage.TransferAll, 16 * 16);
+                GraphicsHostBuffer<int> destination =
+                    new GraphicsHostBuffer<int>(device, GraphicsBufferUsage.TransferAll, 16 * 16);
+                GraphicsHostBuffer<ulong> results =
+                    new GraphicsHostBuffer<ulong>(device, GraphicsBufferUsage.TransferAll, 2);
+
+                ComputeShader shader = new ComputeShader(device, shaderType);
+                ComputeMaterial material = new ComputeMaterial(shader);
+                material.GetProperty(buffer)!.SetBuffer(uniform);
+
+                Texture2D texture = new Texture2D(
+                    device, TextureUsage.TransferAll | TextureUsage.ColorAttachment, 16, 16,
+                    TextureFormat.R8G8B8A8_UNORM
+                );
+                SimpleCamera camera = new SimpleCamera(device) {
+                    RenderTarget = new RenderTexture(texture),
+                    ClearFlags = CameraClearFlags.SolidColor,
+                    ClearColor = Color.Green,
+                    DepthTesting = false
+                };
+
+                PrimitiveCreatorShared shared = PrimitiveCreatorShared.CreateOrGet(device);
+                Mesh mesh = new Mesh<VertexPosition3Color3, ushort>(
+                    device, new VertexPosition3Color3[3], new ushort[] { 0, 1, 2 }
+                );
+                Matrix4x4<pos> transform = new TransformComponent(new pos3(0, 0, 5)).Matrix;
+
+                GraphicsQueryPool timestamps = new GraphicsQueryPool(device, GraphicsQueryType.Timestamp, 1);
+                GraphicsQueryPool occlusion = new GraphicsQueryPool(device, GraphicsQueryType.Occlusion, 1);
+
+                GraphicsCommandBuffer secondary = new GraphicsCommandBuffer(device, false);
+                secondary.BeginSecondary(camera);
+                secondary.DrawMeshUnchecked(mesh, shared.DefaultMaterial, transform);
+
+                GraphicsCommandBuffer commandBuffer = new GraphicsCommandBuffer(device, false);
+                commandBuffer.BeginLabel("Every command", Color.Blue);
+                commandBuffer.InsertLabel("Queries");
+                commandBuffer.ResetQueries(timestamps, 0, 1);
+                commandBuffer.ResetQueries(occlusion, 0, 1);
+                commandBuffer.WriteTimestamp(timestamps, 0);
+
+                commandBuffer.Copy(source, destination, 16 * 16);
+                commandBuffer.CopyUnchecked(source, texture, stackalloc TextureBufferCopyRegion[] {
+                    new TextureBufferCopyRegion(0, Vector3<int>.Zero, texture.Extent, TextureAspect.Color, 0, 0, 1)
+                });
+                commandBuffer.Dispatch(shader.GetKernel(main)!, material, Vector3<uint>.One);
+
+                commandBuffer.BeginQuery(occlusion, 0);
+                commandBuffer.AttachCameraUnchecked(camera);
+                commandBuffer.DrawMeshUnchecked(mesh, shared.DefaultMaterial, transform);
+                commandBuffer.DetachCameraUnchecked();
+                commandBuffer.EndQuery(occlusion, 0);
+                commandBuffer.CopyQueryResults(occlusion, 0, 1, results, 0, true);
+
+                commandBuffer.AttachCameraUnchecked(camera, true);
+                commandBuffer.ExecuteSecondary(new GraphicsCommandBuffer[] { secondary });
+                commandBuffer.DetachCameraUnchecked();
+
+                commandBuffer.CopyUnchecked(texture, destination, stackalloc TextureBufferCopyRegion[] {
+                    new TextureBufferCopyRegion(0, Vector3<int>.Zero, texture.Extent, TextureAspect.Color, 0, 0, 1)
+                });
+                commandBuffer.EndLabel();
+
+                string listing = commandBuffer.Disassemble();
+                Assert.Contains(", clear_color=(0, 1, 0, 1), ", listing);
+
+                // Every command recorded by the managed side is decoded by the disassembler, and then by the
+                // recorder of checked command buffer.
+                HashSet<string> disassembled = listing.Split('\n', StringSplitOptions.RemoveEmptyEntries)
+                    .Select(line => line.Split(' ')[2]).ToHashSet();
+                Assert.Equal(
+                    Enum.GetValues<CommandBufferCommand>().Except(streamOnly).Select(x => x.ToString()).Order(),
+                    disassembled.Order()
+                );
+
+                commandBuffer.Execute().Wait();
+                commandBuffer.Clear();
+            }
+        } finally {
+            GraphicsCommandBuffer.CheckedDecoding = checkedDecoding;
+        }
+
+        // Commands from stream only.
+        float* clearColor = stackalloc float[] { 1, 0.5f, 0, 1 };
+
+        SerializationWriter writer = new SerializationWriter(BitConverter.IsLittleEndian);
+        writer.WriteCommand(CommandBufferCommand.AttachCameraWindow);
+        writer.WriteIntN(0x10);
+        writer.WriteIntN(0x20);
+        writer.WriteIntN((nint)clearColor);
+        writer.WriteFloat32(0);
+        writer.WriteUInt32(0);
+        writer.WriteUInt32(0);
+        writer.WriteInt32(0);
+        writer.WriteCommand(CommandBufferCommand.NextSubpass);
+        writer.WriteUInt32(0);
+
+        if (!GraphicsCommandBufferInterop.Disassemble(writer.AsSpan()).TryGetValue(
+            out InteropString result, out ResultError error
+        )) {
+            error.ThrowAndDispose();
+        }
+
+        string streamListing = result.ToString();
+        result.Dispose();
+
+        Assert.Contains(" AttachCameraWindow render_pass=RenderPass@0x10, swapchain=Swapchain@0x20, ", streamListing);
+        Assert.Contains(", clear_color=(1, 0.5, 0, 1), clear_depth=0, ", streamListing);
+        Assert.EndsWith(" #1 NextSubpass contents=INLINE (0)\n", streamListing);
+    }
+
     private int[] GetRandomData(ulong size = Size) {
         int[] data = new int[size];
         for (int i = 0; i < data.Length; i++)
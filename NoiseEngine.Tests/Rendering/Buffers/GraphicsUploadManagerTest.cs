@@ -0,0 +1,75 @@
+﻿using NoiseEngine.Rendering;
+using NoiseEngine.Rendering.Buffers;
+using NoiseEngine.Tests.Environments;
+using NoiseEngine.Tests.Fixtures;
+using System;
+using System.Linq;
+
+namespace NoiseEngine.Tests.Rendering.Buffers;
+
+public class GraphicsUploadManagerTest : GraphicsTestEnvironment {
+
+    private const ulong Size = 256;
+
+    public GraphicsUploadManagerTest(ApplicationFixture fixture) : base(fixture) {
+    }
+
+    [FactRequire(TestRequirements.Graphics)]
+    public void UploadBuffer() {
+        foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
+            GraphicsUploadManager manager = new GraphicsUploadManager(device);
+            GraphicsDeviceBuffer<int> buffer = new GraphicsDeviceBuffer<int>(
+                device, GraphicsBufferUsage.TransferAll, Size
+            );
+
+            int[] data = Enumerable.Range(0, (int)Size).ToArray();
+            manager.Upload<int>(buffer, data);
+            manager.Flush().Wait();
+
+            int[] read = new int[Size];
+            buffer.GetData(read);
+            Assert.Equal(data, read);
+        }
+    }
+
+    [FactRequire(TestRequirements.Graphics)]
+    public void UploadBufferWrapsStaging() {
+        foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
+            GraphicsUploadManager manager = new GraphicsUploadManager(device, Size * sizeof(int));
+            GraphicsDeviceBuffer<int> buffer = new GraphicsDeviceBuffer<int>(
+                device, GraphicsBufferUsage.TransferAll, Size * 4
+            );
+
+            int[] data = Enumerable.Range(0, (int)Size * 4).ToArray();
+            // Odd split of each quarter does not fit twice into the staging buffer, so it wraps.
+            const int Half = (int)Size / 2 + 1;
+            for (int i = 0; i < 4; i++) {
+                int index = i * (int)Size;
+                manager.Upload<int>(buffer, data.AsSpan(index, Half), (ulong)index);
+                manager.Upload<int>(buffer, data.AsSpan(index + Half, (int)Size - Half), (ulong)(index + Half));
+            }
+            manager.WaitIdle();
+
+            int[] read = new int[Size * 4];
+            buffer.GetData(read);
+            Assert.Equal(data, read);
+        }
+    }
+
+    [FactRequire(TestRequirements.Graphics)]
+    public void UploadTexture() {
+        foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
+            GraphicsUploadManager manager = new GraphicsUploadManager(device);
+            Texture2D texture = new Texture2D(device, TextureUsage.TransferAll, 16, 16);
+
+            Color32[] data = Enumerable.Range(0, 16 * 16).Select(x => new Color32((byte)x, 0, 255, 255)).ToArray();
+            manager.Upload<Color32>(texture, data);
+            manager.Flush().Wait();
+
+            Color32[] read = new Color32[data.Length];
+            texture.GetPixels<Color32>(read);
+            Assert.Equal(data, read);
+        }
+    }
+
+}
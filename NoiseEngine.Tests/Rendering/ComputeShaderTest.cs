@@ -4,10 +4,13 @@ using NoiseEngine.Nesl.Emit;
 using NoiseEngine.Nesl.Emit.Attributes;
 using NoiseEngine.Rendering;
 using NoiseEngine.Rendering.Buffers;
+using NoiseEngine.Rendering.Vulkan;
+using NoiseEngine.Rendering.Vulkan.Descriptors;
 using NoiseEngine.Tests.Environments;
 using NoiseEngine.Tests.Fixtures;
 using NoiseEngine.Tests.Nesl;
 using System;
+using System.Collections.Generic;
 
 namespace NoiseEngine.Tests.Rendering;
 
@@ -128,4 +131,101 @@ public class ComputeShaderTest : GraphicsTestEnvironment {
         }
     }
 
+    [FactRequire(TestRequirements.Graphics)]
+    public void AsyncCompute() {
+        float[] readData = new float[1];
+
+        foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
+            GraphicsHostBuffer<float> bufferA = new GraphicsHostBuffer<float>(
+                device, GraphicsBufferUsage.TransferSource | GraphicsBufferUsage.Storage, 1
+            );
+            GraphicsHostBuffer<float> bufferB = new GraphicsHostBuffer<float>(
+                device, GraphicsBufferUsage.TransferSource | GraphicsBufferUsage.Storage, 1
+            );
+
+            ComputeShader shader = new ComputeShader(device, ShaderType);
+            ComputeMaterial material = new ComputeMaterial(shader);
+            material.GetProperty(ShaderBufferA)!.SetBuffer(bufferA);
+            material.GetProperty(ShaderBufferB)!.SetBuffer(bufferB);
+
+            // Several executions to go through multiple queues of the family.
+            GraphicsCommandBuffer commandBuffer = new GraphicsCommandBuffer(device, true);
+            commandBuffer.AsyncCompute = true;
+            commandBuffer.Dispatch(shader.GetKernel(ShaderMethodA)!, material, Vector3<uint>.One);
+            GraphicsFence.WaitAll(new GraphicsFence[] {
+                commandBuffer.Execute(), commandBuffer.Execute(), commandBuffer.Execute()
+            });
+
+            // Assert.
+            bufferA.GetData(readData);
+            Assert.Equal(new float[] { ValueAA }, readData);
+            bufferB.GetData(readData);
+            Assert.Equal(new float[] { ValueAB }, readData);
+        }
+    }
+
+    [FactRequire(TestRequirements.Graphics)]
+    public void PipelineCompilation() {
+        foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
+            ComputeShader shader = new ComputeShader(device, ShaderType);
+            VulkanComputeShaderDelegation delegation = (VulkanComputeShaderDelegation)shader.Delegation;
+
+            PipelineCompilationBuilder builder = new PipelineCompilationBuilder();
+            builder.AddCompute(delegation.PipelineLayout, new PipelineShaderStage(
+                ShaderStageFlags.Compute, delegation.Module, ShaderMethodA.Guid.ToString()
+            ), PipelineCreateFlags.None);
+            builder.AddCompute(delegation.PipelineLayout, new PipelineShaderStage(
+                ShaderStageFlags.Compute, delegation.Module, ShaderMethodB.Guid.ToString()
+            ), PipelineCreateFlags.None);
+
+            // Specialization constant out of data bounds.
+            builder.AddCompute(delegation.PipelineLayout, new PipelineShaderStage(
+                ShaderStageFlags.Compute, delegation.Module, ShaderMethodA.Guid.ToString(), new SpecializationInfo(
+                    stackalloc SpecializationMapEntry[] { new SpecializationMapEntry(0, 0, sizeof(uint)) },
+                    ReadOnlySpan<byte>.Empty
+                )
+            ), PipelineCreateFlags.None);
+
+            NoiseEngine.Rendering.Vulkan.PipelineCompilation compilation = builder.Start();
+            Assert.True(compilation.Wait(ulong.MaxValue));
+            Assert.True(compilation.IsSignaled);
+
+            IReadOnlyList<PipelineCompilationResult> results = compilation.GetResults();
+            Assert.Equal(3, results.Count);
+            Assert.NotNull(results[0].Pipeline);
+            Assert.NotNull(results[1].Pipeline);
+            Assert.Null(results[2].Pipeline);
+            Assert.IsType<InvalidOperationException>(results[2].Exception);
+        }
+    }
+
+    [FactRequire(TestRequirements.Graphics)]
+    public void Reflection() {
+        foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
+            ComputeShader shader = new ComputeShader(device, ShaderType);
+            ShaderReflection? reflection = ((VulkanComputeShaderDelegation)shader.Delegation).Module.Reflection;
+            Assert.NotNull(reflection);
+
+            Assert.Equal(2, reflection.EntryPoints.Count);
+            foreach (NeslMethodBuilder method in new NeslMethodBuilder[] { ShaderMethodA, ShaderMethodB }) {
+                ShaderEntryPoint entryPoint = Assert.Single(
+                    reflection.EntryPoints, x => x.Name == method.Guid.ToString()
+                );
+                Assert.Equal(ShaderStageFlags.Compute, entryPoint.Stage);
+                Assert.Equal<Vector3<uint>?>(Vector3<uint>.One, entryPoint.WorkgroupSize);
+            }
+
+            Assert.Equal(2, reflection.Bindings.Count);
+            foreach (ShaderBinding binding in reflection.Bindings) {
+                Assert.Equal(0u, binding.Set);
+                Assert.Equal(DescriptorType.Storage, binding.Type);
+                Assert.Equal(1u, binding.Count);
+                Assert.Equal(ShaderStageFlags.Compute, binding.ShaderStageFlags);
+            }
+
+            Assert.Empty(reflection.PushConstantRanges);
+            Assert.Empty(reflection.VertexInputs);
+        }
+    }
+
 }
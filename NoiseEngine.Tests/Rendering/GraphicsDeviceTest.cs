@@ -1,7 +1,13 @@
 ﻿using NoiseEngine.Interop;
 using NoiseEngine.Rendering;
+using NoiseEngine.Rendering.Events;
+using NoiseEngine.Rendering.Vulkan;
 using NoiseEngine.Tests.Environments;
 using NoiseEngine.Tests.Fixtures;
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
 using System.Threading.Tasks;
 
 namespace NoiseEngine.Tests.Rendering;
@@ -14,6 +20,9 @@ public partial class GraphicsDeviceTest : GraphicsTestEnvironment {
     [InteropImport("rendering_vulkan_device_test_get_queue")]
     private static partial InteropResult<None> InteropUnmanagedGetQueue(InteropHandle<GraphicsDevice> device);
 
+    [InteropImport("rendering_vulkan_device_test_mark_lost")]
+    private static partial bool InteropUnmanagedMarkLost(InteropHandle<GraphicsDevice> device);
+
     [FactRequire(TestRequirements.Graphics)]
     public void Properties() {
         Assert.NotEmpty(Fixture.GraphicsDevices);
@@ -28,6 +37,108 @@ public partial class GraphicsDeviceTest : GraphicsTestEnvironment {
             device.Initialize();
     }
 
+    [FactRequire(TestRequirements.Graphics)]
+    public void InitializeWithFeatures() {
+        foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
+            device.Initialize();
+            Assert.Contains("robustBufferAccess", device.SupportedFeatures);
+            Assert.Subset(device.SupportedFeatures.ToHashSet(), device.EnabledFeatures.ToHashSet());
+
+            device.Initialize(device.EnabledFeatures);
+            Assert.Throws<InvalidOperationException>(() => device.Initialize(new[] { "unknownFeature" }));
+        }
+    }
+
+    [FactRequire(TestRequirements.Graphics)]
+    public void InitializeWithQueueFamilies() {
+        foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
+            Assert.NotEmpty(device.QueueFamilies);
+            Assert.Contains(device.QueueFamilies, x => x.QueueCount > 0 && x.SupportsTransfer);
+
+            device.Initialize();
+            Assert.Throws<InvalidOperationException>(() => device.Initialize(
+                Array.Empty<string>(), new[] { new GraphicsQueueFamilyConfiguration(0, new[] { 1f }) }
+            ));
+        }
+    }
+
+    [FactRequire(TestRequirements.Graphics)]
+    public void RankDevices() {
+        foreach (GraphicsInstance instance in Fixture.GraphicsDevices.Select(x => x.Instance).Distinct()) {
+            IReadOnlyList<GraphicsDevice> ranked = instance.RankDevices();
+            Assert.Equal(instance.Devices.ToHashSet(), ranked.ToHashSet());
+
+            Assert.All(
+                instance.RankDevices(new[] { "samplerAnisotropy" }),
+                x => Assert.Contains("samplerAnisotropy", x.SupportedFeatures)
+            );
+            Assert.Empty(instance.RankDevices(requiredExtensions: new[] { "VK_NOISE_nonexistent" }));
+            Assert.Throws<InvalidOperationException>(() => instance.RankDevices(new[] { "unknownFeature" }));
+        }
+    }
+
+    [FactRequire(TestRequirements.Graphics)]
+    public void LimitsAndFormats() {
+        foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
+            Assert.True(device.Limits.MaxImageDimension2D >= 4096);
+            Assert.True(device.Limits.MaxBoundDescriptorSets >= 4);
+            Assert.True(device.Limits.MaxComputeWorkGroupSize.X >= 128);
+            Assert.Contains(device.MemoryHeaps, x => x.IsDeviceLocal && x.Size > 0);
+
+            Assert.True(device.IsTextureFormatSupported(
+                TextureFormat.R8G8B8A8_UNORM, TextureUsage.Sampled | TextureUsage.TransferDestination
+            ));
+        }
+    }
+
+    [FactRequire(TestRequirements.Graphics)]
+    public void RecreateDevice() {
+        // Separate instance keeps devices of the fixture, which are used by the application, untouched.
+        GraphicsInstance instance = GraphicsInstance.Create(true, false, false);
+
+        foreach (GraphicsDevice device in instance.Devices.ToArray()) {
+            device.Initialize();
+            Assert.False(device.IsLost);
+
+            GraphicsDevice recreated = instance.RecreateDevice(device);
+            Assert.NotSame(device, recreated);
+            Assert.Equal(device.Guid, recreated.Guid);
+            Assert.Contains(recreated, instance.Devices);
+            Assert.DoesNotContain(device, instance.Devices);
+            Assert.Equal(device.EnabledFeatures.ToHashSet(), recreated.EnabledFeatures.ToHashSet());
+            Assert.False(recreated.IsLost);
+
+            Assert.Throws<InvalidOperationException>(() => instance.RecreateDevice(device));
+        }
+
+        Assert.All(Fixture.GraphicsDevices, x => Assert.Contains(x, x.Instance.Devices));
+    }
+
+    [FactRequire(TestRequirements.Graphics)]
+    public void LostDevice() {
+        GraphicsInstance instance = GraphicsInstance.Create(true, false, false);
+
+        foreach (GraphicsDevice device in instance.Devices.ToArray()) {
+            device.Initialize();
+
+            List<GraphicsDeviceLostEventArgs> raised = new List<GraphicsDeviceLostEventArgs>();
+            device.Lost += (sender, e) => {
+                Assert.Same(device, sender);
+                raised.Add(e);
+            };
+
+            Assert.True(InteropUnmanagedMarkLost(device.Handle));
+            Assert.True(device.IsLost);
+            Assert.True(InteropUnmanagedMarkLost(device.Handle));
+
+            GraphicsDeviceLostEventArgs e = Assert.Single(raised);
+            Assert.NotEmpty(e.Message);
+
+            GraphicsDevice recreated = instance.RecreateDevice(device);
+            Assert.False(recreated.IsLost);
+        }
+    }
+
     [FactRequire(TestRequirements.Graphics)]
     public void UnmanagedGetQueue() {
         foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
@@ -36,4 +147,30 @@ public partial class GraphicsDeviceTest : GraphicsTestEnvironment {
         }
     }
 
+    [FactRequire(TestRequirements.Graphics)]
+    public void PipelineCache() {
+        string directory = Path.Combine(Path.GetTempPath(), Guid.NewGuid().ToString());
+        string cacheDirectory = VulkanDevice.CacheDirectory;
+        VulkanDevice.CacheDirectory = directory;
+
+        try {
+            foreach (VulkanDevice device in Fixture.GraphicsDevices.OfType<VulkanDevice>()) {
+                device.Initialize();
+                device.SaveCaches();
+
+                string path = device.PipelineCachePath;
+                Assert.StartsWith(directory, path);
+                Assert.True(File.Exists(path));
+                Assert.True(device.LoadPipelineCache(File.ReadAllBytes(path)));
+                Assert.False(device.LoadPipelineCache(new byte[64]));
+
+                File.Delete(path);
+            }
+        } finally {
+            VulkanDevice.CacheDirectory = cacheDirectory;
+            if (Directory.Exists(directory))
+                Directory.Delete(directory, true);
+        }
+    }
+
 }
@@ -0,0 +1,104 @@
+﻿using NoiseEngine.Rendering;
+using NoiseEngine.Rendering.Buffers;
+using NoiseEngine.Tests.Environments;
+using NoiseEngine.Tests.Fixtures;
+using System;
+
+namespace NoiseEngine.Tests.Rendering;
+
+public class GraphicsQueryPoolTest : GraphicsTestEnvironment {
+
+    public GraphicsQueryPoolTest(ApplicationFixture fixture) : base(fixture) {
+    }
+
+    [FactRequire(TestRequirements.Graphics)]
+    public void Timestamp() {
+        ulong[] results = new ulong[2];
+
+        foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
+            GraphicsQueryPool queryPool = new GraphicsQueryPool(device, GraphicsQueryType.Timestamp, 2);
+            GraphicsHostBuffer<int> source = new GraphicsHostBuffer<int>(device, GraphicsBufferUsage.TransferAll, 1024);
+            GraphicsHostBuffer<int> destination =
+                new GraphicsHostBuffer<int>(device, GraphicsBufferUsage.TransferAll, 1024);
+
+            GraphicsCommandBuffer commandBuffer = Fixture.GetCommandBuffer(device);
+            commandBuffer.ResetQueries(queryPool, 0, 2);
+            commandBuffer.WriteTimestamp(queryPool, 0);
+            commandBuffer.Copy(source, destination, 1024);
+            commandBuffer.WriteTimestamp(queryPool, 1);
+            commandBuffer.Execute().Wait();
+            commandBuffer.Clear();
+
+            queryPool.GetResults(0, results);
+            Assert.True(results[1] >= results[0]);
+        }
+    }
+
+    [FactRequire(TestRequirements.Graphics)]
+    public void Occlusion() {
+        ulong[] results = new ulong[2];
+
+        foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
+            GraphicsQueryPool queryPool = new GraphicsQueryPool(device, GraphicsQueryType.Occlusion, 1);
+            GraphicsHostBuffer<ulong> buffer = new GraphicsHostBuffer<ulong>(device, GraphicsBufferUsage.TransferAll, 2);
+
+            // Query without draws does not pass any samples.
+            GraphicsCommandBuffer commandBuffer = Fixture.GetCommandBuffer(device);
+            commandBuffer.ResetQueries(queryPool, 0, 1);
+            commandBuffer.BeginQuery(queryPool, 0);
+            commandBuffer.EndQuery(queryPool, 0);
+            commandBuffer.CopyQueryResults(queryPool, 0, 1, buffer, 0, true);
+            commandBuffer.Execute().Wait();
+            commandBuffer.Clear();
+
+            buffer.GetData(results);
+            Assert.Equal(0ul, results[0]);
+            Assert.NotEqual(0ul, results[1]);
+
+            Assert.True(queryPool.TryGetResults(0, results.AsSpan(0, 1)));
+            Assert.Equal(0ul, results[0]);
+        }
+    }
+
+    [FactRequire(TestRequirements.Graphics)]
+    public void InvalidQuery() {
+        foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
+            GraphicsQueryPool queryPool = new GraphicsQueryPool(device, GraphicsQueryType.Timestamp, 2);
+            GraphicsCommandBuffer commandBuffer = Fixture.GetCommandBuffer(device);
+
+            Assert.Throws<ArgumentOutOfRangeException>(() => commandBuffer.WriteTimestamp(queryPool, 2));
+            Assert.Throws<ArgumentOutOfRangeException>(() => commandBuffer.ResetQueries(queryPool, 1, 2));
+            Assert.Throws<ArgumentException>(() => commandBuffer.BeginQuery(queryPool, 0));
+            Assert.Throws<ArgumentException>(() => queryPool.GetResults(0, Array.Empty<ulong>()));
+
+            commandBuffer.Clear();
+        }
+    }
+
+    [FactRequire(TestRequirements.Graphics)]
+    public void InvalidQueryResultsDestination() {
+        foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
+            GraphicsQueryPool queryPool = new GraphicsQueryPool(device, GraphicsQueryType.Occlusion, 1);
+            GraphicsHostBuffer<ulong> shortBuffer =
+                new GraphicsHostBuffer<ulong>(device, GraphicsBufferUsage.TransferAll, 1);
+            GraphicsHostBuffer<ulong> sourceBuffer =
+                new GraphicsHostBuffer<ulong>(device, GraphicsBufferUsage.TransferSource, 2);
+
+            // Native side rejects results which do not fit into the buffer.
+            GraphicsCommandBuffer commandBuffer = Fixture.GetCommandBuffer(device);
+            commandBuffer.ResetQueries(queryPool, 0, 1);
+            commandBuffer.CopyQueryResultsUnchecked(queryPool, 0, 1, shortBuffer, 0, true);
+            ArgumentException exception = Assert.Throws<ArgumentException>(() => commandBuffer.Execute());
+            Assert.Contains("exceed destination buffer size", exception.Message);
+            commandBuffer.Clear();
+
+            // And buffers without transfer destination usage.
+            commandBuffer.ResetQueries(queryPool, 0, 1);
+            commandBuffer.CopyQueryResultsUnchecked(queryPool, 0, 1, sourceBuffer, 0, false);
+            exception = Assert.Throws<ArgumentException>(() => commandBuffer.Execute());
+            Assert.Contains("transfer destination usage", exception.Message);
+            commandBuffer.Clear();
+        }
+    }
+
+}
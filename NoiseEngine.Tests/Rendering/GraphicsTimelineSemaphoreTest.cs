@@ -0,0 +1,75 @@
+﻿using NoiseEngine.Rendering;
+using NoiseEngine.Rendering.Buffers;
+using NoiseEngine.Tests.Environments;
+using NoiseEngine.Tests.Fixtures;
+
+namespace NoiseEngine.Tests.Rendering;
+
+public class GraphicsTimelineSemaphoreTest : GraphicsTestEnvironment {
+
+    public GraphicsTimelineSemaphoreTest(ApplicationFixture fixture) : base(fixture) {
+    }
+
+    [FactRequire(TestRequirements.Graphics)]
+    public void SignalAndWait() {
+        foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
+            GraphicsTimelineSemaphore semaphore = new GraphicsTimelineSemaphore(device, 1);
+            Assert.Equal(1ul, semaphore.Value);
+
+            Assert.False(semaphore.Wait(2, 0));
+            semaphore.Signal(2);
+            Assert.True(semaphore.Wait(2, 0));
+            Assert.Equal(2ul, semaphore.Value);
+        }
+    }
+
+    [FactRequire(TestRequirements.Graphics)]
+    public void ExecuteWithDependencies() {
+        foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
+            GraphicsTimelineSemaphore semaphore = new GraphicsTimelineSemaphore(device);
+            GraphicsCommandBuffer first = new GraphicsCommandBuffer(device, false);
+            GraphicsCommandBuffer second = new GraphicsCommandBuffer(device, false);
+
+            // Both command buffers can be executed on the same queue, so the signaling one is submitted first.
+            first.Execute(
+                default, new GraphicsTimelineSemaphoreValue[] { new GraphicsTimelineSemaphoreValue(semaphore, 1) }
+            );
+            GraphicsFence fence = second.Execute(
+                new GraphicsTimelineSemaphoreValue[] { new GraphicsTimelineSemaphoreValue(semaphore, 1) },
+                new GraphicsTimelineSemaphoreValue[] { new GraphicsTimelineSemaphoreValue(semaphore, 2) }
+            );
+
+            semaphore.Wait(2);
+            fence.Wait();
+            Assert.Equal(2ul, semaphore.Value);
+
+            GraphicsTimelineSemaphore.WaitAll(new GraphicsTimelineSemaphoreValue[] {
+                new GraphicsTimelineSemaphoreValue(semaphore, 1), new GraphicsTimelineSemaphoreValue(semaphore, 2)
+            });
+
+            first.Clear();
+            second.Clear();
+        }
+    }
+
+    [FactRequire(TestRequirements.Graphics)]
+    public void ExecuteWaitBeforeSignal() {
+        foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
+            GraphicsTimelineSemaphore semaphore = new GraphicsTimelineSemaphore(device);
+            GraphicsCommandBuffer commandBuffer = new GraphicsCommandBuffer(device, false);
+
+            GraphicsFence fence = commandBuffer.Execute(
+                new GraphicsTimelineSemaphoreValue[] { new GraphicsTimelineSemaphoreValue(semaphore, 1) },
+                new GraphicsTimelineSemaphoreValue[] { new GraphicsTimelineSemaphoreValue(semaphore, 2) }
+            );
+            Assert.False(fence.IsSignaled);
+
+            semaphore.Signal(1);
+            Assert.True(semaphore.Wait(2, ulong.MaxValue));
+            fence.Wait();
+
+            commandBuffer.Clear();
+        }
+    }
+
+}
@@ -0,0 +1,76 @@
+﻿using NoiseEngine.Components;
+using NoiseEngine.Mathematics;
+using NoiseEngine.Primitives;
+using NoiseEngine.Rendering;
+using NoiseEngine.Rendering.Buffers;
+using NoiseEngine.Tests.Environments;
+using NoiseEngine.Tests.Fixtures;
+using System;
+using System.Collections.Concurrent;
+
+namespace NoiseEngine.Tests.Rendering;
+
+public class GraphicsValidationTest : GraphicsTestEnvironment {
+
+    public GraphicsValidationTest(ApplicationFixture fixture) : base(fixture) {
+    }
+
+    [FactRequire(TestRequirements.Graphics)]
+    public void FrameWithoutErrors() {
+        ReadOnlySpan<VertexPosition3Color3> vertices = stackalloc VertexPosition3Color3[] {
+            new VertexPosition3Color3(new Vector3<float>(-0.5f, -0.5f, 0), Vector3<float>.Up),
+            new VertexPosition3Color3(new Vector3<float>(-0.5f, 0.5f, 0), Vector3<float>.Up),
+            new VertexPosition3Color3(new Vector3<float>(0.5f, -0.5f, 0), Vector3<float>.Up)
+        };
+        ReadOnlySpan<ushort> indices = stackalloc ushort[] { 0, 1, 2 };
+
+        ConcurrentQueue<GraphicsValidationMessage> errors = new ConcurrentQueue<GraphicsValidationMessage>();
+        void OnMessageReceived(GraphicsValidationMessage message) {
+            if (message.IsValidationError)
+                errors.Enqueue(message);
+        }
+
+        GraphicsValidation.MessageReceived += OnMessageReceived;
+        GraphicsValidation.CaptureErrors = true;
+        try {
+            Assert.True(GraphicsValidation.CaptureErrors);
+
+            foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
+                PrimitiveCreatorShared shared = PrimitiveCreatorShared.CreateOrGet(device);
+
+                Texture2D texture = new Texture2D(
+                    device, TextureUsage.TransferAll | TextureUsage.ColorAttachment, 16, 16,
+                    TextureFormat.R8G8B8A8_UNORM
+                ) {
+                    DebugName = nameof(FrameWithoutErrors)
+                };
+                SimpleCamera camera = new SimpleCamera(device) {
+                    RenderTarget = new RenderTexture(texture),
+                    ClearFlags = CameraClearFlags.SolidColor,
+                    ClearColor = Color.Red,
+                    DepthTesting = true
+                };
+
+                GraphicsCommandBuffer commandBuffer = Fixture.GetCommandBuffer(device);
+                commandBuffer.BeginLabel("Frame");
+                commandBuffer.AttachCameraUnchecked(camera);
+                commandBuffer.DrawMeshUnchecked(
+                    new Mesh<VertexPosition3Color3, ushort>(device, vertices, indices), shared.DefaultMaterial,
+                    new TransformComponent(new pos3(0, 0, 5)).Matrix
+                );
+                commandBuffer.DetachCameraUnchecked();
+                commandBuffer.EndLabel();
+
+                // Captured errors are thrown as GraphicsValidationException by execution or waiting.
+                commandBuffer.Execute().Wait();
+                commandBuffer.Clear();
+            }
+        } finally {
+            GraphicsValidation.CaptureErrors = false;
+            GraphicsValidation.MessageReceived -= OnMessageReceived;
+        }
+
+        Assert.Empty(errors);
+    }
+
+}
@@ -1,4 +1,5 @@
 ﻿using NoiseEngine.Components;
+using NoiseEngine.Interop;
 using NoiseEngine.Mathematics;
 using NoiseEngine.Nesl;
 using NoiseEngine.Nesl.Default;
@@ -9,14 +10,22 @@ using NoiseEngine.Tests.Environments;
 using NoiseEngine.Tests.Fixtures;
 using NoiseEngine.Tests.Nesl;
 using System;
+using System.IO;
+using System.Linq;
+using System.Runtime.InteropServices;
 
 namespace NoiseEngine.Tests.Rendering;
 
-public class MeshT2Test : ApplicationTestEnvironment {
+public partial class MeshT2Test : ApplicationTestEnvironment {
 
     public MeshT2Test(ApplicationFixture fixture) : base(fixture) {
     }
 
+    [InteropImport("rendering_vulkan_capture_test_replay")]
+    private static partial InteropResult<None> InteropUnmanagedReplay(
+        string capturePath, string outputDirectory, Span<byte> texels
+    );
+
     [FactRequire(TestRequirements.Graphics)]
     public void Figure2D() {
         // Create shader.
@@ -99,6 +108,99 @@ public class MeshT2Test : ApplicationTestEnvironment {
         }
     }
 
+    [FactRequire(TestRequirements.Graphics)]
+    public void Figure2DSecondary() {
+        // Create shader.
+        NeslTypeBuilder vertexData = TestEmitHelper.NewType();
+        vertexData.DefineField("Position", Vectors.GetVector4(BuiltInTypes.Float32));
+        vertexData.DefineField("Color", Vectors.GetVector4(BuiltInTypes.Float32));
+
+        NeslTypeBuilder shaderClassData = TestEmitHelper.NewType();
+
+        NeslMethodBuilder vertex = shaderClassData.DefineMethod(
+            "Vertex", vertexData, vertexData
+        );
+        vertex.SetModifiers(NeslModifiers.Static);
+        IlGenerator il = vertex.IlGenerator;
+
+        il.Emit(OpCode.DefVariable, vertexData);
+        il.Emit(OpCode.Load, 1u, 0u);
+        il.Emit(OpCode.ReturnValue, 1u);
+
+        NeslMethodBuilder fragment = shaderClassData.DefineMethod(
+            "Fragment", Vectors.GetVector4(BuiltInTypes.Float32), vertexData
+        );
+        fragment.SetModifiers(NeslModifiers.Static);
+        il = fragment.IlGenerator;
+
+        il.Emit(OpCode.DefVariable, Vectors.GetVector4(BuiltInTypes.Float32));
+        il.Emit(OpCode.LoadField, 1u, 0u, 1u);
+        il.Emit(OpCode.ReturnValue, 1u);
+
+        // Executing.
+        Span<Color32> buffer = stackalloc Color32[16 * 16];
+
+        ReadOnlySpan<(Vector4<float>, Color)> vertices = stackalloc (Vector4<float>, Color)[] {
+            (new Vector4<float>(-1, -1, 0, 1), Color.Red),
+            (new Vector4<float>(-.5f, -1, 0, 1), Color.Red),
+            (new Vector4<float>(-1, 1, 0, 1), Color.Red),
+            (new Vector4<float>(-.5f, 1, 0, 1), Color.Red),
+            (new Vector4<float>(0, -1, 0, 1), Color.Blue),
+            (new Vector4<float>(.5f, -1, 0, 1), Color.Blue),
+            (new Vector4<float>(0, 1, 0, 1), Color.Blue),
+            (new Vector4<float>(.5f, 1, 0, 1), Color.Blue)
+        };
+        ReadOnlySpan<ushort> leftTriangles = stackalloc ushort[] { 0, 1, 2, 1, 3, 2 };
+        ReadOnlySpan<ushort> rightTriangles = stackalloc ushort[] { 4, 5, 6, 5, 7, 6 };
+
+        foreach (GraphicsDevice device in GraphicsDevices) {
+            Shader shader = new Shader(device, shaderClassData);
+            Material material = new Material(shader);
+
+            Texture2D texture = new Texture2D(
+                device, TextureUsage.TransferAll | TextureUsage.ColorAttachment, 16, 16
+            );
+            SimpleCamera camera = new SimpleCamera(device) {
+                RenderTarget = new RenderTexture(texture),
+                ClearFlags = CameraClearFlags.SolidColor,
+                ClearColor = Color.Green,
+                DepthTesting = false
+            };
+
+            GraphicsCommandBuffer left = new GraphicsCommandBuffer(device, false);
+            left.BeginSecondary(camera);
+            left.DrawMeshUnchecked(
+                new Mesh<(float4, Color), ushort>(device, vertices, leftTriangles), material, new Matrix4x4<pos>()
+            );
+
+            GraphicsCommandBuffer right = new GraphicsCommandBuffer(device, false);
+            right.BeginSecondary(camera);
+            right.DrawMeshUnchecked(
+                new Mesh<(float4, Color), ushort>(device, vertices, rightTriangles), material, new Matrix4x4<pos>()
+            );
+
+            GraphicsCommandBuffer commandBuffer = new GraphicsCommandBuffer(device, false);
+            commandBuffer.AttachCameraUnchecked(camera, true);
+            commandBuffer.ExecuteSecondary(new GraphicsCommandBuffer[] { left, right });
+            commandBuffer.DetachCameraUnchecked();
+
+            Assert.Throws<InvalidOperationException>(left.Construct);
+
+            commandBuffer.Execute();
+            commandBuffer.Clear();
+
+            // Assert.
+            texture.GetPixels(buffer);
+
+            for (int i = 0; i < buffer.Length; i += (int)texture.Width) {
+                Assert.Equal(Color32.Red, buffer[i]);
+                Assert.Equal(Color32.Green, buffer[i + 4]);
+                Assert.Equal(Color32.Blue, buffer[i + 8]);
+                Assert.Equal(Color32.Green, buffer[i + 12]);
+            }
+        }
+    }
+
     [FactRequire(TestRequirements.Graphics)]
     public void Figure3D() {
         // Create shader.
@@ -259,4 +361,110 @@ public class MeshT2Test : ApplicationTestEnvironment {
         }
     }
 
+    [FactRequire(TestRequirements.Graphics)]
+    public void Figure2DCaptureReplay() {
+        // Create shader.
+        NeslTypeBuilder vertexData = TestEmitHelper.NewType();
+        vertexData.DefineField("Position", Vectors.GetVector4(BuiltInTypes.Float32));
+        vertexData.DefineField("Color", Vectors.GetVector4(BuiltInTypes.Float32));
+
+        NeslTypeBuilder shaderClassData = TestEmitHelper.NewType();
+
+        NeslMethodBuilder vertex = shaderClassData.DefineMethod(
+            "Vertex", vertexData, vertexData
+        );
+        vertex.SetModifiers(NeslModifiers.Static);
+        IlGenerator il = vertex.IlGenerator;
+
+        il.Emit(OpCode.DefVariable, vertexData);
+        il.Emit(OpCode.Load, 1u, 0u);
+        il.Emit(OpCode.ReturnValue, 1u);
+
+        NeslMethodBuilder fragment = shaderClassData.DefineMethod(
+            "Fragment", Vectors.GetVector4(BuiltInTypes.Float32), vertexData
+        );
+        fragment.SetModifiers(NeslModifiers.Static);
+        il = fragment.IlGenerator;
+
+        il.Emit(OpCode.DefVariable, Vectors.GetVector4(BuiltInTypes.Float32));
+        il.Emit(OpCode.LoadField, 1u, 0u, 1u);
+        il.Emit(OpCode.ReturnValue, 1u);
+
+        // Executing.
+        Color32[] buffer = new Color32[16 * 16];
+        Color32[] replayed = new Color32[buffer.Length];
+
+        ReadOnlySpan<(Vector4<float>, Color)> vertices = stackalloc (Vector4<float>, Color)[] {
+            (new Vector4<float>(-1, -1, 0, 1), Color.Red),
+            (new Vector4<float>(-.5f, -1, 0, 1), Color.Red),
+            (new Vector4<float>(-1, 1, 0, 1), Color.Red),
+            (new Vector4<float>(-.5f, 1, 0, 1), Color.Red),
+            (new Vector4<float>(0, -1, 0, 1), Color.Blue),
+            (new Vector4<float>(.5f, -1, 0, 1), Color.Blue),
+            (new Vector4<float>(0, 1, 0, 1), Color.Blue),
+            (new Vector4<float>(.5f, 1, 0, 1), Color.Blue)
+        };
+        ReadOnlySpan<ushort> triangles = stackalloc ushort[] {
+            0, 1, 2, 1, 3, 2, 4, 5, 6, 5, 7, 6
+        };
+
+        string directory = Path.Combine(Path.GetTempPath(), Guid.NewGuid().ToString());
+        string? captureDirectory = GraphicsCommandBuffer.CaptureDirectory;
+
+        try {
+            foreach (GraphicsDevice device in GraphicsDevices) {
+                string deviceDirectory = Path.Combine(directory, Guid.NewGuid().ToString());
+                Directory.CreateDirectory(deviceDirectory);
+
+                // Resources are created while capturing, so contents of the mesh can be read back.
+                GraphicsCommandBuffer.CaptureDirectory = deviceDirectory;
+
+                Shader shader = new Shader(device, shaderClassData);
+
+                Texture2D texture = new Texture2D(
+                    device, TextureUsage.TransferAll | TextureUsage.ColorAttachment, 16, 16
+                );
+                SimpleCamera camera = new SimpleCamera(device) {
+                    RenderTarget = new RenderTexture(texture),
+                    ClearFlags = CameraClearFlags.SolidColor,
+                    ClearColor = Color.Green,
+                    DepthTesting = false
+                };
+
+                GraphicsCommandBuffer commandBuffer = new GraphicsCommandBuffer(device, false);
+                commandBuffer.AttachCameraUnchecked(camera);
+                commandBuffer.DrawMeshUnchecked(
+                    new Mesh<(float4, Color), ushort>(device, vertices, triangles), new Material(shader),
+                    new Matrix4x4<pos>()
+                );
+                commandBuffer.DetachCameraUnchecked();
+
+                commandBuffer.Execute();
+                commandBuffer.Clear();
+
+                GraphicsCommandBuffer.CaptureDirectory = captureDirectory;
+                texture.GetPixels(buffer);
+
+                // Draw is executed after uploads of the mesh, so it is captured last.
+                string capture = Directory.GetFiles(deviceDirectory, "*.necapture").Order().Last();
+                _ = InteropUnmanagedReplay(
+                    capture, Path.Combine(deviceDirectory, "replay"), MemoryMarshal.AsBytes(replayed.AsSpan())
+                ).Value;
+
+                // Assert.
+                Assert.Equal(buffer, replayed);
+                for (int i = 0; i < replayed.Length; i += (int)texture.Width) {
+                    Assert.Equal(Color32.Red, replayed[i]);
+                    Assert.Equal(Color32.Green, replayed[i + 4]);
+                    Assert.Equal(Color32.Blue, replayed[i + 8]);
+                    Assert.Equal(Color32.Green, replayed[i + 12]);
+                }
+            }
+        } finally {
+            GraphicsCommandBuffer.CaptureDirectory = captureDirectory;
+            if (Directory.Exists(directory))
+                Directory.Delete(directory, true);
+        }
+    }
+
 }
@@ -83,4 +83,64 @@ public class SimpleCameraTest : ApplicationTestEnvironment {
         }
     }
 
+    [FactRequire(TestRequirements.Graphics)]
+    public void SampleCount() {
+        Span<Color32> buffer = stackalloc Color32[16 * 16];
+
+        foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
+            uint sampleCount = device.GetMaxSampleCount();
+            Assert.True(device.IsSampleCountSupported(sampleCount));
+            Assert.False(device.IsSampleCountSupported(3));
+
+            Texture2D texture = new Texture2D(
+                device, TextureUsage.TransferAll | TextureUsage.ColorAttachment, 16, 16, TextureFormat.R8G8B8A8_UNORM
+            );
+            SimpleCamera camera = new SimpleCamera(device) {
+                RenderTarget = new RenderTexture(texture),
+                ClearFlags = CameraClearFlags.SolidColor,
+                ClearColor = Color.Red,
+                SampleCount = sampleCount
+            };
+            Assert.Throws<ArgumentOutOfRangeException>(() => camera.SampleCount = 3);
+
+            GraphicsCommandBuffer commandBuffer = new GraphicsCommandBuffer(device, false);
+            commandBuffer.AttachCameraUnchecked(camera);
+            commandBuffer.DetachCameraUnchecked();
+            commandBuffer.Execute();
+
+            // Assert.
+            texture.GetPixels(buffer);
+            Assert.Equal((Color32)camera.ClearColor, buffer[128]);
+        }
+    }
+
+    [FactRequire(TestRequirements.Graphics)]
+    public void ClearDepthStencil() {
+        Span<Color32> buffer = stackalloc Color32[16 * 16];
+
+        foreach (GraphicsDevice device in Fixture.GraphicsDevices) {
+            Texture2D texture = new Texture2D(
+                device, TextureUsage.TransferAll | TextureUsage.ColorAttachment, 16, 16, TextureFormat.R8G8B8A8_UNORM
+            );
+            SimpleCamera camera = new SimpleCamera(device) {
+                RenderTarget = new RenderTexture(texture),
+                ClearFlags = CameraClearFlags.SolidColor,
+                ClearColor = Color.Red,
+                DepthTesting = true,
+                ClearDepth = 0,
+                ClearStencil = 255,
+                StoreDepthStencil = true
+            };
+
+            GraphicsCommandBuffer commandBuffer = new GraphicsCommandBuffer(device, false);
+            commandBuffer.AttachCameraUnchecked(camera);
+            commandBuffer.DetachCameraUnchecked();
+            commandBuffer.Execute();
+
+            // Assert.
+            texture.GetPixels(buffer);
+            Assert.Equal((Color32)camera.ClearColor, buffer[128]);
+        }
+    }
+
 }
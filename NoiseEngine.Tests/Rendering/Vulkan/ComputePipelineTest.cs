@@ -0,0 +1,185 @@
+﻿using NoiseEngine.Interop;
+using NoiseEngine.Interop.Rendering.Buffers;
+using NoiseEngine.Rendering;
+using NoiseEngine.Rendering.Buffers;
+using NoiseEngine.Rendering.Buffers.CommandBuffers;
+using NoiseEngine.Rendering.Vulkan;
+using NoiseEngine.Rendering.Vulkan.Descriptors;
+using NoiseEngine.Serialization;
+using NoiseEngine.Tests.Environments;
+using NoiseEngine.Tests.Fixtures;
+using System;
+using System.Runtime.InteropServices;
+
+namespace NoiseEngine.Tests.Rendering.Vulkan;
+
+public class ComputePipelineTest : GraphicsTestEnvironment {
+
+    private const uint DefaultValue = 1;
+
+    /// <summary>
+    /// Compute shader which writes specialization constant 0, with default value of 1, to storage buffer at
+    /// binding 0.
+    /// </summary>
+    private static readonly uint[] SpecializedShaderCode = new uint[] {
+        // Header.
+        0x07230203, 0x00010000, 0, 14, 0,
+        0x00020011, 0x00000001, // OpCapability Shader
+        0x0003000e, 0x00000000, 0x00000001, // OpMemoryModel Logical GLSL450
+        0x0005000f, 0x00000005, 0x00000001, 0x6e69616d, 0x00000000, // OpEntryPoint GLCompute %1 "main"
+        0x00060010, 0x00000001, 0x00000011, 0x00000001, 0x00000001, 0x00000001, // OpExecutionMode %1 LocalSize 1 1 1
+        0x00040047, 0x00000005, 0x00000001, 0x00000000, // OpDecorate %5 SpecId 0
+        0x00030047, 0x00000006, 0x00000003, // OpDecorate %6 BufferBlock
+        0x00050048, 0x00000006, 0x00000000, 0x00000023, 0x00000000, // OpMemberDecorate %6 0 Offset 0
+        0x00040047, 0x00000008, 0x00000022, 0x00000000, // OpDecorate %8 DescriptorSet 0
+        0x00040047, 0x00000008, 0x00000021, 0x00000000, // OpDecorate %8 Binding 0
+        0x00020013, 0x00000002, // %2 = OpTypeVoid
+        0x00030021, 0x00000003, 0x00000002, // %3 = OpTypeFunction %2
+        0x00040015, 0x00000004, 0x00000020, 0x00000000, // %4 = OpTypeInt 32 0
+        0x00040032, 0x00000004, 0x00000005, DefaultValue, // %5 = OpSpecConstant %4 1
+        0x0003001e, 0x00000006, 0x00000004, // %6 = OpTypeStruct %4
+        0x00040020, 0x00000007, 0x00000002, 0x00000006, // %7 = OpTypePointer Uniform %6
+        0x0004003b, 0x00000007, 0x00000008, 0x00000002, // %8 = OpVariable %7 Uniform
+        0x00040015, 0x00000009, 0x00000020, 0x00000001, // %9 = OpTypeInt 32 1
+        0x0004002b, 0x00000009, 0x0000000a, 0x00000000, // %10 = OpConstant %9 0
+        0x00040020, 0x0000000b, 0x00000002, 0x00000004, // %11 = OpTypePointer Uniform %4
+        0x00050036, 0x00000002, 0x00000001, 0x00000000, 0x00000003, // %1 = OpFunction %2 None %3
+        0x000200f8, 0x0000000c, // %12 = OpLabel
+        0x00050041, 0x0000000b, 0x0000000d, 0x00000008, 0x0000000a, // %13 = OpAccessChain %11 %8 %10
+        0x0003003e, 0x0000000d, 0x00000005, // OpStore %13 %5
+        0x000100fd, // OpReturn
+        0x00010038 // OpFunctionEnd
+    };
+
+    public ComputePipelineTest(ApplicationFixture fixture) : base(fixture) {
+    }
+
+    [FactRequire(TestRequirements.Vulkan)]
+    public void SpecializationConstantChangesResult() {
+        uint[] readData = new uint[1];
+
+        foreach (VulkanDevice device in Fixture.VulkanDevices) {
+            (ShaderModule module, PipelineLayout layout, DescriptorSet set, GraphicsHostBuffer<uint> buffer) =
+                CreateResources(device);
+
+            foreach (uint? value in new uint?[] { null, 7, 2023 }) {
+                SpecializationInfo? specialization = value is null ?
+                    null : SpecializationInfo.FromConstants<uint>(new uint[] { value.Value });
+                ComputePipeline pipeline = new ComputePipeline(layout, new PipelineShaderStage(
+                    ShaderStageFlags.Compute, module, "main", specialization
+                ), PipelineCreateFlags.None);
+
+                buffer.SetData(new uint[1]);
+                Dispatch(device, pipeline, set);
+
+                buffer.GetData(readData);
+                Assert.Equal(value ?? DefaultValue, readData[0]);
+            }
+        }
+    }
+
+    [FactRequire(TestRequirements.Vulkan)]
+    public void SpecializationEntryOutOfBounds() {
+        foreach (VulkanDevice device in Fixture.VulkanDevices) {
+            (ShaderModule module, PipelineLayout layout, _, _) = CreateResources(device);
+
+            SpecializationInfo specialization = new SpecializationInfo(
+                stackalloc SpecializationMapEntry[] { new SpecializationMapEntry(0, 2, sizeof(uint)) },
+                stackalloc byte[sizeof(uint)]
+            );
+
+            InvalidOperationException exception = Assert.Throws<InvalidOperationException>(
+                () => new ComputePipeline(layout, new PipelineShaderStage(
+                    ShaderStageFlags.Compute, module, "main", specialization
+                ), PipelineCreateFlags.None)
+            );
+            Assert.Equal("Specialization map entry of constant 0 is out of data bounds.", exception.Message);
+        }
+    }
+
+    [FactRequire(TestRequirements.Vulkan)]
+    public void SpecializationDuplicateConstantId() {
+        foreach (VulkanDevice device in Fixture.VulkanDevices) {
+            (ShaderModule module, PipelineLayout layout, _, _) = CreateResources(device);
+
+            SpecializationInfo specialization = new SpecializationInfo(
+                stackalloc SpecializationMapEntry[] {
+                    new SpecializationMapEntry(0, 0, sizeof(uint)),
+                    new SpecializationMapEntry(0, sizeof(uint), sizeof(uint))
+                },
+                stackalloc byte[2 * sizeof(uint)]
+            );
+
+            InvalidOperationException exception = Assert.Throws<InvalidOperationException>(
+                () => new ComputePipeline(layout, new PipelineShaderStage(
+                    ShaderStageFlags.Compute, module, "main", specialization
+                ), PipelineCreateFlags.None)
+            );
+            Assert.Equal("Specialization constant 0 is specified multiple times.", exception.Message);
+        }
+    }
+
+    private static unsafe (ShaderModule, PipelineLayout, DescriptorSet, GraphicsHostBuffer<uint>) CreateResources(
+        VulkanDevice device
+    ) {
+        ShaderModule module = new ShaderModule(device, MemoryMarshal.AsBytes<uint>(SpecializedShaderCode));
+
+        DescriptorSetLayout setLayout = new DescriptorSetLayout(device, stackalloc DescriptorSetLayoutBinding[] {
+            new DescriptorSetLayoutBinding(0, DescriptorType.Storage, 1, ShaderStageFlags.Compute, 0)
+        });
+        PipelineLayout layout = new PipelineLayout(
+            new DescriptorSetLayout[] { setLayout }, ReadOnlySpan<PushConstantRange>.Empty
+        );
+
+        GraphicsHostBuffer<uint> buffer = new GraphicsHostBuffer<uint>(
+            device, GraphicsBufferUsage.TransferAll | GraphicsBufferUsage.Storage, 1
+        );
+
+        DescriptorSet set = new DescriptorSet(setLayout);
+        DescriptorUpdateTemplate template = new DescriptorUpdateTemplate(
+            setLayout, stackalloc DescriptorUpdateTemplateEntry[] {
+                new DescriptorUpdateTemplateEntry(0, 0, 1, DescriptorType.Storage, 0, 0)
+            }
+        );
+
+        Span<byte> data = stackalloc byte[Marshal.SizeOf<DescriptorBufferInfo>()];
+        fixed (byte* pointer = data)
+            Marshal.StructureToPtr(DescriptorBufferInfo.Create(buffer), (nint)pointer, false);
+        set.Update(template, data);
+
+        return (module, layout, set, buffer);
+    }
+
+    private static void Dispatch(VulkanDevice device, ComputePipeline pipeline, DescriptorSet set) {
+        SerializationWriter writer = new SerializationWriter(BitConverter.IsLittleEndian);
+        writer.WriteCommand(CommandBufferCommand.AttachPipeline);
+        writer.WriteUInt32((uint)PipelineBindPoint.Compute);
+        writer.WriteIntN(pipeline.Handle.Pointer);
+        writer.WriteCommand(CommandBufferCommand.AttachMaterial);
+        writer.WriteIntN(set.Handle.Pointer);
+        writer.WriteCommand(CommandBufferCommand.Dispatch);
+        writer.WriteUInt32(1);
+        writer.WriteUInt32(1);
+        writer.WriteUInt32(1);
+
+        InteropHandle<GraphicsCommandBuffer> handle = device.CreateCommandBuffer(
+            writer.AsSpan(), new GraphicsCommandBufferUsage(false, true, false, false), false
+        );
+
+        try {
+            if (!GraphicsCommandBufferInterop.Execute(handle).TryGetValue(
+                out InteropHandle<GraphicsFence> fenceHandle, out ResultError error
+            )) {
+                error.ThrowAndDispose();
+            }
+
+            new GraphicsFence(device, fenceHandle).Wait();
+        } finally {
+            // Command buffer references pipeline and descriptor set only by pointers.
+            GC.KeepAlive(pipeline);
+            GC.KeepAlive(set);
+            GraphicsCommandBufferInterop.Destroy(handle);
+        }
+    }
+
+}
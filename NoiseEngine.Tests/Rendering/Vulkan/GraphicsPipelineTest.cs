@@ -0,0 +1,146 @@
+﻿using NoiseEngine.Interop;
+using NoiseEngine.Interop.Rendering.Vulkan;
+using NoiseEngine.Mathematics;
+using NoiseEngine.Primitives;
+using NoiseEngine.Rendering;
+using NoiseEngine.Rendering.Vulkan;
+using NoiseEngine.Tests.Environments;
+using NoiseEngine.Tests.Fixtures;
+using System;
+
+namespace NoiseEngine.Tests.Rendering.Vulkan;
+
+public partial class GraphicsPipelineTest : ApplicationTestEnvironment {
+
+    public GraphicsPipelineTest(ApplicationFixture fixture) : base(fixture) {
+    }
+
+    [InteropImport("rendering_vulkan_graphics_pipeline_create_info_test_validate_without_features")]
+    private static partial InteropResult<None> InteropValidateWithoutFeatures(GraphicsPipelineCreateInfoRaw createInfo);
+
+    [FactRequire(TestRequirements.Vulkan)]
+    public void CreateWithNonDefaultState() {
+        StencilOpState stencil = new StencilOpState(
+            StencilOp.Keep, StencilOp.Replace, StencilOp.IncrementAndClamp, CompareOp.Always, 0xff, 0xff, 1
+        );
+
+        foreach (VulkanDevice device in VulkanDevices) {
+            Texture2D texture = new Texture2D(
+                device, TextureUsage.TransferAll | TextureUsage.ColorAttachment, 4, 4, TextureFormat.R8G8B8A8_UNORM
+            );
+            SimpleCamera camera = new SimpleCamera(device) {
+                RenderTarget = new RenderTexture(texture),
+                DepthTesting = true
+            };
+            RenderPass renderPass = ((VulkanSimpleCameraDelegation)camera.Delegation).RenderPass;
+
+            VulkanVertexFragmentShaderDelegation shader = (VulkanVertexFragmentShaderDelegation)
+                PrimitiveCreatorShared.CreateOrGet(device).DefaultMaterial.Shader.Delegation;
+
+            GraphicsPipeline pipeline = CreatePipeline(renderPass, shader, new GraphicsPipelineCreateInfo {
+                VertexInputBindingDescription = shader.VertexDescription.Bindings,
+                VertexInputAttributeDescription = shader.VertexDescription.Attributes,
+                PrimitiveTopology = PrimitiveTopology.TriangleList,
+                Rasterization = PipelineRasterizationState.Default with {
+                    CullMode = CullModeFlags.None,
+                    FrontFace = FrontFace.CounterClockwise,
+                    DepthBias = true,
+                    DepthBiasConstantFactor = 1.25f,
+                    DepthBiasSlopeFactor = 1.75f
+                },
+                DepthStencil = new PipelineDepthStencilState(
+                    true, false, CompareOp.GreaterOrEqual, false, true, stencil, stencil with {
+                        PassOp = StencilOp.Zero
+                    }, 0, 1
+                ),
+                ColorBlendAttachments = new PipelineColorBlendAttachmentState[] {
+                    PipelineColorBlendAttachmentState.AlphaBlend with {
+                        ColorWriteMask = ColorComponentFlags.R | ColorComponentFlags.G | ColorComponentFlags.B
+                    }
+                },
+                BlendConstants = new Vector4<float>(0.25f, 0.5f, 0.75f, 1)
+            });
+
+            Assert.Same(renderPass, pipeline.RenderPass);
+        }
+    }
+
+    [FactRequire(TestRequirements.Vulkan)]
+    public void RejectMismatchedColorBlendAttachments() {
+        foreach (VulkanDevice device in VulkanDevices) {
+            Texture2D texture = new Texture2D(
+                device, TextureUsage.TransferAll | TextureUsage.ColorAttachment, 4, 4, TextureFormat.R8G8B8A8_UNORM
+            );
+            SimpleCamera camera = new SimpleCamera(device) {
+                RenderTarget = new RenderTexture(texture)
+            };
+            RenderPass renderPass = ((VulkanSimpleCameraDelegation)camera.Delegation).RenderPass;
+
+            VulkanVertexFragmentShaderDelegation shader = (VulkanVertexFragmentShaderDelegation)
+                PrimitiveCreatorShared.CreateOrGet(device).DefaultMaterial.Shader.Delegation;
+
+            Assert.Throws<InvalidOperationException>(() => CreatePipeline(
+                renderPass, shader, new GraphicsPipelineCreateInfo {
+                    VertexInputBindingDescription = shader.VertexDescription.Bindings,
+                    VertexInputAttributeDescription = shader.VertexDescription.Attributes,
+                    PrimitiveTopology = PrimitiveTopology.TriangleList,
+                    Rasterization = PipelineRasterizationState.Default,
+                    DepthStencil = PipelineDepthStencilState.Default,
+                    ColorBlendAttachments = new PipelineColorBlendAttachmentState[] {
+                        PipelineColorBlendAttachmentState.Default, PipelineColorBlendAttachmentState.AlphaBlend
+                    }
+                }
+            ));
+        }
+    }
+
+    [FactRequire(TestRequirements.Vulkan)]
+    public void RejectDepthClampWithoutFeature() {
+        InvalidOperationException exception = Assert.Throws<InvalidOperationException>(
+            () => ValidateWithoutFeatures(new GraphicsPipelineCreateInfo {
+                Rasterization = PipelineRasterizationState.Default with { DepthClamp = true },
+                DepthStencil = PipelineDepthStencilState.Default
+            })
+        );
+        Assert.Contains("depthClamp", exception.Message);
+    }
+
+    [FactRequire(TestRequirements.Vulkan)]
+    public void RejectNonSolidFillWithoutFeature() {
+        foreach (PolygonMode polygonMode in new PolygonMode[] { PolygonMode.Line, PolygonMode.Point }) {
+            InvalidOperationException exception = Assert.Throws<InvalidOperationException>(
+                () => ValidateWithoutFeatures(new GraphicsPipelineCreateInfo {
+                    Rasterization = PipelineRasterizationState.Default with { PolygonMode = polygonMode },
+                    DepthStencil = PipelineDepthStencilState.Default
+                })
+            );
+            Assert.Contains("fillModeNonSolid", exception.Message);
+        }
+
+        // Default state does not need any optional feature.
+        ValidateWithoutFeatures(new GraphicsPipelineCreateInfo {
+            Rasterization = PipelineRasterizationState.Default,
+            DepthStencil = PipelineDepthStencilState.Default
+        });
+    }
+
+    private static GraphicsPipeline CreatePipeline(
+        RenderPass renderPass, VulkanVertexFragmentShaderDelegation shader, GraphicsPipelineCreateInfo createInfo
+    ) {
+        PipelineShaderStage[] stages = new PipelineShaderStage[] {
+            new PipelineShaderStage(ShaderStageFlags.Vertex, shader.Module, shader.Vertex.Guid.ToString()),
+            new PipelineShaderStage(ShaderStageFlags.Fragment, shader.Module, shader.Fragment.Guid.ToString())
+        };
+
+        return new GraphicsPipeline(renderPass, shader.PipelineLayout, stages, PipelineCreateFlags.None, createInfo);
+    }
+
+    private static void ValidateWithoutFeatures(GraphicsPipelineCreateInfo createInfo) {
+        InteropResult<None> result = default;
+        GraphicsPipeline.UseRawCreateInfo(createInfo, raw => result = InteropValidateWithoutFeatures(raw));
+
+        if (!result.TryGetValue(out _, out ResultError error))
+            error.ThrowAndDispose();
+    }
+
+}
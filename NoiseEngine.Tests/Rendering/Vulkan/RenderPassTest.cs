@@ -0,0 +1,155 @@
+﻿using NoiseEngine.Rendering;
+using NoiseEngine.Rendering.Vulkan;
+using NoiseEngine.Tests.Environments;
+using NoiseEngine.Tests.Fixtures;
+using System;
+
+namespace NoiseEngine.Tests.Rendering.Vulkan;
+
+public class RenderPassTest : ApplicationTestEnvironment {
+
+    public RenderPassTest(ApplicationFixture fixture) : base(fixture) {
+    }
+
+    [FactRequire(TestRequirements.Vulkan)]
+    public void MultipleSubpasses() {
+        foreach (VulkanDevice device in VulkanDevices) {
+            Texture2D[] textures = new Texture2D[3];
+            for (int i = 0; i < textures.Length; i++) {
+                textures[i] = new Texture2D(
+                    device, TextureUsage.TransferAll | TextureUsage.ColorAttachment | TextureUsage.InputAttachment,
+                    4, 4, TextureFormat.R8G8B8A8_UNORM
+                );
+            }
+
+            DeferredRenderPass renderPass = new DeferredRenderPass(device, new RenderTexture(textures[2]), 2);
+
+            Span<VulkanImageViewCreateInfo> attachments = stackalloc VulkanImageViewCreateInfo[textures.Length];
+            for (int i = 0; i < textures.Length; i++) {
+                attachments[i] = new VulkanImageViewCreateInfo(textures[i].Handle, 0, VulkanImageViewType.Type2D,
+                    new ComponentMapping(
+                        ComponentSwizzle.Identity, ComponentSwizzle.Identity, ComponentSwizzle.Identity,
+                        ComponentSwizzle.Identity
+                    ),
+                VulkanImageAspect.Color, 0, 1, 0, 1);
+            }
+
+            _ = new Framebuffer(renderPass, 4, 4, 1, attachments);
+            Assert.Throws<InvalidOperationException>(() => new Framebuffer(renderPass, 4, 4, 1, attachments[1..]));
+        }
+    }
+
+    [FactRequire(TestRequirements.Vulkan)]
+    public void MultipleColorAttachments() {
+        foreach (VulkanDevice device in VulkanDevices) {
+            Texture2D[] textures = new Texture2D[2];
+            for (int i = 0; i < textures.Length; i++) {
+                textures[i] = new Texture2D(
+                    device, TextureUsage.TransferAll | TextureUsage.ColorAttachment, 4, 4, TextureFormat.R8G8B8A8_UNORM
+                );
+            }
+
+            MultipleTargetRenderPass renderPass = new MultipleTargetRenderPass(
+                device, new RenderTexture(textures[0]), textures.Length
+            );
+
+            Span<VulkanImageViewCreateInfo> attachments = stackalloc VulkanImageViewCreateInfo[textures.Length];
+            for (int i = 0; i < textures.Length; i++) {
+                attachments[i] = new VulkanImageViewCreateInfo(textures[i].Handle, 0, VulkanImageViewType.Type2D,
+                    new ComponentMapping(
+                        ComponentSwizzle.Identity, ComponentSwizzle.Identity, ComponentSwizzle.Identity,
+                        ComponentSwizzle.Identity
+                    ),
+                VulkanImageAspect.Color, 0, 1, 0, 1);
+            }
+
+            _ = new Framebuffer(renderPass, 4, 4, 1, attachments);
+            Assert.Throws<InvalidOperationException>(() => new Framebuffer(renderPass, 4, 4, 1, attachments[1..]));
+        }
+    }
+
+    [FactRequire(TestRequirements.Vulkan)]
+    public void InvalidAttachmentReference() {
+        foreach (VulkanDevice device in VulkanDevices) {
+            Texture2D texture = new Texture2D(
+                device, TextureUsage.TransferAll | TextureUsage.ColorAttachment, 4, 4, TextureFormat.R8G8B8A8_UNORM
+            );
+
+            Assert.Throws<InvalidOperationException>(
+                () => new DeferredRenderPass(device, new RenderTexture(texture), 3)
+            );
+        }
+    }
+
+    private class MultipleTargetRenderPass : RenderPass {
+
+        public MultipleTargetRenderPass(
+            VulkanDevice device, ICameraRenderTarget renderTarget, int colorAttachmentCount
+        ) : base(device, renderTarget, CreateDescription(colorAttachmentCount)) {
+        }
+
+        private static RenderPassDescription CreateDescription(int colorAttachmentCount) {
+            AttachmentDescription[] attachments = new AttachmentDescription[colorAttachmentCount];
+            AttachmentReference[] references = new AttachmentReference[colorAttachmentCount];
+            for (int i = 0; i < colorAttachmentCount; i++) {
+                attachments[i] = new AttachmentDescription(
+                    0, TextureFormat.R8G8B8A8_UNORM, 1, AttachmentLoadOp.Clear, AttachmentStoreOp.Store,
+                    AttachmentLoadOp.DontCare, AttachmentStoreOp.DontCare, VulkanImageLayout.Undefined,
+                    VulkanImageLayout.TransferSourceOptimal
+                );
+                references[i] = new AttachmentReference((uint)i, VulkanImageLayout.ColorAttachmentOptimal);
+            }
+
+            return new RenderPassDescription {
+                Attachments = attachments,
+                Subpasses = new SubpassDescription[] { new SubpassDescription(references) },
+                Dependencies = Array.Empty<SubpassDependency>()
+            };
+        }
+
+    }
+
+    private class DeferredRenderPass : RenderPass {
+
+        public DeferredRenderPass(
+            VulkanDevice device, ICameraRenderTarget renderTarget, uint outputAttachment
+        ) : base(device, renderTarget, CreateDescription(outputAttachment)) {
+        }
+
+        private static RenderPassDescription CreateDescription(uint outputAttachment) {
+            AttachmentDescription attachment = new AttachmentDescription(
+                0, TextureFormat.R8G8B8A8_UNORM, 1, AttachmentLoadOp.Clear, AttachmentStoreOp.Store,
+                AttachmentLoadOp.DontCare, AttachmentStoreOp.DontCare, VulkanImageLayout.Undefined,
+                VulkanImageLayout.TransferSourceOptimal
+            );
+
+            return new RenderPassDescription {
+                Attachments = new AttachmentDescription[] { attachment, attachment, attachment },
+                Subpasses = new SubpassDescription[] {
+                    new SubpassDescription(new AttachmentReference[] {
+                        new AttachmentReference(0, VulkanImageLayout.ColorAttachmentOptimal),
+                        new AttachmentReference(1, VulkanImageLayout.ColorAttachmentOptimal)
+                    }),
+                    new SubpassDescription(
+                        new AttachmentReference[] {
+                            new AttachmentReference(0, VulkanImageLayout.ShaderReadOnlyOptimal),
+                            new AttachmentReference(1, VulkanImageLayout.ShaderReadOnlyOptimal)
+                        },
+                        new AttachmentReference[] {
+                            new AttachmentReference(outputAttachment, VulkanImageLayout.ColorAttachmentOptimal)
+                        },
+                        Array.Empty<AttachmentReference>(), null, Array.Empty<uint>()
+                    )
+                },
+                Dependencies = new SubpassDependency[] {
+                    new SubpassDependency(
+                        0, 1, PipelineStageFlags.ColorAttachmentOutput, PipelineStageFlags.FragmentShader,
+                        AccessFlags.ColorAttachmentWrite, AccessFlags.InputAttachmentRead, DependencyFlags.ByRegion
+                    )
+                }
+            };
+        }
+
+    }
+
+}
@@ -151,6 +151,10 @@ public static class Application {
             jobsInvoker?.Dispose();
             jobsInvoker = null;
 
+            if (graphicsInstance is not null) {
+                foreach (GraphicsDevice device in graphicsInstance.Devices)
+                    device.SaveCaches();
+            }
             graphicsInstance = null;
 
             // Tries to collect graphics resources. This is not required, but it relieves the operating system and
@@ -1,7 +1,9 @@
 ﻿using System;
 using System.Runtime.InteropServices;
 using System.Text;
+using NoiseEngine.Interop.InteropMarshalling;
 using NoiseEngine.Logging;
+using NoiseEngine.Rendering;
 using NoiseEngine.Threading;
 
 namespace NoiseEngine.Interop.Logging;
@@ -12,12 +14,16 @@ internal static partial class InteropLogging {
     private static AtomicBool terminated;
 
     private static LoggerHandlerDelegate? LoggerHandler { get; set; }
+    private static ValidationHandlerDelegate? ValidationHandler { get; set; }
 
     private static Logger? Logger { get; set; }
 
     [UnmanagedFunctionPointer(InteropConstants.CallingConvention)]
     private delegate void LoggerHandlerDelegate(LogData logData);
 
+    [UnmanagedFunctionPointer(InteropConstants.CallingConvention)]
+    private delegate void ValidationHandlerDelegate(ValidationLogData validationLogData);
+
     public static void Initialize(Logger logger) {
         if (initialized.Exchange(true))
             throw new InvalidOperationException("Cannot initialize native logging more than once.");
@@ -26,7 +32,8 @@ internal static partial class InteropLogging {
 
         // Prevents GC cleanup (https://stackoverflow.com/a/43227979/14677292)
         LoggerHandler = LoggerHandlerImpl;
-        _ = InteropInitialize(LoggerHandler).Value;
+        ValidationHandler = ValidationHandlerImpl;
+        _ = InteropInitialize(LoggerHandler, ValidationHandler).Value;
     }
 
     public static void Terminate() {
@@ -41,7 +48,9 @@ internal static partial class InteropLogging {
     }
 
     [InteropImport("logging_logging_initialize")]
-    private static partial InteropResult<None> InteropInitialize(LoggerHandlerDelegate handler);
+    private static partial InteropResult<None> InteropInitialize(
+        LoggerHandlerDelegate handler, ValidationHandlerDelegate validationHandler
+    );
 
     [InteropImport("logging_logging_terminate")]
     private static partial void InteropTerminate();
@@ -52,4 +61,31 @@ internal static partial class InteropLogging {
         Logger!.Log(level, message);
     }
 
+    private static void ValidationHandlerImpl(ValidationLogData data) {
+        ReadOnlySpan<ValidationLogObject> rawObjects = data.Objects.AsSpan();
+        GraphicsValidationObject[] objects = new GraphicsValidationObject[rawObjects.Length];
+        for (int i = 0; i < objects.Length; i++) {
+            string name = rawObjects[i].Name.ToString();
+            objects[i] = new GraphicsValidationObject(
+                rawObjects[i].ObjectType.ToString(), rawObjects[i].Handle, name.Length == 0 ? null : name
+            );
+        }
+
+        string messageIdName = Encoding.UTF8.GetString(data.MessageIdName.AsSpan());
+        GraphicsValidation.OnMessageReceived(new GraphicsValidationMessage(
+            (NoiseEngine.Logging.LogLevel)data.Level, (GraphicsValidationMessageType)data.MessageType,
+            messageIdName.Length == 0 ? null : messageIdName, data.MessageIdNumber,
+            Encoding.UTF8.GetString(data.Message.AsSpan()), objects, ToStrings(data.QueueLabels),
+            ToStrings(data.CommandBufferLabels)
+        ));
+    }
+
+    private static string[] ToStrings(InteropReadOnlySpan<InteropString> strings) {
+        ReadOnlySpan<InteropString> span = strings.AsSpan();
+        string[] result = new string[span.Length];
+        for (int i = 0; i < result.Length; i++)
+            result[i] = span[i].ToString();
+        return result;
+    }
+
 }
@@ -0,0 +1,19 @@
+﻿using System.Runtime.InteropServices;
+using NoiseEngine.Interop.InteropMarshalling;
+
+namespace NoiseEngine.Interop.Logging;
+
+/// <remarks>Strings are owned by the native side and must not be disposed.</remarks>
+[StructLayout(LayoutKind.Sequential)]
+internal ref struct ValidationLogData {
+
+    public LogLevel Level { get; init; }
+    public uint MessageType { get; init; }
+    public int MessageIdNumber { get; init; }
+    public InteropReadOnlySpan<byte> MessageIdName { get; init; }
+    public InteropReadOnlySpan<byte> Message { get; init; }
+    public InteropReadOnlySpan<ValidationLogObject> Objects { get; init; }
+    public InteropReadOnlySpan<InteropString> QueueLabels { get; init; }
+    public InteropReadOnlySpan<InteropString> CommandBufferLabels { get; init; }
+
+}
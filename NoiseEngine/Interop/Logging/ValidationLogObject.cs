@@ -0,0 +1,13 @@
+﻿using System.Runtime.InteropServices;
+using NoiseEngine.Interop.InteropMarshalling;
+
+namespace NoiseEngine.Interop.Logging;
+
+[StructLayout(LayoutKind.Sequential)]
+internal struct ValidationLogObject {
+
+    public InteropString ObjectType { get; init; }
+    public ulong Handle { get; init; }
+    public InteropString Name { get; init; }
+
+}
@@ -1,5 +1,7 @@
-﻿using NoiseEngine.Rendering;
+﻿using NoiseEngine.Interop.InteropMarshalling;
+using NoiseEngine.Rendering;
 using NoiseEngine.Rendering.Buffers;
+using System;
 
 namespace NoiseEngine.Interop.Rendering.Buffers;
 
@@ -13,4 +15,33 @@ internal static partial class GraphicsCommandBufferInterop {
         InteropHandle<GraphicsCommandBuffer> handle
     );
 
+    [InteropImport("rendering_buffers_command_buffer_interop_execute_with_timeline_semaphores")]
+    public static partial InteropResult<InteropHandle<GraphicsFence>> ExecuteWithTimelineSemaphores(
+        InteropHandle<GraphicsCommandBuffer> handle, ReadOnlySpan<GraphicsTimelineSemaphoreSubmit> waits,
+        ReadOnlySpan<GraphicsTimelineSemaphoreSubmit> signals
+    );
+
+    [InteropImport("rendering_buffers_command_buffer_interop_record")]
+    public static partial InteropResult<None> Record(
+        InteropHandle<GraphicsCommandBuffer> handle, ReadOnlySpan<byte> data, bool simultaneousExecute
+    );
+
+    [InteropImport("rendering_buffers_command_buffer_interop_set_name")]
+    public static partial InteropResult<None> SetName(InteropHandle<GraphicsCommandBuffer> handle, string name);
+
+    [InteropImport("rendering_buffers_command_buffer_interop_get_checked_decoding")]
+    public static partial bool GetCheckedDecoding();
+
+    [InteropImport("rendering_buffers_command_buffer_interop_set_checked_decoding")]
+    public static partial void SetCheckedDecoding(bool checkedDecoding);
+
+    [InteropImport("rendering_buffers_command_buffer_interop_get_capture_directory")]
+    public static partial InteropOption<InteropString> GetCaptureDirectory();
+
+    [InteropImport("rendering_buffers_command_buffer_interop_set_capture_directory")]
+    public static partial void SetCaptureDirectory(InteropOption<InteropString> directory);
+
+    [InteropImport("rendering_buffers_command_buffer_interop_disassemble")]
+    public static partial InteropResult<InteropString> Disassemble(ReadOnlySpan<byte> data);
+
 }
@@ -5,5 +5,5 @@ namespace NoiseEngine.Interop.Rendering.Buffers;
 
 [StructLayout(LayoutKind.Sequential)]
 internal readonly record struct GraphicsCommandBufferUsage(
-    InteropBool Graphics, InteropBool Computing, InteropBool Transfer
+    InteropBool Graphics, InteropBool Computing, InteropBool Transfer, InteropBool Dedicated
 );
@@ -0,0 +1,29 @@
+﻿using NoiseEngine.Interop.InteropMarshalling;
+using NoiseEngine.Rendering;
+using System;
+
+namespace NoiseEngine.Interop.Rendering;
+
+internal static partial class GraphicsTimelineSemaphoreInterop {
+
+    [InteropImport("rendering_timeline_semaphore_interop_destroy")]
+    public static partial void Destroy(InteropHandle<GraphicsTimelineSemaphore> handle);
+
+    [InteropImport("rendering_timeline_semaphore_interop_value")]
+    public static partial InteropResult<ulong> Value(InteropHandle<GraphicsTimelineSemaphore> semaphore);
+
+    [InteropImport("rendering_timeline_semaphore_interop_wait")]
+    public static partial InteropResult<InteropBool> Wait(
+        InteropHandle<GraphicsTimelineSemaphore> semaphore, ulong value, ulong nanosecondsTimeout
+    );
+
+    [InteropImport("rendering_timeline_semaphore_interop_signal")]
+    public static partial InteropResult<None> Signal(InteropHandle<GraphicsTimelineSemaphore> semaphore, ulong value);
+
+    [InteropImport("rendering_timeline_semaphore_interop_wait_multiple")]
+    public static partial InteropResult<InteropBool> WaitMultiple(
+        ReadOnlySpan<InteropHandle<GraphicsTimelineSemaphore>> semaphores, ReadOnlySpan<ulong> values, bool waitAll,
+        ulong nanosecondsTimeout
+    );
+
+}
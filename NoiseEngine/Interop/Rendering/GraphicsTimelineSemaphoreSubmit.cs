@@ -0,0 +1,9 @@
+﻿using NoiseEngine.Rendering;
+using System.Runtime.InteropServices;
+
+namespace NoiseEngine.Interop.Rendering;
+
+[StructLayout(LayoutKind.Sequential)]
+internal readonly record struct GraphicsTimelineSemaphoreSubmit(
+    InteropHandle<GraphicsTimelineSemaphore> Semaphore, ulong Value
+);
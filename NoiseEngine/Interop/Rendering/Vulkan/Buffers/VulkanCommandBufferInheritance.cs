@@ -0,0 +1,9 @@
+﻿using NoiseEngine.Rendering.Vulkan;
+using System.Runtime.InteropServices;
+
+namespace NoiseEngine.Interop.Rendering.Vulkan.Buffers;
+
+[StructLayout(LayoutKind.Sequential)]
+internal readonly record struct VulkanCommandBufferInheritance(
+    InteropHandle<RenderPass> RenderPass, uint Subpass, ulong Framebuffer, uint Width, uint Height
+);
@@ -0,0 +1,10 @@
+﻿using NoiseEngine.Rendering.Vulkan.Buffers;
+
+namespace NoiseEngine.Interop.Rendering.Vulkan.Buffers;
+
+internal static partial class VulkanCommandBufferInterop {
+
+    [InteropImport("rendering_vulkan_buffers_command_buffer_interop_destroy_secondary")]
+    public static partial void DestroySecondary(InteropHandle<VulkanSecondaryCommandBuffer> handle);
+
+}
@@ -1,4 +1,5 @@
 ﻿using NoiseEngine.Interop.InteropMarshalling;
+using NoiseEngine.Mathematics;
 using NoiseEngine.Rendering.Vulkan;
 using System.Runtime.InteropServices;
 
@@ -10,5 +11,11 @@ internal readonly ref struct GraphicsPipelineCreateInfoRaw {
     public InteropReadOnlySpan<VertexInputBindingDescription> VertexInputBindingDescription { get; init; }
     public InteropReadOnlySpan<VertexInputAttributeDescription> VertexInputAttributeDescription { get; init; }
     public PrimitiveTopology PrimitiveTopology { get; init; }
+    public PipelineRasterizationState Rasterization { get; init; }
+    public PipelineDepthStencilState DepthStencil { get; init; }
+    public PipelineMultisampleState Multisample { get; init; }
+    public InteropReadOnlySpan<PipelineColorBlendAttachmentState> ColorBlendAttachments { get; init; }
+    public Vector4<float> BlendConstants { get; init; }
+    public uint Subpass { get; init; }
 
 };
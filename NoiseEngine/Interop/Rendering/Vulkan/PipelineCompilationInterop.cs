@@ -0,0 +1,56 @@
+﻿using NoiseEngine.Interop.InteropMarshalling;
+using NoiseEngine.Rendering.Vulkan;
+using System;
+
+namespace NoiseEngine.Interop.Rendering.Vulkan;
+
+internal static partial class PipelineCompilationInterop {
+
+    [InteropImport("rendering_vulkan_pipeline_compilation_interop_create_builder")]
+    public static partial InteropHandle<PipelineCompilationBuilder> CreateBuilder();
+
+    [InteropImport("rendering_vulkan_pipeline_compilation_interop_destroy_builder")]
+    public static partial void DestroyBuilder(InteropHandle<PipelineCompilationBuilder> handle);
+
+    [InteropImport("rendering_vulkan_pipeline_compilation_interop_builder_add_graphics")]
+    public static partial void BuilderAddGraphics(
+        InteropHandle<PipelineCompilationBuilder> builder, InteropHandle<RenderPass> renderPass,
+        InteropHandle<PipelineLayout> layout, ReadOnlySpan<PipelineShaderStageRaw> stages, PipelineCreateFlags flags,
+        GraphicsPipelineCreateInfoRaw createInfo
+    );
+
+    [InteropImport("rendering_vulkan_pipeline_compilation_interop_builder_add_compute")]
+    public static partial void BuilderAddCompute(
+        InteropHandle<PipelineCompilationBuilder> builder, InteropHandle<PipelineLayout> layout,
+        PipelineShaderStageRaw stage, PipelineCreateFlags flags
+    );
+
+    /// <summary>
+    /// Starts compilation of pipelines added to <paramref name="builder"/>.
+    /// </summary>
+    /// <remarks><paramref name="builder"/> is consumed by this method.</remarks>
+    /// <param name="builder">Handle of builder.</param>
+    /// <returns>Handle of started compilation.</returns>
+    [InteropImport("rendering_vulkan_pipeline_compilation_interop_start")]
+    public static partial InteropHandle<PipelineCompilation> Start(InteropHandle<PipelineCompilationBuilder> builder);
+
+    [InteropImport("rendering_vulkan_pipeline_compilation_interop_destroy")]
+    public static partial void Destroy(InteropHandle<PipelineCompilation> handle);
+
+    [InteropImport("rendering_vulkan_pipeline_compilation_interop_wait")]
+    public static partial InteropBool Wait(InteropHandle<PipelineCompilation> compilation, ulong nanosecondsTimeout);
+
+    [InteropImport("rendering_vulkan_pipeline_compilation_interop_is_signaled")]
+    public static partial InteropBool IsSignaled(InteropHandle<PipelineCompilation> compilation);
+
+    [InteropImport("rendering_vulkan_pipeline_compilation_interop_wait_multiple")]
+    public static partial InteropBool WaitMultiple(
+        ReadOnlySpan<InteropHandle<PipelineCompilation>> compilations, bool waitAll, ulong nanosecondsTimeout
+    );
+
+    [InteropImport("rendering_vulkan_pipeline_compilation_interop_take_results")]
+    public static partial InteropResult<InteropArray<InteropResult<InteropHandle<Pipeline>>>> TakeResults(
+        InteropHandle<PipelineCompilation> compilation
+    );
+
+}
@@ -7,4 +7,7 @@ internal static partial class PipelineInterop {
     [InteropImport("rendering_vulkan_pipeline_destroy")]
     public static partial void Destroy(InteropHandle<Pipeline> handle);
 
+    [InteropImport("rendering_vulkan_pipeline_set_name")]
+    public static partial InteropResult<None> SetName(InteropHandle<Pipeline> handle, string name);
+
 }
@@ -7,11 +7,15 @@ namespace NoiseEngine.Interop.Rendering.Vulkan;
 
 [StructLayout(LayoutKind.Sequential)]
 internal record struct PipelineShaderStageRaw(
-    ShaderStageFlags Stage, InteropHandle<ShaderModule> Module, InteropString Name
+    ShaderStageFlags Stage, InteropHandle<ShaderModule> Module, InteropString Name,
+    nint SpecializationMapEntries, int SpecializationMapEntryCount, nint SpecializationData, int SpecializationDataSize
 ) : IDisposable {
 
-    public PipelineShaderStageRaw(PipelineShaderStage stage)
-        : this(stage.Stage, stage.Module.Handle, new InteropString(stage.Name)) {
+    public PipelineShaderStageRaw(PipelineShaderStage stage) : this(
+        stage.Stage, stage.Module.Handle, new InteropString(stage.Name),
+        stage.Specialization?.MapEntriesPointer ?? 0, stage.Specialization?.MapEntries.Length ?? 0,
+        stage.Specialization?.DataPointer ?? 0, stage.Specialization?.Data.Length ?? 0
+    ) {
     }
 
     public void Dispose() {
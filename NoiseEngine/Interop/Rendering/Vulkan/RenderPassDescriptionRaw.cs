@@ -0,0 +1,14 @@
+﻿using NoiseEngine.Interop.InteropMarshalling;
+using NoiseEngine.Rendering.Vulkan;
+using System.Runtime.InteropServices;
+
+namespace NoiseEngine.Interop.Rendering.Vulkan;
+
+[StructLayout(LayoutKind.Sequential)]
+internal readonly ref struct RenderPassDescriptionRaw {
+
+    public InteropReadOnlySpan<AttachmentDescription> Attachments { get; init; }
+    public InteropReadOnlySpan<SubpassDescriptionRaw> Subpasses { get; init; }
+    public InteropReadOnlySpan<SubpassDependency> Dependencies { get; init; }
+
+}
@@ -10,7 +10,15 @@ internal static partial class RenderPassInterop {
         InteropHandle<GraphicsDevice> device, RenderPassCreateInfo createInfo
     );
 
+    [InteropImport("rendering_vulkan_render_pass_create_with_description")]
+    public static partial InteropResult<InteropHandle<RenderPass>> CreateWithDescription(
+        InteropHandle<GraphicsDevice> device, RenderPassDescriptionRaw description
+    );
+
     [InteropImport("rendering_vulkan_render_pass_destroy")]
     public static partial void Destroy(InteropHandle<RenderPass> handle);
 
+    [InteropImport("rendering_vulkan_render_pass_set_name")]
+    public static partial InteropResult<None> SetName(InteropHandle<RenderPass> handle, string name);
+
 }
@@ -0,0 +1,11 @@
+﻿using NoiseEngine.Interop.InteropMarshalling;
+using NoiseEngine.Mathematics;
+using NoiseEngine.Rendering.Vulkan;
+using System.Runtime.InteropServices;
+
+namespace NoiseEngine.Interop.Rendering.Vulkan;
+
+[StructLayout(LayoutKind.Sequential)]
+internal readonly record struct ShaderEntryPointRaw(
+    InteropString Name, ShaderStageFlags Stage, InteropBool HasWorkgroupSize, Vector3<uint> WorkgroupSize
+);
@@ -14,4 +14,7 @@ internal static partial class ShaderModuleInterop {
     [InteropImport("rendering_vulkan_shader_module_destroy")]
     public static partial void Destroy(InteropHandle<ShaderModule> handle);
 
+    [InteropImport("rendering_vulkan_shader_module_get_reflection")]
+    public static partial InteropOption<ShaderReflectionRaw> GetReflection(InteropHandle<ShaderModule> handle);
+
 }
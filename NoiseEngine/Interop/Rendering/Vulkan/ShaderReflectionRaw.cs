@@ -0,0 +1,25 @@
+﻿using NoiseEngine.Rendering.Vulkan;
+using System;
+using System.Runtime.InteropServices;
+
+namespace NoiseEngine.Interop.Rendering.Vulkan;
+
+[StructLayout(LayoutKind.Sequential)]
+internal readonly record struct ShaderReflectionRaw(
+    InteropArray<ShaderEntryPointRaw> EntryPoints,
+    InteropArray<ShaderBinding> Bindings,
+    InteropArray<PushConstantRange> PushConstantRanges,
+    InteropArray<ShaderVertexInput> VertexInputs
+) : IDisposable {
+
+    public void Dispose() {
+        foreach (ShaderEntryPointRaw entryPoint in EntryPoints)
+            entryPoint.Name.Dispose();
+
+        EntryPoints.Dispose();
+        Bindings.Dispose();
+        PushConstantRanges.Dispose();
+        VertexInputs.Dispose();
+    }
+
+}
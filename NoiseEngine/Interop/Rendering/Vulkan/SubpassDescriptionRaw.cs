@@ -0,0 +1,32 @@
+﻿using NoiseEngine.Rendering.Vulkan;
+using System;
+using System.Runtime.InteropServices;
+
+namespace NoiseEngine.Interop.Rendering.Vulkan;
+
+[StructLayout(LayoutKind.Sequential)]
+internal readonly record struct SubpassDescriptionRaw(
+    InteropArray<AttachmentReference> InputAttachments,
+    InteropArray<AttachmentReference> ColorAttachments,
+    InteropArray<AttachmentReference> ResolveAttachments,
+    AttachmentReference DepthStencilAttachment,
+    InteropArray<uint> PreserveAttachments
+) : IDisposable {
+
+    public SubpassDescriptionRaw(SubpassDescription description) : this(
+        new InteropArray<AttachmentReference>(description.InputAttachments),
+        new InteropArray<AttachmentReference>(description.ColorAttachments),
+        new InteropArray<AttachmentReference>(description.ResolveAttachments),
+        description.DepthStencilAttachment ?? AttachmentReference.Unused,
+        new InteropArray<uint>(description.PreserveAttachments)
+    ) {
+    }
+
+    public void Dispose() {
+        InputAttachments.Dispose();
+        ColorAttachments.Dispose();
+        ResolveAttachments.Dispose();
+        PreserveAttachments.Dispose();
+    }
+
+}
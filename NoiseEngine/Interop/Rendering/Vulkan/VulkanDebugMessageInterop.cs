@@ -0,0 +1,11 @@
+﻿namespace NoiseEngine.Interop.Rendering.Vulkan;
+
+internal static partial class VulkanDebugMessageInterop {
+
+    [InteropImport("rendering_vulkan_debug_message_interop_get_capture_errors")]
+    public static partial bool GetCaptureErrors();
+
+    [InteropImport("rendering_vulkan_debug_message_interop_set_capture_errors")]
+    public static partial void SetCaptureErrors(bool capture);
+
+}
@@ -0,0 +1,16 @@
+﻿using NoiseEngine.Interop.InteropMarshalling;
+using System;
+
+namespace NoiseEngine.Interop.Rendering.Vulkan;
+
+internal readonly record struct VulkanDeviceFeatureValue(
+    InteropString Name,
+    InteropBool Supported,
+    InteropBool Enabled
+) : IDisposable {
+
+    public void Dispose() {
+        Name.Dispose();
+    }
+
+}
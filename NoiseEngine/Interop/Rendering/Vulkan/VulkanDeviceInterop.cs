@@ -1,7 +1,10 @@
 ﻿using NoiseEngine.Interop.InteropMarshalling;
 using NoiseEngine.Interop.Rendering.Buffers;
+using NoiseEngine.Interop.Rendering.Vulkan.Buffers;
 using NoiseEngine.Rendering;
 using NoiseEngine.Rendering.Buffers;
+using NoiseEngine.Rendering.Vulkan;
+using NoiseEngine.Rendering.Vulkan.Buffers;
 using System;
 
 namespace NoiseEngine.Interop.Rendering.Vulkan;
@@ -17,16 +20,82 @@ internal static partial class VulkanDeviceInterop {
     /// <remarks>This method must be synchronized by caller.</remarks>
     /// <param name="device">Handle of existing device.</param>
     /// <param name="enabledExtensions">Extensions to enable.</param>
+    /// <param name="enabledFeatures">Features to enable, in addition to optional ones used by the engine.</param>
+    /// <param name="queues">Queues to create, or empty to create default count of queues in every family.</param>
     /// <returns><see cref="InteropResult{None}"/> with potential error.</returns>
     [InteropImport("rendering_vulkan_device_interop_initialize")]
     public static partial InteropResult<None> Initialize(
-        InteropHandle<GraphicsDevice> device, ReadOnlySpan<InteropString> enabledExtensions
+        InteropHandle<GraphicsDevice> device, ReadOnlySpan<InteropString> enabledExtensions,
+        ReadOnlySpan<InteropString> enabledFeatures, ReadOnlySpan<VulkanQueueConfiguration> queues
     );
 
+    [InteropImport("rendering_vulkan_device_interop_get_id")]
+    public static partial ulong GetId(InteropHandle<GraphicsDevice> device);
+
+    [InteropImport("rendering_vulkan_device_interop_is_lost")]
+    public static partial bool IsLost(InteropHandle<GraphicsDevice> device);
+
+    /// <summary>
+    /// Creates new not initialized device for the same physical device as <paramref name="device"/>.
+    /// </summary>
+    /// <param name="device">Handle of existing device.</param>
+    /// <returns>Handle of new device.</returns>
+    [InteropImport("rendering_vulkan_device_interop_recreate")]
+    public static partial InteropHandle<GraphicsDevice> Recreate(InteropHandle<GraphicsDevice> device);
+
     [InteropImport("rendering_vulkan_device_interop_create_command_buffer")]
     public static partial InteropResult<InteropHandle<GraphicsCommandBuffer>> CreateCommandBuffer(
         InteropHandle<GraphicsDevice> device, ReadOnlySpan<byte> data, GraphicsCommandBufferUsage usage,
         bool simultaneousExecute
     );
 
+    /// <summary>
+    /// Records secondary command buffers in parallel, from consecutive streams in <paramref name="data"/> with
+    /// given <paramref name="lengths"/>.
+    /// </summary>
+    [InteropImport("rendering_vulkan_device_interop_create_secondary_command_buffers")]
+    public static partial InteropResult<InteropArray<InteropHandle<VulkanSecondaryCommandBuffer>>>
+        CreateSecondaryCommandBuffers(
+            InteropHandle<GraphicsDevice> device, ReadOnlySpan<byte> data, ReadOnlySpan<int> lengths,
+            GraphicsCommandBufferUsage usage, bool simultaneousExecute, VulkanCommandBufferInheritance inheritance
+        );
+
+    [InteropImport("rendering_vulkan_device_interop_load_pipeline_cache")]
+    public static partial InteropResult<InteropBool> LoadPipelineCache(
+        InteropHandle<GraphicsDevice> device, ReadOnlySpan<byte> data
+    );
+
+    [InteropImport("rendering_vulkan_device_interop_get_pipeline_cache_data")]
+    public static partial InteropResult<InteropArray<byte>> GetPipelineCacheData(InteropHandle<GraphicsDevice> device);
+
+    [InteropImport("rendering_vulkan_device_interop_get_supported_sample_counts")]
+    public static partial uint GetSupportedSampleCounts(InteropHandle<GraphicsDevice> device, bool depthStencil);
+
+    [InteropImport("rendering_vulkan_device_interop_create_timeline_semaphore")]
+    public static partial InteropResult<InteropHandle<GraphicsTimelineSemaphore>> CreateTimelineSemaphore(
+        InteropHandle<GraphicsDevice> device, ulong initialValue
+    );
+
+    [InteropImport("rendering_vulkan_device_interop_set_object_name")]
+    public static partial InteropResult<None> SetObjectName(
+        InteropHandle<GraphicsDevice> device, ObjectType objectType, ulong handle, string name
+    );
+
+    [InteropImport("rendering_vulkan_device_interop_get_features")]
+    public static partial InteropArray<VulkanDeviceFeatureValue> GetFeatures(InteropHandle<GraphicsDevice> device);
+
+    [InteropImport("rendering_vulkan_device_interop_get_limits")]
+    public static partial GraphicsDeviceLimits GetLimits(InteropHandle<GraphicsDevice> device);
+
+    [InteropImport("rendering_vulkan_device_interop_is_image_format_supported")]
+    public static partial bool IsImageFormatSupported(
+        InteropHandle<GraphicsDevice> device, TextureFormat format, TextureUsage usage
+    );
+
+    [InteropImport("rendering_vulkan_device_interop_get_memory_heaps")]
+    public static partial InteropArray<VulkanMemoryHeapValue> GetMemoryHeaps(InteropHandle<GraphicsDevice> device);
+
+    [InteropImport("rendering_vulkan_device_interop_get_queue_families")]
+    public static partial InteropArray<VulkanQueueFamilyValue> GetQueueFamilies(InteropHandle<GraphicsDevice> device);
+
 }
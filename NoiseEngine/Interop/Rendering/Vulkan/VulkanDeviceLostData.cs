@@ -0,0 +1,13 @@
+﻿using System.Runtime.InteropServices;
+using NoiseEngine.Interop.InteropMarshalling;
+
+namespace NoiseEngine.Interop.Rendering.Vulkan;
+
+/// <remarks>Strings are owned by the native side and must not be disposed.</remarks>
+[StructLayout(LayoutKind.Sequential)]
+internal ref struct VulkanDeviceLostData {
+
+    public InteropReadOnlySpan<byte> Message { get; init; }
+    public InteropReadOnlySpan<byte> Fault { get; init; }
+
+}
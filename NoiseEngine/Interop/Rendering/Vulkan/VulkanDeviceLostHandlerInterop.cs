@@ -0,0 +1,8 @@
+﻿namespace NoiseEngine.Interop.Rendering.Vulkan;
+
+internal static partial class VulkanDeviceLostHandlerInterop {
+
+    [InteropImport("rendering_vulkan_device_lost_handler_interop_initialize")]
+    public static partial InteropResult<None> Initialize(VulkanDeviceLostHandlerRaw handler);
+
+}
@@ -0,0 +1,9 @@
+﻿using System.Runtime.InteropServices;
+using static NoiseEngine.Rendering.Vulkan.VulkanDeviceLostHandler;
+
+namespace NoiseEngine.Interop.Rendering.Vulkan;
+
+[StructLayout(LayoutKind.Sequential)]
+internal readonly record struct VulkanDeviceLostHandlerRaw(
+    LostDelegate LostHandler
+);
@@ -13,6 +13,7 @@ internal readonly record struct VulkanDeviceValue(
     Guid Guid,
     InteropBool SupportsGraphics,
     InteropBool SupportsComputing,
+    InteropBool SupportsAsyncComputing,
     InteropBool SupportsPresentation,
     InteropHandle<GraphicsDevice> Handle
 ) : IDisposable {
@@ -47,6 +48,7 @@ internal readonly record struct VulkanDeviceValue(
             Guid,
             SupportsGraphics,
             SupportsComputing,
+            SupportsAsyncComputing,
             SupportsPresentation,
             Handle
         );
@@ -1,4 +1,5 @@
 ﻿using NoiseEngine.Interop.InteropMarshalling;
+using NoiseEngine.Rendering;
 using NoiseEngine.Rendering.Vulkan;
 using System;
 
@@ -20,4 +21,20 @@ internal static partial class VulkanInstanceInterop {
         InteropHandle<VulkanInstance> instance
     );
 
+    /// <summary>
+    /// Returns indices of <paramref name="devices"/> which meet requirements, ordered from the best one.
+    /// </summary>
+    /// <param name="instance">Instance of <paramref name="devices"/>.</param>
+    /// <param name="devices">Devices to rank.</param>
+    /// <param name="requiredExtensions">Extensions which must be supported.</param>
+    /// <param name="requiredFeatures">Features which must be supported.</param>
+    /// <param name="window">Window to which devices must be able to present, or zero handle.</param>
+    /// <returns><see cref="InteropResult{T}"/> with indices or potential error.</returns>
+    [InteropImport("rendering_vulkan_instance_interop_rank_devices")]
+    public static partial InteropResult<InteropArray<uint>> RankDevices(
+        InteropHandle<VulkanInstance> instance, ReadOnlySpan<InteropHandle<GraphicsDevice>> devices,
+        ReadOnlySpan<InteropString> requiredExtensions, ReadOnlySpan<InteropString> requiredFeatures,
+        InteropHandle<Window> window
+    );
+
 }
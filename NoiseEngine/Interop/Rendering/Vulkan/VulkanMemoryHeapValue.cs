@@ -0,0 +1,18 @@
+﻿using NoiseEngine.Rendering;
+
+namespace NoiseEngine.Interop.Rendering.Vulkan;
+
+internal readonly record struct VulkanMemoryHeapValue(ulong Size, uint Flags, uint MemoryProperties) {
+
+    private const uint HeapDeviceLocal = 1 << 0;
+    private const uint MemoryHostVisible = 1 << 1;
+
+    public GraphicsMemoryHeap ToGraphics() {
+        return new GraphicsMemoryHeap(
+            Size,
+            (Flags & HeapDeviceLocal) != 0,
+            (MemoryProperties & MemoryHostVisible) != 0
+        );
+    }
+
+}
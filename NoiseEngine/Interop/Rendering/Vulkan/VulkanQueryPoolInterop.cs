@@ -0,0 +1,23 @@
+﻿using NoiseEngine.Interop.InteropMarshalling;
+using NoiseEngine.Rendering;
+using System;
+
+namespace NoiseEngine.Interop.Rendering.Vulkan;
+
+internal static partial class VulkanQueryPoolInterop {
+
+    [InteropImport("rendering_vulkan_query_pool_interop_create")]
+    public static partial InteropResult<InteropHandle<GraphicsQueryPool>> Create(
+        InteropHandle<GraphicsDevice> device, GraphicsQueryType type, uint count,
+        GraphicsPipelineStatistics pipelineStatistics
+    );
+
+    [InteropImport("rendering_vulkan_query_pool_interop_destroy")]
+    public static partial void Destroy(InteropHandle<GraphicsQueryPool> handle);
+
+    [InteropImport("rendering_vulkan_query_pool_interop_get_results")]
+    public static partial InteropResult<InteropBool> GetResults(
+        InteropHandle<GraphicsQueryPool> queryPool, uint first, uint count, Span<ulong> results, bool wait
+    );
+
+}
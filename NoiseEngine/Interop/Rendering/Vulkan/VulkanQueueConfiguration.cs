@@ -0,0 +1,3 @@
+﻿namespace NoiseEngine.Interop.Rendering.Vulkan;
+
+internal readonly record struct VulkanQueueConfiguration(uint FamilyIndex, float Priority);
@@ -0,0 +1,18 @@
+﻿using NoiseEngine.Interop.InteropMarshalling;
+using NoiseEngine.Rendering;
+
+namespace NoiseEngine.Interop.Rendering.Vulkan;
+
+internal readonly record struct VulkanQueueFamilyValue(
+    uint Index,
+    uint QueueCount,
+    InteropBool Graphics,
+    InteropBool Computing,
+    InteropBool Transfer
+) {
+
+    public GraphicsQueueFamily ToGraphics() {
+        return new GraphicsQueueFamily(Index, QueueCount, Graphics, Computing, Transfer);
+    }
+
+}
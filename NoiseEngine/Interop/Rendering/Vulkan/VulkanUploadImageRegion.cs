@@ -0,0 +1,10 @@
+﻿using NoiseEngine.Rendering;
+using System.Runtime.InteropServices;
+
+namespace NoiseEngine.Interop.Rendering.Vulkan;
+
+[StructLayout(LayoutKind.Sequential)]
+internal readonly record struct VulkanUploadImageRegion(
+    TextureAspect Aspect, uint MipLevel, uint LayerStartIndex, uint LayerCount, int OffsetX, int OffsetY,
+    int OffsetZ, uint Width, uint Height, uint Depth
+);
@@ -0,0 +1,38 @@
+﻿using NoiseEngine.Interop.InteropMarshalling;
+using NoiseEngine.Rendering;
+using NoiseEngine.Rendering.Buffers;
+using System;
+
+namespace NoiseEngine.Interop.Rendering.Vulkan;
+
+internal static partial class VulkanUploadManagerInterop {
+
+    [InteropImport("rendering_vulkan_upload_manager_interop_create")]
+    public static partial InteropResult<InteropHandle<GraphicsUploadManager>> Create(
+        InteropHandle<GraphicsDevice> device, ulong capacity
+    );
+
+    [InteropImport("rendering_vulkan_upload_manager_interop_destroy")]
+    public static partial void Destroy(InteropHandle<GraphicsUploadManager> handle);
+
+    [InteropImport("rendering_vulkan_upload_manager_interop_upload_buffer")]
+    public static partial InteropResult<None> UploadBuffer(
+        InteropHandle<GraphicsUploadManager> manager, InteropHandle<GraphicsReadOnlyBuffer> buffer, ulong offset,
+        ReadOnlySpan<byte> data
+    );
+
+    [InteropImport("rendering_vulkan_upload_manager_interop_upload_image")]
+    public static partial InteropResult<None> UploadImage(
+        InteropHandle<GraphicsUploadManager> manager, InteropHandle<Texture> image, VulkanUploadImageRegion region,
+        ReadOnlySpan<byte> data
+    );
+
+    [InteropImport("rendering_vulkan_upload_manager_interop_flush")]
+    public static partial InteropResult<InteropHandle<GraphicsFence>> Flush(
+        InteropHandle<GraphicsUploadManager> manager
+    );
+
+    [InteropImport("rendering_vulkan_upload_manager_interop_wait_idle")]
+    public static partial InteropResult<None> WaitIdle(InteropHandle<GraphicsUploadManager> manager);
+
+}
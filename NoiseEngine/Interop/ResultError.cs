@@ -57,6 +57,7 @@ internal struct ResultError : IDisposable {
             ResultErrorKind.GraphicsOutOfDeviceMemory =>
                 new GraphicsOutOfDeviceMemoryException(Message, innerException),
             ResultErrorKind.GraphicsDeviceLost => new GraphicsDeviceLostException(Message, innerException),
+            ResultErrorKind.GraphicsValidation => new GraphicsValidationException(Message, innerException),
 
             _ => throw new NotImplementedException()
         };
@@ -13,5 +13,6 @@ internal enum ResultErrorKind : uint {
     GraphicsInstanceCreate = 1001,
     GraphicsOutOfHostMemory = 1002,
     GraphicsOutOfDeviceMemory = 1003,
-    GraphicsDeviceLost = 1004
+    GraphicsDeviceLost = 1004,
+    GraphicsValidation = 1006
 }
@@ -9,7 +9,18 @@ internal enum CommandBufferCommand : ushort {
     AttachCameraTexture = 5,
     DetachCamera = 6,
     DrawMesh = 7,
+    ExecuteSecondary = 8,
+    NextSubpass = 9,
+    ResetQueries = 10,
+    WriteTimestamp = 11,
+    BeginQuery = 12,
+    EndQuery = 13,
+    CopyQueryResults = 14,
+    BeginLabel = 15,
+    EndLabel = 16,
+    InsertLabel = 17,
 
     AttachPipeline = 10000,
-    AttachMaterial = 10001
+    AttachMaterial = 10001,
+    PushConstants = 10002
 }
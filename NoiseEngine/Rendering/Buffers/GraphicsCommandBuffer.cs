@@ -1,13 +1,17 @@
 ﻿using NoiseEngine.Collections;
 using NoiseEngine.Common;
 using NoiseEngine.Interop;
+using NoiseEngine.Interop.InteropMarshalling;
+using NoiseEngine.Interop.Rendering;
 using NoiseEngine.Interop.Rendering.Buffers;
 using NoiseEngine.Mathematics;
 using NoiseEngine.Rendering.Buffers.CommandBuffers;
 using NoiseEngine.Rendering.Exceptions;
+using NoiseEngine.Rendering.Vulkan;
 using NoiseEngine.Rendering.Vulkan.Buffers;
 using NoiseEngine.Serialization;
 using System;
+using System.IO;
 using System.Linq;
 using System.Runtime.InteropServices;
 
@@ -19,6 +23,11 @@ namespace NoiseEngine.Rendering.Buffers;
 /// <remarks>Must be externally synchronized.</remarks>
 public class GraphicsCommandBuffer {
 
+    // Raw VkQueryControlFlagBits and VkQueryResultFlagBits.
+    private const uint QueryControlPrecise = 1 << 0;
+    private const uint QueryResultWait = 1 << 1;
+    private const uint QueryResultWithAvailability = 1 << 2;
+
     private readonly GraphicsCommandBufferDelegation delegation;
     private readonly FastList<object> references = new FastList<object>();
     private readonly FastList<IReferenceCoutable> rcReferences = new FastList<IReferenceCoutable>();
@@ -27,7 +36,12 @@ public class GraphicsCommandBuffer {
     private readonly GCHandle gcHandle;
 
     private bool simultaneousExecute;
+    private string? debugName;
+    private int openLabels;
+    private bool isSecondary;
+    private bool secondaryContents;
     private int writerCountOnHandleCreation;
+    private GraphicsCommandBufferUsage usageOnHandleCreation;
     private InteropHandle<GraphicsCommandBuffer> handle;
 
     private bool graphics;
@@ -37,6 +51,10 @@ public class GraphicsCommandBuffer {
     public GraphicsDevice Device { get; }
     public SimpleCamera? AttachedCamera { get; private set; }
 
+    internal InteropHandle<GraphicsCommandBuffer> Handle => handle;
+
+    internal ReadOnlySpan<byte> RecordedData => writer.AsSpan();
+
     /// <summary>
     /// Specifies that a <see cref="GraphicsCommandBuffer"/> can be simultaneous executed
     /// and attached into multiple primary <see cref="GraphicsCommandBuffer"/>.
@@ -52,6 +70,62 @@ public class GraphicsCommandBuffer {
         }
     }
 
+    /// <summary>
+    /// Specifies that this <see cref="GraphicsCommandBuffer"/>, when it records only compute and transfer commands,
+    /// is executed on a dedicated compute queue if <see cref="GraphicsDevice.SupportsAsyncComputing"/>, so it can
+    /// overlap graphics work. Queues of that family are handed out round-robin.
+    /// </summary>
+    public bool AsyncCompute { get; set; }
+
+    /// <summary>
+    /// Name of this <see cref="GraphicsCommandBuffer"/>, which is visible in validation messages and graphics
+    /// debuggers.
+    /// </summary>
+    public string? DebugName {
+        get => debugName;
+        set {
+            debugName = value;
+            if (handle != InteropHandle<GraphicsCommandBuffer>.Zero)
+                SetNameOfHandle();
+        }
+    }
+
+    /// <summary>
+    /// Specifies that recorded data of every <see cref="GraphicsCommandBuffer"/> is validated during construction,
+    /// which reports malformed data as <see cref="ArgumentException"/> instead of undefined behavior.
+    /// </summary>
+    /// <remarks>Enabled by default only in debug builds of the native library.</remarks>
+    public static bool CheckedDecoding {
+        get => GraphicsCommandBufferInterop.GetCheckedDecoding();
+        set => GraphicsCommandBufferInterop.SetCheckedDecoding(value);
+    }
+
+    /// <summary>
+    /// Directory into which every executed primary <see cref="GraphicsCommandBuffer"/> is captured, together
+    /// with contents of used buffers and textures at the moment of execution. Captures can be replayed by the
+    /// noise_engine_replay tool.
+    /// </summary>
+    /// <remarks>
+    /// Capturing is disabled when value is <see langword="null"/>. Command buffers which use secondary command
+    /// buffers or queries cannot be replayed, so they are not captured. Contents of buffers are captured when they
+    /// can be read back, which is always the case for buffers created while capturing is enabled.
+    /// </remarks>
+    public static string? CaptureDirectory {
+        get {
+            if (!GraphicsCommandBufferInterop.GetCaptureDirectory().TryGetValue(out InteropString directory))
+                return null;
+
+            string result = directory.ToString();
+            directory.Dispose();
+            return result;
+        }
+        set {
+            GraphicsCommandBufferInterop.SetCaptureDirectory(
+                value is null ? default(InteropOption<InteropString>) : new InteropString(Path.GetFullPath(value))
+            );
+        }
+    }
+
     public GraphicsCommandBuffer(GraphicsDevice device, bool simultaneousExecute) {
         device.Initialize();
 
@@ -102,6 +176,30 @@ public class GraphicsCommandBuffer {
         return new ArgumentException($"Usage of the {paramName} does not include the {usage} flag.", paramName);
     }
 
+    private void SetNameOfHandle() {
+        if (!GraphicsCommandBufferInterop.SetName(handle, debugName ?? string.Empty).TryGetValue(
+            out _, out ResultError error
+        )) {
+            error.ThrowAndDispose();
+        }
+    }
+
+    private void UseQueryPool(GraphicsQueryPool queryPool) {
+        references.Add(queryPool);
+
+        // Occlusion queries are supported only by graphics queues, and dedicated transfer queues may not support
+        // timestamps.
+        if (queryPool.Type == GraphicsQueryType.Occlusion)
+            graphics = true;
+        else
+            computing = true;
+    }
+
+    private void AssertQueryPool(GraphicsQueryPool queryPool) {
+        if (queryPool.Device != Device)
+            throw CreateInvalidDeviceException(nameof(queryPool), "Query pool");
+    }
+
     private static ArgumentException CreateInvalidDeviceException(string paramName, string messageBeginning) {
         return new ArgumentException(
             $"{messageBeginning} is from a device other than this {nameof(GraphicsCommandBuffer)}.", paramName
@@ -122,6 +220,58 @@ public class GraphicsCommandBuffer {
             error.ThrowAndDispose();
         }
 
+        return AddFence(fenceHandle);
+    }
+
+    /// <summary>
+    /// Executes this <see cref="GraphicsCommandBuffer"/> after all <paramref name="waits"/> are reached, and signals
+    /// <paramref name="signals"/> when execution completes.
+    /// </summary>
+    /// <remarks>
+    /// This method also calls <see cref="Construct"/> method. Semaphores can be signaled by executions on other
+    /// queues, which allows to chain e.g. computing, transfer and graphics work without waiting on the CPU.
+    /// Executions on the same queue are processed in submission order, so an execution waiting for a value which
+    /// is signaled by a later execution on the same queue never completes. Such values must be signaled by earlier
+    /// executions, other queues or <see cref="GraphicsTimelineSemaphore.Signal"/>.
+    /// </remarks>
+    /// <param name="waits">Values of <see cref="GraphicsTimelineSemaphore"/>s to wait for.</param>
+    /// <param name="signals">Values of <see cref="GraphicsTimelineSemaphore"/>s to signal.</param>
+    /// <returns>New <see cref="GraphicsFence"/> associated with this execution.</returns>
+    public GraphicsFence Execute(
+        ReadOnlySpan<GraphicsTimelineSemaphoreValue> waits, ReadOnlySpan<GraphicsTimelineSemaphoreValue> signals
+    ) {
+        Span<GraphicsTimelineSemaphoreSubmit> rawWaits = stackalloc GraphicsTimelineSemaphoreSubmit[waits.Length];
+        for (int i = 0; i < waits.Length; i++)
+            rawWaits[i] = CreateTimelineSemaphoreSubmit(waits[i], nameof(waits));
+
+        Span<GraphicsTimelineSemaphoreSubmit> rawSignals =
+            stackalloc GraphicsTimelineSemaphoreSubmit[signals.Length];
+        for (int i = 0; i < signals.Length; i++)
+            rawSignals[i] = CreateTimelineSemaphoreSubmit(signals[i], nameof(signals));
+
+        Construct();
+
+        if (!GraphicsCommandBufferInterop.ExecuteWithTimelineSemaphores(handle, rawWaits, rawSignals).TryGetValue(
+            out InteropHandle<GraphicsFence> fenceHandle, out ResultError error
+        )) {
+            error.ThrowAndDispose();
+        }
+
+        return AddFence(fenceHandle);
+    }
+
+    private GraphicsTimelineSemaphoreSubmit CreateTimelineSemaphoreSubmit(
+        GraphicsTimelineSemaphoreValue value, string paramName
+    ) {
+        if (value.Semaphore.Device != Device)
+            throw CreateInvalidDeviceException(paramName, "Timeline semaphore");
+
+        // Semaphore must be alive until execution is completed.
+        references.Add(value.Semaphore);
+        return new GraphicsTimelineSemaphoreSubmit(value.Semaphore.Handle, value.Value);
+    }
+
+    private GraphicsFence AddFence(InteropHandle<GraphicsFence> fenceHandle) {
         FastList<GraphicsFence> fences = this.fences;
         if (fences.Count > 0) {
             int i;
@@ -148,36 +298,88 @@ public class GraphicsCommandBuffer {
     }
 
     /// <summary>
-    /// Waits for pending <see cref="GraphicsFence"/>s, destroys native handle and clears this
-    /// <see cref="GraphicsCommandBuffer"/>.
+    /// Waits for pending <see cref="GraphicsFence"/>s and clears this <see cref="GraphicsCommandBuffer"/>. Native
+    /// handle is kept, so the next <see cref="Construct"/> records it again instead of creating a new one.
     /// </summary>
     public void Clear() {
-        Deconstruct();
+        GraphicsFence.WaitAll(fences);
+        fences.Clear();
+        writerCountOnHandleCreation = -1;
+
         ReleaseRcReferences(rcReferences);
         references.Clear();
         writer.Clear();
         delegation.Clear();
+        openLabels = 0;
+        isSecondary = false;
+        secondaryContents = false;
+        AttachedCamera = null;
     }
 
     /// <summary>
     /// Construct native handle from recorded data.
     /// </summary>
     /// <remarks>
-    /// In a situation where the command buffer is already constructed and the amount of recorded data differs,
-    /// the <see cref="Deconstruct"/> method is called first.
+    /// In a situation where the command buffer is already constructed and the amount of recorded data differs or
+    /// it was cleared, pending <see cref="GraphicsFence"/>s are waited and native handle is recorded again. When usage of the
+    /// recorded commands changes, the <see cref="Deconstruct"/> method is called first.
     /// </remarks>
     public void Construct() {
+        if (isSecondary) {
+            throw new InvalidOperationException(
+                $"Secondary {nameof(GraphicsCommandBuffer)} cannot be constructed, it is executed by a primary one."
+            );
+        }
+
+        GraphicsCommandBufferUsage usage = new GraphicsCommandBufferUsage(
+            graphics, computing, transfer, AsyncCompute && !graphics
+        );
+
         if (handle != InteropHandle<GraphicsCommandBuffer>.Zero) {
-            if (writerCountOnHandleCreation != writer.Count)
-                Deconstruct();
-            else
+            if (writerCountOnHandleCreation == writer.Count)
                 return;
+
+            if (usageOnHandleCreation == usage) {
+                GraphicsFence.WaitAll(fences);
+                fences.Clear();
+
+                if (!GraphicsCommandBufferInterop.Record(handle, writer.AsSpan(), SimultaneousExecute).TryGetValue(
+                    out _, out ResultError error
+                )) {
+                    // Native command buffer is left in undefined state after failed recording.
+                    Deconstruct();
+                    error.ThrowAndDispose();
+                }
+
+                writerCountOnHandleCreation = writer.Count;
+                return;
+            }
+
+            Deconstruct();
         }
 
+        handle = Device.CreateCommandBuffer(writer.AsSpan(), usage, SimultaneousExecute);
         writerCountOnHandleCreation = writer.Count;
-        handle = Device.CreateCommandBuffer(
-            writer.AsSpan(), new GraphicsCommandBufferUsage(graphics, computing, transfer), SimultaneousExecute
-        );
+        usageOnHandleCreation = usage;
+
+        if (debugName is not null)
+            SetNameOfHandle();
+    }
+
+    /// <summary>
+    /// Returns text listing of recorded commands, with one line per command and its decoded arguments.
+    /// </summary>
+    /// <returns>Listing of recorded commands.</returns>
+    internal string Disassemble() {
+        if (!GraphicsCommandBufferInterop.Disassemble(writer.AsSpan()).TryGetValue(
+            out InteropString listing, out ResultError error
+        )) {
+            error.ThrowAndDispose();
+        }
+
+        string result = listing.ToString();
+        listing.Dispose();
+        return result;
     }
 
     /// <summary>
@@ -255,6 +457,154 @@ public class GraphicsCommandBuffer {
         DispatchUnchecked(kernel, material, groupCount);
     }
 
+    /// <summary>
+    /// Resets <paramref name="count"/> of queries of <paramref name="queryPool"/> starting with
+    /// <paramref name="firstQuery"/>, which is required before they are written.
+    /// </summary>
+    /// <param name="queryPool">Reset <see cref="GraphicsQueryPool"/>.</param>
+    /// <param name="firstQuery">Index of the first reset query.</param>
+    /// <param name="count">Count of reset queries.</param>
+    /// <exception cref="InvalidOperationException">Camera is attached.</exception>
+    public void ResetQueries(GraphicsQueryPool queryPool, uint firstQuery, uint count) {
+        AssertQueryPool(queryPool);
+        if (firstQuery > queryPool.Count || count > queryPool.Count - firstQuery)
+            throw new ArgumentOutOfRangeException(nameof(count));
+        if (AttachedCamera is not null)
+            throw new InvalidOperationException("Queries cannot be reset when camera is attached.");
+
+        ResetQueriesUnchecked(queryPool, firstQuery, count);
+    }
+
+    /// <summary>
+    /// Writes GPU time to <paramref name="query"/> of <paramref name="queryPool"/>, when all previous commands
+    /// are completed.
+    /// </summary>
+    /// <remarks>
+    /// Construction fails when the queue family used by this <see cref="GraphicsCommandBuffer"/> does not support
+    /// timestamps.
+    /// </remarks>
+    /// <param name="queryPool"><see cref="GraphicsQueryPool"/> of <see cref="GraphicsQueryType.Timestamp"/>.</param>
+    /// <param name="query">Index of the written query.</param>
+    public void WriteTimestamp(GraphicsQueryPool queryPool, uint query) {
+        AssertQueryPool(queryPool);
+        if (queryPool.Type != GraphicsQueryType.Timestamp)
+            throw new ArgumentException("Query pool is not a timestamp query pool.", nameof(queryPool));
+        queryPool.AssertQuery(query, nameof(query));
+
+        WriteTimestampUnchecked(queryPool, query, PipelineStageFlags.BottomOfPipe);
+    }
+
+    /// <summary>
+    /// Begins <paramref name="query"/> of <paramref name="queryPool"/>, which counts following commands until
+    /// <see cref="EndQuery"/>.
+    /// </summary>
+    /// <param name="queryPool">
+    /// <see cref="GraphicsQueryPool"/> of <see cref="GraphicsQueryType.Occlusion"/> or
+    /// <see cref="GraphicsQueryType.PipelineStatistics"/>.
+    /// </param>
+    /// <param name="query">Index of the begun query.</param>
+    /// <param name="precise">
+    /// Specifies that occlusion query returns exact count of passed samples instead of any non-zero value when
+    /// some samples passed. Requires device support.
+    /// </param>
+    public void BeginQuery(GraphicsQueryPool queryPool, uint query, bool precise = false) {
+        AssertQueryPool(queryPool);
+        if (queryPool.Type == GraphicsQueryType.Timestamp)
+            throw new ArgumentException("Timestamp query pool cannot be begun.", nameof(queryPool));
+        if (precise && queryPool.Type != GraphicsQueryType.Occlusion)
+            throw new ArgumentException("Only occlusion queries can be precise.", nameof(precise));
+        queryPool.AssertQuery(query, nameof(query));
+
+        BeginQueryUnchecked(queryPool, query, precise ? QueryControlPrecise : 0);
+    }
+
+    /// <summary>
+    /// Ends <paramref name="query"/> of <paramref name="queryPool"/> begun by <see cref="BeginQuery"/>.
+    /// </summary>
+    /// <param name="queryPool">
+    /// <see cref="GraphicsQueryPool"/> of <see cref="GraphicsQueryType.Occlusion"/> or
+    /// <see cref="GraphicsQueryType.PipelineStatistics"/>.
+    /// </param>
+    /// <param name="query">Index of the ended query.</param>
+    public void EndQuery(GraphicsQueryPool queryPool, uint query) {
+        AssertQueryPool(queryPool);
+        if (queryPool.Type == GraphicsQueryType.Timestamp)
+            throw new ArgumentException("Timestamp query pool cannot be ended.", nameof(queryPool));
+        queryPool.AssertQuery(query, nameof(query));
+
+        EndQueryUnchecked(queryPool, query);
+    }
+
+    /// <summary>
+    /// Copies results of <paramref name="count"/> of queries of <paramref name="queryPool"/> starting with
+    /// <paramref name="firstQuery"/> to <paramref name="buffer"/> starting with <paramref name="index"/>, e.g. for
+    /// conditional rendering or GPU culling. Every query writes <see cref="GraphicsQueryPool.ValuesPerQuery"/> values,
+    /// timestamps are in GPU ticks instead of nanoseconds and are not masked to valid bits.
+    /// </summary>
+    /// <param name="queryPool">Source <see cref="GraphicsQueryPool"/>.</param>
+    /// <param name="firstQuery">Index of the first copied query.</param>
+    /// <param name="count">Count of copied queries.</param>
+    /// <param name="buffer">Destination buffer with <see cref="GraphicsBufferUsage.TransferDestination"/> flag.</param>
+    /// <param name="index">Start index in <paramref name="buffer"/>.</param>
+    /// <param name="withAvailability">
+    /// When <see langword="true"/>, copy does not wait for results and after values of every query writes
+    /// non-zero value when they are available, otherwise copy waits for results.
+    /// </param>
+    /// <exception cref="InvalidOperationException">Camera is attached.</exception>
+    public void CopyQueryResults(
+        GraphicsQueryPool queryPool, uint firstQuery, uint count, GraphicsBuffer<ulong> buffer, ulong index = 0,
+        bool withAvailability = false
+    ) {
+        AssertQueryPool(queryPool);
+        if (count == 0 || firstQuery > queryPool.Count || count > queryPool.Count - firstQuery)
+            throw new ArgumentOutOfRangeException(nameof(count));
+        if (!buffer.Usage.HasFlag(GraphicsBufferUsage.TransferDestination))
+            throw CreateUsageNotIncludeException(nameof(buffer), GraphicsBufferUsage.TransferDestination);
+        if (buffer.Device != Device)
+            throw CreateInvalidDeviceException(nameof(buffer), "Buffer");
+
+        ulong values = (ulong)queryPool.ValuesPerQuery + (withAvailability ? 1ul : 0ul);
+        if (index > buffer.Count || count * values > buffer.Count - index)
+            throw new ArgumentOutOfRangeException(nameof(buffer), "Buffer is shorter than copied results.");
+        if (AttachedCamera is not null)
+            throw new InvalidOperationException("Query results cannot be copied when camera is attached.");
+
+        CopyQueryResultsUnchecked(queryPool, firstQuery, count, buffer, index, withAvailability);
+    }
+
+    /// <summary>
+    /// Begins region of following commands named <paramref name="name"/> until <see cref="EndLabel"/>, which is
+    /// visible in validation messages and graphics debuggers. Regions can be nested.
+    /// </summary>
+    /// <param name="name">Name of the region.</param>
+    /// <param name="color">Optional color of the region in graphics debuggers.</param>
+    public void BeginLabel(string name, Color? color = null) {
+        openLabels++;
+        WriteLabel(CommandBufferCommand.BeginLabel, name, color);
+    }
+
+    /// <summary>
+    /// Ends region begun by <see cref="BeginLabel"/>.
+    /// </summary>
+    /// <exception cref="InvalidOperationException">No region is begun.</exception>
+    public void EndLabel() {
+        if (openLabels == 0)
+            throw new InvalidOperationException("No label region is begun.");
+
+        openLabels--;
+        writer.WriteCommand(CommandBufferCommand.EndLabel);
+    }
+
+    /// <summary>
+    /// Inserts single label named <paramref name="name"/> between commands, which is visible in validation messages
+    /// and graphics debuggers.
+    /// </summary>
+    /// <param name="name">Name of the label.</param>
+    /// <param name="color">Optional color of the label in graphics debuggers.</param>
+    public void InsertLabel(string name, Color? color = null) {
+        WriteLabel(CommandBufferCommand.InsertLabel, name, color);
+    }
+
     internal void CopyUnchecked<T1, T2>(
         GraphicsReadOnlyBuffer<T1> sourceBuffer, GraphicsBuffer<T2> destinationBuffer,
         ReadOnlySpan<BufferCopyRegion> regions
@@ -318,22 +668,153 @@ public class GraphicsCommandBuffer {
             region.Write(writer);
     }
 
+    internal void ResetQueriesUnchecked(GraphicsQueryPool queryPool, uint firstQuery, uint count) {
+        UseQueryPool(queryPool);
+
+        writer.WriteCommand(CommandBufferCommand.ResetQueries);
+        writer.WriteIntN(queryPool.Handle.Pointer);
+        writer.WriteUInt32(firstQuery);
+        writer.WriteUInt32(count);
+    }
+
+    internal void WriteTimestampUnchecked(GraphicsQueryPool queryPool, uint query, PipelineStageFlags stage) {
+        UseQueryPool(queryPool);
+
+        writer.WriteCommand(CommandBufferCommand.WriteTimestamp);
+        writer.WriteIntN(queryPool.Handle.Pointer);
+        writer.WriteUInt32((uint)stage);
+        writer.WriteUInt32(query);
+    }
+
+    internal void BeginQueryUnchecked(GraphicsQueryPool queryPool, uint query, uint flags) {
+        UseQueryPool(queryPool);
+
+        writer.WriteCommand(CommandBufferCommand.BeginQuery);
+        writer.WriteIntN(queryPool.Handle.Pointer);
+        writer.WriteUInt32(query);
+        writer.WriteUInt32(flags);
+    }
+
+    internal void EndQueryUnchecked(GraphicsQueryPool queryPool, uint query) {
+        writer.WriteCommand(CommandBufferCommand.EndQuery);
+        writer.WriteIntN(queryPool.Handle.Pointer);
+        writer.WriteUInt32(query);
+    }
+
+    internal void CopyQueryResultsUnchecked(
+        GraphicsQueryPool queryPool, uint firstQuery, uint count, GraphicsBuffer<ulong> buffer, ulong index,
+        bool withAvailability
+    ) {
+        transfer = true;
+        UseQueryPool(queryPool);
+        references.Add(buffer);
+
+        writer.WriteCommand(CommandBufferCommand.CopyQueryResults);
+        writer.WriteIntN(queryPool.Handle.Pointer);
+        writer.WriteUInt32(firstQuery);
+        writer.WriteUInt32(count);
+        writer.WriteIntN(buffer.InnerHandle.Pointer);
+        writer.WriteUInt64(GraphicsReadOnlyBuffer<ulong>.GetSize(index));
+        writer.WriteUInt32(withAvailability ? QueryResultWithAvailability : QueryResultWait);
+    }
+
+    private void WriteLabel(CommandBufferCommand command, string name, Color? color) {
+        Color value = color ?? Color.Transparent;
+
+        writer.WriteCommand(command);
+        writer.WriteFloat32(value.R);
+        writer.WriteFloat32(value.G);
+        writer.WriteFloat32(value.B);
+        writer.WriteFloat32(value.A);
+        writer.WriteString(name);
+    }
+
     internal void DispatchUnchecked(ComputeKernel kernel, ComputeMaterial material, Vector3<uint> groupCount) {
         computing = true;
         delegation.DispatchWorker(kernel, material, groupCount);
     }
 
-    internal void AttachCameraUnchecked(SimpleCamera camera) {
+    /// <summary>
+    /// Pushes constants at <paramref name="offset"/> for following dispatches of <paramref name="kernel"/>.
+    /// </summary>
+    internal void PushConstantsUnchecked(ComputeKernel kernel, uint offset, ReadOnlySpan<byte> data) {
+        computing = true;
+        delegation.PushConstantsWorker(kernel, offset, data);
+    }
+
+    /// <summary>
+    /// Attaches <paramref name="camera"/>, whose render pass is recorded directly into this
+    /// <see cref="GraphicsCommandBuffer"/>, or only by secondary ones when <paramref name="secondaryContents"/> is
+    /// <see langword="true"/>.
+    /// </summary>
+    internal void AttachCameraUnchecked(SimpleCamera camera, bool secondaryContents = false) {
         graphics = true;
         AttachedCamera = camera;
-        delegation.AttachCameraWorker(camera);
+        this.secondaryContents = secondaryContents;
+        delegation.AttachCameraWorker(camera, secondaryContents);
     }
 
     internal void DetachCameraUnchecked() {
         AttachedCamera = null;
+        secondaryContents = false;
         writer.WriteCommand(CommandBufferCommand.DetachCamera);
     }
 
+    /// <summary>
+    /// Begins recording of this empty <see cref="GraphicsCommandBuffer"/> as a secondary one, which draws inside
+    /// render pass of <paramref name="camera"/> and is executed by <see cref="ExecuteSecondary"/> of a primary one.
+    /// </summary>
+    /// <remarks>Secondary command buffers can be recorded on other threads than the primary one.</remarks>
+    /// <param name="camera">Camera attached to the primary <see cref="GraphicsCommandBuffer"/>.</param>
+    /// <exception cref="InvalidOperationException">This command buffer is not empty.</exception>
+    internal void BeginSecondary(SimpleCamera camera) {
+        if (writer.Count > 0 || handle != InteropHandle<GraphicsCommandBuffer>.Zero)
+            throw new InvalidOperationException($"Secondary {nameof(GraphicsCommandBuffer)} must be empty.");
+        if (camera.GraphicsDevice != Device)
+            throw CreateInvalidDeviceException(nameof(camera), "Camera");
+
+        graphics = true;
+        isSecondary = true;
+        AttachedCamera = camera;
+        delegation.BeginSecondaryWorker(camera);
+    }
+
+    /// <summary>
+    /// Executes <paramref name="commandBuffers"/>, which are recorded in parallel into native secondary command
+    /// buffers, inside render pass of the attached camera.
+    /// </summary>
+    /// <remarks>
+    /// Executed command buffers are referenced by this <see cref="GraphicsCommandBuffer"/> until it is cleared, and
+    /// must not be recorded further.
+    /// </remarks>
+    /// <param name="commandBuffers">
+    /// <see cref="GraphicsCommandBuffer"/>s begun by <see cref="BeginSecondary"/> with the attached camera.
+    /// </param>
+    /// <exception cref="InvalidOperationException">
+    /// Camera is not attached with secondary contents.
+    /// </exception>
+    internal void ExecuteSecondary(ReadOnlySpan<GraphicsCommandBuffer> commandBuffers) {
+        if (AttachedCamera is null || !secondaryContents) {
+            throw new InvalidOperationException(
+                "Secondary command buffers can be executed only when camera is attached with secondary contents."
+            );
+        }
+
+        foreach (GraphicsCommandBuffer commandBuffer in commandBuffers) {
+            if (commandBuffer.Device != Device)
+                throw CreateInvalidDeviceException(nameof(commandBuffers), "Secondary command buffer");
+            if (!commandBuffer.isSecondary || commandBuffer.AttachedCamera != AttachedCamera) {
+                throw new ArgumentException(
+                    "Command buffer is not begun as secondary with the attached camera.", nameof(commandBuffers)
+                );
+            }
+            if (commandBuffer.openLabels != 0)
+                throw new ArgumentException("Command buffer has unclosed label regions.", nameof(commandBuffers));
+        }
+
+        delegation.ExecuteSecondaryWorker(commandBuffers);
+    }
+
     internal void DrawMeshUnchecked(Mesh mesh, Material material, Matrix4x4<pos> transform) {
         graphics = true;
         delegation.DrawMeshWorker(mesh, material, transform);
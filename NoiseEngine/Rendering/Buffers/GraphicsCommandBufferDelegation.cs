@@ -2,6 +2,7 @@
 using NoiseEngine.Common;
 using NoiseEngine.Mathematics;
 using NoiseEngine.Serialization;
+using System;
 
 namespace NoiseEngine.Rendering.Buffers;
 
@@ -24,10 +25,16 @@ internal abstract class GraphicsCommandBufferDelegation {
 
     public abstract void Clear();
 
-    public abstract void AttachCameraWorker(SimpleCamera camera);
+    public abstract void AttachCameraWorker(SimpleCamera camera, bool secondaryContents);
+
+    public abstract void BeginSecondaryWorker(SimpleCamera camera);
+
+    public abstract void ExecuteSecondaryWorker(ReadOnlySpan<GraphicsCommandBuffer> commandBuffers);
 
     public abstract void DispatchWorker(ComputeKernel kernel, ComputeMaterial material, uint3 groupCount);
 
+    public abstract void PushConstantsWorker(ComputeKernel kernel, uint offset, ReadOnlySpan<byte> data);
+
     public abstract void DrawMeshWorker(Mesh mesh, Material material, Matrix4x4<pos> transform);
 
 }
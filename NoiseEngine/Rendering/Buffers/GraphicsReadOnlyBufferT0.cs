@@ -4,9 +4,22 @@ namespace NoiseEngine.Rendering.Buffers;
 
 public abstract class GraphicsReadOnlyBuffer {
 
+    private string? debugName;
+
     public GraphicsDevice Device { get; }
     public ulong Count { get; }
 
+    /// <summary>
+    /// Name of this buffer, which is visible in validation messages and graphics debuggers.
+    /// </summary>
+    public string? DebugName {
+        get => debugName;
+        set {
+            Device.SetDebugName(this, value ?? string.Empty);
+            debugName = value;
+        }
+    }
+
     internal abstract InteropHandle<GraphicsReadOnlyBuffer> HandleUniversal { get; }
     internal abstract InteropHandle<GraphicsReadOnlyBuffer> InnerHandleUniversal { get; }
 
@@ -0,0 +1,175 @@
+﻿using NoiseEngine.Collections;
+using NoiseEngine.Interop;
+using NoiseEngine.Interop.InteropMarshalling;
+using NoiseEngine.Interop.Rendering.Vulkan;
+using NoiseEngine.Mathematics;
+using NoiseEngine.Rendering.Exceptions;
+using System;
+using System.Runtime.InteropServices;
+
+namespace NoiseEngine.Rendering.Buffers;
+
+/// <summary>
+/// Uploads data to <see cref="GraphicsBuffer{T}"/>s and <see cref="Texture"/>s through a ring staging buffer.
+/// Copies are batched and executed on the dedicated transfer queue when the device has one, so streamed resources do
+/// not stall rendering.
+/// </summary>
+/// <remarks>
+/// Uploaded resources can be used after the <see cref="GraphicsFence"/> returned by <see cref="Flush"/> is signaled.
+/// Must be externally synchronized.
+/// </remarks>
+public class GraphicsUploadManager {
+
+    public const ulong DefaultStagingCapacity = 64 * 1024 * 1024;
+
+    private readonly InteropHandle<GraphicsUploadManager> handle;
+    private readonly FastList<object> pendingReferences = new FastList<object>();
+    private readonly FastList<(GraphicsFence fence, object[] references)> inFlight =
+        new FastList<(GraphicsFence fence, object[] references)>();
+
+    public GraphicsDevice Device { get; }
+
+    /// <summary>
+    /// Size in bytes of the staging buffer. Single upload cannot be larger.
+    /// </summary>
+    public ulong StagingCapacity { get; }
+
+    /// <summary>
+    /// Creates new <see cref="GraphicsUploadManager"/>.
+    /// </summary>
+    /// <param name="device"><see cref="GraphicsDevice"/> of uploaded resources.</param>
+    /// <param name="stagingCapacity">Size in bytes of the staging buffer.</param>
+    public GraphicsUploadManager(GraphicsDevice device, ulong stagingCapacity = DefaultStagingCapacity) {
+        if (stagingCapacity == 0)
+            throw new ArgumentOutOfRangeException(nameof(stagingCapacity));
+
+        device.Initialize();
+
+        Device = device;
+        StagingCapacity = stagingCapacity;
+
+        InteropResult<InteropHandle<GraphicsUploadManager>> result = device.Instance.Api switch {
+            GraphicsApi.Vulkan => VulkanUploadManagerInterop.Create(device.Handle, stagingCapacity),
+            _ => throw new GraphicsApiNotSupportedException(device.Instance.Api),
+        };
+
+        if (!result.TryGetValue(out handle, out ResultError error))
+            error.ThrowAndDispose();
+    }
+
+    ~GraphicsUploadManager() {
+        if (handle == InteropHandle<GraphicsUploadManager>.Zero)
+            return;
+
+        VulkanUploadManagerInterop.Destroy(handle);
+    }
+
+    /// <summary>
+    /// Queues upload of <paramref name="data"/> to <paramref name="buffer"/> starting with <paramref name="index"/>.
+    /// </summary>
+    /// <typeparam name="T">Type of the element in <paramref name="buffer"/>.</typeparam>
+    /// <param name="buffer">
+    /// Destination buffer with <see cref="GraphicsBufferUsage.TransferDestination"/> flag.
+    /// </param>
+    /// <param name="data">Data to upload.</param>
+    /// <param name="index">Start index of upload.</param>
+    public void Upload<T>(GraphicsBuffer<T> buffer, ReadOnlySpan<T> data, ulong index = 0) where T : unmanaged {
+        if (buffer.Device != Device)
+            throw new ArgumentException("Buffer is from a device other than this upload manager.", nameof(buffer));
+        if (!buffer.Usage.HasFlag(GraphicsBufferUsage.TransferDestination)) {
+            throw new ArgumentException(
+                "GraphicsBuffer has not GraphicsBufferUsage.TransferDestination flag.", nameof(buffer)
+            );
+        }
+        if (index > buffer.Count)
+            throw new ArgumentOutOfRangeException(nameof(index));
+        if ((ulong)data.Length + index > buffer.Count)
+            throw new ArgumentOutOfRangeException(nameof(data));
+
+        if (!VulkanUploadManagerInterop.UploadBuffer(
+            handle, buffer.HandleUniversal, GraphicsReadOnlyBuffer<T>.GetSize(index), MemoryMarshal.AsBytes(data)
+        ).TryGetValue(out _, out ResultError error)) {
+            error.ThrowAndDispose();
+        }
+
+        pendingReferences.Add(buffer);
+    }
+
+    /// <summary>
+    /// Queues upload of <paramref name="data"/> to <paramref name="texture"/>.
+    /// </summary>
+    /// <typeparam name="T">
+    /// Type of element in <paramref name="data"/>. Must have the same size as <paramref name="texture"/> pixel.
+    /// </typeparam>
+    /// <param name="texture">
+    /// Destination texture with <see cref="TextureUsage.TransferDestination"/> flag.
+    /// </param>
+    /// <param name="data">Data to upload.</param>
+    public void Upload<T>(Texture texture, ReadOnlySpan<T> data) where T : unmanaged {
+        if (texture.Device != Device)
+            throw new ArgumentException("Texture is from a device other than this upload manager.", nameof(texture));
+        if (!texture.Usage.HasFlag(TextureUsage.TransferDestination)) {
+            throw new ArgumentException(
+                "Texture has not TextureUsage.TransferDestination flag.", nameof(texture)
+            );
+        }
+
+        Vector3<uint> extent = texture.Extent;
+        VulkanUploadImageRegion region = new VulkanUploadImageRegion(
+            TextureAspect.Color, 0, 0, 1, 0, 0, 0, extent.X, extent.Y, extent.Z
+        );
+
+        if (!VulkanUploadManagerInterop.UploadImage(
+            handle, texture.Handle, region, MemoryMarshal.AsBytes(data)
+        ).TryGetValue(out _, out ResultError error)) {
+            error.ThrowAndDispose();
+        }
+
+        pendingReferences.Add(texture);
+    }
+
+    /// <summary>
+    /// Submits queued uploads.
+    /// </summary>
+    /// <returns>
+    /// <see cref="GraphicsFence"/> which is signaled when uploaded resources can be used for rendering.
+    /// </returns>
+    public GraphicsFence Flush() {
+        if (!VulkanUploadManagerInterop.Flush(handle).TryGetValue(
+            out InteropHandle<GraphicsFence> fenceHandle, out ResultError error
+        )) {
+            error.ThrowAndDispose();
+        }
+
+        int i = 0;
+        while (i < inFlight.Count) {
+            if (inFlight[i].fence.IsSignaled) {
+                inFlight[i] = inFlight[inFlight.Count - 1];
+                inFlight.RemoveLast(1);
+            } else {
+                i++;
+            }
+        }
+
+        GraphicsFence fence = new GraphicsFence(Device, fenceHandle);
+        if (pendingReferences.Count > 0) {
+            // Keep destination resources alive until their uploads are executed.
+            inFlight.Add((fence, pendingReferences.ToArray()));
+            pendingReferences.Clear();
+        }
+
+        return fence;
+    }
+
+    /// <summary>
+    /// Submits queued uploads and waits for execution of all uploads.
+    /// </summary>
+    public void WaitIdle() {
+        Flush();
+
+        if (!VulkanUploadManagerInterop.WaitIdle(handle).TryGetValue(out _, out ResultError error))
+            error.ThrowAndDispose();
+        inFlight.Clear();
+    }
+
+}
@@ -0,0 +1,14 @@
+﻿using System;
+
+namespace NoiseEngine.Rendering.Events;
+
+public class GraphicsDeviceLostEventArgs : EventArgs {
+
+    public string Message { get; init; } = string.Empty;
+
+    /// <summary>
+    /// Description of the fault reported by the driver, or <see langword="null"/> when it is not available.
+    /// </summary>
+    public string? FaultDescription { get; init; }
+
+}
@@ -0,0 +1,16 @@
+﻿using System;
+
+namespace NoiseEngine.Rendering.Exceptions;
+
+public class GraphicsValidationException : GraphicsException {
+
+    public GraphicsValidationException() : base("Validation of the graphics API reported an error.") {
+    }
+
+    public GraphicsValidationException(string? message) : base(message) {
+    }
+
+    public GraphicsValidationException(string? message, Exception? innerException) : base(message, innerException) {
+    }
+
+}
@@ -1,7 +1,11 @@
 ﻿using NoiseEngine.Interop;
 using NoiseEngine.Interop.Rendering.Buffers;
 using NoiseEngine.Rendering.Buffers;
+using NoiseEngine.Rendering.Events;
 using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Numerics;
 using System.Threading;
 
 namespace NoiseEngine.Rendering;
@@ -11,8 +15,14 @@ namespace NoiseEngine.Rendering;
 public abstract class GraphicsDevice {
 
     private readonly object initializeLocker = new object();
+    private readonly Lazy<GraphicsDeviceLimits> limits;
+    private readonly Lazy<IReadOnlyList<GraphicsMemoryHeap>> memoryHeaps;
+    private readonly Lazy<IReadOnlyList<GraphicsQueueFamily>> queueFamilies;
+    private readonly Lazy<IReadOnlySet<string>> supportedFeatures;
 
     private bool isInitialized;
+    private string[]? initializedFeatures;
+    private GraphicsQueueFamilyConfiguration[]? initializedQueueFamilies;
     private GraphicsBufferPool? bufferPool;
     private TextureSampler? defaultTextureSampler;
 
@@ -24,8 +34,31 @@ public abstract class GraphicsDevice {
     public Guid Guid { get; }
     public bool SupportsGraphics { get; }
     public bool SupportsComputing { get; }
+    public bool SupportsAsyncComputing { get; }
     public bool SupportsPresentation { get; }
 
+    public GraphicsDeviceLimits Limits => limits.Value;
+    public IReadOnlyList<GraphicsMemoryHeap> MemoryHeaps => memoryHeaps.Value;
+    public IReadOnlyList<GraphicsQueueFamily> QueueFamilies => queueFamilies.Value;
+
+    /// <summary>
+    /// Names of features supported by this <see cref="GraphicsDevice"/>, which are names of members of Vulkan
+    /// feature structures, e.g. <c>samplerAnisotropy</c>.
+    /// </summary>
+    public IReadOnlySet<string> SupportedFeatures => supportedFeatures.Value;
+
+    /// <summary>
+    /// Names of features enabled by initialization of this <see cref="GraphicsDevice"/>, empty when it is not
+    /// initialized yet.
+    /// </summary>
+    public IReadOnlySet<string> EnabledFeatures { get; private set; } = new HashSet<string>();
+
+    /// <summary>
+    /// Determines whether this <see cref="GraphicsDevice"/> was lost, e.g. after reset of the driver. Lost device
+    /// cannot execute any work and must be replaced by <see cref="GraphicsInstance.RecreateDevice"/>.
+    /// </summary>
+    public bool IsLost => GetIsLost();
+
     public TextureSampler DefaultTextureSampler {
         get {
             TextureSampler? sampler = defaultTextureSampler;
@@ -39,6 +72,7 @@ public abstract class GraphicsDevice {
     }
 
     internal InteropHandle<GraphicsDevice> Handle { get; }
+    private protected GraphicsDeviceValue Value { get; }
 
     internal GraphicsBufferPool BufferPool {
         get {
@@ -52,6 +86,11 @@ public abstract class GraphicsDevice {
         }
     }
 
+    /// <summary>
+    /// Raised once, when this <see cref="GraphicsDevice"/> is lost. It can be raised on any thread.
+    /// </summary>
+    public event EventHandler<GraphicsDeviceLostEventArgs>? Lost;
+
     private protected GraphicsDevice(GraphicsInstance instance, GraphicsDeviceValue value) {
         Instance = instance;
         Name = value.Name;
@@ -61,8 +100,17 @@ public abstract class GraphicsDevice {
         Guid = value.Guid;
         SupportsGraphics = value.SupportGraphics;
         SupportsComputing = value.SupportComputing;
+        SupportsAsyncComputing = value.SupportAsyncComputing;
         SupportsPresentation = value.SupportPresentation;
         Handle = value.Handle;
+        Value = value;
+
+        limits = new Lazy<GraphicsDeviceLimits>(GetLimits);
+        memoryHeaps = new Lazy<IReadOnlyList<GraphicsMemoryHeap>>(GetMemoryHeaps);
+        queueFamilies = new Lazy<IReadOnlyList<GraphicsQueueFamily>>(GetQueueFamilies);
+        supportedFeatures = new Lazy<IReadOnlySet<string>>(
+            () => GetFeatures().Where(x => x.Supported).Select(x => x.Name).ToHashSet()
+        );
     }
 
     /// <summary>
@@ -79,23 +127,170 @@ public abstract class GraphicsDevice {
             $"{nameof(Handle)} = {Handle} }}";
     }
 
+    /// <summary>
+    /// Returns the highest number of samples per pixel which can be used by <see cref="SimpleCamera.SampleCount"/>.
+    /// </summary>
+    /// <param name="depthTesting">
+    /// <see langword="true"/> when camera uses <see cref="SimpleCamera.DepthTesting"/>.
+    /// </param>
+    /// <returns>Power of two sample count, <c>1</c> when multisampling is not supported.</returns>
+    public uint GetMaxSampleCount(bool depthTesting = true) {
+        return 1u << (31 - BitOperations.LeadingZeroCount(GetSupportedSampleCounts(depthTesting) | 1));
+    }
+
+    /// <summary>
+    /// Checks if <paramref name="sampleCount"/> can be used by <see cref="SimpleCamera.SampleCount"/>.
+    /// </summary>
+    /// <param name="sampleCount">Number of samples per pixel.</param>
+    /// <param name="depthTesting">
+    /// <see langword="true"/> when camera uses <see cref="SimpleCamera.DepthTesting"/>.
+    /// </param>
+    /// <returns><see langword="true"/> when <paramref name="sampleCount"/> is supported.</returns>
+    public bool IsSampleCountSupported(uint sampleCount, bool depthTesting = true) {
+        return BitOperations.IsPow2(sampleCount) && (GetSupportedSampleCounts(depthTesting) & sampleCount) != 0;
+    }
+
+    /// <summary>
+    /// Checks if <paramref name="format"/> can be used by 2D textures with <paramref name="usage"/>.
+    /// </summary>
+    /// <param name="format">Format of the texture.</param>
+    /// <param name="usage">Usage of the texture.</param>
+    /// <returns><see langword="true"/> when the combination is supported.</returns>
+    public bool IsTextureFormatSupported(TextureFormat format, TextureUsage usage) {
+        return IsTextureFormatSupportedWorker(format, usage);
+    }
+
+    /// <summary>
+    /// Initializes this <see cref="GraphicsDevice"/> with <paramref name="features"/> enabled. Otherwise device is
+    /// initialized on first usage, only with optional features used by the engine.
+    /// </summary>
+    /// <param name="features">Names of features from <see cref="SupportedFeatures"/>.</param>
+    /// <param name="queueFamilies">
+    /// Queues to create, or <see langword="null"/> to create a few queues in every family of
+    /// <see cref="QueueFamilies"/>. Families without configuration have no queues.
+    /// </param>
+    /// <exception cref="InvalidOperationException">
+    /// Some of <paramref name="features"/> is not supported, <paramref name="queueFamilies"/> are not valid for this
+    /// <see cref="GraphicsDevice"/>, or it is already initialized without some of <paramref name="features"/> or
+    /// with other queues.
+    /// </exception>
+    public void Initialize(
+        IEnumerable<string> features, IEnumerable<GraphicsQueueFamilyConfiguration>? queueFamilies = null
+    ) {
+        string[] array = features.ToArray();
+        GraphicsQueueFamilyConfiguration[] queueFamiliesArray =
+            queueFamilies?.ToArray() ?? Array.Empty<GraphicsQueueFamilyConfiguration>();
+
+        lock (initializeLocker) {
+            if (isInitialized) {
+                if (queueFamilies is not null)
+                    throw new InvalidOperationException($"{this} is already initialized with other queues.");
+
+                string? missing = array.FirstOrDefault(x => !EnabledFeatures.Contains(x));
+                if (missing is not null)
+                    throw new InvalidOperationException($"{this} is already initialized without feature `{missing}`.");
+                return;
+            }
+
+            InitializeWorker(array, queueFamiliesArray);
+            EnabledFeatures = GetFeatures().Where(x => x.Enabled).Select(x => x.Name).ToHashSet();
+            initializedFeatures = array;
+            initializedQueueFamilies = queueFamilies is null ? null : queueFamiliesArray;
+            isInitialized = true;
+        }
+    }
+
     internal void Initialize() {
         if (isInitialized)
             return;
 
+        Initialize(Array.Empty<string>());
+    }
+
+    /// <summary>
+    /// Saves caches of this <see cref="GraphicsDevice"/> to the user's cache directory, so they can be reused by
+    /// next launches. Does nothing when this <see cref="GraphicsDevice"/> is not initialized.
+    /// </summary>
+    internal void SaveCaches() {
         lock (initializeLocker) {
             if (isInitialized)
-                return;
+                SaveCachesWorker();
+        }
+    }
 
-            InitializeWorker();
-            isInitialized = true;
+    /// <summary>
+    /// Creates new <see cref="GraphicsDevice"/> of the same physical device, which is initialized with the same
+    /// features and queues when this <see cref="GraphicsDevice"/> is initialized.
+    /// </summary>
+    /// <returns>New <see cref="GraphicsDevice"/>.</returns>
+    internal GraphicsDevice Recreate() {
+        GraphicsDevice device = RecreateWorker();
+
+        lock (initializeLocker) {
+            if (isInitialized)
+                device.Initialize(initializedFeatures!, initializedQueueFamilies);
         }
+
+        return device;
+    }
+
+    internal void RaiseLost(string message, string? faultDescription) {
+        Lost?.Invoke(this, new GraphicsDeviceLostEventArgs {
+            Message = message,
+            FaultDescription = faultDescription
+        });
     }
 
     internal abstract InteropHandle<GraphicsCommandBuffer> CreateCommandBuffer(
         ReadOnlySpan<byte> data, GraphicsCommandBufferUsage usage, bool simultaneousExecute
     );
 
-    protected abstract void InitializeWorker();
+    internal abstract InteropHandle<GraphicsTimelineSemaphore> CreateTimelineSemaphore(ulong initialValue);
+
+    /// <summary>
+    /// Sets debug name of native object of <paramref name="buffer"/>, empty <paramref name="name"/> removes it.
+    /// </summary>
+    internal abstract void SetDebugName(GraphicsReadOnlyBuffer buffer, string name);
+
+    /// <summary>
+    /// Sets debug name of native object of <paramref name="texture"/>, empty <paramref name="name"/> removes it.
+    /// </summary>
+    internal abstract void SetDebugName(Texture texture, string name);
+
+    /// <summary>
+    /// Returns mask of supported sample counts, where each sample count is represented by its own bit.
+    /// </summary>
+    private protected abstract uint GetSupportedSampleCounts(bool depthStencil);
+
+    private protected abstract GraphicsDeviceLimits GetLimits();
+
+    private protected abstract IReadOnlyList<GraphicsMemoryHeap> GetMemoryHeaps();
+
+    private protected abstract IReadOnlyList<GraphicsQueueFamily> GetQueueFamilies();
+
+    /// <summary>
+    /// Returns all known features with their support, and state after initialization.
+    /// </summary>
+    private protected abstract IEnumerable<(string Name, bool Supported, bool Enabled)> GetFeatures();
+
+    private protected abstract bool IsTextureFormatSupportedWorker(TextureFormat format, TextureUsage usage);
+
+    private protected abstract bool GetIsLost();
+
+    /// <summary>
+    /// Creates new not initialized <see cref="GraphicsDevice"/> of the same physical device.
+    /// </summary>
+    private protected abstract GraphicsDevice RecreateWorker();
+
+    /// <summary>
+    /// Initializes this <see cref="GraphicsDevice"/>.
+    /// </summary>
+    /// <param name="features">Features to enable.</param>
+    /// <param name="queueFamilies">Queues to create, or empty to create default queues.</param>
+    protected abstract void InitializeWorker(
+        IReadOnlyList<string> features, IReadOnlyList<GraphicsQueueFamilyConfiguration> queueFamilies
+    );
+
+    protected abstract void SaveCachesWorker();
 
 }
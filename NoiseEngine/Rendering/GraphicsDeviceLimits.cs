@@ -0,0 +1,312 @@
+﻿using System.Runtime.InteropServices;
+
+namespace NoiseEngine.Rendering;
+
+/// <summary>
+/// Limits of <see cref="GraphicsDevice"/>. Limits introduced after Vulkan 1.0 are zero when they are not supported by
+/// the device.
+/// https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkPhysicalDeviceLimits.html
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+public readonly struct GraphicsDeviceLimits {
+
+    private readonly uint maxImageDimension1D;
+    private readonly uint maxImageDimension2D;
+    private readonly uint maxImageDimension3D;
+    private readonly uint maxImageDimensionCube;
+    private readonly uint maxImageArrayLayers;
+    private readonly uint maxTexelBufferElements;
+    private readonly uint maxUniformBufferRange;
+    private readonly uint maxStorageBufferRange;
+    private readonly uint maxPushConstantsSize;
+    private readonly uint maxMemoryAllocationCount;
+    private readonly uint maxSamplerAllocationCount;
+    private readonly ulong bufferImageGranularity;
+    private readonly ulong sparseAddressSpaceSize;
+    private readonly uint maxBoundDescriptorSets;
+    private readonly uint maxPerStageDescriptorSamplers;
+    private readonly uint maxPerStageDescriptorUniformBuffers;
+    private readonly uint maxPerStageDescriptorStorageBuffers;
+    private readonly uint maxPerStageDescriptorSampledImages;
+    private readonly uint maxPerStageDescriptorStorageImages;
+    private readonly uint maxPerStageDescriptorInputAttachments;
+    private readonly uint maxPerStageResources;
+    private readonly uint maxDescriptorSetSamplers;
+    private readonly uint maxDescriptorSetUniformBuffers;
+    private readonly uint maxDescriptorSetUniformBuffersDynamic;
+    private readonly uint maxDescriptorSetStorageBuffers;
+    private readonly uint maxDescriptorSetStorageBuffersDynamic;
+    private readonly uint maxDescriptorSetSampledImages;
+    private readonly uint maxDescriptorSetStorageImages;
+    private readonly uint maxDescriptorSetInputAttachments;
+    private readonly uint maxVertexInputAttributes;
+    private readonly uint maxVertexInputBindings;
+    private readonly uint maxVertexInputAttributeOffset;
+    private readonly uint maxVertexInputBindingStride;
+    private readonly uint maxVertexOutputComponents;
+    private readonly uint maxTessellationGenerationLevel;
+    private readonly uint maxTessellationPatchSize;
+    private readonly uint maxTessellationControlPerVertexInputComponents;
+    private readonly uint maxTessellationControlPerVertexOutputComponents;
+    private readonly uint maxTessellationControlPerPatchOutputComponents;
+    private readonly uint maxTessellationControlTotalOutputComponents;
+    private readonly uint maxTessellationEvaluationInputComponents;
+    private readonly uint maxTessellationEvaluationOutputComponents;
+    private readonly uint maxGeometryShaderInvocations;
+    private readonly uint maxGeometryInputComponents;
+    private readonly uint maxGeometryOutputComponents;
+    private readonly uint maxGeometryOutputVertices;
+    private readonly uint maxGeometryTotalOutputComponents;
+    private readonly uint maxFragmentInputComponents;
+    private readonly uint maxFragmentOutputAttachments;
+    private readonly uint maxFragmentDualSrcAttachments;
+    private readonly uint maxFragmentCombinedOutputResources;
+    private readonly uint maxComputeSharedMemorySize;
+    private readonly uint maxComputeWorkGroupCountX;
+    private readonly uint maxComputeWorkGroupCountY;
+    private readonly uint maxComputeWorkGroupCountZ;
+    private readonly uint maxComputeWorkGroupInvocations;
+    private readonly uint maxComputeWorkGroupSizeX;
+    private readonly uint maxComputeWorkGroupSizeY;
+    private readonly uint maxComputeWorkGroupSizeZ;
+    private readonly uint subPixelPrecisionBits;
+    private readonly uint subTexelPrecisionBits;
+    private readonly uint mipmapPrecisionBits;
+    private readonly uint maxDrawIndexedIndexValue;
+    private readonly uint maxDrawIndirectCount;
+    private readonly float maxSamplerLodBias;
+    private readonly float maxSamplerAnisotropy;
+    private readonly uint maxViewports;
+    private readonly uint maxViewportDimensionsWidth;
+    private readonly uint maxViewportDimensionsHeight;
+    private readonly float viewportBoundsRangeMin;
+    private readonly float viewportBoundsRangeMax;
+    private readonly uint viewportSubPixelBits;
+    private readonly nuint minMemoryMapAlignment;
+    private readonly ulong minTexelBufferOffsetAlignment;
+    private readonly ulong minUniformBufferOffsetAlignment;
+    private readonly ulong minStorageBufferOffsetAlignment;
+    private readonly int minTexelOffset;
+    private readonly uint maxTexelOffset;
+    private readonly int minTexelGatherOffset;
+    private readonly uint maxTexelGatherOffset;
+    private readonly float minInterpolationOffset;
+    private readonly float maxInterpolationOffset;
+    private readonly uint subPixelInterpolationOffsetBits;
+    private readonly uint maxFramebufferWidth;
+    private readonly uint maxFramebufferHeight;
+    private readonly uint maxFramebufferLayers;
+    private readonly uint framebufferColorSampleCounts;
+    private readonly uint framebufferDepthSampleCounts;
+    private readonly uint framebufferStencilSampleCounts;
+    private readonly uint framebufferNoAttachmentsSampleCounts;
+    private readonly uint maxColorAttachments;
+    private readonly uint sampledImageColorSampleCounts;
+    private readonly uint sampledImageIntegerSampleCounts;
+    private readonly uint sampledImageDepthSampleCounts;
+    private readonly uint sampledImageStencilSampleCounts;
+    private readonly uint storageImageSampleCounts;
+    private readonly uint maxSampleMaskWords;
+    private readonly uint timestampComputeAndGraphics;
+    private readonly float timestampPeriod;
+    private readonly uint maxClipDistances;
+    private readonly uint maxCullDistances;
+    private readonly uint maxCombinedClipAndCullDistances;
+    private readonly uint discreteQueuePriorities;
+    private readonly float pointSizeRangeMin;
+    private readonly float pointSizeRangeMax;
+    private readonly float lineWidthRangeMin;
+    private readonly float lineWidthRangeMax;
+    private readonly float pointSizeGranularity;
+    private readonly float lineWidthGranularity;
+    private readonly uint strictLines;
+    private readonly uint standardSampleLocations;
+    private readonly ulong optimalBufferCopyOffsetAlignment;
+    private readonly ulong optimalBufferCopyRowPitchAlignment;
+    private readonly ulong nonCoherentAtomSize;
+    private readonly uint subgroupSize;
+    private readonly uint maxMultiviewViewCount;
+    private readonly uint maxMultiviewInstanceIndex;
+    private readonly uint maxPerSetDescriptors;
+    private readonly ulong maxMemoryAllocationSize;
+    private readonly uint maxUpdateAfterBindDescriptorsInAllPools;
+    private readonly uint maxPerStageDescriptorUpdateAfterBindSamplers;
+    private readonly uint maxPerStageDescriptorUpdateAfterBindUniformBuffers;
+    private readonly uint maxPerStageDescriptorUpdateAfterBindStorageBuffers;
+    private readonly uint maxPerStageDescriptorUpdateAfterBindSampledImages;
+    private readonly uint maxPerStageDescriptorUpdateAfterBindStorageImages;
+    private readonly uint maxPerStageDescriptorUpdateAfterBindInputAttachments;
+    private readonly uint maxPerStageUpdateAfterBindResources;
+    private readonly uint maxDescriptorSetUpdateAfterBindSamplers;
+    private readonly uint maxDescriptorSetUpdateAfterBindUniformBuffers;
+    private readonly uint maxDescriptorSetUpdateAfterBindUniformBuffersDynamic;
+    private readonly uint maxDescriptorSetUpdateAfterBindStorageBuffers;
+    private readonly uint maxDescriptorSetUpdateAfterBindStorageBuffersDynamic;
+    private readonly uint maxDescriptorSetUpdateAfterBindSampledImages;
+    private readonly uint maxDescriptorSetUpdateAfterBindStorageImages;
+    private readonly uint maxDescriptorSetUpdateAfterBindInputAttachments;
+    private readonly ulong maxTimelineSemaphoreValueDifference;
+    private readonly uint minSubgroupSize;
+    private readonly uint maxSubgroupSize;
+    private readonly uint maxComputeWorkgroupSubgroups;
+    private readonly uint maxInlineUniformBlockSize;
+    private readonly uint maxPerStageDescriptorInlineUniformBlocks;
+    private readonly uint maxPerStageDescriptorUpdateAfterBindInlineUniformBlocks;
+    private readonly uint maxDescriptorSetInlineUniformBlocks;
+    private readonly uint maxDescriptorSetUpdateAfterBindInlineUniformBlocks;
+    private readonly uint maxInlineUniformTotalSize;
+    private readonly ulong storageTexelBufferOffsetAlignmentBytes;
+    private readonly ulong uniformTexelBufferOffsetAlignmentBytes;
+    private readonly ulong maxBufferSize;
+
+    public uint MaxImageDimension1D => maxImageDimension1D;
+    public uint MaxImageDimension2D => maxImageDimension2D;
+    public uint MaxImageDimension3D => maxImageDimension3D;
+    public uint MaxImageDimensionCube => maxImageDimensionCube;
+    public uint MaxImageArrayLayers => maxImageArrayLayers;
+    public uint MaxTexelBufferElements => maxTexelBufferElements;
+    public uint MaxUniformBufferRange => maxUniformBufferRange;
+    public uint MaxStorageBufferRange => maxStorageBufferRange;
+    public uint MaxPushConstantsSize => maxPushConstantsSize;
+    public uint MaxMemoryAllocationCount => maxMemoryAllocationCount;
+    public uint MaxSamplerAllocationCount => maxSamplerAllocationCount;
+    public ulong BufferImageGranularity => bufferImageGranularity;
+    public ulong SparseAddressSpaceSize => sparseAddressSpaceSize;
+    public uint MaxBoundDescriptorSets => maxBoundDescriptorSets;
+    public uint MaxPerStageDescriptorSamplers => maxPerStageDescriptorSamplers;
+    public uint MaxPerStageDescriptorUniformBuffers => maxPerStageDescriptorUniformBuffers;
+    public uint MaxPerStageDescriptorStorageBuffers => maxPerStageDescriptorStorageBuffers;
+    public uint MaxPerStageDescriptorSampledImages => maxPerStageDescriptorSampledImages;
+    public uint MaxPerStageDescriptorStorageImages => maxPerStageDescriptorStorageImages;
+    public uint MaxPerStageDescriptorInputAttachments => maxPerStageDescriptorInputAttachments;
+    public uint MaxPerStageResources => maxPerStageResources;
+    public uint MaxDescriptorSetSamplers => maxDescriptorSetSamplers;
+    public uint MaxDescriptorSetUniformBuffers => maxDescriptorSetUniformBuffers;
+    public uint MaxDescriptorSetUniformBuffersDynamic => maxDescriptorSetUniformBuffersDynamic;
+    public uint MaxDescriptorSetStorageBuffers => maxDescriptorSetStorageBuffers;
+    public uint MaxDescriptorSetStorageBuffersDynamic => maxDescriptorSetStorageBuffersDynamic;
+    public uint MaxDescriptorSetSampledImages => maxDescriptorSetSampledImages;
+    public uint MaxDescriptorSetStorageImages => maxDescriptorSetStorageImages;
+    public uint MaxDescriptorSetInputAttachments => maxDescriptorSetInputAttachments;
+    public uint MaxVertexInputAttributes => maxVertexInputAttributes;
+    public uint MaxVertexInputBindings => maxVertexInputBindings;
+    public uint MaxVertexInputAttributeOffset => maxVertexInputAttributeOffset;
+    public uint MaxVertexInputBindingStride => maxVertexInputBindingStride;
+    public uint MaxVertexOutputComponents => maxVertexOutputComponents;
+    public uint MaxTessellationGenerationLevel => maxTessellationGenerationLevel;
+    public uint MaxTessellationPatchSize => maxTessellationPatchSize;
+    public uint MaxTessellationControlPerVertexInputComponents => maxTessellationControlPerVertexInputComponents;
+    public uint MaxTessellationControlPerVertexOutputComponents => maxTessellationControlPerVertexOutputComponents;
+    public uint MaxTessellationControlPerPatchOutputComponents => maxTessellationControlPerPatchOutputComponents;
+    public uint MaxTessellationControlTotalOutputComponents => maxTessellationControlTotalOutputComponents;
+    public uint MaxTessellationEvaluationInputComponents => maxTessellationEvaluationInputComponents;
+    public uint MaxTessellationEvaluationOutputComponents => maxTessellationEvaluationOutputComponents;
+    public uint MaxGeometryShaderInvocations => maxGeometryShaderInvocations;
+    public uint MaxGeometryInputComponents => maxGeometryInputComponents;
+    public uint MaxGeometryOutputComponents => maxGeometryOutputComponents;
+    public uint MaxGeometryOutputVertices => maxGeometryOutputVertices;
+    public uint MaxGeometryTotalOutputComponents => maxGeometryTotalOutputComponents;
+    public uint MaxFragmentInputComponents => maxFragmentInputComponents;
+    public uint MaxFragmentOutputAttachments => maxFragmentOutputAttachments;
+    public uint MaxFragmentDualSrcAttachments => maxFragmentDualSrcAttachments;
+    public uint MaxFragmentCombinedOutputResources => maxFragmentCombinedOutputResources;
+    public uint MaxComputeSharedMemorySize => maxComputeSharedMemorySize;
+    public (uint X, uint Y, uint Z) MaxComputeWorkGroupCount =>
+        (maxComputeWorkGroupCountX, maxComputeWorkGroupCountY, maxComputeWorkGroupCountZ);
+    public uint MaxComputeWorkGroupInvocations => maxComputeWorkGroupInvocations;
+    public (uint X, uint Y, uint Z) MaxComputeWorkGroupSize =>
+        (maxComputeWorkGroupSizeX, maxComputeWorkGroupSizeY, maxComputeWorkGroupSizeZ);
+    public uint SubPixelPrecisionBits => subPixelPrecisionBits;
+    public uint SubTexelPrecisionBits => subTexelPrecisionBits;
+    public uint MipmapPrecisionBits => mipmapPrecisionBits;
+    public uint MaxDrawIndexedIndexValue => maxDrawIndexedIndexValue;
+    public uint MaxDrawIndirectCount => maxDrawIndirectCount;
+    public float MaxSamplerLodBias => maxSamplerLodBias;
+    public float MaxSamplerAnisotropy => maxSamplerAnisotropy;
+    public uint MaxViewports => maxViewports;
+    public (uint Width, uint Height) MaxViewportDimensions => (maxViewportDimensionsWidth, maxViewportDimensionsHeight);
+    public (float Min, float Max) ViewportBoundsRange => (viewportBoundsRangeMin, viewportBoundsRangeMax);
+    public uint ViewportSubPixelBits => viewportSubPixelBits;
+    public nuint MinMemoryMapAlignment => minMemoryMapAlignment;
+    public ulong MinTexelBufferOffsetAlignment => minTexelBufferOffsetAlignment;
+    public ulong MinUniformBufferOffsetAlignment => minUniformBufferOffsetAlignment;
+    public ulong MinStorageBufferOffsetAlignment => minStorageBufferOffsetAlignment;
+    public int MinTexelOffset => minTexelOffset;
+    public uint MaxTexelOffset => maxTexelOffset;
+    public int MinTexelGatherOffset => minTexelGatherOffset;
+    public uint MaxTexelGatherOffset => maxTexelGatherOffset;
+    public float MinInterpolationOffset => minInterpolationOffset;
+    public float MaxInterpolationOffset => maxInterpolationOffset;
+    public uint SubPixelInterpolationOffsetBits => subPixelInterpolationOffsetBits;
+    public uint MaxFramebufferWidth => maxFramebufferWidth;
+    public uint MaxFramebufferHeight => maxFramebufferHeight;
+    public uint MaxFramebufferLayers => maxFramebufferLayers;
+    public uint FramebufferColorSampleCounts => framebufferColorSampleCounts;
+    public uint FramebufferDepthSampleCounts => framebufferDepthSampleCounts;
+    public uint FramebufferStencilSampleCounts => framebufferStencilSampleCounts;
+    public uint FramebufferNoAttachmentsSampleCounts => framebufferNoAttachmentsSampleCounts;
+    public uint MaxColorAttachments => maxColorAttachments;
+    public uint SampledImageColorSampleCounts => sampledImageColorSampleCounts;
+    public uint SampledImageIntegerSampleCounts => sampledImageIntegerSampleCounts;
+    public uint SampledImageDepthSampleCounts => sampledImageDepthSampleCounts;
+    public uint SampledImageStencilSampleCounts => sampledImageStencilSampleCounts;
+    public uint StorageImageSampleCounts => storageImageSampleCounts;
+    public uint MaxSampleMaskWords => maxSampleMaskWords;
+    public bool TimestampComputeAndGraphics => timestampComputeAndGraphics != 0;
+    public float TimestampPeriod => timestampPeriod;
+    public uint MaxClipDistances => maxClipDistances;
+    public uint MaxCullDistances => maxCullDistances;
+    public uint MaxCombinedClipAndCullDistances => maxCombinedClipAndCullDistances;
+    public uint DiscreteQueuePriorities => discreteQueuePriorities;
+    public (float Min, float Max) PointSizeRange => (pointSizeRangeMin, pointSizeRangeMax);
+    public (float Min, float Max) LineWidthRange => (lineWidthRangeMin, lineWidthRangeMax);
+    public float PointSizeGranularity => pointSizeGranularity;
+    public float LineWidthGranularity => lineWidthGranularity;
+    public bool StrictLines => strictLines != 0;
+    public bool StandardSampleLocations => standardSampleLocations != 0;
+    public ulong OptimalBufferCopyOffsetAlignment => optimalBufferCopyOffsetAlignment;
+    public ulong OptimalBufferCopyRowPitchAlignment => optimalBufferCopyRowPitchAlignment;
+    public ulong NonCoherentAtomSize => nonCoherentAtomSize;
+    public uint SubgroupSize => subgroupSize;
+    public uint MaxMultiviewViewCount => maxMultiviewViewCount;
+    public uint MaxMultiviewInstanceIndex => maxMultiviewInstanceIndex;
+    public uint MaxPerSetDescriptors => maxPerSetDescriptors;
+    public ulong MaxMemoryAllocationSize => maxMemoryAllocationSize;
+    public uint MaxUpdateAfterBindDescriptorsInAllPools => maxUpdateAfterBindDescriptorsInAllPools;
+    public uint MaxPerStageDescriptorUpdateAfterBindSamplers => maxPerStageDescriptorUpdateAfterBindSamplers;
+    public uint MaxPerStageDescriptorUpdateAfterBindUniformBuffers =>
+        maxPerStageDescriptorUpdateAfterBindUniformBuffers;
+    public uint MaxPerStageDescriptorUpdateAfterBindStorageBuffers =>
+        maxPerStageDescriptorUpdateAfterBindStorageBuffers;
+    public uint MaxPerStageDescriptorUpdateAfterBindSampledImages => maxPerStageDescriptorUpdateAfterBindSampledImages;
+    public uint MaxPerStageDescriptorUpdateAfterBindStorageImages => maxPerStageDescriptorUpdateAfterBindStorageImages;
+    public uint MaxPerStageDescriptorUpdateAfterBindInputAttachments =>
+        maxPerStageDescriptorUpdateAfterBindInputAttachments;
+    public uint MaxPerStageUpdateAfterBindResources => maxPerStageUpdateAfterBindResources;
+    public uint MaxDescriptorSetUpdateAfterBindSamplers => maxDescriptorSetUpdateAfterBindSamplers;
+    public uint MaxDescriptorSetUpdateAfterBindUniformBuffers => maxDescriptorSetUpdateAfterBindUniformBuffers;
+    public uint MaxDescriptorSetUpdateAfterBindUniformBuffersDynamic =>
+        maxDescriptorSetUpdateAfterBindUniformBuffersDynamic;
+    public uint MaxDescriptorSetUpdateAfterBindStorageBuffers => maxDescriptorSetUpdateAfterBindStorageBuffers;
+    public uint MaxDescriptorSetUpdateAfterBindStorageBuffersDynamic =>
+        maxDescriptorSetUpdateAfterBindStorageBuffersDynamic;
+    public uint MaxDescriptorSetUpdateAfterBindSampledImages => maxDescriptorSetUpdateAfterBindSampledImages;
+    public uint MaxDescriptorSetUpdateAfterBindStorageImages => maxDescriptorSetUpdateAfterBindStorageImages;
+    public uint MaxDescriptorSetUpdateAfterBindInputAttachments => maxDescriptorSetUpdateAfterBindInputAttachments;
+    public ulong MaxTimelineSemaphoreValueDifference => maxTimelineSemaphoreValueDifference;
+    public uint MinSubgroupSize => minSubgroupSize;
+    public uint MaxSubgroupSize => maxSubgroupSize;
+    public uint MaxComputeWorkgroupSubgroups => maxComputeWorkgroupSubgroups;
+    public uint MaxInlineUniformBlockSize => maxInlineUniformBlockSize;
+    public uint MaxPerStageDescriptorInlineUniformBlocks => maxPerStageDescriptorInlineUniformBlocks;
+    public uint MaxPerStageDescriptorUpdateAfterBindInlineUniformBlocks =>
+        maxPerStageDescriptorUpdateAfterBindInlineUniformBlocks;
+    public uint MaxDescriptorSetInlineUniformBlocks => maxDescriptorSetInlineUniformBlocks;
+    public uint MaxDescriptorSetUpdateAfterBindInlineUniformBlocks =>
+        maxDescriptorSetUpdateAfterBindInlineUniformBlocks;
+    public uint MaxInlineUniformTotalSize => maxInlineUniformTotalSize;
+    public ulong StorageTexelBufferOffsetAlignmentBytes => storageTexelBufferOffsetAlignmentBytes;
+    public ulong UniformTexelBufferOffsetAlignmentBytes => uniformTexelBufferOffsetAlignmentBytes;
+    public ulong MaxBufferSize => maxBufferSize;
+
+}
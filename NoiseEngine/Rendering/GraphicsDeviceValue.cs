@@ -12,6 +12,7 @@ internal record struct GraphicsDeviceValue(
     Guid Guid,
     bool SupportGraphics,
     bool SupportComputing,
+    bool SupportAsyncComputing,
     bool SupportPresentation,
     InteropHandle<GraphicsDevice> Handle
 );
@@ -1,10 +1,14 @@
 ﻿using NoiseEngine.Rendering.Vulkan;
+using System;
 using System.Collections.Generic;
+using System.Linq;
 
 namespace NoiseEngine.Rendering;
 
 public abstract class GraphicsInstance {
 
+    private readonly object devicesLocker = new object();
+
     public abstract GraphicsApi Api { get; }
     public abstract bool SupportsPresentation { get; }
 
@@ -39,4 +43,57 @@ public abstract class GraphicsInstance {
         );
     }
 
+    /// <summary>
+    /// Returns <see cref="Devices"/> which meet requirements, ordered from the best one. Devices are compared by
+    /// their type, where discrete ones are preferred, and next by size of their local memory.
+    /// </summary>
+    /// <param name="requiredFeatures">Names of features, which must be supported.</param>
+    /// <param name="requiredExtensions">Names of Vulkan device extensions, which must be supported.</param>
+    /// <param name="presentationWindow">
+    /// <see cref="Window"/> to which devices must be able to present, or <see langword="null"/>.
+    /// </param>
+    /// <returns>Suitable devices, from the best one.</returns>
+    public IReadOnlyList<GraphicsDevice> RankDevices(
+        IEnumerable<string>? requiredFeatures = null, IEnumerable<string>? requiredExtensions = null,
+        Window? presentationWindow = null
+    ) {
+        return RankDevicesWorker(
+            requiredFeatures?.ToArray() ?? Array.Empty<string>(),
+            requiredExtensions?.ToArray() ?? Array.Empty<string>(),
+            presentationWindow
+        );
+    }
+
+    /// <summary>
+    /// Replaces <paramref name="device"/> in <see cref="Devices"/> by new <see cref="GraphicsDevice"/> of the same
+    /// physical device, which is initialized with the same features and queues when <paramref name="device"/> is
+    /// initialized. Objects created by <paramref name="device"/> are not moved to the new one, and must be created
+    /// again. This is the way to continue rendering after <paramref name="device"/> was lost.
+    /// </summary>
+    /// <param name="device"><see cref="GraphicsDevice"/> from <see cref="Devices"/>.</param>
+    /// <returns>New <see cref="GraphicsDevice"/>, which is in <see cref="Devices"/>.</returns>
+    /// <exception cref="ArgumentException">
+    /// <paramref name="device"/> is not from this <see cref="GraphicsInstance"/>.
+    /// </exception>
+    /// <exception cref="InvalidOperationException"><paramref name="device"/> was already recreated.</exception>
+    public GraphicsDevice RecreateDevice(GraphicsDevice device) {
+        if (device.Instance != this)
+            throw new ArgumentException($"{device} is not from {this}.", nameof(device));
+
+        lock (devicesLocker) {
+            GraphicsDevice[] devices = ProtectedDevices.ToArray();
+            int index = Array.IndexOf(devices, device);
+            if (index == -1)
+                throw new InvalidOperationException($"{device} was already recreated.");
+
+            devices[index] = device.Recreate();
+            ProtectedDevices = devices;
+            return devices[index];
+        }
+    }
+
+    private protected abstract IReadOnlyList<GraphicsDevice> RankDevicesWorker(
+        IReadOnlyList<string> requiredFeatures, IReadOnlyList<string> requiredExtensions, Window? presentationWindow
+    );
+
 }
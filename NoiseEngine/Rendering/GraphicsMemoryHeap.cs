@@ -0,0 +1,11 @@
+﻿namespace NoiseEngine.Rendering;
+
+/// <summary>
+/// Memory heap of <see cref="GraphicsDevice"/>.
+/// </summary>
+/// <param name="Size">Size of the heap in bytes.</param>
+/// <param name="IsDeviceLocal">
+/// <see langword="true"/> when the heap is local to the device, which is the fastest memory for it.
+/// </param>
+/// <param name="IsHostVisible"><see langword="true"/> when memory of the heap can be mapped by CPU.</param>
+public readonly record struct GraphicsMemoryHeap(ulong Size, bool IsDeviceLocal, bool IsHostVisible);
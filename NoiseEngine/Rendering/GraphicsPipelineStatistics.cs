@@ -0,0 +1,22 @@
+﻿using System;
+
+namespace NoiseEngine.Rendering;
+
+/// <summary>
+/// https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkQueryPipelineStatisticFlagBits.html
+/// </summary>
+[Flags]
+public enum GraphicsPipelineStatistics : uint {
+    None = 0,
+    InputAssemblyVertices = 1 << 0,
+    InputAssemblyPrimitives = 1 << 1,
+    VertexShaderInvocations = 1 << 2,
+    GeometryShaderInvocations = 1 << 3,
+    GeometryShaderPrimitives = 1 << 4,
+    ClippingInvocations = 1 << 5,
+    ClippingPrimitives = 1 << 6,
+    FragmentShaderInvocations = 1 << 7,
+    TessellationControlShaderPatches = 1 << 8,
+    TessellationEvaluationShaderInvocations = 1 << 9,
+    ComputeShaderInvocations = 1 << 10
+}
@@ -0,0 +1,138 @@
+﻿using NoiseEngine.Interop;
+using NoiseEngine.Interop.InteropMarshalling;
+using NoiseEngine.Interop.Rendering.Vulkan;
+using NoiseEngine.Rendering.Exceptions;
+using System;
+using System.Numerics;
+
+namespace NoiseEngine.Rendering;
+
+/// <summary>
+/// Set of GPU queries, which are written by <see cref="Buffers.GraphicsCommandBuffer"/> commands.
+/// </summary>
+/// <remarks>
+/// Queries must be reset by <see cref="Buffers.GraphicsCommandBuffer.ResetQueries"/> before they are written.
+/// </remarks>
+public class GraphicsQueryPool {
+
+    internal InteropHandle<GraphicsQueryPool> Handle { get; }
+
+    public GraphicsDevice Device { get; }
+    public GraphicsQueryType Type { get; }
+    public uint Count { get; }
+    public GraphicsPipelineStatistics PipelineStatistics { get; }
+
+    /// <summary>
+    /// Count of values written by single query. For <see cref="GraphicsQueryType.PipelineStatistics"/> it is count
+    /// of collected statistics, ordered by flag value, otherwise it is one.
+    /// </summary>
+    public int ValuesPerQuery => Type == GraphicsQueryType.PipelineStatistics ?
+        BitOperations.PopCount((uint)PipelineStatistics) : 1;
+
+    /// <summary>
+    /// Creates new <see cref="GraphicsQueryPool"/>.
+    /// </summary>
+    /// <param name="device"><see cref="GraphicsDevice"/> which executes queries.</param>
+    /// <param name="type">Type of queries.</param>
+    /// <param name="count">Count of queries.</param>
+    /// <param name="pipelineStatistics">
+    /// Collected statistics, used only by <see cref="GraphicsQueryType.PipelineStatistics"/>.
+    /// </param>
+    public GraphicsQueryPool(
+        GraphicsDevice device, GraphicsQueryType type, uint count,
+        GraphicsPipelineStatistics pipelineStatistics = GraphicsPipelineStatistics.None
+    ) {
+        if (count == 0)
+            throw new ArgumentOutOfRangeException(nameof(count));
+        if (type == GraphicsQueryType.PipelineStatistics && pipelineStatistics == GraphicsPipelineStatistics.None) {
+            throw new ArgumentException(
+                "Pipeline statistics query pool must collect at least one statistic.", nameof(pipelineStatistics)
+            );
+        }
+
+        device.Initialize();
+
+        Device = device;
+        Type = type;
+        Count = count;
+        PipelineStatistics = type == GraphicsQueryType.PipelineStatistics ?
+            pipelineStatistics : GraphicsPipelineStatistics.None;
+
+        InteropResult<InteropHandle<GraphicsQueryPool>> result = device.Instance.Api switch {
+            GraphicsApi.Vulkan => VulkanQueryPoolInterop.Create(device.Handle, type, count, PipelineStatistics),
+            _ => throw new GraphicsApiNotSupportedException(device.Instance.Api),
+        };
+
+        if (!result.TryGetValue(out InteropHandle<GraphicsQueryPool> handle, out ResultError error))
+            error.ThrowAndDispose();
+        Handle = handle;
+    }
+
+    ~GraphicsQueryPool() {
+        if (Handle == InteropHandle<GraphicsQueryPool>.Zero)
+            return;
+
+        VulkanQueryPoolInterop.Destroy(Handle);
+    }
+
+    /// <summary>
+    /// Waits for results of queries starting with <paramref name="firstQuery"/> and writes them to
+    /// <paramref name="results"/>. Timestamps are in nanoseconds.
+    /// </summary>
+    /// <param name="firstQuery">Index of the first read query.</param>
+    /// <param name="results">
+    /// Destination of results, which length must be a multiple of <see cref="ValuesPerQuery"/>.
+    /// </param>
+    public void GetResults(uint firstQuery, Span<ulong> results) {
+        GetResultsWorker(firstQuery, results, true);
+    }
+
+    /// <summary>
+    /// Writes results of queries starting with <paramref name="firstQuery"/> to <paramref name="results"/>
+    /// without waiting. Timestamps are in nanoseconds.
+    /// </summary>
+    /// <param name="firstQuery">Index of the first read query.</param>
+    /// <param name="results">
+    /// Destination of results, which length must be a multiple of <see cref="ValuesPerQuery"/>.
+    /// </param>
+    /// <returns>
+    /// <see langword="true"/> when all results were available, otherwise <see langword="false"/> and contents of
+    /// <paramref name="results"/> are undefined.
+    /// </returns>
+    public bool TryGetResults(uint firstQuery, Span<ulong> results) {
+        return GetResultsWorker(firstQuery, results, false);
+    }
+
+    internal void AssertQuery(uint query, string paramName) {
+        if (query >= Count)
+            throw new ArgumentOutOfRangeException(paramName);
+    }
+
+    private bool GetResultsWorker(uint firstQuery, Span<ulong> results, bool wait) {
+        uint count = GetQueryCount(firstQuery, results.Length);
+
+        if (!VulkanQueryPoolInterop.GetResults(Handle, firstQuery, count, results, wait).TryGetValue(
+            out InteropBool isAvailable, out ResultError error
+        )) {
+            error.ThrowAndDispose();
+        }
+
+        return isAvailable;
+    }
+
+    private uint GetQueryCount(uint firstQuery, int length) {
+        int valuesPerQuery = ValuesPerQuery;
+        if (length == 0 || length % valuesPerQuery != 0) {
+            throw new ArgumentException(
+                $"Length of results must be a multiple of {nameof(ValuesPerQuery)}.", "results"
+            );
+        }
+
+        uint count = (uint)(length / valuesPerQuery);
+        if (firstQuery > Count || count > Count - firstQuery)
+            throw new ArgumentOutOfRangeException(nameof(firstQuery));
+
+        return count;
+    }
+
+}
@@ -0,0 +1,16 @@
+﻿namespace NoiseEngine.Rendering;
+
+public enum GraphicsQueryType : uint {
+    /// <summary>
+    /// Counts samples which pass depth and stencil tests between begin and end of the query.
+    /// </summary>
+    Occlusion = 0,
+    /// <summary>
+    /// Counts <see cref="GraphicsPipelineStatistics"/> between begin and end of the query.
+    /// </summary>
+    PipelineStatistics = 1,
+    /// <summary>
+    /// Writes GPU time in nanoseconds.
+    /// </summary>
+    Timestamp = 2
+}
@@ -0,0 +1,17 @@
+﻿namespace NoiseEngine.Rendering;
+
+/// <summary>
+/// Family of queues of <see cref="GraphicsDevice"/>, where all queues have the same capabilities.
+/// </summary>
+/// <param name="Index">Index of the family, used by <see cref="GraphicsQueueFamilyConfiguration"/>.</param>
+/// <param name="QueueCount">Maximum count of queues which can be created in this family.</param>
+/// <param name="SupportsGraphics"><see langword="true"/> when queues support graphics commands.</param>
+/// <param name="SupportsComputing"><see langword="true"/> when queues support compute commands.</param>
+/// <param name="SupportsTransfer"><see langword="true"/> when queues support transfer commands.</param>
+public readonly record struct GraphicsQueueFamily(
+    uint Index,
+    uint QueueCount,
+    bool SupportsGraphics,
+    bool SupportsComputing,
+    bool SupportsTransfer
+);
@@ -0,0 +1,12 @@
+﻿using System.Collections.Generic;
+
+namespace NoiseEngine.Rendering;
+
+/// <summary>
+/// Queues created in family of <see cref="GraphicsDevice"/> by its initialization.
+/// </summary>
+/// <param name="FamilyIndex">Index of <see cref="GraphicsQueueFamily"/>.</param>
+/// <param name="Priorities">
+/// Priorities of created queues, between <c>0</c> and <c>1</c>. Count of priorities is count of created queues.
+/// </param>
+public sealed record GraphicsQueueFamilyConfiguration(uint FamilyIndex, IReadOnlyList<float> Priorities);
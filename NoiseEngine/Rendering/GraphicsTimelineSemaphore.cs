@@ -0,0 +1,170 @@
+﻿using NoiseEngine.Interop;
+using NoiseEngine.Interop.InteropMarshalling;
+using NoiseEngine.Interop.Rendering;
+using System;
+
+namespace NoiseEngine.Rendering;
+
+/// <summary>
+/// Synchronization object with monotonically increasing value, which can be signaled and waited by executions of
+/// <see cref="Buffers.GraphicsCommandBuffer"/>s on different queues and by the CPU.
+/// </summary>
+public class GraphicsTimelineSemaphore {
+
+    public GraphicsDevice Device { get; }
+
+    /// <summary>
+    /// Current value of this <see cref="GraphicsTimelineSemaphore"/>.
+    /// </summary>
+    public ulong Value {
+        get {
+            if (!GraphicsTimelineSemaphoreInterop.Value(Handle).TryGetValue(out ulong value, out ResultError error))
+                error.ThrowAndDispose();
+            return value;
+        }
+    }
+
+    internal InteropHandle<GraphicsTimelineSemaphore> Handle { get; }
+
+    /// <summary>
+    /// Creates new <see cref="GraphicsTimelineSemaphore"/>.
+    /// </summary>
+    /// <param name="device"><see cref="GraphicsDevice"/> of the semaphore.</param>
+    /// <param name="initialValue">Initial value of the semaphore.</param>
+    public GraphicsTimelineSemaphore(GraphicsDevice device, ulong initialValue = 0) {
+        device.Initialize();
+
+        Device = device;
+        Handle = device.CreateTimelineSemaphore(initialValue);
+    }
+
+    ~GraphicsTimelineSemaphore() {
+        GraphicsTimelineSemaphoreInterop.Destroy(Handle);
+    }
+
+    /// <summary>
+    /// Waits for all <paramref name="values"/> to be reached or the timeout will be exceeded.
+    /// </summary>
+    /// <param name="values">
+    /// Values of <see cref="GraphicsTimelineSemaphore"/>s to wait. They must belong to the same
+    /// <see cref="GraphicsDevice"/>.
+    /// </param>
+    /// <param name="nanosecondsTimeout">The timeout period in units of nanoseconds.</param>
+    /// <returns>
+    /// <see langword="true"/> when all <paramref name="values"/> have been reached; otherwise
+    /// <see langword="false"/>.
+    /// </returns>
+    public static bool WaitAll(ReadOnlySpan<GraphicsTimelineSemaphoreValue> values, ulong nanosecondsTimeout) {
+        return WaitMultiple(values, true, nanosecondsTimeout);
+    }
+
+    /// <summary>
+    /// Waits for all <paramref name="values"/> to be reached.
+    /// </summary>
+    /// <param name="values">
+    /// Values of <see cref="GraphicsTimelineSemaphore"/>s to wait. They must belong to the same
+    /// <see cref="GraphicsDevice"/>.
+    /// </param>
+    public static void WaitAll(ReadOnlySpan<GraphicsTimelineSemaphoreValue> values) {
+        WaitAll(values, ulong.MaxValue);
+    }
+
+    /// <summary>
+    /// Waits until at least one of the <paramref name="values"/> is reached or the timeout will be exceeded.
+    /// </summary>
+    /// <param name="values">
+    /// Values of <see cref="GraphicsTimelineSemaphore"/>s to wait. They must belong to the same
+    /// <see cref="GraphicsDevice"/>.
+    /// </param>
+    /// <param name="nanosecondsTimeout">The timeout period in units of nanoseconds.</param>
+    /// <returns>
+    /// <see langword="true"/> when at least one of the <paramref name="values"/> has been reached; otherwise
+    /// <see langword="false"/>.
+    /// </returns>
+    public static bool WaitAny(ReadOnlySpan<GraphicsTimelineSemaphoreValue> values, ulong nanosecondsTimeout) {
+        return WaitMultiple(values, false, nanosecondsTimeout);
+    }
+
+    /// <summary>
+    /// Waits until at least one of the <paramref name="values"/> is reached.
+    /// </summary>
+    /// <param name="values">
+    /// Values of <see cref="GraphicsTimelineSemaphore"/>s to wait. They must belong to the same
+    /// <see cref="GraphicsDevice"/>.
+    /// </param>
+    public static void WaitAny(ReadOnlySpan<GraphicsTimelineSemaphoreValue> values) {
+        WaitAny(values, ulong.MaxValue);
+    }
+
+    private static bool WaitMultiple(
+        ReadOnlySpan<GraphicsTimelineSemaphoreValue> values, bool waitAll, ulong nanosecondsTimeout
+    ) {
+        int count = values.Length;
+        if (count == 0)
+            return true;
+
+        Span<InteropHandle<GraphicsTimelineSemaphore>> handles = count <= 512 ?
+            stackalloc InteropHandle<GraphicsTimelineSemaphore>[count] :
+            new InteropHandle<GraphicsTimelineSemaphore>[count];
+        Span<ulong> rawValues = count <= 512 ? stackalloc ulong[count] : new ulong[count];
+
+        GraphicsDevice device = values[0].Semaphore.Device;
+
+        for (int i = 0; i < count; i++) {
+            GraphicsTimelineSemaphoreValue value = values[i];
+            if (device != value.Semaphore.Device) {
+                throw new ArgumentException(
+                    $"Timeline semaphores are not from the same {nameof(GraphicsDevice)}.", nameof(values)
+                );
+            }
+
+            handles[i] = value.Semaphore.Handle;
+            rawValues[i] = value.Value;
+        }
+
+        if (!GraphicsTimelineSemaphoreInterop.WaitMultiple(handles, rawValues, waitAll, nanosecondsTimeout).TryGetValue(
+            out InteropBool isReached, out ResultError error
+        )) {
+            error.ThrowAndDispose();
+        }
+
+        return isReached;
+    }
+
+    /// <summary>
+    /// Waits for this <see cref="GraphicsTimelineSemaphore"/> to reach <paramref name="value"/> or the timeout will
+    /// be exceeded.
+    /// </summary>
+    /// <param name="value">Value to wait for.</param>
+    /// <param name="nanosecondsTimeout">The timeout period in units of nanoseconds.</param>
+    /// <returns>
+    /// <see langword="true"/> when <paramref name="value"/> has been reached; otherwise <see langword="false"/>.
+    /// </returns>
+    public bool Wait(ulong value, ulong nanosecondsTimeout) {
+        if (!GraphicsTimelineSemaphoreInterop.Wait(Handle, value, nanosecondsTimeout).TryGetValue(
+            out InteropBool isReached, out ResultError error
+        )) {
+            error.ThrowAndDispose();
+        }
+
+        return isReached;
+    }
+
+    /// <summary>
+    /// Waits for this <see cref="GraphicsTimelineSemaphore"/> to reach <paramref name="value"/>.
+    /// </summary>
+    /// <param name="value">Value to wait for.</param>
+    public void Wait(ulong value) {
+        Wait(value, ulong.MaxValue);
+    }
+
+    /// <summary>
+    /// Sets value of this <see cref="GraphicsTimelineSemaphore"/> from the CPU.
+    /// </summary>
+    /// <param name="value">New value, which must be greater than the current value.</param>
+    public void Signal(ulong value) {
+        if (!GraphicsTimelineSemaphoreInterop.Signal(Handle, value).TryGetValue(out _, out ResultError error))
+            error.ThrowAndDispose();
+    }
+
+}
@@ -0,0 +1,9 @@
+﻿namespace NoiseEngine.Rendering;
+
+/// <summary>
+/// Value of the <see cref="GraphicsTimelineSemaphore"/> which is waited for or signaled by execution of a
+/// <see cref="Buffers.GraphicsCommandBuffer"/>.
+/// </summary>
+/// <param name="Semaphore"><see cref="GraphicsTimelineSemaphore"/> to wait for or signal.</param>
+/// <param name="Value">Value to wait for or signal.</param>
+public readonly record struct GraphicsTimelineSemaphoreValue(GraphicsTimelineSemaphore Semaphore, ulong Value);
@@ -0,0 +1,32 @@
+﻿using NoiseEngine.Interop.Rendering.Vulkan;
+using NoiseEngine.Rendering.Exceptions;
+using System;
+
+namespace NoiseEngine.Rendering;
+
+public static class GraphicsValidation {
+
+    /// <summary>
+    /// Occurs on the reporting thread when validation of the graphics API reports a message. Text of the message is
+    /// also written to the <see cref="Log"/>.
+    /// </summary>
+    public static event Action<GraphicsValidationMessage>? MessageReceived;
+
+    /// <summary>
+    /// Specifies that validation errors are captured and thrown as <see cref="GraphicsValidationException"/> by the
+    /// next call to the native library, instead of being only logged. Errors are not assigned to threads, so the
+    /// exception can be thrown by a call which did not cause them.
+    /// </summary>
+    /// <remarks>
+    /// Intended for tests. Disabling discards already captured errors. Validation layers must be enabled.
+    /// </remarks>
+    public static bool CaptureErrors {
+        get => VulkanDebugMessageInterop.GetCaptureErrors();
+        set => VulkanDebugMessageInterop.SetCaptureErrors(value);
+    }
+
+    internal static void OnMessageReceived(GraphicsValidationMessage message) {
+        MessageReceived?.Invoke(message);
+    }
+
+}
@@ -0,0 +1,31 @@
+﻿using NoiseEngine.Logging;
+using System.Collections.Generic;
+
+namespace NoiseEngine.Rendering;
+
+/// <summary>
+/// Message reported by validation of the graphics API.
+/// </summary>
+/// <param name="Severity">Severity of the message.</param>
+/// <param name="Type">Type of the message.</param>
+/// <param name="MessageIdName">Name of the violated rule, e.g. a VUID.</param>
+/// <param name="MessageIdNumber">Number of the violated rule.</param>
+/// <param name="Message">Text of the message.</param>
+/// <param name="Objects">Objects related to the message.</param>
+/// <param name="QueueLabels">Labels of the queue which were active when the message was reported.</param>
+/// <param name="CommandBufferLabels">
+/// Labels of the command buffer which were active when the message was reported.
+/// </param>
+public sealed record GraphicsValidationMessage(
+    LogLevel Severity, GraphicsValidationMessageType Type, string? MessageIdName, int MessageIdNumber, string Message,
+    IReadOnlyList<GraphicsValidationObject> Objects, IReadOnlyList<string> QueueLabels,
+    IReadOnlyList<string> CommandBufferLabels
+) {
+
+    /// <summary>
+    /// <see langword="true"/> when this message is an error of <see cref="GraphicsValidationMessageType.Validation"/>.
+    /// </summary>
+    public bool IsValidationError =>
+        Severity == LogLevel.Error && Type.HasFlag(GraphicsValidationMessageType.Validation);
+
+}
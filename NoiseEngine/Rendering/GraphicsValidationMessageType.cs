@@ -0,0 +1,11 @@
+﻿using System;
+
+namespace NoiseEngine.Rendering;
+
+[Flags]
+public enum GraphicsValidationMessageType : uint {
+    None = 0,
+    General = 1 << 0,
+    Validation = 1 << 1,
+    Performance = 1 << 2
+}
@@ -0,0 +1,9 @@
+﻿namespace NoiseEngine.Rendering;
+
+/// <summary>
+/// Native object referenced by a <see cref="GraphicsValidationMessage"/>.
+/// </summary>
+/// <param name="Type">Name of the graphics API object type.</param>
+/// <param name="Handle">Raw handle of the object.</param>
+/// <param name="Name">Debug name of the object, or <see langword="null"/> when it is not named.</param>
+public readonly record struct GraphicsValidationObject(string Type, ulong Handle, string? Name);
@@ -4,6 +4,7 @@ using NoiseEngine.Rendering.Exceptions;
 using NoiseEngine.Rendering.Vulkan;
 using System;
 using System.ComponentModel;
+using System.Numerics;
 
 namespace NoiseEngine.Rendering;
 
@@ -17,6 +18,8 @@ public class SimpleCamera {
     private CameraClearFlags clearFlags = CameraClearFlags.SolidColor;
     private Color clearColor = new Color(0.26666f, 0.45882f, 0.87058f);
     private bool depthTesting = true;
+    private uint sampleCount = 1;
+    private bool storeDepthStencil;
     private ICameraRenderTarget? renderTarget;
 
     public GraphicsDevice GraphicsDevice { get; }
@@ -61,6 +64,46 @@ public class SimpleCamera {
         }
     }
 
+    /// <summary>
+    /// Value to which depth is cleared at the start of rendering. Reverse depth uses <c>0</c> instead of the
+    /// default <c>1</c>.
+    /// </summary>
+    public float ClearDepth { get; set; } = 1f;
+
+    /// <summary>
+    /// Value to which stencil is cleared at the start of rendering.
+    /// </summary>
+    public uint ClearStencil { get; set; }
+
+    /// <summary>
+    /// When <see langword="true"/>, depth and stencil are stored after rendering, so
+    /// <see cref="RenderTexture.DepthStencil"/> can be used later. Otherwise they are discarded.
+    /// </summary>
+    public bool StoreDepthStencil {
+        get => storeDepthStencil;
+        set {
+            storeDepthStencil = value;
+            Delegation.UpdateDepthTesting();
+        }
+    }
+
+    /// <summary>
+    /// Number of samples per pixel, which are resolved into <see cref="RenderTarget"/>. Values greater than one
+    /// enable multisample anti-aliasing, and must be supported by <see cref="GraphicsDevice"/>, see
+    /// <see cref="GraphicsDevice.GetMaxSampleCount(bool)"/>.
+    /// </summary>
+    /// <exception cref="ArgumentOutOfRangeException">Value is not a power of two.</exception>
+    public uint SampleCount {
+        get => sampleCount;
+        set {
+            if (!BitOperations.IsPow2(value))
+                throw new ArgumentOutOfRangeException(nameof(value), "Sample count must be a power of two.");
+
+            sampleCount = value;
+            Delegation.UpdateSampleCount();
+        }
+    }
+
     public float FieldOfViewDegrees {
         get => FloatingPointIeee754Helper<float>.ConvertRadiansToDegrees(FieldOfViewRadians);
         set => FieldOfViewRadians = FloatingPointIeee754Helper<float>.ConvertDegreesToRadians(value);
@@ -12,6 +12,7 @@ internal abstract class SimpleCameraDelegation {
     public abstract void UpdateClearFlags();
     public abstract void UpdateClearColor();
     public abstract void UpdateDepthTesting();
+    public abstract void UpdateSampleCount();
     public abstract void RaiseRenderTargetSet(ICameraRenderTarget? newRenderTarget);
 
     public abstract uint ChangeFramesInFlightCount(uint targetFramesInFlightCount);
@@ -12,10 +12,23 @@ namespace NoiseEngine.Rendering;
 
 public abstract class Texture {
 
+    private string? debugName;
+
     public GraphicsDevice Device { get; }
     public TextureUsage Usage { get; }
     public TextureFormat Format { get; }
 
+    /// <summary>
+    /// Name of this texture, which is visible in validation messages and graphics debuggers.
+    /// </summary>
+    public string? DebugName {
+        get => debugName;
+        set {
+            Device.SetDebugName(this, value ?? string.Empty);
+            debugName = value;
+        }
+    }
+
     internal abstract Vector3<uint> Extent { get; }
     internal abstract uint SampleCountInternal { get; }
 
@@ -0,0 +1,19 @@
+﻿using System.Runtime.InteropServices;
+
+namespace NoiseEngine.Rendering.Vulkan;
+
+/// <summary>
+/// https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkAttachmentDescription.html
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+internal readonly record struct AttachmentDescription(
+    uint Flags,
+    TextureFormat Format,
+    uint SampleCount,
+    AttachmentLoadOp LoadOp,
+    AttachmentStoreOp StoreOp,
+    AttachmentLoadOp StencilLoadOp,
+    AttachmentStoreOp StencilStoreOp,
+    VulkanImageLayout InitialLayout,
+    VulkanImageLayout FinalLayout
+);
@@ -0,0 +1,10 @@
+﻿namespace NoiseEngine.Rendering.Vulkan;
+
+/// <summary>
+/// https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkAttachmentLoadOp.html
+/// </summary>
+internal enum AttachmentLoadOp : uint {
+    Load = 0,
+    Clear = 1,
+    DontCare = 2
+}
@@ -0,0 +1,14 @@
+﻿using System.Runtime.InteropServices;
+
+namespace NoiseEngine.Rendering.Vulkan;
+
+/// <summary>
+/// https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkAttachmentReference.html
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+internal readonly record struct AttachmentReference(uint Attachment, VulkanImageLayout Layout) {
+
+    public static AttachmentReference Unused =>
+        new AttachmentReference(VulkanConstants.AttachmentUnused, VulkanImageLayout.Undefined);
+
+}
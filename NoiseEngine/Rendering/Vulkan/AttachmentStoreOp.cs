@@ -0,0 +1,10 @@
+﻿namespace NoiseEngine.Rendering.Vulkan;
+
+/// <summary>
+/// https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkAttachmentStoreOp.html
+/// </summary>
+internal enum AttachmentStoreOp : uint {
+    Store = 0,
+    DontCare = 1,
+    None = 1000301000
+}
@@ -1,4 +1,5 @@
 ﻿using NoiseEngine.Collections;
+using NoiseEngine.Interop.Rendering.Vulkan.Buffers;
 using NoiseEngine.Common;
 using NoiseEngine.Mathematics;
 using NoiseEngine.Rendering.Buffers;
@@ -43,7 +44,12 @@ internal class VulkanCommandBufferDelegation : GraphicsCommandBufferDelegation {
         writer.WriteUInt32(groupCount.Z);
     }
 
-    public override void AttachCameraWorker(SimpleCamera camera) {
+    public override void PushConstantsWorker(ComputeKernel kernel, uint offset, ReadOnlySpan<byte> data) {
+        AttachKernel(kernel);
+        WritePushConstants(ShaderStageFlags.Compute, offset, data);
+    }
+
+    public override void AttachCameraWorker(SimpleCamera camera, bool secondaryContents) {
         VulkanSimpleCameraDelegation cameraDelegation = (VulkanSimpleCameraDelegation)camera.Delegation;
         RenderPass = cameraDelegation.RenderPass;
 
@@ -76,10 +82,58 @@ internal class VulkanCommandBufferDelegation : GraphicsCommandBufferDelegation {
         }
 
         writer.WriteIntN(cameraDelegation.ClearColor);
+        writer.WriteFloat32(camera.ClearDepth);
+        writer.WriteUInt32(camera.ClearStencil);
+        writer.WriteUInt32(
+            (uint)(secondaryContents ? SubpassContents.SecondaryCommandBuffers : SubpassContents.Inline)
+        );
+        // Additional clear values, remaining attachments are cleared with the clear color or the far depth.
+        writer.WriteInt32(0);
+
+        CameraProjectionViewMatrix = camera.ProjectionViewMatrix;
+    }
 
+    public override void BeginSecondaryWorker(SimpleCamera camera) {
+        RenderPass = ((VulkanSimpleCameraDelegation)camera.Delegation).RenderPass;
+        references.Add(RenderPass);
         CameraProjectionViewMatrix = camera.ProjectionViewMatrix;
     }
 
+    public override void ExecuteSecondaryWorker(ReadOnlySpan<GraphicsCommandBuffer> commandBuffers) {
+        int[] lengths = new int[commandBuffers.Length];
+        int length = 0;
+        for (int i = 0; i < commandBuffers.Length; i++) {
+            lengths[i] = commandBuffers[i].RecordedData.Length;
+            length += lengths[i];
+        }
+
+        byte[] data = new byte[length];
+        length = 0;
+        foreach (GraphicsCommandBuffer secondary in commandBuffers) {
+            secondary.RecordedData.CopyTo(data.AsSpan(length));
+            length += secondary.RecordedData.Length;
+        }
+
+        Vector3<uint> extent = RenderPass!.RenderTarget.Extent;
+        VulkanSecondaryCommandBuffer[] secondaries = VulkanSecondaryCommandBuffer.Create(
+            (VulkanDevice)commandBuffer.Device, data, lengths, commandBuffer.SimultaneousExecute,
+            new VulkanCommandBufferInheritance(RenderPass.Handle, 0, 0, extent.X, extent.Y)
+        );
+
+        // Recorded command buffers keep alive objects referenced by their commands.
+        FastList<object> references = this.references;
+        references.EnsureCapacity(references.Count + commandBuffers.Length + secondaries.Length);
+        foreach (GraphicsCommandBuffer secondary in commandBuffers)
+            references.UnsafeAdd(secondary);
+        foreach (VulkanSecondaryCommandBuffer secondary in secondaries)
+            references.UnsafeAdd(secondary);
+
+        writer.WriteCommand(CommandBufferCommand.ExecuteSecondary);
+        writer.WriteInt32(secondaries.Length);
+        foreach (VulkanSecondaryCommandBuffer secondary in secondaries)
+            writer.WriteIntN(secondary.Handle.Pointer);
+    }
+
     public override void DrawMeshWorker(Mesh mesh, Material material, Matrix4x4<pos> transform) {
         AttachShader(material.Shader);
         AttachMaterial(material);
@@ -91,17 +145,8 @@ internal class VulkanCommandBufferDelegation : GraphicsCommandBufferDelegation {
         references.UnsafeAdd(vertexBuffer);
         references.UnsafeAdd(indexBuffer);
 
-        writer.WriteCommand(CommandBufferCommand.DrawMesh);
-        writer.WriteIntN(vertexBuffer.InnerHandleUniversal.Pointer);
-        writer.WriteIntN(indexBuffer.InnerHandleUniversal.Pointer);
-        writer.WriteUInt32((uint)mesh.IndexFormat);
-        writer.WriteUInt32((uint)indexBuffer.Count);
-
         VulkanCommonShaderDelegation shaderDelegation = AttachedCommonShaderDelegation!;
-        if (shaderDelegation.PushConstantDescriptors.Length == 0) {
-            writer.WriteUInt32(0);
-        } else {
-            writer.WriteUInt32(shaderDelegation.PushConstantSize);
+        if (shaderDelegation.PushConstantDescriptors.Length > 0) {
             Span<byte> data = stackalloc byte[(int)shaderDelegation.PushConstantSize];
 
             foreach (PushConstantDescriptor descriptor in shaderDelegation.PushConstantDescriptors) {
@@ -115,8 +160,15 @@ internal class VulkanCommandBufferDelegation : GraphicsCommandBufferDelegation {
                 }
             }
 
-            writer.WriteBytes(data);
+            WritePushConstants(shaderDelegation.PushConstantStageFlags, 0, data);
         }
+
+        writer.WriteCommand(CommandBufferCommand.DrawMesh);
+        writer.WriteIntN(vertexBuffer.InnerHandleUniversal.Pointer);
+        writer.WriteIntN(indexBuffer.InnerHandleUniversal.Pointer);
+        writer.WriteUInt32((uint)mesh.IndexFormat);
+        writer.WriteUInt32((uint)indexBuffer.Count);
+        writer.WriteUInt32(0);
     }
 
     private void AttachShader(Shader shader) {
@@ -144,6 +196,14 @@ internal class VulkanCommandBufferDelegation : GraphicsCommandBufferDelegation {
         writer.WriteIntN(Unsafe.As<VulkanComputeKernel>(kernel).Pipeline.Handle.Pointer);
     }
 
+    private void WritePushConstants(ShaderStageFlags stages, uint offset, ReadOnlySpan<byte> data) {
+        writer.WriteCommand(CommandBufferCommand.PushConstants);
+        writer.WriteUInt32((uint)stages);
+        writer.WriteUInt32(offset);
+        writer.WriteUInt32((uint)data.Length);
+        writer.WriteBytes(data);
+    }
+
     private void AttachMaterial(CommonMaterial material) {
         if (AttachedMaterial == material || material.Delegation is null)
             return;
@@ -0,0 +1,56 @@
+﻿using NoiseEngine.Interop;
+using NoiseEngine.Interop.Rendering.Buffers;
+using NoiseEngine.Interop.Rendering.Vulkan;
+using NoiseEngine.Interop.Rendering.Vulkan.Buffers;
+using System;
+
+namespace NoiseEngine.Rendering.Vulkan.Buffers;
+
+/// <summary>
+/// Native secondary command buffer, which is kept alive by primary command buffers executing it.
+/// </summary>
+internal sealed class VulkanSecondaryCommandBuffer {
+
+    public InteropHandle<VulkanSecondaryCommandBuffer> Handle { get; }
+
+    private VulkanSecondaryCommandBuffer(InteropHandle<VulkanSecondaryCommandBuffer> handle) {
+        Handle = handle;
+    }
+
+    ~VulkanSecondaryCommandBuffer() {
+        VulkanCommandBufferInterop.DestroySecondary(Handle);
+    }
+
+    /// <summary>
+    /// Records secondary command buffers in parallel from consecutive streams in <paramref name="data"/>.
+    /// </summary>
+    /// <param name="device"><see cref="VulkanDevice"/> of created command buffers.</param>
+    /// <param name="data">Concatenated recorded data of command buffers.</param>
+    /// <param name="lengths">Lengths of data of each command buffer.</param>
+    /// <param name="simultaneousExecute">
+    /// Specifies that command buffers can be executed by multiple pending primary command buffers.
+    /// </param>
+    /// <param name="inheritance">Render pass state inherited from primary command buffers.</param>
+    /// <returns>Created command buffers.</returns>
+    public static VulkanSecondaryCommandBuffer[] Create(
+        VulkanDevice device, ReadOnlySpan<byte> data, ReadOnlySpan<int> lengths, bool simultaneousExecute,
+        VulkanCommandBufferInheritance inheritance
+    ) {
+        if (!VulkanDeviceInterop.CreateSecondaryCommandBuffers(
+            device.Handle, data, lengths, new GraphicsCommandBufferUsage(true, false, false, false),
+            simultaneousExecute, inheritance
+        ).TryGetValue(
+            out InteropArray<InteropHandle<VulkanSecondaryCommandBuffer>> handles, out ResultError error
+        )) {
+            error.ThrowAndDispose();
+        }
+
+        VulkanSecondaryCommandBuffer[] result = new VulkanSecondaryCommandBuffer[handles.Length];
+        for (int i = 0; i < result.Length; i++)
+            result[i] = new VulkanSecondaryCommandBuffer(handles[i]);
+
+        handles.Dispose();
+        return result;
+    }
+
+}
@@ -0,0 +1,16 @@
+﻿using System;
+
+namespace NoiseEngine.Rendering.Vulkan;
+
+/// <summary>
+/// https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkColorComponentFlagBits.html
+/// </summary>
+[Flags]
+internal enum ColorComponentFlags : uint {
+    None = 0,
+    R = 0x00000001,
+    G = 0x00000002,
+    B = 0x00000004,
+    A = 0x00000008,
+    Rgba = 0x0000000F
+}
@@ -10,6 +10,11 @@ internal class ComputePipeline : Pipeline {
     ) : base(layout, new PipelineShaderStage[] { stage }, CreateHandle(layout, stage, flags)) {
     }
 
+    internal ComputePipeline(
+        PipelineLayout layout, PipelineShaderStage stage, InteropHandle<Pipeline> handle
+    ) : base(layout, new PipelineShaderStage[] { stage }, handle) {
+    }
+
     private static InteropHandle<Pipeline> CreateHandle(
         PipelineLayout layout, PipelineShaderStage stage, PipelineCreateFlags flags
     ) {
@@ -0,0 +1,14 @@
+﻿using System;
+
+namespace NoiseEngine.Rendering.Vulkan;
+
+/// <summary>
+/// https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkCullModeFlagBits.html
+/// </summary>
+[Flags]
+internal enum CullModeFlags : uint {
+    None = 0,
+    Front = 0x00000001,
+    Back = 0x00000002,
+    FrontAndBack = 0x00000003
+}
@@ -0,0 +1,14 @@
+﻿using System;
+
+namespace NoiseEngine.Rendering.Vulkan;
+
+/// <summary>
+/// https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkDependencyFlagBits.html
+/// </summary>
+[Flags]
+internal enum DependencyFlags : uint {
+    None = 0,
+    ByRegion = 0x00000001,
+    ViewLocal = 0x00000002,
+    DeviceGroup = 0x00000004
+}
@@ -4,7 +4,16 @@
 /// https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkDescriptorType.html
 /// </summary>
 internal enum DescriptorType : uint {
+    Sampler = 0,
     CombinedImageSampler = 1,
+    SampledImage = 2,
+    StorageImage = 3,
+    UniformTexelBuffer = 4,
+    StorageTexelBuffer = 5,
     Uniform = 6,
-    Storage = 7
+    Storage = 7,
+    UniformDynamic = 8,
+    StorageDynamic = 9,
+    InputAttachment = 10,
+    AccelerationStructure = 1000150000
 }
@@ -0,0 +1,9 @@
+﻿namespace NoiseEngine.Rendering.Vulkan;
+
+/// <summary>
+/// https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkFrontFace.html
+/// </summary>
+internal enum FrontFace : uint {
+    CounterClockwise = 0,
+    Clockwise = 1
+}
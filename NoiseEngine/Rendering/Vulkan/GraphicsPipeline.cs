@@ -8,6 +8,8 @@ namespace NoiseEngine.Rendering.Vulkan;
 
 internal class GraphicsPipeline : Pipeline {
 
+    internal delegate void RawCreateInfoAction(GraphicsPipelineCreateInfoRaw raw);
+
     public RenderPass RenderPass { get; }
 
     public GraphicsPipeline(
@@ -17,38 +19,61 @@ internal class GraphicsPipeline : Pipeline {
         RenderPass = renderPass;
     }
 
-    private static InteropHandle<Pipeline> CreateHandle(
+    internal GraphicsPipeline(
         RenderPass renderPass, PipelineLayout layout, IReadOnlyList<PipelineShaderStage> stages,
-        PipelineCreateFlags flags, GraphicsPipelineCreateInfo createInfo
-    ) {
-        Span<PipelineShaderStageRaw> rawStages = stackalloc PipelineShaderStageRaw[stages.Count];
-        for (int i = 0; i < stages.Count; i++)
-            rawStages[i] = new PipelineShaderStageRaw(stages[i]);
+        InteropHandle<Pipeline> handle
+    ) : base(layout, stages, handle) {
+        RenderPass = renderPass;
+    }
 
-        InteropHandle<Pipeline> handle;
-        unsafe {
-            fixed (VertexInputBindingDescription* vertexBinding = createInfo.VertexInputBindingDescription) {
-                fixed (VertexInputAttributeDescription* vertexAtttribute = createInfo.VertexInputAttributeDescription) {
-                    GraphicsPipelineCreateInfoRaw raw = new GraphicsPipelineCreateInfoRaw() {
+    /// <summary>
+    /// Converts <paramref name="createInfo"/> to raw form, which is valid only during <paramref name="action"/>.
+    /// </summary>
+    /// <param name="createInfo"><see cref="GraphicsPipelineCreateInfo"/> to convert.</param>
+    /// <param name="action">Action which uses raw form.</param>
+    internal static unsafe void UseRawCreateInfo(
+        GraphicsPipelineCreateInfo createInfo, RawCreateInfoAction action
+    ) {
+        fixed (VertexInputBindingDescription* vertexBinding = createInfo.VertexInputBindingDescription) {
+            fixed (VertexInputAttributeDescription* vertexAtttribute = createInfo.VertexInputAttributeDescription) {
+                fixed (PipelineColorBlendAttachmentState* colorBlend = createInfo.ColorBlendAttachments) {
+                    action(new GraphicsPipelineCreateInfoRaw() {
                         VertexInputBindingDescription = new InteropReadOnlySpan<VertexInputBindingDescription>(
                             vertexBinding, createInfo.VertexInputBindingDescription.Length
                         ),
                         VertexInputAttributeDescription = new InteropReadOnlySpan<VertexInputAttributeDescription>(
                             vertexAtttribute, createInfo.VertexInputAttributeDescription.Length
                         ),
-                        PrimitiveTopology = createInfo.PrimitiveTopology
-                    };
-
-                    if (!GraphicsPipelineInterop.Create(
-                        renderPass.Handle, layout.Handle, rawStages, flags, raw
-                    ).TryGetValue(
-                        out handle, out ResultError error
-                    )) {
-                        error.ThrowAndDispose();
-                    }
+                        PrimitiveTopology = createInfo.PrimitiveTopology,
+                        Rasterization = createInfo.Rasterization,
+                        DepthStencil = createInfo.DepthStencil,
+                        Multisample = createInfo.Multisample,
+                        ColorBlendAttachments = new InteropReadOnlySpan<PipelineColorBlendAttachmentState>(
+                            colorBlend, createInfo.ColorBlendAttachments.Length
+                        ),
+                        BlendConstants = createInfo.BlendConstants,
+                        Subpass = createInfo.Subpass
+                    });
                 }
             }
         }
+    }
+
+    private static InteropHandle<Pipeline> CreateHandle(
+        RenderPass renderPass, PipelineLayout layout, IReadOnlyList<PipelineShaderStage> stages,
+        PipelineCreateFlags flags, GraphicsPipelineCreateInfo createInfo
+    ) {
+        PipelineShaderStageRaw[] rawStages = new PipelineShaderStageRaw[stages.Count];
+        for (int i = 0; i < stages.Count; i++)
+            rawStages[i] = new PipelineShaderStageRaw(stages[i]);
+
+        InteropResult<InteropHandle<Pipeline>> result = default;
+        UseRawCreateInfo(createInfo, raw => {
+            result = GraphicsPipelineInterop.Create(renderPass.Handle, layout.Handle, rawStages, flags, raw);
+        });
+
+        if (!result.TryGetValue(out InteropHandle<Pipeline> handle, out ResultError error))
+            error.ThrowAndDispose();
 
         return handle;
     }
@@ -1,4 +1,5 @@
-﻿using System;
+﻿using NoiseEngine.Mathematics;
+using System;
 
 namespace NoiseEngine.Rendering.Vulkan;
 
@@ -7,5 +8,18 @@ internal readonly ref struct GraphicsPipelineCreateInfo {
     public ReadOnlySpan<VertexInputBindingDescription> VertexInputBindingDescription { get; init; }
     public ReadOnlySpan<VertexInputAttributeDescription> VertexInputAttributeDescription { get; init; }
     public PrimitiveTopology PrimitiveTopology { get; init; }
+    public PipelineRasterizationState Rasterization { get; init; }
+    public PipelineDepthStencilState DepthStencil { get; init; }
+    public PipelineMultisampleState Multisample { get; init; }
+    /// <summary>
+    /// Blend state of each color attachment. When empty, <see cref="PipelineColorBlendAttachmentState.Default"/>
+    /// is used.
+    /// </summary>
+    public ReadOnlySpan<PipelineColorBlendAttachmentState> ColorBlendAttachments { get; init; }
+    public Vector4<float> BlendConstants { get; init; }
+    /// <summary>
+    /// Index of the <see cref="RenderPass"/> subpass in which the pipeline is used.
+    /// </summary>
+    public uint Subpass { get; init; }
 
 }
@@ -0,0 +1,12 @@
+﻿namespace NoiseEngine.Rendering.Vulkan;
+
+/// <summary>
+/// https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkObjectType.html
+/// </summary>
+internal enum ObjectType : int {
+    CommandBuffer = 6,
+    Buffer = 9,
+    Image = 10,
+    RenderPass = 18,
+    Pipeline = 19
+}
@@ -1,4 +1,5 @@
 ﻿using NoiseEngine.Interop;
+using NoiseEngine.Interop.InteropMarshalling;
 using NoiseEngine.Interop.Rendering.Vulkan;
 using System.Collections.Generic;
 
@@ -26,4 +27,12 @@ internal abstract class Pipeline {
         PipelineInterop.Destroy(Handle);
     }
 
+    /// <summary>
+    /// Sets name of this <see cref="Pipeline"/>, which is visible in validation messages and graphics debuggers.
+    /// </summary>
+    public void SetDebugName(string name) {
+        if (!PipelineInterop.SetName(Handle, name).TryGetValue(out _, out ResultError error))
+            error.ThrowAndDispose();
+    }
+
 }
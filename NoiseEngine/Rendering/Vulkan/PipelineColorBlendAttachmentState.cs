@@ -0,0 +1,37 @@
+﻿using NoiseEngine.Interop.InteropMarshalling;
+using System.Runtime.InteropServices;
+
+namespace NoiseEngine.Rendering.Vulkan;
+
+/// <summary>
+/// https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkPipelineColorBlendAttachmentState.html
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+internal readonly record struct PipelineColorBlendAttachmentState(
+    InteropBool Blend,
+    BlendFactor SrcColorBlendFactor,
+    BlendFactor DstColorBlendFactor,
+    BlendOp ColorBlendOp,
+    BlendFactor SrcAlphaBlendFactor,
+    BlendFactor DstAlphaBlendFactor,
+    BlendOp AlphaBlendOp,
+    ColorComponentFlags ColorWriteMask
+) {
+
+    /// <summary>
+    /// Blending disabled, all components are written.
+    /// </summary>
+    public static PipelineColorBlendAttachmentState Default => new PipelineColorBlendAttachmentState(
+        false, BlendFactor.One, BlendFactor.Zero, BlendOp.Add, BlendFactor.One, BlendFactor.Zero, BlendOp.Add,
+        ColorComponentFlags.Rgba
+    );
+
+    /// <summary>
+    /// Blending of color by source alpha, used by transparent materials.
+    /// </summary>
+    public static PipelineColorBlendAttachmentState AlphaBlend => new PipelineColorBlendAttachmentState(
+        true, BlendFactor.SrcAlpha, BlendFactor.OneMinusSrcAlpha, BlendOp.Add, BlendFactor.One,
+        BlendFactor.OneMinusSrcAlpha, BlendOp.Add, ColorComponentFlags.Rgba
+    );
+
+}
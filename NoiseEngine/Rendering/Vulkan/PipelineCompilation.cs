@@ -0,0 +1,141 @@
+﻿using NoiseEngine.Interop;
+using NoiseEngine.Interop.InteropMarshalling;
+using NoiseEngine.Interop.Rendering.Vulkan;
+using System;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace NoiseEngine.Rendering.Vulkan;
+
+/// <summary>
+/// Batch of pipelines compiled asynchronously, which can be waited like <see cref="GraphicsFence"/>.
+/// </summary>
+internal sealed class PipelineCompilation {
+
+    private readonly InteropHandle<PipelineCompilation> handle;
+    private readonly IReadOnlyList<Func<InteropHandle<Pipeline>, Pipeline>> factories;
+    private readonly object resultsLocker = new object();
+
+    private PipelineCompilationResult[]? results;
+
+    public int Count => factories.Count;
+
+    public bool IsSignaled => PipelineCompilationInterop.IsSignaled(handle);
+
+    internal PipelineCompilation(
+        InteropHandle<PipelineCompilation> handle, IReadOnlyList<Func<InteropHandle<Pipeline>, Pipeline>> factories
+    ) {
+        this.handle = handle;
+        this.factories = factories;
+    }
+
+    ~PipelineCompilation() {
+        PipelineCompilationInterop.Destroy(handle);
+    }
+
+    /// <summary>
+    /// Waits for all <paramref name="compilations"/> to finish or the timeout will be exceeded.
+    /// </summary>
+    /// <param name="compilations"><see cref="PipelineCompilation"/>s to wait.</param>
+    /// <param name="nanosecondsTimeout">The timeout period in units of nanoseconds.</param>
+    /// <returns>
+    /// <see langword="true"/> when all <paramref name="compilations"/> have finished;
+    /// otherwise <see langword="false"/>.
+    /// </returns>
+    public static bool WaitAll(IEnumerable<PipelineCompilation> compilations, ulong nanosecondsTimeout) {
+        return WaitMultiple(compilations, true, nanosecondsTimeout);
+    }
+
+    /// <summary>
+    /// Waits for all <paramref name="compilations"/> to finish.
+    /// </summary>
+    /// <param name="compilations"><see cref="PipelineCompilation"/>s to wait.</param>
+    public static void WaitAll(IEnumerable<PipelineCompilation> compilations) {
+        WaitAll(compilations, ulong.MaxValue);
+    }
+
+    /// <summary>
+    /// Waits until at least one of the <paramref name="compilations"/> finishes or the timeout will be exceeded.
+    /// </summary>
+    /// <param name="compilations"><see cref="PipelineCompilation"/>s to wait.</param>
+    /// <param name="nanosecondsTimeout">The timeout period in units of nanoseconds.</param>
+    /// <returns>
+    /// <see langword="true"/> when at least one of the <paramref name="compilations"/> has finished;
+    /// otherwise <see langword="false"/>.
+    /// </returns>
+    public static bool WaitAny(IEnumerable<PipelineCompilation> compilations, ulong nanosecondsTimeout) {
+        return WaitMultiple(compilations, false, nanosecondsTimeout);
+    }
+
+    /// <summary>
+    /// Waits until at least one of the <paramref name="compilations"/> finishes.
+    /// </summary>
+    /// <param name="compilations"><see cref="PipelineCompilation"/>s to wait.</param>
+    public static void WaitAny(IEnumerable<PipelineCompilation> compilations) {
+        WaitAny(compilations, ulong.MaxValue);
+    }
+
+    private static bool WaitMultiple(
+        IEnumerable<PipelineCompilation> compilations, bool waitAll, ulong nanosecondsTimeout
+    ) {
+        InteropHandle<PipelineCompilation>[] handles = compilations.Select(x => x.handle).ToArray();
+        if (handles.Length == 0)
+            return true;
+
+        return PipelineCompilationInterop.WaitMultiple(handles, waitAll, nanosecondsTimeout);
+    }
+
+    /// <summary>
+    /// Waits for this <see cref="PipelineCompilation"/> to finish or the timeout will be exceeded.
+    /// </summary>
+    /// <param name="nanosecondsTimeout">The timeout period in units of nanoseconds.</param>
+    /// <returns>
+    /// <see langword="true"/> when this <see cref="PipelineCompilation"/> has finished;
+    /// otherwise <see langword="false"/>.
+    /// </returns>
+    public bool Wait(ulong nanosecondsTimeout) {
+        return PipelineCompilationInterop.Wait(handle, nanosecondsTimeout);
+    }
+
+    /// <summary>
+    /// Waits for this <see cref="PipelineCompilation"/> to finish.
+    /// </summary>
+    public void Wait() {
+        Wait(ulong.MaxValue);
+    }
+
+    /// <summary>
+    /// Waits for this <see cref="PipelineCompilation"/> to finish and returns results of all pipelines, in order of
+    /// adding to <see cref="PipelineCompilationBuilder"/>.
+    /// </summary>
+    /// <returns>Result of each pipeline.</returns>
+    public IReadOnlyList<PipelineCompilationResult> GetResults() {
+        Wait();
+
+        lock (resultsLocker) {
+            if (results is not null)
+                return results;
+
+            if (!PipelineCompilationInterop.TakeResults(handle).TryGetValue(
+                out InteropArray<InteropResult<InteropHandle<Pipeline>>> rawResults, out ResultError error
+            )) {
+                error.ThrowAndDispose();
+            }
+
+            PipelineCompilationResult[] final = new PipelineCompilationResult[rawResults.Length];
+            for (int i = 0; i < final.Length; i++) {
+                if (rawResults[i].TryGetValue(out InteropHandle<Pipeline> pipelineHandle, out ResultError pipelineError)) {
+                    final[i] = new PipelineCompilationResult(factories[i](pipelineHandle), null);
+                } else {
+                    final[i] = new PipelineCompilationResult(null, pipelineError.ToException());
+                    pipelineError.Dispose();
+                }
+            }
+
+            rawResults.Dispose();
+            results = final;
+            return final;
+        }
+    }
+
+}
@@ -0,0 +1,82 @@
+﻿using NoiseEngine.Interop;
+using NoiseEngine.Interop.Rendering.Vulkan;
+using System;
+using System.Collections.Generic;
+
+namespace NoiseEngine.Rendering.Vulkan;
+
+internal sealed class PipelineCompilationBuilder {
+
+    private readonly List<Func<InteropHandle<Pipeline>, Pipeline>> factories =
+        new List<Func<InteropHandle<Pipeline>, Pipeline>>();
+
+    private InteropHandle<PipelineCompilationBuilder> handle;
+
+    public int Count => factories.Count;
+
+    public PipelineCompilationBuilder() {
+        handle = PipelineCompilationInterop.CreateBuilder();
+    }
+
+    ~PipelineCompilationBuilder() {
+        if (handle.IsNull)
+            return;
+
+        PipelineCompilationInterop.DestroyBuilder(handle);
+    }
+
+    public void AddGraphics(
+        RenderPass renderPass, PipelineLayout layout, IReadOnlyList<PipelineShaderStage> stages,
+        PipelineCreateFlags flags, GraphicsPipelineCreateInfo createInfo
+    ) {
+        AssertNotStarted();
+
+        PipelineShaderStageRaw[] rawStages = new PipelineShaderStageRaw[stages.Count];
+        for (int i = 0; i < stages.Count; i++)
+            rawStages[i] = new PipelineShaderStageRaw(stages[i]);
+
+        GraphicsPipeline.UseRawCreateInfo(createInfo, raw => {
+            PipelineCompilationInterop.BuilderAddGraphics(
+                handle, renderPass.Handle, layout.Handle, rawStages, flags, raw
+            );
+        });
+
+        // Stages are copied by native side.
+        foreach (PipelineShaderStageRaw rawStage in rawStages)
+            rawStage.Dispose();
+
+        factories.Add(pipelineHandle => new GraphicsPipeline(renderPass, layout, stages, pipelineHandle));
+    }
+
+    public void AddCompute(PipelineLayout layout, PipelineShaderStage stage, PipelineCreateFlags flags) {
+        AssertNotStarted();
+
+        PipelineShaderStageRaw rawStage = new PipelineShaderStageRaw(stage);
+        PipelineCompilationInterop.BuilderAddCompute(handle, layout.Handle, rawStage, flags);
+
+        // Stage is copied by native side.
+        rawStage.Dispose();
+
+        factories.Add(pipelineHandle => new ComputePipeline(layout, stage, pipelineHandle));
+    }
+
+    /// <summary>
+    /// Starts compilation of added pipelines on native worker threads. This builder cannot be used after.
+    /// </summary>
+    /// <returns><see cref="PipelineCompilation"/> with results in order of adding.</returns>
+    public PipelineCompilation Start() {
+        AssertNotStarted();
+
+        InteropHandle<PipelineCompilation> compilationHandle = PipelineCompilationInterop.Start(handle);
+        handle = InteropHandle<PipelineCompilationBuilder>.Zero;
+        GC.SuppressFinalize(this);
+
+        return new PipelineCompilation(compilationHandle, factories);
+    }
+
+    private void AssertNotStarted() {
+        if (handle.IsNull)
+            throw new InvalidOperationException($"{nameof(PipelineCompilationBuilder)} was already started.");
+    }
+
+}
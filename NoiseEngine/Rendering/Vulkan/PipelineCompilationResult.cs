@@ -0,0 +1,9 @@
+﻿using System;
+
+namespace NoiseEngine.Rendering.Vulkan;
+
+/// <summary>
+/// Result of compilation of single pipeline from <see cref="PipelineCompilation"/>. Exactly one of
+/// <see cref="Pipeline"/> and <see cref="Exception"/> is not <see langword="null"/>.
+/// </summary>
+internal readonly record struct PipelineCompilationResult(Pipeline? Pipeline, Exception? Exception);
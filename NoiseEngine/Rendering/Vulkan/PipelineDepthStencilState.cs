@@ -0,0 +1,29 @@
+﻿using NoiseEngine.Interop.InteropMarshalling;
+using System.Runtime.InteropServices;
+
+namespace NoiseEngine.Rendering.Vulkan;
+
+/// <summary>
+/// Depth and stencil state of the pipeline. Ignored when render pass does not use depth testing.
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+internal readonly record struct PipelineDepthStencilState(
+    InteropBool DepthTest,
+    InteropBool DepthWrite,
+    CompareOp DepthCompareOp,
+    InteropBool DepthBoundsTest,
+    InteropBool StencilTest,
+    StencilOpState Front,
+    StencilOpState Back,
+    float MinDepthBounds,
+    float MaxDepthBounds
+) {
+
+    /// <summary>
+    /// Depth test with <see cref="CompareOp.Less"/> and depth write, without stencil test.
+    /// </summary>
+    public static PipelineDepthStencilState Default => new PipelineDepthStencilState(
+        true, true, CompareOp.Less, false, false, default, default, 0, 1
+    );
+
+}
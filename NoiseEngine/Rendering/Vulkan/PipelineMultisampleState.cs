@@ -0,0 +1,18 @@
+﻿using NoiseEngine.Interop.InteropMarshalling;
+using System.Runtime.InteropServices;
+
+namespace NoiseEngine.Rendering.Vulkan;
+
+/// <summary>
+/// Multisample state of the pipeline. Sample count is taken from the subpass of the render pass.
+/// </summary>
+/// <param name="SampleShading">
+/// Runs fragment shader for at least <paramref name="MinSampleShading"/> fraction of samples, instead of once per
+/// pixel.
+/// </param>
+[StructLayout(LayoutKind.Sequential)]
+internal readonly record struct PipelineMultisampleState(
+    InteropBool SampleShading,
+    float MinSampleShading,
+    InteropBool AlphaToCoverage
+);
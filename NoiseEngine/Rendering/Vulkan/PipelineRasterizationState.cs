@@ -0,0 +1,26 @@
+﻿using NoiseEngine.Interop.InteropMarshalling;
+using System.Runtime.InteropServices;
+
+namespace NoiseEngine.Rendering.Vulkan;
+
+[StructLayout(LayoutKind.Sequential)]
+internal readonly record struct PipelineRasterizationState(
+    InteropBool DepthClamp,
+    PolygonMode PolygonMode,
+    CullModeFlags CullMode,
+    FrontFace FrontFace,
+    InteropBool DepthBias,
+    float DepthBiasConstantFactor,
+    float DepthBiasClamp,
+    float DepthBiasSlopeFactor,
+    float LineWidth
+) {
+
+    /// <summary>
+    /// Filled polygons with culled back faces, where front faces are wound clockwise.
+    /// </summary>
+    public static PipelineRasterizationState Default => new PipelineRasterizationState(
+        false, PolygonMode.Fill, CullModeFlags.Back, FrontFace.Clockwise, false, 0, 0, 0, 1
+    );
+
+}
@@ -1,3 +1,5 @@
 ﻿namespace NoiseEngine.Rendering.Vulkan;
 
-internal record struct PipelineShaderStage(ShaderStageFlags Stage, ShaderModule Module, string Name);
+internal record struct PipelineShaderStage(
+    ShaderStageFlags Stage, ShaderModule Module, string Name, SpecializationInfo? Specialization = null
+);
@@ -0,0 +1,10 @@
+﻿namespace NoiseEngine.Rendering.Vulkan;
+
+/// <summary>
+/// https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkPolygonMode.html
+/// </summary>
+internal enum PolygonMode : uint {
+    Fill = 0,
+    Line = 1,
+    Point = 2
+}
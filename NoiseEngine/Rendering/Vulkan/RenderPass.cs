@@ -1,4 +1,5 @@
 ﻿using NoiseEngine.Interop;
+using NoiseEngine.Interop.InteropMarshalling;
 using NoiseEngine.Interop.Rendering.Vulkan;
 using System.Collections.Concurrent;
 
@@ -27,6 +28,45 @@ internal abstract class RenderPass {
         Handle = handle;
     }
 
+    protected unsafe RenderPass(
+        VulkanDevice device, ICameraRenderTarget renderTarget, RenderPassDescription description
+    ) {
+        RenderTarget = renderTarget;
+
+        SubpassDescriptionRaw[] subpasses = new SubpassDescriptionRaw[description.Subpasses.Length];
+        for (int i = 0; i < subpasses.Length; i++)
+            subpasses[i] = new SubpassDescriptionRaw(description.Subpasses[i]);
+
+        InteropResult<InteropHandle<RenderPass>> result;
+        try {
+            fixed (AttachmentDescription* attachments = description.Attachments) {
+                fixed (SubpassDescriptionRaw* subpassesPointer = subpasses) {
+                    fixed (SubpassDependency* dependencies = description.Dependencies) {
+                        result = RenderPassInterop.CreateWithDescription(device.Handle, new RenderPassDescriptionRaw {
+                            Attachments = new InteropReadOnlySpan<AttachmentDescription>(
+                                attachments, description.Attachments.Length
+                            ),
+                            Subpasses = new InteropReadOnlySpan<SubpassDescriptionRaw>(
+                                subpassesPointer, subpasses.Length
+                            ),
+                            Dependencies = new InteropReadOnlySpan<SubpassDependency>(
+                                dependencies, description.Dependencies.Length
+                            )
+                        });
+                    }
+                }
+            }
+        } finally {
+            foreach (SubpassDescriptionRaw subpass in subpasses)
+                subpass.Dispose();
+        }
+
+        if (!result.TryGetValue(out InteropHandle<RenderPass> handle, out ResultError error))
+            error.ThrowAndDispose();
+
+        Handle = handle;
+    }
+
     ~RenderPass() {
         if (Handle == InteropHandle<RenderPass>.Zero)
             return;
@@ -34,23 +74,40 @@ internal abstract class RenderPass {
         RenderPassInterop.Destroy(Handle);
     }
 
+    /// <summary>
+    /// Sets name of this <see cref="RenderPass"/>, which is visible in validation messages and graphics debuggers.
+    /// </summary>
+    public void SetDebugName(string name) {
+        if (!RenderPassInterop.SetName(Handle, name).TryGetValue(out _, out ResultError error))
+            error.ThrowAndDispose();
+    }
+
     public GraphicsPipeline GetPipeline(Shader shader) {
         return pipelines.GetOrAdd(shader, _ => {
             VulkanVertexFragmentShaderDelegation shaderDelegation =
                 (VulkanVertexFragmentShaderDelegation)shader.Delegation;
-            return new GraphicsPipeline(this, shaderDelegation.PipelineLayout, new PipelineShaderStage[] {
+            PipelineShaderStage[] stages = new PipelineShaderStage[] {
                 new PipelineShaderStage(
                     ShaderStageFlags.Vertex, shaderDelegation.Module, shaderDelegation.Vertex.Guid.ToString()
                 ),
                 new PipelineShaderStage(
                     ShaderStageFlags.Fragment, shaderDelegation.Module, shaderDelegation.Fragment.Guid.ToString()
                 )
-            }, PipelineCreateFlags.None, new GraphicsPipelineCreateInfo() {
-                VertexInputBindingDescription = shaderDelegation.VertexDescription.Bindings,
-                VertexInputAttributeDescription = shaderDelegation.VertexDescription.Attributes,
-                PrimitiveTopology = PrimitiveTopology.TriangleList
-            });
-            ;
+            };
+
+            GraphicsPipeline pipeline = new GraphicsPipeline(
+                this, shaderDelegation.PipelineLayout, stages, PipelineCreateFlags.None,
+                new GraphicsPipelineCreateInfo() {
+                    VertexInputBindingDescription = shaderDelegation.VertexDescription.Bindings,
+                    VertexInputAttributeDescription = shaderDelegation.VertexDescription.Attributes,
+                    PrimitiveTopology = PrimitiveTopology.TriangleList,
+                    Rasterization = PipelineRasterizationState.Default,
+                    DepthStencil = PipelineDepthStencilState.Default
+                }
+            );
+
+            pipeline.SetDebugName(shader.ClassData.FullName);
+            return pipeline;
         });
     }
 
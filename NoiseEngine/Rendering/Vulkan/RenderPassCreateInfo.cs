@@ -11,5 +11,9 @@ internal readonly record struct RenderPassCreateInfo(
     VulkanImageLayout FinalLayout,
     InteropBool DepthTesting,
     TextureFormat DepthStencilFormat,
-    uint DepthStencilSampleCount
+    uint DepthStencilSampleCount,
+    uint MultisampleCount,
+    AttachmentStoreOp DepthStoreOp,
+    AttachmentLoadOp StencilLoadOp,
+    AttachmentStoreOp StencilStoreOp
 );
@@ -0,0 +1,15 @@
+﻿using System;
+
+namespace NoiseEngine.Rendering.Vulkan;
+
+/// <summary>
+/// Render pass with any number of attachments and subpasses, e.g. for G-buffer and input attachments of
+/// deferred shading.
+/// </summary>
+internal readonly ref struct RenderPassDescription {
+
+    public ReadOnlySpan<AttachmentDescription> Attachments { get; init; }
+    public ReadOnlySpan<SubpassDescription> Subpasses { get; init; }
+    public ReadOnlySpan<SubpassDependency> Dependencies { get; init; }
+
+}
@@ -1,52 +1,84 @@
-﻿using System;
+﻿using NoiseEngine.Mathematics;
+using System;
 using System.Runtime.CompilerServices;
 
 namespace NoiseEngine.Rendering.Vulkan;
 
 internal class RenderTextureRenderPass : RenderPass {
 
+    private readonly Texture2D? multisampleColor;
+    private readonly Texture2D? multisampleDepthStencil;
+
     public Framebuffer Framebuffer { get; }
 
     public new RenderTexture RenderTarget => Unsafe.As<RenderTexture>(base.RenderTarget);
 
     public RenderTextureRenderPass(
-        VulkanDevice device, RenderTexture renderTarget, CameraClearFlags clearFlags, bool depthTesting
-    ) : base(device, renderTarget, CreateRenderPassCreateInfo(renderTarget, clearFlags, depthTesting)) {
-        Span<VulkanImageViewCreateInfo> attachments = stackalloc VulkanImageViewCreateInfo[depthTesting ? 2 : 1];
+        VulkanDevice device, RenderTexture renderTarget, CameraClearFlags clearFlags, bool depthTesting,
+        uint sampleCount = 1, bool storeDepthStencil = false
+    ) : base(device, renderTarget, CreateRenderPassCreateInfo(
+        renderTarget, clearFlags, depthTesting, sampleCount, storeDepthStencil
+    )) {
+        Span<VulkanImageViewCreateInfo> attachments = stackalloc VulkanImageViewCreateInfo[
+            1 + (sampleCount > 1 ? 1 : 0) + (depthTesting ? 1 : 0)
+        ];
+        int index = 0;
 
-        attachments[0] = new VulkanImageViewCreateInfo(RenderTarget.Color.Handle, 0, VulkanImageViewType.Type2D,
-            new ComponentMapping(
-                ComponentSwizzle.Identity, ComponentSwizzle.Identity, ComponentSwizzle.Identity,
-                ComponentSwizzle.Identity
-            ),
-        VulkanImageAspect.Color, 0, 1, 0, 1);
+        attachments[index++] = CreateViewCreateInfo(RenderTarget.Color, VulkanImageAspect.Color);
 
-        if (depthTesting) {
-            attachments[1] = new VulkanImageViewCreateInfo(
-                RenderTarget.DepthStencil.Handle, 0, VulkanImageViewType.Type2D, new ComponentMapping(
-                    ComponentSwizzle.Identity, ComponentSwizzle.Identity, ComponentSwizzle.Identity,
-                    ComponentSwizzle.Identity
-                ), VulkanImageAspect.Depth, 0, 1, 0, 1
+        // Multisampled attachments are resolved into the render target, so they are never read back.
+        Vector3<uint> extent = renderTarget.Color.Extent;
+        if (sampleCount > 1) {
+            multisampleColor = new Texture2D(
+                device, TextureUsage.ColorAttachment | TextureUsage.TransientAttachment, extent.X, extent.Y,
+                renderTarget.Color.Format, 1, false, sampleCount
             );
+            attachments[index++] = CreateViewCreateInfo(multisampleColor, VulkanImageAspect.Color);
+        }
+
+        if (depthTesting) {
+            if (sampleCount > 1) {
+                multisampleDepthStencil = new Texture2D(
+                    device, TextureUsage.DepthStencilAttachment | TextureUsage.TransientAttachment, extent.X, extent.Y,
+                    renderTarget.DepthStencil.Format, 1, false, sampleCount
+                );
+                attachments[index++] = CreateViewCreateInfo(multisampleDepthStencil, VulkanImageAspect.Depth);
+            } else {
+                attachments[index++] = CreateViewCreateInfo(RenderTarget.DepthStencil, VulkanImageAspect.Depth);
+            }
         }
 
-        Framebuffer = new Framebuffer(this, renderTarget.Color.Extent.X, renderTarget.Color.Extent.Y, 1, attachments);
+        Framebuffer = new Framebuffer(this, extent.X, extent.Y, 1, attachments);
+    }
+
+    private static VulkanImageViewCreateInfo CreateViewCreateInfo(Texture texture, VulkanImageAspect aspect) {
+        return new VulkanImageViewCreateInfo(
+            texture.Handle, 0, VulkanImageViewType.Type2D, new ComponentMapping(
+                ComponentSwizzle.Identity, ComponentSwizzle.Identity, ComponentSwizzle.Identity,
+                ComponentSwizzle.Identity
+            ), aspect, 0, 1, 0, 1
+        );
     }
 
     private static RenderPassCreateInfo CreateRenderPassCreateInfo(
-        RenderTexture renderTarget, CameraClearFlags clearFlags, bool depthTesting
+        RenderTexture renderTarget, CameraClearFlags clearFlags, bool depthTesting, uint sampleCount,
+        bool storeDepthStencil
     ) {
+        AttachmentStoreOp storeOp = storeDepthStencil ? AttachmentStoreOp.Store : AttachmentStoreOp.DontCare;
+
         if (depthTesting) {
             return new RenderPassCreateInfo(
                 renderTarget.Color.Format, renderTarget.Color.SampleCountInternal, clearFlags,
                 VulkanImageLayout.TransferDestinationOptimal, true, renderTarget.DepthStencil.Format,
-                renderTarget.DepthStencil.SampleCountInternal
+                sampleCount > 1 ? sampleCount : renderTarget.DepthStencil.SampleCountInternal, sampleCount,
+                storeOp, AttachmentLoadOp.Clear, storeOp
             );
         }
 
         return new RenderPassCreateInfo(
             renderTarget.Color.Format, renderTarget.Color.SampleCountInternal, clearFlags,
-            VulkanImageLayout.TransferDestinationOptimal, false, TextureFormat.R8G8B8A8_SRGB, 1
+            VulkanImageLayout.TransferDestinationOptimal, false, TextureFormat.R8G8B8A8_SRGB, 1, sampleCount,
+            AttachmentStoreOp.DontCare, AttachmentLoadOp.DontCare, AttachmentStoreOp.DontCare
         );
     }
 
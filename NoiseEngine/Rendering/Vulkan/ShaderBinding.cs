@@ -0,0 +1,12 @@
+﻿using NoiseEngine.Rendering.Vulkan.Descriptors;
+using System.Runtime.InteropServices;
+
+namespace NoiseEngine.Rendering.Vulkan;
+
+/// <summary>
+/// Descriptor binding declared by <see cref="ShaderModule"/>. <see cref="Count"/> is zero for runtime sized arrays.
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+internal readonly record struct ShaderBinding(
+    uint Set, uint Binding, DescriptorType Type, uint Count, ShaderStageFlags ShaderStageFlags
+);
@@ -0,0 +1,13 @@
+﻿using NoiseEngine.Interop.Rendering.Vulkan;
+using NoiseEngine.Mathematics;
+
+namespace NoiseEngine.Rendering.Vulkan;
+
+internal record ShaderEntryPoint(string Name, ShaderStageFlags Stage, Vector3<uint>? WorkgroupSize) {
+
+    internal ShaderEntryPoint(ShaderEntryPointRaw raw) : this(
+        raw.Name.ToString(), raw.Stage, raw.HasWorkgroupSize ? raw.WorkgroupSize : null
+    ) {
+    }
+
+}
@@ -6,8 +6,27 @@ namespace NoiseEngine.Rendering.Vulkan;
 
 internal class ShaderModule {
 
+    private ShaderReflection? reflection;
+    private bool isReflectionLoaded;
+
     public VulkanDevice Device { get; }
 
+    /// <summary>
+    /// Resource interface of this module, or <see langword="null"/> when its code could not be reflected.
+    /// </summary>
+    public ShaderReflection? Reflection {
+        get {
+            if (!isReflectionLoaded) {
+                if (ShaderModuleInterop.GetReflection(Handle).TryGetValue(out ShaderReflectionRaw raw)) {
+                    reflection = new ShaderReflection(raw);
+                    raw.Dispose();
+                }
+                isReflectionLoaded = true;
+            }
+            return reflection;
+        }
+    }
+
     internal InteropHandle<ShaderModule> Handle { get; }
 
     public ShaderModule(VulkanDevice device, ReadOnlySpan<byte> code) {
@@ -0,0 +1,24 @@
+﻿using NoiseEngine.Interop.Rendering.Vulkan;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace NoiseEngine.Rendering.Vulkan;
+
+/// <summary>
+/// Resource interface of <see cref="ShaderModule"/> read from its SPIR-V code.
+/// </summary>
+internal class ShaderReflection {
+
+    public IReadOnlyList<ShaderEntryPoint> EntryPoints { get; }
+    public IReadOnlyList<ShaderBinding> Bindings { get; }
+    public IReadOnlyList<PushConstantRange> PushConstantRanges { get; }
+    public IReadOnlyList<ShaderVertexInput> VertexInputs { get; }
+
+    internal ShaderReflection(ShaderReflectionRaw raw) {
+        EntryPoints = raw.EntryPoints.Select(x => new ShaderEntryPoint(x)).ToArray();
+        Bindings = raw.Bindings.ToArray();
+        PushConstantRanges = raw.PushConstantRanges.ToArray();
+        VertexInputs = raw.VertexInputs.ToArray();
+    }
+
+}
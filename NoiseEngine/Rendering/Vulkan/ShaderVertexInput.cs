@@ -0,0 +1,9 @@
+﻿using System.Runtime.InteropServices;
+
+namespace NoiseEngine.Rendering.Vulkan;
+
+/// <summary>
+/// Input of vertex entry point with index <see cref="EntryPoint"/> in <see cref="ShaderReflection.EntryPoints"/>.
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+internal readonly record struct ShaderVertexInput(uint EntryPoint, uint Location, VulkanFormat Format);
@@ -0,0 +1,50 @@
+﻿using System;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+
+namespace NoiseEngine.Rendering.Vulkan;
+
+/// <summary>
+/// Values of shader specialization constants.
+/// </summary>
+internal sealed class SpecializationInfo {
+
+    private readonly SpecializationMapEntry[] mapEntries;
+    private readonly byte[] data;
+
+    public ReadOnlySpan<SpecializationMapEntry> MapEntries => mapEntries;
+    public ReadOnlySpan<byte> Data => data;
+
+    internal unsafe nint MapEntriesPointer =>
+        (nint)Unsafe.AsPointer(ref MemoryMarshal.GetArrayDataReference(mapEntries));
+    internal unsafe nint DataPointer => (nint)Unsafe.AsPointer(ref MemoryMarshal.GetArrayDataReference(data));
+
+    /// <summary>
+    /// Creates new <see cref="SpecializationInfo"/>.
+    /// </summary>
+    /// <param name="mapEntries">Entries which map constant ids to ranges of <paramref name="data"/>.</param>
+    /// <param name="data">Values of constants.</param>
+    public SpecializationInfo(ReadOnlySpan<SpecializationMapEntry> mapEntries, ReadOnlySpan<byte> data) {
+        // Arrays are pinned, because native side reads them by pointers.
+        this.mapEntries = GC.AllocateUninitializedArray<SpecializationMapEntry>(mapEntries.Length, true);
+        mapEntries.CopyTo(this.mapEntries);
+        this.data = GC.AllocateUninitializedArray<byte>(data.Length, true);
+        data.CopyTo(this.data);
+    }
+
+    /// <summary>
+    /// Creates new <see cref="SpecializationInfo"/> with constant ids from 0 to length of
+    /// <paramref name="constants"/> - 1.
+    /// </summary>
+    /// <typeparam name="T">Type of constants.</typeparam>
+    /// <param name="constants">Values of constants.</param>
+    /// <returns>New <see cref="SpecializationInfo"/>.</returns>
+    public static unsafe SpecializationInfo FromConstants<T>(ReadOnlySpan<T> constants) where T : unmanaged {
+        Span<SpecializationMapEntry> mapEntries = stackalloc SpecializationMapEntry[constants.Length];
+        for (int i = 0; i < constants.Length; i++)
+            mapEntries[i] = new SpecializationMapEntry((uint)i, (uint)(i * sizeof(T)), (nuint)sizeof(T));
+
+        return new SpecializationInfo(mapEntries, MemoryMarshal.AsBytes(constants));
+    }
+
+}
@@ -0,0 +1,9 @@
+﻿using System.Runtime.InteropServices;
+
+namespace NoiseEngine.Rendering.Vulkan;
+
+/// <summary>
+/// https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkSpecializationMapEntry.html
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+internal readonly record struct SpecializationMapEntry(uint ConstantId, uint Offset, nuint Size);
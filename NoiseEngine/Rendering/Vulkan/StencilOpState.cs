@@ -0,0 +1,17 @@
+﻿using System.Runtime.InteropServices;
+
+namespace NoiseEngine.Rendering.Vulkan;
+
+/// <summary>
+/// https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkStencilOpState.html
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+internal readonly record struct StencilOpState(
+    StencilOp FailOp,
+    StencilOp PassOp,
+    StencilOp DepthFailOp,
+    CompareOp CompareOp,
+    uint CompareMask,
+    uint WriteMask,
+    uint Reference
+);
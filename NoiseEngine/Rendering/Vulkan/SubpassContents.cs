@@ -0,0 +1,6 @@
+﻿namespace NoiseEngine.Rendering.Vulkan;
+
+internal enum SubpassContents : uint {
+    Inline = 0,
+    SecondaryCommandBuffers = 1
+}
@@ -0,0 +1,17 @@
+﻿using System.Runtime.InteropServices;
+
+namespace NoiseEngine.Rendering.Vulkan;
+
+/// <summary>
+/// https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkSubpassDependency.html
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+internal readonly record struct SubpassDependency(
+    uint SourceSubpass,
+    uint DestinationSubpass,
+    PipelineStageFlags SourceStageMask,
+    PipelineStageFlags DestinationStageMask,
+    AccessFlags SourceAccessMask,
+    AccessFlags DestinationAccessMask,
+    DependencyFlags DependencyFlags
+);
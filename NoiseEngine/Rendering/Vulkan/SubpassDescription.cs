@@ -0,0 +1,25 @@
+﻿using System;
+
+namespace NoiseEngine.Rendering.Vulkan;
+
+/// <summary>
+/// https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkSubpassDescription.html
+/// </summary>
+/// <param name="ResolveAttachments">Empty, or single sampled attachment for each color attachment.</param>
+internal readonly record struct SubpassDescription(
+    AttachmentReference[] InputAttachments,
+    AttachmentReference[] ColorAttachments,
+    AttachmentReference[] ResolveAttachments,
+    AttachmentReference? DepthStencilAttachment,
+    uint[] PreserveAttachments
+) {
+
+    public SubpassDescription(
+        AttachmentReference[] colorAttachments, AttachmentReference? depthStencilAttachment = null
+    ) : this(
+        Array.Empty<AttachmentReference>(), colorAttachments, Array.Empty<AttachmentReference>(),
+        depthStencilAttachment, Array.Empty<uint>()
+    ) {
+    }
+
+}
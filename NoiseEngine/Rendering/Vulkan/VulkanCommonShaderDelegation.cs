@@ -17,12 +17,15 @@ internal abstract class VulkanCommonShaderDelegation : CommonShaderDelegation {
     public DescriptorSetLayout Layout { get; }
     public PushConstantDescriptor[] PushConstantDescriptors { get; }
     public uint PushConstantSize { get; }
+    public ShaderStageFlags PushConstantStageFlags { get; }
 
     public VulkanCommonShaderDelegation(ICommonShader shader, SpirVCompilationResult result) : base(shader) {
         Module = new ShaderModule(Device, result.GetCode());
         PushConstantDescriptors = result.PushConstantDescriptors.ToArray();
         PushConstantSize = (uint)PushConstantDescriptors.Sum(x => x.Size);
 
+        // Checks against the module are skipped when its code could not be reflected.
+        ShaderReflection? reflection = Module.Reflection;
         Span<DescriptorSetLayoutBinding> bindings = stackalloc DescriptorSetLayoutBinding[result.Bindings.Count];
         if (result.Bindings.Count > 0) {
             Properties = new (NeslField, MaterialProperty)[result.Bindings.Count];
@@ -32,14 +35,28 @@ internal abstract class VulkanCommonShaderDelegation : CommonShaderDelegation {
             foreach ((NeslField field, uint binding) in result.Bindings) {
                 DescriptorType descriptorType = GetDescriptorTypeFromNeslType(field.FieldType);
 
-                // Binding.
-                bindings[i] = new DescriptorSetLayoutBinding(
-                    binding, descriptorType, 1, shader.Type switch {
-                        ShaderType.VertexFragment => ShaderStageFlags.Vertex | ShaderStageFlags.Fragment,
-                        ShaderType.Compute => ShaderStageFlags.Compute,
-                        _ => throw new NotImplementedException()
-                    }, 0
-                );
+                // Binding, stages are taken from the module when the binding is used by any of its entry points.
+                ShaderStageFlags stageFlags = shader.Type switch {
+                    ShaderType.VertexFragment => ShaderStageFlags.Vertex | ShaderStageFlags.Fragment,
+                    ShaderType.Compute => ShaderStageFlags.Compute,
+                    _ => throw new NotImplementedException()
+                };
+
+                ShaderBinding? reflected = reflection?.Bindings
+                    .Where(x => x.Set == 0 && x.Binding == binding).Cast<ShaderBinding?>().FirstOrDefault();
+                if (reflected is not null) {
+                    if (reflected.Value.Type != descriptorType || reflected.Value.Count != 1) {
+                        throw new InvalidOperationException(
+                            $"Binding {binding} of field {field.Name} is {descriptorType}, but the shader module " +
+                            $"declares it as {reflected.Value.Count} of {reflected.Value.Type}."
+                        );
+                    }
+
+                    if (reflected.Value.ShaderStageFlags != 0)
+                        stageFlags = reflected.Value.ShaderStageFlags;
+                }
+
+                bindings[i] = new DescriptorSetLayoutBinding(binding, descriptorType, 1, stageFlags, 0);
 
                 // Property.
                 VulkanMaterialProperty property = new VulkanMaterialProperty(
@@ -51,6 +68,15 @@ internal abstract class VulkanCommonShaderDelegation : CommonShaderDelegation {
             }
         }
 
+        foreach (ShaderBinding reflected in reflection?.Bindings ?? Array.Empty<ShaderBinding>()) {
+            if (reflected.Set != 0 || !result.Bindings.Any(x => x.Item2 == reflected.Binding)) {
+                throw new InvalidOperationException(
+                    $"Shader module uses binding {reflected.Binding} of set {reflected.Set}, " +
+                    "which has no field in the shader."
+                );
+            }
+        }
+
         Layout = new DescriptorSetLayout(Device, bindings);
 
         Span<PushConstantRange> pushConstantRanges = stackalloc PushConstantRange[
@@ -60,11 +86,26 @@ internal abstract class VulkanCommonShaderDelegation : CommonShaderDelegation {
             PushConstantDescriptor descriptor = PushConstantDescriptors[0];
             switch (descriptor.Features) {
                 case RenderingFeatures.ObjectToClipPos:
+                    // Stages are taken from the module, because push constants may be used by any of its entry points.
+                    PushConstantStageFlags = reflection is not null && reflection.PushConstantRanges.Count > 0 ?
+                        reflection.PushConstantRanges.Aggregate((ShaderStageFlags)0, (x, y) => x | y.StageFlags) :
+                        ShaderStageFlags.Vertex;
                     unsafe {
                         pushConstantRanges[0] = new PushConstantRange(
-                            ShaderStageFlags.Vertex, 0, (uint)sizeof(Matrix4x4<float>)
+                            PushConstantStageFlags, 0, (uint)sizeof(Matrix4x4<float>)
                         );
                     }
+
+                    foreach (
+                        PushConstantRange reflected in reflection?.PushConstantRanges ?? Array.Empty<PushConstantRange>()
+                    ) {
+                        if (reflected.Offset + reflected.Size > pushConstantRanges[0].Size) {
+                            throw new InvalidOperationException(
+                                $"Shader module uses push constants up to {reflected.Offset + reflected.Size} " +
+                                $"bytes, which exceeds {pushConstantRanges[0].Size} bytes of the shader."
+                            );
+                        }
+                    }
                     break;
                 default:
                     throw new NotImplementedException();
@@ -21,6 +21,7 @@ internal class VulkanComputeShaderDelegation : VulkanCommonShaderDelegation {
             ComputePipeline pipeline = new ComputePipeline(PipelineLayout, new PipelineShaderStage(
                 ShaderStageFlags.Compute, Module, kernel.Guid.ToString()
             ), PipelineCreateFlags.None);
+            pipeline.SetDebugName($"{shader.ClassData.FullName}.{kernel.Name}");
             Kernels.Add(kernel, new VulkanComputeKernel(kernel, (ComputeShader)shader, pipeline));
         }
     }
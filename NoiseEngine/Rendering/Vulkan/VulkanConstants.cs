@@ -4,5 +4,7 @@ internal static class VulkanConstants {
 
     public const int MaxExtensionNameSize = 256;
     public const int MaxDescriptionSize = 256;
+    public const uint AttachmentUnused = uint.MaxValue;
+    public const uint SubpassExternal = uint.MaxValue;
 
 }
@@ -4,6 +4,9 @@ using NoiseEngine.Interop.Rendering.Buffers;
 using NoiseEngine.Interop.Rendering.Vulkan;
 using NoiseEngine.Rendering.Buffers;
 using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
 using System.Runtime.CompilerServices;
 
 namespace NoiseEngine.Rendering.Vulkan;
@@ -12,16 +15,29 @@ internal sealed class VulkanDevice : GraphicsDevice {
 
     public new VulkanInstance Instance => Unsafe.As<VulkanInstance>(base.Instance);
 
-    public VulkanDevice(VulkanInstance instance, VulkanDeviceValue value) : base(
-        instance, value.ToGraphics()
-    ) {
+    /// <summary>
+    /// Directory of the user cache, in which pipeline caches of devices are stored.
+    /// </summary>
+    internal static string CacheDirectory { get; set; } = GetCacheDirectory();
+
+    internal string PipelineCachePath => Path.Combine(CacheDirectory, "NoiseEngine", "PipelineCache", $"{Guid}.bin");
+
+    internal ulong Id { get; }
+
+    public VulkanDevice(VulkanInstance instance, VulkanDeviceValue value) : this(instance, value.ToGraphics()) {
         value.Dispose();
     }
 
+    private VulkanDevice(VulkanInstance instance, GraphicsDeviceValue value) : base(instance, value) {
+        Id = VulkanDeviceInterop.GetId(Handle);
+        VulkanDeviceLostHandler.RegisterDevice(this);
+    }
+
     ~VulkanDevice() {
         if (Handle == InteropHandle<GraphicsDevice>.Zero)
             return;
 
+        VulkanDeviceLostHandler.UnregisterDevice(Id);
         VulkanDeviceInterop.Destroy(Handle);
     }
 
@@ -37,20 +53,166 @@ internal sealed class VulkanDevice : GraphicsDevice {
         return handle;
     }
 
-    protected override void InitializeWorker() {
+    internal override InteropHandle<GraphicsTimelineSemaphore> CreateTimelineSemaphore(ulong initialValue) {
+        if (!VulkanDeviceInterop.CreateTimelineSemaphore(Handle, initialValue).TryGetValue(
+            out InteropHandle<GraphicsTimelineSemaphore> handle, out ResultError error
+        )) {
+            error.ThrowAndDispose();
+        }
+
+        return handle;
+    }
+
+    internal override void SetDebugName(GraphicsReadOnlyBuffer buffer, string name) {
+        SetObjectName(ObjectType.Buffer, (ulong)buffer.InnerHandleUniversal.Pointer, name);
+    }
+
+    internal override void SetDebugName(Texture texture, string name) {
+        SetObjectName(ObjectType.Image, (ulong)texture.InnerHandle.Pointer, name);
+    }
+
+    private protected override uint GetSupportedSampleCounts(bool depthStencil) {
+        return VulkanDeviceInterop.GetSupportedSampleCounts(Handle, depthStencil);
+    }
+
+    private protected override GraphicsDeviceLimits GetLimits() {
+        return VulkanDeviceInterop.GetLimits(Handle);
+    }
+
+    private protected override IReadOnlyList<GraphicsMemoryHeap> GetMemoryHeaps() {
+        InteropArray<VulkanMemoryHeapValue> heaps = VulkanDeviceInterop.GetMemoryHeaps(Handle);
+        GraphicsMemoryHeap[] result = heaps.Select(x => x.ToGraphics()).ToArray();
+        heaps.Dispose();
+        return result;
+    }
+
+    private protected override IReadOnlyList<GraphicsQueueFamily> GetQueueFamilies() {
+        InteropArray<VulkanQueueFamilyValue> families = VulkanDeviceInterop.GetQueueFamilies(Handle);
+        GraphicsQueueFamily[] result = families.Select(x => x.ToGraphics()).ToArray();
+        families.Dispose();
+        return result;
+    }
+
+    private protected override IEnumerable<(string Name, bool Supported, bool Enabled)> GetFeatures() {
+        InteropArray<VulkanDeviceFeatureValue> features = VulkanDeviceInterop.GetFeatures(Handle);
+        (string, bool, bool)[] result = new (string, bool, bool)[features.Length];
+
+        int i = 0;
+        foreach (VulkanDeviceFeatureValue feature in features) {
+            result[i++] = (feature.Name.ToString(), feature.Supported, feature.Enabled);
+            feature.Dispose();
+        }
+
+        features.Dispose();
+        return result;
+    }
+
+    private protected override bool IsTextureFormatSupportedWorker(TextureFormat format, TextureUsage usage) {
+        return VulkanDeviceInterop.IsImageFormatSupported(Handle, format, usage);
+    }
+
+    private protected override bool GetIsLost() {
+        return VulkanDeviceInterop.IsLost(Handle);
+    }
+
+    private protected override GraphicsDevice RecreateWorker() {
+        return new VulkanDevice(Instance, Value with { Handle = VulkanDeviceInterop.Recreate(Handle) });
+    }
+
+    protected override void InitializeWorker(
+        IReadOnlyList<string> features, IReadOnlyList<GraphicsQueueFamilyConfiguration> queueFamilies
+    ) {
         bool activatePresentation = Instance.PresentationEnabled && SupportsPresentation;
         Span<InteropString> enabledExtensions = stackalloc InteropString[activatePresentation ? 1 : 0];
         if (activatePresentation)
             enabledExtensions[0] = new InteropString("VK_KHR_swapchain");
 
-        InteropResult<None> result = VulkanDeviceInterop.Initialize(Handle, enabledExtensions);
+        InteropString[] enabledFeatures = features.Select(x => new InteropString(x)).ToArray();
+        VulkanQueueConfiguration[] queues = queueFamilies.SelectMany(
+            family => family.Priorities.Select(priority => new VulkanQueueConfiguration(family.FamilyIndex, priority))
+        ).ToArray();
+
+        InteropResult<None> result = VulkanDeviceInterop.Initialize(
+            Handle, enabledExtensions, enabledFeatures, queues
+        );
 
-        // Dispose extensions.
+        // Dispose extensions and features.
         foreach (InteropString extension in enabledExtensions)
             extension.Dispose();
+        foreach (InteropString feature in enabledFeatures)
+            feature.Dispose();
 
         if (!result.TryGetValue(out _, out ResultError error))
             error.ThrowAndDispose();
+
+        byte[] pipelineCache;
+        try {
+            if (!File.Exists(PipelineCachePath))
+                return;
+            pipelineCache = File.ReadAllBytes(PipelineCachePath);
+        } catch (Exception exception) when (exception is IOException or UnauthorizedAccessException) {
+            Log.Warning($"Unable to read pipeline cache of {this}: {exception.Message}");
+            return;
+        }
+
+        LoadPipelineCache(pipelineCache);
+    }
+
+    protected override void SaveCachesWorker() {
+        if (!VulkanDeviceInterop.GetPipelineCacheData(Handle).TryGetValue(
+            out InteropArray<byte> data, out ResultError error
+        )) {
+            error.ThrowAndDispose();
+        }
+
+        string path = PipelineCachePath;
+        string temporaryPath = path + ".tmp";
+        try {
+            Directory.CreateDirectory(Path.GetDirectoryName(path)!);
+            using (FileStream stream = File.Create(temporaryPath))
+                stream.Write(data.AsSpan());
+            File.Move(temporaryPath, path, true);
+        } catch (Exception exception) when (exception is IOException or UnauthorizedAccessException) {
+            Log.Warning($"Unable to save pipeline cache of {this}: {exception.Message}");
+        } finally {
+            data.Dispose();
+        }
+    }
+
+    /// <summary>
+    /// Merges serialized pipeline cache into cache of this <see cref="VulkanDevice"/>.
+    /// </summary>
+    /// <param name="data">Data created by the same device and driver version.</param>
+    /// <returns><see langword="true"/> when <paramref name="data"/> was loaded; otherwise
+    /// <see langword="false"/>.</returns>
+    internal bool LoadPipelineCache(ReadOnlySpan<byte> data) {
+        if (!VulkanDeviceInterop.LoadPipelineCache(Handle, data).TryGetValue(
+            out InteropBool loaded, out ResultError error
+        )) {
+            error.ThrowAndDispose();
+        }
+
+        return loaded;
+    }
+
+    private void SetObjectName(ObjectType objectType, ulong handle, string name) {
+        if (!VulkanDeviceInterop.SetObjectName(Handle, objectType, handle, name).TryGetValue(
+            out _, out ResultError error
+        )) {
+            error.ThrowAndDispose();
+        }
+    }
+
+    private static string GetCacheDirectory() {
+        if (OperatingSystem.IsWindows())
+            return Environment.GetFolderPath(Environment.SpecialFolder.LocalApplicationData);
+
+        string home = Environment.GetFolderPath(Environment.SpecialFolder.UserProfile);
+        if (OperatingSystem.IsMacOS())
+            return Path.Combine(home, "Library", "Caches");
+
+        string? xdgCacheHome = Environment.GetEnvironmentVariable("XDG_CACHE_HOME");
+        return string.IsNullOrEmpty(xdgCacheHome) ? Path.Combine(home, ".cache") : xdgCacheHome;
     }
 
 }
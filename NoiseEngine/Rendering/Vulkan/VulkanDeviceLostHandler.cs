@@ -0,0 +1,48 @@
+﻿using NoiseEngine.Interop;
+using NoiseEngine.Interop.Rendering.Vulkan;
+using System;
+using System.Collections.Concurrent;
+using System.Runtime.InteropServices;
+using System.Text;
+
+namespace NoiseEngine.Rendering.Vulkan;
+
+internal static class VulkanDeviceLostHandler {
+
+    private static readonly ConcurrentDictionary<ulong, WeakReference<VulkanDevice>> devices =
+        new ConcurrentDictionary<ulong, WeakReference<VulkanDevice>>();
+
+    private static readonly VulkanDeviceLostHandlerRaw raw;
+
+    [UnmanagedFunctionPointer(InteropConstants.CallingConvention)]
+    public delegate void LostDelegate(ulong id, VulkanDeviceLostData data);
+
+    static VulkanDeviceLostHandler() {
+        // Prevents GC cleanup (https://stackoverflow.com/a/43227979/14677292)
+        raw = new VulkanDeviceLostHandlerRaw(LostImpl);
+
+        if (!VulkanDeviceLostHandlerInterop.Initialize(raw).TryGetValue(out _, out ResultError error))
+            error.ThrowAndDispose();
+    }
+
+    public static void RegisterDevice(VulkanDevice device) {
+        devices.TryAdd(device.Id, new WeakReference<VulkanDevice>(device));
+    }
+
+    public static void UnregisterDevice(ulong id) {
+        devices.TryRemove(id, out _);
+    }
+
+    private static void LostImpl(ulong id, VulkanDeviceLostData data) {
+        if (
+            !devices.TryGetValue(id, out WeakReference<VulkanDevice>? weak) ||
+            !weak.TryGetTarget(out VulkanDevice? device)
+        ) {
+            return;
+        }
+
+        string fault = Encoding.UTF8.GetString(data.Fault.AsSpan());
+        device.RaiseLost(Encoding.UTF8.GetString(data.Message.AsSpan()), fault.Length == 0 ? null : fault);
+    }
+
+}
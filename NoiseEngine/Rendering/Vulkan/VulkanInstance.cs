@@ -4,13 +4,14 @@ using NoiseEngine.Interop.Rendering.Vulkan;
 using System;
 using System.Collections.Generic;
 using System.Linq;
-using System.Runtime.CompilerServices;
 
 namespace NoiseEngine.Rendering.Vulkan;
 
 internal sealed class VulkanInstance : GraphicsInstance {
 
-    public new IReadOnlyList<VulkanDevice> Devices => Unsafe.As<IReadOnlyList<VulkanDevice>>(ProtectedDevices);
+    private VulkanDevice[] devices;
+
+    public new IReadOnlyList<VulkanDevice> Devices => devices;
 
     public override GraphicsApi Api => GraphicsApi.Vulkan;
     public override bool SupportsPresentation => Library.SupportsPresentation;
@@ -18,7 +19,10 @@ internal sealed class VulkanInstance : GraphicsInstance {
     public VulkanLibrary Library { get; }
     public InteropHandle<VulkanInstance> Handle { get; }
 
-    protected override IReadOnlyList<GraphicsDevice> ProtectedDevices { get; set; }
+    protected override IReadOnlyList<GraphicsDevice> ProtectedDevices {
+        get => devices;
+        set => devices = value.Cast<VulkanDevice>().ToArray();
+    }
 
     private InteropHandle<VulkanInstance> InnerHandle { get; }
 
@@ -65,13 +69,13 @@ internal sealed class VulkanInstance : GraphicsInstance {
         Log.Info($"Created new {this}.");
 
         if (!VulkanInstanceInterop.GetDevices(Handle).TryGetValue(
-            out InteropArray<VulkanDeviceValue> devices, out error
+            out InteropArray<VulkanDeviceValue> deviceValues, out error
         )) {
             error.ThrowAndDispose();
         }
 
-        ProtectedDevices = devices.Select(x => new VulkanDevice(this, x)).ToArray();
-        devices.Dispose();
+        devices = deviceValues.Select(x => new VulkanDevice(this, x)).ToArray();
+        deviceValues.Dispose();
     }
 
     ~VulkanInstance() {
@@ -81,6 +85,30 @@ internal sealed class VulkanInstance : GraphicsInstance {
         VulkanInstanceInterop.Destroy(Handle);
     }
 
+    private protected override IReadOnlyList<GraphicsDevice> RankDevicesWorker(
+        IReadOnlyList<string> requiredFeatures, IReadOnlyList<string> requiredExtensions, Window? presentationWindow
+    ) {
+        InteropHandle<GraphicsDevice>[] devices = Devices.Select(x => x.Handle).ToArray();
+        InteropString[] features = requiredFeatures.Select(x => new InteropString(x)).ToArray();
+        InteropString[] extensions = requiredExtensions.Select(x => new InteropString(x)).ToArray();
+
+        InteropResult<InteropArray<uint>> result = VulkanInstanceInterop.RankDevices(
+            Handle, devices, extensions, features, presentationWindow?.Handle ?? InteropHandle<Window>.Zero
+        );
+
+        foreach (InteropString feature in features)
+            feature.Dispose();
+        foreach (InteropString extension in extensions)
+            extension.Dispose();
+
+        if (!result.TryGetValue(out InteropArray<uint> ranking, out ResultError error))
+            error.ThrowAndDispose();
+
+        GraphicsDevice[] ranked = ranking.Select(x => (GraphicsDevice)Devices[(int)x]).ToArray();
+        ranking.Dispose();
+        return ranked;
+    }
+
     public override string ToString() {
         return $"{nameof(VulkanInstance)} {{ {nameof(InnerHandle)} = {InnerHandle} }}";
     }
@@ -45,6 +45,10 @@ internal class VulkanSimpleCameraDelegation : SimpleCameraDelegation {
         recalcutate = true;
     }
 
+    public override void UpdateSampleCount() {
+        recalcutate = true;
+    }
+
     public override void RaiseRenderTargetSet(ICameraRenderTarget? newRenderTarget) {
         recalcutate = true;
         lock (calculateLocker) {
@@ -85,7 +89,8 @@ internal class VulkanSimpleCameraDelegation : SimpleCameraDelegation {
                 CreateRenderPassWindow(window);
             } else if (renderTarget is RenderTexture renderTexture) {
                 renderPass = new RenderTextureRenderPass(
-                    GraphicsDevice, renderTexture, Camera.ClearFlags, Camera.DepthTesting
+                    GraphicsDevice, renderTexture, Camera.ClearFlags, Camera.DepthTesting, Camera.SampleCount,
+                    Camera.StoreDepthStencil
                 );
             } else {
                 throw new NotImplementedException("Camera render target is not implemented.");
@@ -104,7 +109,10 @@ internal class VulkanSimpleCameraDelegation : SimpleCameraDelegation {
         else
             swapchain = ((WindowRenderPass)oldRenderPass).Swapchain;
 
-        renderPass = new WindowRenderPass(GraphicsDevice, swapchain, window, Camera.ClearFlags, Camera.DepthTesting);
+        renderPass = new WindowRenderPass(
+            GraphicsDevice, swapchain, window, Camera.ClearFlags, Camera.DepthTesting, Camera.SampleCount,
+            Camera.StoreDepthStencil
+        );
     }
 
     private bool TryGetSwapchain([NotNullWhen(true)] out Swapchain? swapchain) {
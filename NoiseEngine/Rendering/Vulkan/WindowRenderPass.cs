@@ -5,10 +5,13 @@ internal class WindowRenderPass : RenderPass {
     public Swapchain Swapchain { get; }
 
     public WindowRenderPass(
-        VulkanDevice device, Swapchain swapchain, Window renderTarget, CameraClearFlags clearFlags, bool depthTesting
+        VulkanDevice device, Swapchain swapchain, Window renderTarget, CameraClearFlags clearFlags, bool depthTesting,
+        uint sampleCount, bool storeDepthStencil
     ) : base(device, renderTarget, new RenderPassCreateInfo(
         swapchain.Format, 1, clearFlags, VulkanImageLayout.PresentSourceKHR,
-        depthTesting, TextureFormat.D32_SFloat, 1
+        depthTesting, TextureFormat.D32_SFloat, sampleCount, sampleCount,
+        storeDepthStencil ? AttachmentStoreOp.Store : AttachmentStoreOp.DontCare, AttachmentLoadOp.Clear,
+        storeDepthStencil ? AttachmentStoreOp.Store : AttachmentStoreOp.DontCare
     )) {
         Swapchain = swapchain;
     }